@@ -5,15 +5,17 @@ use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use anyhow::{anyhow, ensure};
 use binius_core::{
 	constraint_system::{
-		channel::{ChannelId, Flush, FlushDirection},
+		channel::{ChannelId, Flush, FlushDirection, FlushMultiplicity},
 		exp::{Exp, ExpBase},
+		permutation::Permutation,
 		ConstraintSystem,
 	},
 	oracle::{
-		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId,
-		ProjectionVariant, ShiftVariant,
+		AuxiliaryOracle, ConstraintSetBuilder, DegreeReduction, Error as OracleError,
+		MultilinearOracleSet, OracleId, ProjectionVariant, ShiftVariant,
 	},
 	polynomial::MultivariatePoly,
+	tower::CanonicalTowerFamily,
 	transparent::step_down::StepDown,
 	witness::MultilinearExtensionIndex,
 };
@@ -30,8 +32,10 @@ use crate::builder::{
 pub struct ConstraintSystemBuilder<'arena> {
 	oracles: Rc<RefCell<MultilinearOracleSet<F>>>,
 	constraints: ConstraintSetBuilder<F>,
+	auxiliary_oracles: Vec<AuxiliaryOracle<F>>,
 	non_zero_oracle_ids: Vec<OracleId>,
 	flushes: Vec<Flush>,
+	permutations: Vec<Permutation>,
 	exponents: Vec<Exp<F>>,
 	step_down_dedup: HashMap<(usize, usize), OracleId>,
 	witness: Option<witness::Builder<'arena>>,
@@ -72,6 +76,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 				.into_inner(),
 			flushes: self.flushes,
 			exponents: self.exponents,
+			permutations: self.permutations,
 		})
 	}
 
@@ -82,11 +87,17 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 	pub fn take_witness(
 		&mut self,
 	) -> Result<MultilinearExtensionIndex<'arena, U, F>, anyhow::Error> {
-		Option::take(&mut self.witness)
+		let mut witness = Option::take(&mut self.witness)
 			.ok_or_else(|| {
 				anyhow!("Witness is missing. Are you in verifier mode, or have you already extraced the witness?")
 			})?
-			.build()
+			.build()?;
+
+		let oracles = self.oracles.borrow();
+		for auxiliary in &self.auxiliary_oracles {
+			auxiliary.populate_witness::<U, CanonicalTowerFamily>(&oracles, &mut witness)?;
+		}
+		Ok(witness)
 	}
 
 	pub fn flush(
@@ -108,7 +119,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		channel_id: ChannelId,
 		count: usize,
 		oracle_ids: impl IntoIterator<Item = OracleId> + Clone,
-		multiplicity: u64,
+		multiplicity: impl Into<FlushMultiplicity>,
 	) -> anyhow::Result<()>
 	where
 		U: PackScalar<BinaryField1b>,
@@ -141,7 +152,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		channel_id: ChannelId,
 		selector: OracleId,
 		oracle_ids: impl IntoIterator<Item = OracleId>,
-		multiplicity: u64,
+		multiplicity: impl Into<FlushMultiplicity>,
 	) -> anyhow::Result<()> {
 		let oracles = oracle_ids.into_iter().collect::<Vec<_>>();
 		let log_rows = self.log_rows(oracles.iter().copied())?;
@@ -152,6 +163,18 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			oracles
 		);
 
+		let multiplicity = multiplicity.into();
+		if let FlushMultiplicity::Bits(bit_ids) = &multiplicity {
+			for &bit_id in bit_ids {
+				ensure!(
+					log_rows == self.log_rows([bit_id])?,
+					"Multiplicity bit {} n_vars does not match flush {:?}",
+					bit_id,
+					oracles
+				);
+			}
+		}
+
 		self.flushes.push(Flush {
 			channel_id,
 			direction,
@@ -197,10 +220,44 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.add_zerocheck(name, oracle_ids, composition);
 	}
 
+	/// Rewrites the constraints asserted so far to have compositions of at most the target degree.
+	///
+	/// Subexpressions exceeding the degree are replaced with auxiliary oracles. Their witnesses
+	/// are generated from the other columns when the witness is taken, so this must be called
+	/// before [`Self::take_witness`].
+	pub fn reduce_degree(&mut self, reduction: DegreeReduction) -> anyhow::Result<()> {
+		let auxiliary = self
+			.constraints
+			.reduce_degree(&mut self.oracles.borrow_mut(), reduction)?;
+		self.auxiliary_oracles.extend(auxiliary);
+		Ok(())
+	}
+
 	pub fn assert_not_zero(&mut self, oracle_id: OracleId) {
 		self.non_zero_oracle_ids.push(oracle_id);
 	}
 
+	/// Constrains the rows of the oracles in `a` to be a permutation of the rows of the oracles in
+	/// `b`.
+	pub fn assert_permutation(
+		&mut self,
+		a: impl IntoIterator<Item = OracleId>,
+		b: impl IntoIterator<Item = OracleId>,
+	) -> anyhow::Result<()> {
+		let a = a.into_iter().collect::<Vec<_>>();
+		let b = b.into_iter().collect::<Vec<_>>();
+		ensure!(
+			a.len() == b.len(),
+			"Permutation sides {:?} and {:?} have different numbers of oracles",
+			a,
+			b
+		);
+		self.log_rows(a.iter().chain(&b).copied())?;
+
+		self.permutations.push(Permutation { a, b });
+		Ok(())
+	}
+
 	pub const fn add_channel(&mut self) -> ChannelId {
 		let channel_id = self.next_channel_id;
 		self.next_channel_id += 1;
@@ -312,6 +369,29 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.composite_mle(n_vars, inner, comp)
 	}
 
+	pub fn add_concatenated(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+	) -> Result<OracleId, OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.concatenated(n_vars, inner)
+	}
+
+	pub fn add_interleaved(
+		&mut self,
+		name: impl ToString,
+		inner: impl IntoIterator<Item = OracleId>,
+	) -> Result<OracleId, OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.interleaved(inner)
+	}
+
 	pub fn add_packed(
 		&mut self,
 		name: impl ToString,
@@ -363,6 +443,19 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.shifted(id, offset, block_bits, variant)
 	}
 
+	pub fn add_strided(
+		&mut self,
+		name: impl ToString,
+		id: OracleId,
+		log_stride: usize,
+		offset: usize,
+	) -> Result<OracleId, OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.strided(id, log_stride, offset)
+	}
+
 	pub fn add_transparent(
 		&mut self,
 		name: impl ToString,
@@ -374,6 +467,18 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.transparent(poly)
 	}
 
+	pub fn add_unpacked(
+		&mut self,
+		name: impl ToString,
+		id: OracleId,
+		log_degree: usize,
+	) -> Result<OracleId, OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.unpacked(id, log_degree)
+	}
+
 	pub fn add_zero_padded(
 		&mut self,
 		name: impl ToString,
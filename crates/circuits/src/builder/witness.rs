@@ -1,10 +1,10 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{cell::RefCell, iter, marker::PhantomData, rc::Rc, slice, sync::Arc};
 
 use anyhow::{anyhow, Error};
 use binius_core::{
-	oracle::{MultilinearOracleSet, OracleId},
+	oracle::{MultilinearOracleSet, MultilinearPolyVariant, OracleId},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 use binius_field::{
@@ -12,7 +12,7 @@ use binius_field::{
 	underlier::WithUnderlier,
 	ExtensionField, PackedField, TowerField,
 };
-use binius_math::MultilinearExtension;
+use binius_math::{MultilinearExtension, PackingDeref};
 use binius_utils::bail;
 use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
 
@@ -25,12 +25,54 @@ pub struct Builder<'arena> {
 
 	#[allow(clippy::type_complexity)]
 	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena>>>>>,
+
+	/// Storage of the committed columns, which is kept alive until the builder is consumed.
+	///
+	/// Committed columns are allocated outside of the arena, so that the witness index owns their
+	/// storage and the prover can release it once they have been committed.
+	committed: Rc<RefCell<Vec<Arc<[U]>>>>,
 }
 
 struct WitnessBuilderEntry<'arena> {
 	witness: Result<MultilinearWitness<'arena, PackedType<U, F>>, binius_math::Error>,
 	tower_level: usize,
-	data: &'arena [U],
+	data: ColumnData<'arena>,
+}
+
+#[derive(Clone)]
+enum ColumnData<'arena> {
+	Arena(&'arena [U]),
+	Committed(Arc<[U]>),
+}
+
+impl<'arena> ColumnData<'arena> {
+	fn underliers(&self) -> &[U] {
+		match self {
+			Self::Arena(data) => data,
+			Self::Committed(data) => data,
+		}
+	}
+
+	fn witness<FS>(
+		&self,
+		log_rows: usize,
+	) -> Result<MultilinearWitness<'arena, PackedType<U, F>>, binius_math::Error>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		match self {
+			Self::Arena(data) => {
+				MultilinearExtension::new(log_rows, PackedType::<U, FS>::from_underliers_ref(data))
+					.map(|x| x.specialize_arc_dyn())
+			}
+			Self::Committed(data) => {
+				MultilinearExtension::new(log_rows, PackingDeref::<U, FS, _>::new(data.clone()))
+					.map(|x| x.specialize_arc_dyn())
+			}
+		}
+	}
 }
 
 impl<'arena> Builder<'arena> {
@@ -42,6 +84,7 @@ impl<'arena> Builder<'arena> {
 			bump: allocator,
 			oracles,
 			entries: Rc::new(RefCell::new(Vec::new())),
+			committed: Rc::new(RefCell::new(Vec::new())),
 		}
 	}
 
@@ -50,17 +93,7 @@ impl<'arena> Builder<'arena> {
 		U: PackScalar<FS>,
 		F: ExtensionField<FS>,
 	{
-		let oracles = self.oracles.borrow();
-		let log_rows = oracles.n_vars(id);
-		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
-		let data = bumpalo::vec![in self.bump; U::default(); len].into_bump_slice_mut();
-		EntryBuilder {
-			_marker: PhantomData,
-			log_rows,
-			id,
-			data: Some(data),
-			entries: self.entries.clone(),
-		}
+		self.new_column_with_default(id, FS::ZERO)
 	}
 
 	pub fn new_column_with_default<FS: TowerField>(
@@ -76,17 +109,22 @@ impl<'arena> Builder<'arena> {
 		let log_rows = oracles.n_vars(id);
 		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
 		let default = WithUnderlier::to_underlier(PackedType::<U, FS>::broadcast(default));
-		let data = bumpalo::vec![in self.bump; default; len].into_bump_slice_mut();
+		let data = if matches!(oracles.oracle(id).variant, MultilinearPolyVariant::Committed) {
+			ColumnDataMut::Committed(iter::repeat_n(default, len).collect())
+		} else {
+			ColumnDataMut::Arena(bumpalo::vec![in self.bump; default; len].into_bump_slice_mut())
+		};
 		EntryBuilder {
 			_marker: PhantomData,
 			log_rows,
 			id,
 			data: Some(data),
 			entries: self.entries.clone(),
+			committed: self.committed.clone(),
 		}
 	}
 
-	pub fn get<FS>(&self, id: OracleId) -> Result<WitnessEntry<'arena, FS>, Error>
+	pub fn get<FS>(&self, id: OracleId) -> Result<WitnessEntry<'_, FS>, Error>
 	where
 		FS: TowerField,
 		U: PackScalar<FS>,
@@ -111,8 +149,17 @@ impl<'arena> Builder<'arena> {
 			));
 		}
 
+		let data = match &entry.data {
+			ColumnData::Arena(data) => *data,
+			// SAFETY: the storage of committed columns is kept alive by `self.committed` until the
+			// builder is consumed, so it outlives the borrow of `self`.
+			ColumnData::Committed(data) => unsafe {
+				slice::from_raw_parts(data.as_ptr(), data.len())
+			},
+		};
 		Ok(WitnessEntry {
-			data: entry.data,
+			data,
+			source: id,
 			log_rows: oracles.n_vars(id),
 			_marker: PhantomData,
 		})
@@ -121,7 +168,7 @@ impl<'arena> Builder<'arena> {
 	pub fn set<FS: TowerField>(
 		&self,
 		id: OracleId,
-		entry: WitnessEntry<'arena, FS>,
+		entry: WitnessEntry<'_, FS>,
 	) -> Result<(), Error>
 	where
 		U: PackScalar<FS>,
@@ -132,14 +179,24 @@ impl<'arena> Builder<'arena> {
 			bail!(anyhow!("OracleId {id} does not exist in MultilinearOracleSet"));
 		}
 		let mut entries = self.entries.borrow_mut();
+		// The entry shares the storage of the column it was read from
+		let data = entries[entry.source]
+			.as_ref()
+			.map(|source| source.data.clone())
+			.filter(|data| data.underliers().as_ptr() == entry.data.as_ptr())
+			.ok_or_else(|| {
+				anyhow!(
+					"Witness for {} was replaced after it was read",
+					oracles.label(entry.source)
+				)
+			})?;
 		if id >= entries.len() {
 			entries.resize_with(id + 1, || None);
 		}
 		entries[id] = Some(WitnessBuilderEntry {
-			data: entry.data,
+			witness: data.witness::<FS>(entry.log_rows),
 			tower_level: FS::TOWER_LEVEL,
-			witness: MultilinearExtension::new(entry.log_rows, entry.packed())
-				.map(|x| x.specialize_arc_dyn()),
+			data,
 		});
 		Ok(())
 	}
@@ -159,46 +216,67 @@ impl<'arena> Builder<'arena> {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct WitnessEntry<'arena, FS: TowerField>
+pub struct WitnessEntry<'a, FS: TowerField>
 where
 	U: PackScalar<FS>,
 {
-	data: &'arena [U],
+	data: &'a [U],
+	source: OracleId,
 	log_rows: usize,
 	_marker: PhantomData<FS>,
 }
 
-impl<'arena, FS: TowerField> WitnessEntry<'arena, FS>
+impl<'a, FS: TowerField> WitnessEntry<'a, FS>
 where
 	U: PackScalar<FS>,
 {
 	#[inline]
-	pub fn packed(&self) -> &'arena [PackedType<U, FS>] {
+	pub fn packed(&self) -> &'a [PackedType<U, FS>] {
 		WithUnderlier::from_underliers_ref(self.data)
 	}
 
 	#[inline]
-	pub const fn as_slice<T: Pod>(&self) -> &'arena [T] {
+	pub const fn as_slice<T: Pod>(&self) -> &'a [T] {
 		must_cast_slice(self.data)
 	}
 
-	pub const fn repacked<FE>(&self) -> WitnessEntry<'arena, FE>
+	pub const fn repacked<FE>(&self) -> WitnessEntry<'a, FE>
 	where
 		FE: TowerField + ExtensionField<FS>,
 		U: PackScalar<FE>,
 	{
 		WitnessEntry {
 			data: self.data,
+			source: self.source,
 			log_rows: self.log_rows - <FE as ExtensionField<FS>>::LOG_DEGREE,
 			_marker: PhantomData,
 		}
 	}
 
+	pub const fn unpacked<FSub>(&self) -> WitnessEntry<'a, FSub>
+	where
+		FSub: TowerField,
+		FS: ExtensionField<FSub>,
+		U: PackScalar<FSub>,
+	{
+		WitnessEntry {
+			data: self.data,
+			source: self.source,
+			log_rows: self.log_rows + <FS as ExtensionField<FSub>>::LOG_DEGREE,
+			_marker: PhantomData,
+		}
+	}
+
 	pub const fn low_rows(&self) -> usize {
 		self.log_rows
 	}
 }
 
+enum ColumnDataMut<'arena> {
+	Arena(&'arena mut [U]),
+	Committed(Arc<[U]>),
+}
+
 pub struct EntryBuilder<'arena, FS>
 where
 	FS: TowerField,
@@ -208,9 +286,10 @@ where
 	_marker: PhantomData<FS>,
 	#[allow(clippy::type_complexity)]
 	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena>>>>>,
+	committed: Rc<RefCell<Vec<Arc<[U]>>>>,
 	id: OracleId,
 	log_rows: usize,
-	data: Option<&'arena mut [U]>,
+	data: Option<ColumnDataMut<'arena>>,
 }
 
 impl<FS> EntryBuilder<'_, FS>
@@ -230,10 +309,17 @@ where
 	}
 
 	#[inline]
-	const fn underliers(&mut self) -> &mut [U] {
-		self.data
+	fn underliers(&mut self) -> &mut [U] {
+		match self
+			.data
 			.as_mut()
 			.expect("Should only be None after Drop::drop has run")
+		{
+			ColumnDataMut::Arena(data) => data,
+			ColumnDataMut::Committed(data) => {
+				Arc::get_mut(data).expect("storage is not shared until Drop::drop has run")
+			}
+		}
 	}
 }
 
@@ -244,20 +330,23 @@ where
 	F: ExtensionField<FS>,
 {
 	fn drop(&mut self) {
-		let data = Option::take(&mut self.data).expect("data is always Some until this point");
+		let data = match Option::take(&mut self.data).expect("data is always Some until this point")
+		{
+			ColumnDataMut::Arena(data) => ColumnData::Arena(data),
+			ColumnDataMut::Committed(data) => {
+				self.committed.borrow_mut().push(data.clone());
+				ColumnData::Committed(data)
+			}
+		};
 		let mut entries = self.entries.borrow_mut();
 		let id = self.id;
 		if id >= entries.len() {
 			entries.resize_with(id + 1, || None);
 		}
 		entries[id] = Some(WitnessBuilderEntry {
-			data,
+			witness: data.witness::<FS>(self.log_rows),
 			tower_level: FS::TOWER_LEVEL,
-			witness: MultilinearExtension::new(
-				self.log_rows,
-				PackedType::<U, FS>::from_underliers_ref(data),
-			)
-			.map(|x| x.specialize_arc_dyn()),
+			data,
 		})
 	}
 }
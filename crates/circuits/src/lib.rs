@@ -29,21 +29,29 @@ mod tests {
 	use binius_core::{
 		constraint_system::{
 			self,
-			channel::{validate_witness, Boundary, FlushDirection},
+			channel::{validate_witness, Boundary, FlushDirection, FlushMultiplicity},
+			ConstraintSystem,
 		},
 		fiat_shamir::HasherChallenger,
-		oracle::ShiftVariant,
+		oracle::{AuxiliaryOracleKind, DegreeReduction, ShiftVariant},
 		polynomial::ArithCircuitPoly,
 		tower::CanonicalTowerFamily,
+		witness::{
+			concatenated_witness, interleaved_witness, strided_witness, MultilinearExtensionIndex,
+		},
 	};
 	use binius_field::{
-		arch::OptimalUnderlier, as_packed_field::PackedType, underlier::WithUnderlier,
-		BinaryField128b, BinaryField64b, BinaryField8b, Field,
+		as_packed_field::PackedType, packed::set_packed_slice, underlier::WithUnderlier,
+		BinaryField128b, BinaryField1b, BinaryField32b, BinaryField64b, BinaryField8b, Field,
+		TowerField,
+	};
+	use binius_hal::{
+		make_portable_backend, ComputationBackend, DifferentialBackend, Operation, RecordingBackend,
 	};
-	use binius_hal::make_portable_backend;
 	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
 	use binius_macros::arith_expr;
-	use binius_math::CompositionPoly;
+	use binius_math::{ArithExpr, CompositionPoly, MultilinearExtension};
+	use rand::{rngs::StdRng, RngCore, SeedableRng};
 
 	type B128 = BinaryField128b;
 	type B64 = BinaryField64b;
@@ -53,6 +61,53 @@ mod tests {
 		ConstraintSystemBuilder,
 	};
 
+	/// Proves and verifies `constraint_system` with the Groestl-based commitment scheme on the
+	/// portable backend.
+	fn prove_and_verify(
+		constraint_system: &ConstraintSystem<F>,
+		boundaries: &[Boundary<F>],
+		witness: MultilinearExtensionIndex<U, F>,
+		log_inv_rate: usize,
+		security_bits: usize,
+	) -> Result<(), constraint_system::error::Error> {
+		prove_and_verify_with_backend(
+			constraint_system,
+			boundaries,
+			witness,
+			log_inv_rate,
+			security_bits,
+			&make_portable_backend(),
+		)
+	}
+
+	/// Like [`prove_and_verify`], but proves on the given backend.
+	fn prove_and_verify_with_backend(
+		constraint_system: &ConstraintSystem<F>,
+		boundaries: &[Boundary<F>],
+		witness: MultilinearExtensionIndex<U, F>,
+		log_inv_rate: usize,
+		security_bits: usize,
+		backend: &impl ComputationBackend,
+	) -> Result<(), constraint_system::error::Error> {
+		let proof =
+			constraint_system::prove::<
+				U,
+				CanonicalTowerFamily,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(constraint_system, log_inv_rate, security_bits, boundaries, witness, backend)?;
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(constraint_system, log_inv_rate, security_bits, boundaries, proof)
+	}
+
 	#[test]
 	fn test_boundaries() {
 		// Proving Collatz Orbits
@@ -144,32 +199,467 @@ mod tests {
 
 		let constraint_system = builder.build().unwrap();
 
-		let backend = make_portable_backend();
+		prove_and_verify(&constraint_system, &boundaries, witness, 1, 10).unwrap();
+	}
 
-		let proof = constraint_system::prove::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, 1, 10, &boundaries, witness, &backend)
-		.unwrap();
+	/// Builds a lookup of `lookups` into the table of all bytes, where every table row is pulled
+	/// as many times as it is looked up, with the multiplicity given by two bit columns. The
+	/// lookups are pushed `push_count` times.
+	fn prove_and_verify_bit_multiplicity_lookup(
+		push_count: usize,
+	) -> Result<(), constraint_system::error::Error> {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
 
-		constraint_system::verify::<
-			U,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-		>(&constraint_system, 1, 10, &boundaries, proof)
-		.unwrap();
+		let log_size = 8;
+		let lookups = [3u8, 17, 42, 42, 200, 42, 3, 255, 0, 128];
+		let boundary_value = 99u8;
+
+		let channel_id = builder.add_channel();
+
+		let boundaries = vec![Boundary {
+			values: vec![F::from(BinaryField8b::new(boundary_value))],
+			channel_id,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		}];
+
+		let table = builder.add_committed("table", log_size, 3);
+		let looked_up = builder.add_committed("looked_up", log_size, 3);
+		let multiplicity_bits = builder.add_committed_multiple::<2>("multiplicity", log_size, 0);
+
+		if let Some(witness) = builder.witness() {
+			let mut table = witness.new_column::<BinaryField8b>(table);
+			let mut looked_up = witness.new_column::<BinaryField8b>(looked_up);
+			let mut multiplicity_bits =
+				multiplicity_bits.map(|bit| witness.new_column::<BinaryField1b>(bit));
+
+			for (i, value) in table.as_mut_slice::<u8>().iter_mut().enumerate() {
+				*value = i as u8;
+			}
+			looked_up.as_mut_slice::<u8>()[..lookups.len()].copy_from_slice(&lookups);
+
+			let mut counts = [0usize; 256];
+			for &value in lookups.iter().chain([&boundary_value]) {
+				counts[value as usize] += 1;
+			}
+			for (k, bit) in multiplicity_bits.iter_mut().enumerate() {
+				let bit = bit.packed();
+				for (value, &count) in counts.iter().enumerate() {
+					if (count >> k) & 1 == 1 {
+						set_packed_slice(bit, value, BinaryField1b::ONE);
+					}
+				}
+			}
+		}
+
+		for _ in 0..push_count {
+			builder
+				.send(channel_id, lookups.len(), [looked_up])
+				.unwrap();
+		}
+		builder
+			.flush_with_multiplicity(
+				FlushDirection::Pull,
+				channel_id,
+				1 << log_size,
+				[table],
+				FlushMultiplicity::Bits(multiplicity_bits.to_vec()),
+			)
+			.unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+
+		let constraint_system = builder.build().unwrap();
+
+		let channel_result = validate_witness(
+			&witness,
+			&constraint_system.flushes,
+			&boundaries,
+			constraint_system.max_channel_id,
+		);
+		assert_eq!(channel_result.is_ok(), push_count == 1);
+
+		prove_and_verify(&constraint_system, &boundaries, witness, 1, 10)
+	}
+
+	#[test]
+	fn test_bit_multiplicity_lookup() {
+		prove_and_verify_bit_multiplicity_lookup(1).unwrap();
+	}
+
+	#[test]
+	fn test_bit_multiplicity_lookup_duplicate_push() {
+		// Pushing every lookup twice must not cancel out
+		assert!(prove_and_verify_bit_multiplicity_lookup(2).is_err());
+	}
+
+	#[test]
+	fn test_permutation() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 6;
+		let keys = builder.add_committed("keys", log_size, 5);
+		let values = builder.add_committed("values", log_size, 5);
+		let sorted_keys = builder.add_committed("sorted_keys", log_size, 5);
+		let sorted_values = builder.add_committed("sorted_values", log_size, 5);
+
+		if let Some(witness) = builder.witness() {
+			let mut keys = witness.new_column::<BinaryField32b>(keys);
+			let mut values = witness.new_column::<BinaryField32b>(values);
+			let mut sorted_keys = witness.new_column::<BinaryField32b>(sorted_keys);
+			let mut sorted_values = witness.new_column::<BinaryField32b>(sorted_values);
+
+			let mut rows = (0..1u32 << log_size)
+				.map(|i| ((i * 37) % 23, i * i))
+				.collect::<Vec<_>>();
+			for (i, &(key, value)) in rows.iter().enumerate() {
+				keys.as_mut_slice::<u32>()[i] = key;
+				values.as_mut_slice::<u32>()[i] = value;
+			}
+			rows.sort();
+			for (i, &(key, value)) in rows.iter().enumerate() {
+				sorted_keys.as_mut_slice::<u32>()[i] = key;
+				sorted_values.as_mut_slice::<u32>()[i] = value;
+			}
+		}
+
+		builder
+			.assert_permutation([keys, values], [sorted_keys, sorted_values])
+			.unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+
+		let constraint_system = builder.build().unwrap();
+
+		constraint_system::validate::validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		prove_and_verify(&constraint_system, &[], witness, 1, 10).unwrap();
+	}
+
+	#[test]
+	fn test_permutation_rejects_mismatch() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 6;
+		let a = builder.add_committed("a", log_size, 5);
+		let b = builder.add_committed("b", log_size, 5);
+
+		if let Some(witness) = builder.witness() {
+			let mut a = witness.new_column::<BinaryField32b>(a);
+			let mut b = witness.new_column::<BinaryField32b>(b);
+			for i in 0..1 << log_size {
+				a.as_mut_slice::<u32>()[i] = i as u32;
+				b.as_mut_slice::<u32>()[i] = ((1 << log_size) - i) as u32;
+			}
+		}
+
+		builder.assert_permutation([a], [b]).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		assert!(constraint_system::validate::validate_witness(&constraint_system, &[], &witness)
+			.is_err());
+	}
+
+	#[test]
+	fn test_interleaved_strided() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 6;
+		let a = builder.add_committed("a", log_size, 5);
+		let b = builder.add_committed("b", log_size, 5);
+		let rows = builder.add_committed("rows", log_size + 1, 5);
+		let interleaved = builder.add_interleaved("interleaved", [a, b]).unwrap();
+		let strided = builder.add_strided("strided", rows, 1, 1).unwrap();
+
+		if let Some(witness) = builder.witness() {
+			let mut a = witness.new_column::<BinaryField32b>(a);
+			let mut b = witness.new_column::<BinaryField32b>(b);
+			let mut rows = witness.new_column::<BinaryField32b>(rows);
+			let mut interleaved = witness.new_column::<BinaryField32b>(interleaved);
+			let mut strided = witness.new_column::<BinaryField32b>(strided);
+
+			for i in 0..1 << log_size {
+				let (a_value, b_value) = (i as u32 * 3, i as u32 ^ 0xff);
+				a.as_mut_slice::<u32>()[i] = a_value;
+				b.as_mut_slice::<u32>()[i] = b_value;
+				rows.as_mut_slice::<u32>()[2 * i] = a_value;
+				rows.as_mut_slice::<u32>()[2 * i + 1] = b_value;
+			}
+
+			let inner = [a.packed(), b.packed()]
+				.map(|evals| MultilinearExtension::from_values_slice(evals).unwrap());
+			interleaved
+				.packed()
+				.copy_from_slice(interleaved_witness(&inner).unwrap().evals());
+			let rows = MultilinearExtension::from_values_slice(rows.packed()).unwrap();
+			strided
+				.packed()
+				.copy_from_slice(strided_witness(&rows, 1, 1).unwrap().evals());
+		}
+
+		builder.assert_zero(
+			"interleaved",
+			[interleaved, rows],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+		builder.assert_zero("strided", [strided, b], arith_expr!([x, y] = x - y).convert_field());
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+
+		let constraint_system = builder.build().unwrap();
+
+		constraint_system::validate::validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		prove_and_verify(&constraint_system, &[], witness, 1, 10).unwrap();
+	}
+
+	#[test]
+	fn test_concatenated() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let a = builder.add_committed("a", 6, 5);
+		let b = builder.add_committed("b", 5, 5);
+		let rows = builder.add_committed("rows", 7, 5);
+		let concatenated = builder.add_concatenated("concatenated", 7, [a, b]).unwrap();
+
+		if let Some(witness) = builder.witness() {
+			let mut a = witness.new_column::<BinaryField32b>(a);
+			let mut b = witness.new_column::<BinaryField32b>(b);
+			let mut rows = witness.new_column::<BinaryField32b>(rows);
+			let mut concatenated = witness.new_column::<BinaryField32b>(concatenated);
+
+			for i in 0..1 << 6 {
+				a.as_mut_slice::<u32>()[i] = i as u32 * 5;
+				rows.as_mut_slice::<u32>()[i] = i as u32 * 5;
+			}
+			for i in 0..1 << 5 {
+				b.as_mut_slice::<u32>()[i] = i as u32 + 1000;
+				rows.as_mut_slice::<u32>()[(1 << 6) + i] = i as u32 + 1000;
+			}
+
+			let inner = [a.packed(), b.packed()]
+				.map(|evals| MultilinearExtension::from_values_slice(evals).unwrap());
+			concatenated
+				.packed()
+				.copy_from_slice(concatenated_witness(7, &inner).unwrap().evals());
+		}
+
+		builder.assert_zero(
+			"concatenated",
+			[concatenated, rows],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+
+		let constraint_system = builder.build().unwrap();
+
+		constraint_system::validate::validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		prove_and_verify(&constraint_system, &[], witness, 1, 10).unwrap();
+	}
+
+	#[test]
+	fn test_unpacked() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 5;
+		let words = builder.add_committed("words", log_size, 5);
+		let bits = builder.add_committed("bits", log_size + 5, 0);
+		let bytes = builder.add_committed("bytes", log_size + 2, 3);
+		let words_bits = builder.add_unpacked("words_bits", words, 5).unwrap();
+		let words_bytes = builder.add_unpacked("words_bytes", words, 2).unwrap();
+
+		if let Some(witness) = builder.witness() {
+			let mut words_col = witness.new_column::<BinaryField32b>(words);
+			let mut bits_col = witness.new_column::<BinaryField1b>(bits);
+			let mut bytes_col = witness.new_column::<BinaryField8b>(bytes);
+
+			for i in 0..1 << log_size {
+				let word = (i as u32).wrapping_mul(0x9e3779b9);
+				words_col.as_mut_slice::<u32>()[i] = word;
+				bits_col.as_mut_slice::<u32>()[i] = word;
+				bytes_col.as_mut_slice::<u32>()[i] = word;
+			}
+		}
+
+		if let Some(witness) = builder.witness() {
+			let words_entry = witness.get::<BinaryField32b>(words).unwrap();
+			witness
+				.set(words_bits, words_entry.unpacked::<BinaryField1b>())
+				.unwrap();
+			witness
+				.set(words_bytes, words_entry.unpacked::<BinaryField8b>())
+				.unwrap();
+		}
+
+		builder.assert_zero(
+			"words_bits",
+			[words_bits, bits],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+		builder.assert_zero(
+			"words_bytes",
+			[words_bytes, bytes],
+			arith_expr!([x, y] = x - y).convert_field(),
+		);
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+
+		let constraint_system = builder.build().unwrap();
+
+		constraint_system::validate::validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		prove_and_verify(&constraint_system, &[], witness, 1, 10).unwrap();
+	}
+
+	#[test]
+	fn test_optimized_constraint_system() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let log_size = 6;
+		let a = builder.add_committed("a", log_size, 5);
+		let b = builder.add_committed("b", log_size, 5);
+		let product = builder.add_committed("product", log_size, 5);
+		let unused = builder.add_committed("unused", log_size, 5);
+		let sum = builder
+			.add_linear_combination("sum", log_size, [(a, F::ONE), (b, F::ONE)])
+			.unwrap();
+		let sum_again = builder
+			.add_linear_combination("sum_again", log_size, [(a, F::ONE), (b, F::ONE)])
+			.unwrap();
+		let b_again = builder
+			.add_linear_combination("b_again", log_size, [(sum, F::ONE), (a, F::ONE)])
+			.unwrap();
+
+		if let Some(witness) = builder.witness() {
+			let mut a = witness.new_column::<BinaryField32b>(a);
+			let mut b = witness.new_column::<BinaryField32b>(b);
+			let mut product = witness.new_column::<BinaryField32b>(product);
+			let mut unused = witness.new_column::<BinaryField32b>(unused);
+			let mut sum = witness.new_column::<BinaryField32b>(sum);
+			let mut sum_again = witness.new_column::<BinaryField32b>(sum_again);
+			let mut b_again = witness.new_column::<BinaryField32b>(b_again);
+
+			for i in 0..1 << log_size {
+				let a_value = BinaryField32b::new(i as u32 * 7 + 1);
+				let b_value = BinaryField32b::new(i as u32 ^ 0x1234);
+				a.as_mut_slice::<BinaryField32b>()[i] = a_value;
+				b.as_mut_slice::<BinaryField32b>()[i] = b_value;
+				product.as_mut_slice::<BinaryField32b>()[i] = (a_value + b_value) * a_value;
+				unused.as_mut_slice::<BinaryField32b>()[i] = BinaryField32b::new(i as u32);
+				sum.as_mut_slice::<BinaryField32b>()[i] = a_value + b_value;
+				sum_again.as_mut_slice::<BinaryField32b>()[i] = a_value + b_value;
+				b_again.as_mut_slice::<BinaryField32b>()[i] = b_value;
+			}
+		}
+
+		builder.assert_zero(
+			"product",
+			[sum, a, product],
+			arith_expr!([x, y, z] = x * y - z).convert_field(),
+		);
+		builder.assert_zero(
+			"product_again",
+			[a, sum_again, product],
+			arith_expr!([x, y, z] = x * y - z).convert_field(),
+		);
+		builder.assert_zero("b_again", [b_again, b], arith_expr!([x, y] = x - y).convert_field());
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+
+		let constraint_system = builder.build().unwrap();
+		let (constraint_system, remapping) = constraint_system.optimize().unwrap();
+		let witness = remapping.remap_witness(&witness).unwrap();
+
+		assert_eq!(remapping.get(unused), None);
+		assert_eq!(remapping.get(sum), remapping.get(sum_again));
+		assert_eq!(remapping.get(b_again), remapping.get(b));
+		assert_eq!(constraint_system.oracles.size(), 4);
+		assert_eq!(constraint_system.table_constraints.len(), 1);
+		assert_eq!(constraint_system.table_constraints[0].constraints.len(), 1);
+
+		constraint_system::validate::validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		prove_and_verify(&constraint_system, &[], witness, 1, 10).unwrap();
+	}
+
+	#[test]
+	fn test_degree_reduction() {
+		for oracle_kind in [
+			AuxiliaryOracleKind::Committed,
+			AuxiliaryOracleKind::Composite,
+		] {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+			let log_size = 6;
+			let x = builder.add_committed("x", log_size, 3);
+			let x_inv = builder.add_committed("x_inv", log_size, 3);
+
+			if let Some(witness) = builder.witness() {
+				let mut x = witness.new_column::<BinaryField8b>(x);
+				let mut x_inv = witness.new_column::<BinaryField8b>(x_inv);
+				for i in 0..1 << log_size {
+					let value = BinaryField8b::new((i as u8).wrapping_mul(73) ^ 0x5a);
+					x.as_mut_slice::<BinaryField8b>()[i] = value;
+					x_inv.as_mut_slice::<BinaryField8b>()[i] =
+						value.invert().unwrap_or(BinaryField8b::ZERO);
+				}
+			}
+
+			// x^254 is the inverse of x in B8, with 0 mapping to 0
+			builder.assert_zero("sbox", [x, x_inv], ArithExpr::Var(0).pow(254) - ArithExpr::Var(1));
+			builder
+				.reduce_degree(DegreeReduction::new(3).with_oracle_kind(oracle_kind))
+				.unwrap();
+
+			let witness = builder
+				.take_witness()
+				.expect("builder created with witness");
+
+			let constraint_system = builder.build().unwrap();
+			for constraint in constraint_system
+				.table_constraints
+				.iter()
+				.flat_map(|set| &set.constraints)
+			{
+				assert!(constraint.composition.degree() <= 3);
+			}
+
+			constraint_system::validate::validate_witness(&constraint_system, &[], &witness)
+				.unwrap();
+
+			prove_and_verify(&constraint_system, &[], witness, 1, 10).unwrap();
+		}
 	}
 
 	#[test]
 	#[ignore]
 	fn test_composite_circuit() {
-		let backend = make_portable_backend();
 		let allocator = bumpalo::Bump::new();
 		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
 		let n_vars = 8;
@@ -362,23 +852,193 @@ mod tests {
 
 		validate_witness(&witness, &[], &[], 1).unwrap();
 
-		let proof = binius_core::constraint_system::prove::<
-			OptimalUnderlier,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-			_,
-		>(&constraint_system, log_inv_rate, security_bits, &[], witness, &backend)
-		.unwrap();
+		prove_and_verify(&constraint_system, &[], witness, log_inv_rate, security_bits).unwrap();
+	}
 
-		binius_core::constraint_system::verify::<
-			OptimalUnderlier,
-			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-		>(&constraint_system, log_inv_rate, security_bits, &[], proof)
-		.unwrap();
+	#[test]
+	fn test_recording_differential_backend() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let x = builder.add_committed("x", 10, 0);
+		let y = builder.add_committed("y", 10, 0);
+		let z = builder.add_committed("z", 10, 0);
+
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut x = witness.new_column::<BinaryField1b>(x);
+			let mut y = witness.new_column::<BinaryField1b>(y);
+			let mut z = witness.new_column::<BinaryField1b>(z);
+			rng.fill_bytes(x.as_mut_slice::<u8>());
+			rng.fill_bytes(y.as_mut_slice::<u8>());
+			for (z, (x, y)) in z.as_mut_slice::<u8>().iter_mut().zip(
+				x.as_mut_slice::<u8>()
+					.iter()
+					.zip(y.as_mut_slice::<u8>().iter()),
+			) {
+				*z = x & y;
+			}
+		}
+
+		builder.assert_zero("and", [x, y, z], arith_expr!([x, y, z] = x * y - z).convert_field());
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let backend = RecordingBackend::new(DifferentialBackend::new(
+			make_portable_backend(),
+			make_portable_backend(),
+		));
+
+		prove_and_verify_with_backend(&constraint_system, &[], witness, 1, 10, &backend).unwrap();
+
+		let stats = backend.operation_stats();
+		for operation in [
+			Operation::SumcheckComputeRoundEvals,
+			Operation::SumcheckFoldMultilinears,
+			Operation::ReedSolomonEncodeBatch,
+			Operation::MerkleCompressLayer,
+		] {
+			assert!(stats.get(&operation).is_some_and(|stats| stats.calls > 0), "{operation:?}");
+		}
+		assert!(backend.records().iter().any(|record| {
+			record.operation == Operation::SumcheckComputeRoundEvals && record.degree == Some(2)
+		}));
+
+		let mut call_log = Vec::new();
+		backend.write_call_log(&mut call_log).unwrap();
+		assert_eq!(String::from_utf8(call_log).unwrap().lines().count(), backend.records().len());
+	}
+
+	#[test]
+	fn test_prove_with_spilled_committed_witness() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let x = builder.add_committed("x", 10, 0);
+		let y = builder.add_committed("y", 10, 0);
+		let z = builder.add_committed("z", 10, 0);
+
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut x = witness.new_column::<BinaryField1b>(x);
+			let mut y = witness.new_column::<BinaryField1b>(y);
+			let mut z = witness.new_column::<BinaryField1b>(z);
+			rng.fill_bytes(x.as_mut_slice::<u8>());
+			rng.fill_bytes(y.as_mut_slice::<u8>());
+			for (z, (x, y)) in z.as_mut_slice::<u8>().iter_mut().zip(
+				x.as_mut_slice::<u8>()
+					.iter()
+					.zip(y.as_mut_slice::<u8>().iter()),
+			) {
+				*z = x & y;
+			}
+		}
+
+		builder.assert_zero("and", [x, y, z], arith_expr!([x, y, z] = x * y - z).convert_field());
+
+		let spill_dir =
+			std::env::temp_dir().join(format!("binius-circuits-spill-{}", std::process::id()));
+		std::fs::create_dir_all(&spill_dir).unwrap();
+
+		let mut witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		witness.set_spill_dir(&spill_dir);
+		let constraint_system = builder.build().unwrap();
+
+		prove_and_verify(&constraint_system, &[], witness, 1, 10).unwrap();
+
+		// The spill files are removed together with the witness at the end of proving
+		assert_eq!(std::fs::read_dir(&spill_dir).unwrap().count(), 0);
+		std::fs::remove_dir(&spill_dir).unwrap();
+	}
+
+	const PEAK_RSS_MODE_VAR: &str = "BINIUS_CIRCUITS_PEAK_RSS_MODE";
+
+	/// Proves a circuit that sends 128-bit committed columns over a channel and receives them
+	/// back, one flush per column, and prints the peak RSS of the process.
+	#[cfg(target_os = "linux")]
+	fn run_peak_rss_proof(spill: bool) {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+
+		let n_vars = 15;
+		let columns = (0..8)
+			.map(|i| builder.add_committed(format!("column_{i}"), n_vars, B128::TOWER_LEVEL))
+			.collect::<Vec<_>>();
+
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			for &column in &columns {
+				let mut column = witness.new_column::<B128>(column);
+				rng.fill_bytes(column.as_mut_slice::<u8>());
+			}
+		}
+
+		let channel = builder.add_channel();
+		for &column in &columns {
+			builder.send(channel, 1 << n_vars, [column]).unwrap();
+			builder.receive(channel, 1 << n_vars, [column]).unwrap();
+		}
+
+		let spill_dir =
+			std::env::temp_dir().join(format!("binius-circuits-rss-{}", std::process::id()));
+		std::fs::create_dir_all(&spill_dir).unwrap();
+
+		let mut witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		if spill {
+			witness.set_spill_dir(&spill_dir);
+		}
+		let constraint_system = builder.build().unwrap();
+
+		prove_and_verify(&constraint_system, &[], witness, 1, 10).unwrap();
+		println!("peak_rss_kib={}", binius_utils::memory::peak_rss_kib().unwrap());
+
+		std::fs::remove_dir(&spill_dir).unwrap();
+	}
+
+	#[test]
+	#[cfg(target_os = "linux")]
+	fn test_spill_reduces_peak_rss_of_proof() {
+		if let Ok(mode) = std::env::var(PEAK_RSS_MODE_VAR) {
+			run_peak_rss_proof(mode == "spill");
+			return;
+		}
+
+		// Peak RSS is tracked per process, so each proof runs in a fresh copy of this test
+		let peak_rss_kib = |mode: &str| -> usize {
+			let output = std::process::Command::new(std::env::current_exe().unwrap())
+				.args([
+					"tests::test_spill_reduces_peak_rss_of_proof",
+					"--exact",
+					"--nocapture",
+					"--test-threads=1",
+				])
+				.env(PEAK_RSS_MODE_VAR, mode)
+				.output()
+				.unwrap();
+			assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+			String::from_utf8(output.stdout)
+				.unwrap()
+				.lines()
+				.find_map(|line| Some(line.split_once("peak_rss_kib=")?.1))
+				.expect("proof prints its peak RSS")
+				.parse()
+				.unwrap()
+		};
+
+		let resident_peak = peak_rss_kib("resident");
+		let spilled_peak = peak_rss_kib("spill");
+		// The committed witness takes 4 MiB. The peak is reached while proving the grand products
+		// of the flushes, before which the spilled witness is evicted.
+		assert!(
+			spilled_peak + (2 << 10) < resident_peak,
+			"spilled peak {spilled_peak} KiB, resident peak {resident_peak} KiB"
+		);
 	}
 }
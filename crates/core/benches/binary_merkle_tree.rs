@@ -4,6 +4,7 @@ use std::iter::repeat_with;
 
 use binius_core::merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver};
 use binius_field::{BinaryField128b, Field};
+use binius_hal::make_portable_backend;
 use binius_hash::{
 	groestl::{Groestl256, Groestl256ByteCompression},
 	multi_digest::ParallelDigest,
@@ -24,6 +25,7 @@ where
 	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
 {
 	let merkle_prover = BinaryMerkleTreeProver::<_, H, C>::new(compression);
+	let backend = make_portable_backend();
 	let mut rng = thread_rng();
 	let data: Vec<F> = repeat_with(|| Field::random(&mut rng))
 		.take(1 << (LOG_ELEMS + LOG_ELEMS_IN_LEAF))
@@ -36,7 +38,7 @@ where
 	group.bench_function(
 		format!("{} log elems size {}xBinaryField128b leaf", LOG_ELEMS, 1 << LOG_ELEMS_IN_LEAF),
 		|b| {
-			b.iter(|| merkle_prover.commit(&data, 1 << LOG_ELEMS_IN_LEAF));
+			b.iter(|| merkle_prover.commit(&data, 1 << LOG_ELEMS_IN_LEAF, &backend));
 		},
 	);
 	group.finish()
@@ -93,6 +93,7 @@ where
 						MultilinearExtension::<P, &[P]>::from_values_generic(numerator).unwrap();
 					let gpa_witness = GrandProductWitness::<P>::new(
 						MLEDirectAdapter::from(numerator).upcast_arc_dyn(),
+						backend,
 					)
 					.unwrap();
 
@@ -150,7 +151,7 @@ fn bench_gpa_polyval_with_isomorphism<U>(
 
 					let gpa_witness = GrandProductWitness::<
 						<U as PackScalar<BinaryField128bPolyval>>::Packed,
-					>::new(numerator.specialize_arc_dyn())
+					>::new(numerator.specialize_arc_dyn(), backend)
 					.unwrap();
 
 					let product = gpa_witness.grand_product_evaluation();
@@ -4,20 +4,31 @@ use std::iter::repeat_with;
 
 use binius_core::{
 	composition::BivariateProduct,
-	fiat_shamir::HasherChallenger,
-	polynomial::MultilinearComposite,
-	protocols::sumcheck::{batch_prove, prove::RegularSumcheckProver, CompositeSumClaim},
+	fiat_shamir::{CanSample, HasherChallenger},
+	polynomial::{ArithCircuitPoly, MultilinearComposite},
+	protocols::sumcheck::{
+		batch_prove,
+		prove::{
+			batch_prove_with_start, batch_prove_zerocheck_univariate_round, RegularSumcheckProver,
+			SumcheckProver, UnivariateZerocheck,
+		},
+		standard_small_value_heuristic, standard_switchover_heuristic, CompositeSumClaim,
+	},
 	transcript::ProverTranscript,
 };
 use binius_field::{
-	arch::OptimalUnderlier, as_packed_field::PackedType, AESTowerField8b, BinaryField,
-	BinaryField128b, BinaryField128bPolyval, BinaryField8b, ByteSlicedAES32x128b, ExtensionField,
-	PackedExtension, PackedField, TowerField,
+	arch::OptimalUnderlier,
+	as_packed_field::{PackScalar, PackedType},
+	packed::set_packed_slice,
+	AESTowerField8b, BinaryField, BinaryField128b, BinaryField128bPolyval, BinaryField1b,
+	BinaryField8b, ByteSlicedAES32x128b, ExtensionField, Field, PackedExtension, PackedField,
+	TowerField,
 };
 use binius_hal::make_portable_backend;
 use binius_hash::groestl::Groestl256;
 use binius_math::{
-	EvaluationOrder, IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension,
+	ArithExpr, EvaluationOrder, IsomorphicEvaluationDomainFactory, MLEDirectAdapter,
+	MLEEmbeddingAdapter, MultilinearExtension,
 };
 use binius_maybe_rayon::prelude::*;
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
@@ -106,6 +117,189 @@ fn regular_sumcheck(c: &mut Criterion) {
 	}
 }
 
-criterion_group!(sumcheck_benches, regular_sumcheck);
+#[derive(Debug, Clone, Copy)]
+enum Sparsity {
+	/// All rows are active.
+	Dense,
+	/// All columns are zero outside of the active rows.
+	Padding,
+	/// The selector is zero outside of the active rows, other columns are random.
+	Selector,
+}
+
+fn bench_zerocheck_with_sparsity(c: &mut Criterion, n_vars: usize, sparsity: Sparsity) {
+	type U = OptimalUnderlier;
+	type FBase = BinaryField8b;
+	type P = PackedType<U, BinaryField128b>;
+
+	// 90% sparse traces keep only the first tenth of the rows active.
+	let active_rows = match sparsity {
+		Sparsity::Dense => 1 << n_vars,
+		Sparsity::Padding | Sparsity::Selector => (1 << n_vars) / 10,
+	};
+
+	let mut rng = thread_rng();
+	let mut columns = vec![
+		vec![
+			PackedType::<U, FBase>::default();
+			1 << n_vars.saturating_sub(PackedType::<U, FBase>::LOG_WIDTH)
+		];
+		4
+	];
+	for i in 0..1 << n_vars {
+		let is_active = i < active_rows;
+		if !is_active && matches!(sparsity, Sparsity::Padding) {
+			continue;
+		}
+		let a = <FBase as Field>::random(&mut rng);
+		let b = <FBase as Field>::random(&mut rng);
+		let (selector, product) = if is_active {
+			(FBase::ONE, a * b)
+		} else {
+			(FBase::ZERO, <FBase as Field>::random(&mut rng))
+		};
+		for (column, value) in columns.iter_mut().zip([selector, a, b, product]) {
+			set_packed_slice(column, i, value);
+		}
+	}
+	let multilins = columns
+		.into_iter()
+		.map(|values| {
+			MLEEmbeddingAdapter::<_, P, _>::from(MultilinearExtension::new(n_vars, values).unwrap())
+		})
+		.collect::<Vec<_>>();
+
+	// selector * (a * b - product)
+	let composition = ArithCircuitPoly::<FBase>::new(
+		ArithExpr::Var(0) * (ArithExpr::Var(1) * ArithExpr::Var(2) - ArithExpr::Var(3)),
+	);
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FBase>::default();
+	let skip_rounds = 6;
+
+	let mut group = c.benchmark_group("Zerocheck/BinaryField8b");
+	group.bench_function(format!("n_vars={n_vars}/{sparsity:?}"), |b| {
+		b.iter_batched(
+			|| {
+				let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+				let challenges: Vec<BinaryField128b> = transcript.sample_vec(n_vars - skip_rounds);
+				let prover = UnivariateZerocheck::<FBase, FBase, P, _, _, _, _, _, _>::new(
+					multilins.clone(),
+					[("sparse".to_string(), composition.clone(), composition.clone())],
+					&challenges,
+					domain_factory.clone(),
+					|_| 1,
+					&backend,
+				)
+				.unwrap();
+				(prover, transcript)
+			},
+			|(prover, mut transcript)| {
+				let output = batch_prove_zerocheck_univariate_round(
+					vec![prover],
+					skip_rounds,
+					&mut transcript,
+				)
+				.expect("failed to prove univariate round");
+				batch_prove_with_start(
+					output.batch_prove_start,
+					Vec::<Box<dyn SumcheckProver<_>>>::new(),
+					&mut transcript,
+				)
+				.expect("failed to prove zerocheck")
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn sparse_zerocheck(c: &mut Criterion) {
+	for sparsity in [Sparsity::Dense, Sparsity::Padding, Sparsity::Selector] {
+		bench_zerocheck_with_sparsity(c, 20, sparsity);
+	}
+}
+
+fn bench_zerocheck_small_value<FWitness>(
+	c: &mut Criterion,
+	field: &str,
+	n_vars: usize,
+	max_rounds: usize,
+) where
+	FWitness: TowerField,
+	BinaryField8b: ExtensionField<FWitness>,
+	BinaryField128b: ExtensionField<FWitness>,
+	OptimalUnderlier: PackScalar<FWitness>,
+{
+	type U = OptimalUnderlier;
+	type FBase = BinaryField8b;
+	type P = PackedType<U, BinaryField128b>;
+
+	let mut rng = thread_rng();
+	let mut columns = vec![
+		vec![
+			PackedType::<U, FWitness>::default();
+			1 << n_vars.saturating_sub(PackedType::<U, FWitness>::LOG_WIDTH)
+		];
+		3
+	];
+	for i in 0..1 << n_vars {
+		let a = <FWitness as Field>::random(&mut rng);
+		let b = <FWitness as Field>::random(&mut rng);
+		for (column, value) in columns.iter_mut().zip([a, b, a * b]) {
+			set_packed_slice(column, i, value);
+		}
+	}
+	let multilins = columns
+		.into_iter()
+		.map(|values| {
+			MLEEmbeddingAdapter::<_, P, _>::from(MultilinearExtension::new(n_vars, values).unwrap())
+		})
+		.collect::<Vec<_>>();
+
+	// a * b - product
+	let composition =
+		ArithCircuitPoly::<FBase>::new(ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2));
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FBase>::default();
+	let switchover_fn = standard_switchover_heuristic(-2);
+	let small_value_fn = standard_small_value_heuristic(max_rounds);
+
+	let mut group = c.benchmark_group(format!("Zerocheck/{field}"));
+	group.bench_function(format!("n_vars={n_vars}/small_value_rounds<={max_rounds}"), |b| {
+		b.iter_batched(
+			|| {
+				let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+				let challenges: Vec<BinaryField128b> = transcript.sample_vec(n_vars);
+				let prover = UnivariateZerocheck::<FBase, FBase, P, _, _, _, _, _, _>::new(
+					multilins.clone(),
+					[("product".to_string(), composition.clone(), composition.clone())],
+					&challenges,
+					domain_factory.clone(),
+					switchover_fn,
+					&backend,
+				)
+				.unwrap()
+				.with_small_value_heuristic(small_value_fn);
+				(prover, transcript)
+			},
+			|(prover, mut transcript)| {
+				let prover = prover.into_regular_zerocheck().unwrap();
+				batch_prove(vec![prover], &mut transcript).expect("failed to prove zerocheck")
+			},
+			BatchSize::SmallInput,
+		);
+	});
+}
+
+fn small_value_zerocheck(c: &mut Criterion) {
+	for max_rounds in [0, 2, 4] {
+		bench_zerocheck_small_value::<BinaryField1b>(c, "BinaryField1b", 20, max_rounds);
+		bench_zerocheck_small_value::<BinaryField8b>(c, "BinaryField8b", 20, max_rounds);
+	}
+}
+
+criterion_group!(sumcheck_benches, regular_sumcheck, sparse_zerocheck, small_value_zerocheck);
 
 criterion_main!(sumcheck_benches);
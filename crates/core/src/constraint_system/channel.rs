@@ -13,6 +13,12 @@
 //! multiset check. If you want any kind of ordering, you have to
 //! use polynomial constraints to additionally constraint this.
 //!
+//! Flushes either send every selected row a constant number of times, or a per-row number of
+//! times given by bit oracles, see [`FlushMultiplicity`]. Multiplicities are integers, so a row
+//! pushed twice must be pulled twice. Channels are balanced with grand products rather than a
+//! LogUp fractional sum, because in characteristic 2 a sum of fractions only counts each
+//! multiplicity modulo 2.
+//!
 //! The example below shows a channel with width=2, with multiple inputs
 //! and outputs.
 //! ```txt
@@ -59,13 +65,36 @@ use crate::{oracle::OracleId, witness::MultilinearExtensionIndex};
 
 pub type ChannelId = usize;
 
+/// The maximum number of bit oracles in a [`FlushMultiplicity::Bits`].
+pub const MAX_MULTIPLICITY_BITS: usize = 64;
+
 #[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
 pub struct Flush {
 	pub oracles: Vec<OracleId>,
 	pub channel_id: ChannelId,
 	pub direction: FlushDirection,
 	pub selector: OracleId,
-	pub multiplicity: u64,
+	pub multiplicity: FlushMultiplicity,
+}
+
+/// The number of times each selected row of a [`Flush`] is sent over its channel.
+#[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
+pub enum FlushMultiplicity {
+	/// Every selected row is flushed the same number of times.
+	Constant(u64),
+	/// Every selected row is flushed $m = \sum_k 2^k b_k$ times, where the $b_k$ are the values
+	/// of the given bit oracles in little-endian order.
+	///
+	/// The bit oracles must have tower level 0. The flush is balanced as the product of
+	/// $(\gamma + v)^m$ over its rows, by splitting it into one flush per bit with the constant
+	/// multiplicity $2^k$, selected by the product of the selector and $b_k$.
+	Bits(Vec<OracleId>),
+}
+
+impl From<u64> for FlushMultiplicity {
+	fn from(multiplicity: u64) -> Self {
+		Self::Constant(multiplicity)
+	}
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, SerializeBytes, DeserializeBytes)]
@@ -111,13 +140,14 @@ where
 	}
 
 	for flush in flushes {
-		let &Flush {
-			ref oracles,
+		let Flush {
+			oracles,
 			channel_id,
 			direction,
 			selector,
 			multiplicity,
 		} = flush;
+		let (channel_id, direction, selector) = (*channel_id, *direction, *selector);
 
 		if channel_id > max_channel_id {
 			return Err(Error::ChannelIdOutOfRange {
@@ -152,15 +182,55 @@ where
 				return Err(Error::IncompatibleFlushSelector { id, selector });
 			}
 
+			let bit_polys = match multiplicity {
+				FlushMultiplicity::Constant(_) => Vec::new(),
+				FlushMultiplicity::Bits(bit_ids) => {
+					if bit_ids.len() > MAX_MULTIPLICITY_BITS {
+						return Err(Error::TooManyMultiplicityBits {
+							max: MAX_MULTIPLICITY_BITS,
+							got: bit_ids.len(),
+						});
+					}
+					bit_ids
+						.iter()
+						.map(|&id| {
+							let poly = witness.get_multilin_poly(id)?;
+							if poly.n_vars() != n_vars {
+								return Err(Error::ChannelFlushNvarsMismatch {
+									expected: n_vars,
+									got: poly.n_vars(),
+								});
+							}
+							Ok((id, poly))
+						})
+						.collect::<Result<Vec<_>, _>>()?
+				}
+			};
+
 			for i in 0..1 << selector_poly.n_vars() {
 				if selector_poly.evaluate_on_hypercube(i)?.is_zero() {
 					continue;
 				}
+				let row_multiplicity = match multiplicity {
+					FlushMultiplicity::Constant(multiplicity) => *multiplicity,
+					FlushMultiplicity::Bits(_) => {
+						let mut row_multiplicity = 0u64;
+						for (k, (id, poly)) in bit_polys.iter().enumerate() {
+							let bit = poly.evaluate_on_hypercube(i)?;
+							if bit == F::ONE {
+								row_multiplicity |= 1 << k;
+							} else if bit != F::ZERO {
+								return Err(Error::NonBinaryMultiplicityBit { id: *id });
+							}
+						}
+						row_multiplicity
+					}
+				};
 				let values = polys
 					.iter()
 					.map(|poly| poly.evaluate_on_hypercube(i))
 					.collect::<Result<Vec<_>, _>>()?;
-				channel.flush(direction, multiplicity, values)?;
+				channel.flush(direction, row_multiplicity, values)?;
 			}
 		}
 	}
@@ -177,7 +247,7 @@ where
 #[derive(Default, Debug, Clone)]
 struct Channel<F: TowerField> {
 	width: Option<usize>,
-	multiplicities: HashMap<Vec<F>, i64>,
+	multiplicities: HashMap<Vec<F>, i128>,
 }
 
 impl<F: TowerField> Channel<F> {
@@ -193,12 +263,7 @@ impl<F: TowerField> Channel<F> {
 		}
 	}
 
-	fn flush(
-		&mut self,
-		direction: FlushDirection,
-		multiplicity: u64,
-		values: Vec<F>,
-	) -> Result<(), Error> {
+	fn check_width(&mut self, values: &[F]) -> Result<(), Error> {
 		if self.width.is_none() {
 			self.width = Some(values.len());
 		} else if self.width.expect("checked for None above") != values.len() {
@@ -207,10 +272,20 @@ impl<F: TowerField> Channel<F> {
 				got: values.len(),
 			});
 		}
-		*self.multiplicities.entry(values).or_default() += (multiplicity as i64)
+		Ok(())
+	}
+
+	fn flush(
+		&mut self,
+		direction: FlushDirection,
+		multiplicity: u64,
+		values: Vec<F>,
+	) -> Result<(), Error> {
+		self.check_width(&values)?;
+		*self.multiplicities.entry(values).or_default() += (multiplicity as i128)
 			* (match direction {
-				FlushDirection::Pull => -1i64,
-				FlushDirection::Push => 1i64,
+				FlushDirection::Pull => -1i128,
+				FlushDirection::Push => 1i128,
 			});
 		Ok(())
 	}
@@ -322,6 +397,22 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_flush_duplicate_push() {
+		let mut channel = Channel::<BinaryField64b>::new();
+
+		// A row pushed twice does not cancel out on its own
+		let values = vec![BinaryField64b::from(3), BinaryField64b::from(4)];
+		channel
+			.flush(FlushDirection::Push, 1, values.clone())
+			.unwrap();
+		channel
+			.flush(FlushDirection::Push, 1, values.clone())
+			.unwrap();
+		assert!(!channel.is_balanced());
+		assert_eq!(channel.multiplicities.get(&values).unwrap(), &2);
+	}
+
 	#[test]
 	fn test_flush_direction_effects() {
 		let mut channel = Channel::<BinaryField64b>::new();
@@ -40,6 +40,18 @@ pub enum Error {
 	#[error("flush selector oracle {selector} incompatible with oracle {id}")]
 	IncompatibleFlushSelector { id: OracleId, selector: OracleId },
 
+	#[error("multiplicity bit oracle {id} must have tower level 0 and binary values")]
+	NonBinaryMultiplicityBit { id: OracleId },
+
+	#[error("a flush multiplicity can have at most {max} bits, got {got}")]
+	TooManyMultiplicityBits { max: usize, got: usize },
+
+	#[error("both sides of a permutation must have the same, non-zero number of oracles, got {a} and {b}")]
+	PermutationWidthMismatch { a: usize, b: usize },
+
+	#[error("all oracles of a permutation must have the same n_vars. Expected oracle with n_vars={expected} got {got}")]
+	PermutationNvarsMismatch { expected: usize, got: usize },
+
 	#[error("Non-zero oracles contain zeros")]
 	Zeros,
 
@@ -108,4 +120,6 @@ pub enum VerificationError {
 		"Channel with id={id} is not balanced. Pushes and pulls do not contain the same elements"
 	)]
 	ChannelUnbalanced { id: ChannelId },
+	#[error("the sides of permutation with index={index} are not permutations of each other")]
+	PermutationMismatch { index: usize },
 }
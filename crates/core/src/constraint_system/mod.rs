@@ -4,6 +4,8 @@ pub mod channel;
 mod common;
 pub mod error;
 pub mod exp;
+mod optimize;
+pub mod permutation;
 mod prove;
 pub mod validate;
 mod verify;
@@ -13,15 +15,18 @@ use binius_macros::SerializeBytes;
 use binius_utils::{DeserializeBytes, SerializationError, SerializationMode};
 use channel::{ChannelId, Flush};
 use exp::Exp;
-pub use prove::prove;
-pub use verify::verify;
+pub use optimize::OracleIdRemapping;
+use permutation::Permutation;
+pub use prove::{prove, prove_with_pcs};
+pub use verify::{verify, verify_with_pcs};
 
 use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
 
-/// Contains the 3 things that place constraints on witness data in Binius
+/// Contains the 4 things that place constraints on witness data in Binius
 /// - virtual oracles
 /// - polynomial constraints
 /// - channel flushes
+/// - permutations
 ///
 /// As a result, a ConstraintSystem allows us to validate all of these
 /// constraints against a witness, as well as enabling generic prove/verify
@@ -33,6 +38,7 @@ pub struct ConstraintSystem<F: TowerField> {
 	pub flushes: Vec<Flush>,
 	pub exponents: Vec<Exp<F>>,
 	pub max_channel_id: ChannelId,
+	pub permutations: Vec<Permutation>,
 }
 
 impl DeserializeBytes for ConstraintSystem<BinaryField128b> {
@@ -50,6 +56,7 @@ impl DeserializeBytes for ConstraintSystem<BinaryField128b> {
 			flushes: DeserializeBytes::deserialize(&mut read_buf, mode)?,
 			exponents: DeserializeBytes::deserialize(&mut read_buf, mode)?,
 			max_channel_id: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			permutations: DeserializeBytes::deserialize(&mut read_buf, mode)?,
 		})
 	}
 }
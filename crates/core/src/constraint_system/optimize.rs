@@ -0,0 +1,689 @@
+// Copyright 2025 Irreducible Inc.
+
+//! An optimization pass that rewrites a [`ConstraintSystem`] into an equivalent, smaller one.
+
+use std::{
+	cmp::Ordering,
+	collections::HashMap,
+	mem::{self, Discriminant},
+};
+
+use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, Field, TowerField};
+use binius_math::ArithExpr;
+use binius_utils::graph::connected_components;
+
+use super::{
+	channel::{Flush, FlushMultiplicity},
+	error::Error,
+	exp::{Exp, ExpBase},
+	permutation::Permutation,
+	ConstraintSystem,
+};
+use crate::{
+	oracle::{
+		Constraint, ConstraintPredicate, ConstraintSet, LinearCombination, MultilinearOracleSet,
+		MultilinearPolyOracle, MultilinearPolyVariant, OracleId,
+	},
+	witness::{self, MultilinearExtensionIndex},
+};
+
+/// Maps oracle IDs of a constraint system to the oracle IDs of its optimized version.
+///
+/// Returned by [`ConstraintSystem::optimize`].
+#[derive(Debug, Clone)]
+pub struct OracleIdRemapping {
+	new_ids: Vec<Option<OracleId>>,
+}
+
+impl OracleIdRemapping {
+	/// Returns the ID of the oracle in the optimized system that has the same values as the oracle
+	/// `id` in the original system, or `None` if the oracle was removed.
+	pub fn get(&self, id: OracleId) -> Option<OracleId> {
+		self.new_ids.get(id).copied().flatten()
+	}
+
+	/// Rearranges a witness for the original system into a witness for the optimized system.
+	///
+	/// Oracles merged into one keep the witness of the first oracle that has one.
+	pub fn remap_witness<'a, U, FW>(
+		&self,
+		witness: &MultilinearExtensionIndex<'a, U, FW>,
+	) -> Result<MultilinearExtensionIndex<'a, U, FW>, witness::Error>
+	where
+		U: UnderlierType + PackScalar<FW>,
+		FW: Field,
+	{
+		let mut remapped = MultilinearExtensionIndex::new();
+		for (old_id, new_id) in self.new_ids.iter().enumerate() {
+			if let Some(new_id) = *new_id {
+				if witness.has(old_id) && !remapped.has(new_id) {
+					remapped
+						.update_multilin_poly([(new_id, witness.get_multilin_poly(old_id)?)])?;
+				}
+			}
+		}
+		Ok(remapped)
+	}
+}
+
+impl<F: TowerField> ConstraintSystem<F> {
+	/// Returns an equivalent constraint system along with the mapping of its oracle IDs.
+	///
+	/// The pass
+	/// - folds linear combinations of linear combinations into single linear combinations,
+	/// - merges derived oracles with the same definition,
+	/// - removes oracles that no constraint, flush, exponentiation or permutation depends on,
+	/// - simplifies constraint compositions and removes duplicate constraints, and
+	/// - regroups the constraints into constraint sets by the oracles they share.
+	///
+	/// The witness must be rearranged with [`OracleIdRemapping::remap_witness`] before proving the
+	/// optimized system.
+	pub fn optimize(&self) -> Result<(Self, OracleIdRemapping), Error> {
+		let (representatives, variants) = merge_oracles(&self.oracles);
+
+		let mut roots = Vec::new();
+		for constraint_set in &self.table_constraints {
+			roots.extend(&constraint_set.oracle_ids);
+		}
+		roots.extend(&self.non_zero_oracle_ids);
+		for flush in &self.flushes {
+			roots.extend(&flush.oracles);
+			roots.push(flush.selector);
+			if let FlushMultiplicity::Bits(bit_ids) = &flush.multiplicity {
+				roots.extend(bit_ids);
+			}
+		}
+		for exp in &self.exponents {
+			roots.extend(&exp.bits_ids);
+			roots.push(exp.exp_result_id);
+			if let ExpBase::Dynamic(id) = exp.base {
+				roots.push(id);
+			}
+		}
+		for permutation in &self.permutations {
+			roots.extend(permutation.a.iter().chain(&permutation.b));
+		}
+
+		// Mark the oracles reachable from the roots. Inner oracles always have smaller IDs, so a
+		// single reverse sweep suffices.
+		let mut reachable = vec![false; self.oracles.size()];
+		for id in roots {
+			reachable[representatives[id]] = true;
+		}
+		for id in (0..self.oracles.size()).rev() {
+			if reachable[id] {
+				for inner_id in variants[id].inner_ids() {
+					reachable[inner_id] = true;
+				}
+			}
+		}
+
+		let mut oracles = MultilinearOracleSet::new();
+		let mut compacted_ids = vec![None; self.oracles.size()];
+		for oracle in self.oracles.iter() {
+			let id = oracle.id();
+			if representatives[id] != id || !reachable[id] {
+				continue;
+			}
+			let variant = variants[id].map_inner_ids(|inner_id| {
+				compacted_ids[inner_id].expect("inner oracles of reachable oracles are reachable")
+			});
+			compacted_ids[id] =
+				Some(oracles.add_oracle(MultilinearPolyOracle { variant, ..oracle }));
+		}
+
+		let remapping = OracleIdRemapping {
+			new_ids: representatives
+				.iter()
+				.map(|&representative| compacted_ids[representative])
+				.collect(),
+		};
+		let remap = |id: OracleId| remapping.get(id).expect("roots are reachable");
+
+		let table_constraints = optimize_constraint_sets(&self.table_constraints, &oracles, remap);
+
+		let mut non_zero_oracle_ids = Vec::with_capacity(self.non_zero_oracle_ids.len());
+		for id in self.non_zero_oracle_ids.iter().copied().map(remap) {
+			if !non_zero_oracle_ids.contains(&id) {
+				non_zero_oracle_ids.push(id);
+			}
+		}
+
+		let flushes = self
+			.flushes
+			.iter()
+			.map(|flush| Flush {
+				oracles: flush.oracles.iter().copied().map(remap).collect(),
+				channel_id: flush.channel_id,
+				direction: flush.direction,
+				selector: remap(flush.selector),
+				multiplicity: match &flush.multiplicity {
+					FlushMultiplicity::Constant(multiplicity) => {
+						FlushMultiplicity::Constant(*multiplicity)
+					}
+					FlushMultiplicity::Bits(bit_ids) => {
+						FlushMultiplicity::Bits(bit_ids.iter().copied().map(remap).collect())
+					}
+				},
+			})
+			.collect();
+
+		let exponents = self
+			.exponents
+			.iter()
+			.map(|exp| Exp {
+				bits_ids: exp.bits_ids.iter().copied().map(remap).collect(),
+				base: match &exp.base {
+					ExpBase::Dynamic(id) => ExpBase::Dynamic(remap(*id)),
+					base => base.clone(),
+				},
+				exp_result_id: remap(exp.exp_result_id),
+			})
+			.collect();
+
+		let permutations = self
+			.permutations
+			.iter()
+			.map(|permutation| Permutation {
+				a: permutation.a.iter().copied().map(remap).collect(),
+				b: permutation.b.iter().copied().map(remap).collect(),
+			})
+			.collect();
+
+		let constraint_system = Self {
+			oracles,
+			table_constraints,
+			non_zero_oracle_ids,
+			flushes,
+			exponents,
+			max_channel_id: self.max_channel_id,
+			permutations,
+		};
+		Ok((constraint_system, remapping))
+	}
+}
+
+type OracleKey<F> = (usize, usize, Discriminant<MultilinearPolyVariant<F>>, Vec<OracleId>);
+
+/// Folds nested linear combinations and merges oracles with the same definition.
+///
+/// Returns, for every oracle, the ID of the oracle it is merged into, and its variant with inner
+/// oracle IDs replaced by those of the oracles they are merged into. Oracles are only ever merged
+/// into oracles with smaller IDs.
+fn merge_oracles<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+) -> (Vec<OracleId>, Vec<MultilinearPolyVariant<F>>) {
+	let mut representatives = Vec::<OracleId>::with_capacity(oracles.size());
+	let mut variants = Vec::<MultilinearPolyVariant<F>>::with_capacity(oracles.size());
+	let mut definitions = HashMap::<OracleKey<F>, Vec<OracleId>>::new();
+
+	for oracle in oracles.iter() {
+		let id = oracle.id();
+		let mut variant = oracle
+			.variant
+			.map_inner_ids(|inner_id| representatives[inner_id]);
+		if let MultilinearPolyVariant::LinearCombination(linear_combination) = &variant {
+			variant = MultilinearPolyVariant::LinearCombination(fold_linear_combination(
+				linear_combination,
+				&variants,
+			));
+		}
+
+		let representative = match &variant {
+			// Committed oracles are independent even if they have the same shape.
+			MultilinearPolyVariant::Committed => id,
+			// A linear combination with a single unit term is the inner oracle itself.
+			MultilinearPolyVariant::LinearCombination(linear_combination)
+				if linear_combination.offset() == F::ZERO
+					&& linear_combination.n_polys() == 1
+					&& linear_combination
+						.coefficients()
+						.all(|coeff| coeff == F::ONE)
+					&& linear_combination
+						.polys()
+						.all(|inner_id| oracles.tower_level(inner_id) == oracle.tower_level) =>
+			{
+				linear_combination
+					.polys()
+					.next()
+					.expect("linear combination has one term")
+			}
+			_ => {
+				let key = (
+					oracle.n_vars,
+					oracle.tower_level,
+					mem::discriminant(&variant),
+					variant.inner_ids(),
+				);
+				let candidates = definitions.entry(key).or_default();
+				match candidates
+					.iter()
+					.find(|&&candidate| variants[candidate] == variant)
+				{
+					Some(&candidate) => candidate,
+					None => {
+						candidates.push(id);
+						id
+					}
+				}
+			}
+		};
+
+		representatives.push(representative);
+		variants.push(variant);
+	}
+
+	(representatives, variants)
+}
+
+/// Substitutes the inner linear combinations of a linear combination.
+fn fold_linear_combination<F: TowerField>(
+	linear_combination: &LinearCombination<F>,
+	variants: &[MultilinearPolyVariant<F>],
+) -> LinearCombination<F> {
+	let mut offset = linear_combination.offset();
+	let mut terms = Vec::<(OracleId, F)>::new();
+	let mut add_term =
+		|id: OracleId, coeff: F| match terms.iter_mut().find(|(term_id, _)| *term_id == id) {
+			Some((_, term_coeff)) => *term_coeff += coeff,
+			None => terms.push((id, coeff)),
+		};
+
+	for (id, coeff) in linear_combination
+		.polys()
+		.zip(linear_combination.coefficients())
+	{
+		match &variants[id] {
+			MultilinearPolyVariant::LinearCombination(inner) => {
+				offset += coeff * inner.offset();
+				for (inner_id, inner_coeff) in inner.polys().zip(inner.coefficients()) {
+					add_term(inner_id, coeff * inner_coeff);
+				}
+			}
+			_ => add_term(id, coeff),
+		}
+	}
+	terms.retain(|(_, coeff)| *coeff != F::ZERO);
+
+	LinearCombination::from_terms(linear_combination.n_vars(), offset, terms)
+}
+
+/// A constraint over an explicit list of distinct oracles.
+struct OracleConstraint<F: Field> {
+	name: String,
+	oracle_ids: Vec<OracleId>,
+	composition: ArithExpr<F>,
+	predicate: ConstraintPredicate<F>,
+}
+
+/// Simplifies and deduplicates the constraints, then regroups them by the oracles they share.
+fn optimize_constraint_sets<F: TowerField>(
+	constraint_sets: &[ConstraintSet<F>],
+	oracles: &MultilinearOracleSet<F>,
+	remap: impl Fn(OracleId) -> OracleId,
+) -> Vec<ConstraintSet<F>> {
+	let mut constraints = Vec::<OracleConstraint<F>>::new();
+	for constraint_set in constraint_sets {
+		let set_oracle_ids = constraint_set
+			.oracle_ids
+			.iter()
+			.copied()
+			.map(&remap)
+			.collect::<Vec<_>>();
+
+		let mut unique_ids = set_oracle_ids.clone();
+		unique_ids.sort_unstable();
+		unique_ids.dedup();
+		let unique_positions = set_oracle_ids
+			.iter()
+			.map(|id| {
+				unique_ids
+					.binary_search(id)
+					.expect("unique_ids contains all oracle IDs of the set")
+			})
+			.collect::<Vec<_>>();
+
+		for constraint in &constraint_set.constraints {
+			// Merged oracles collapse to a single variable, which may cancel terms.
+			let composition = canonicalize(
+				constraint
+					.composition
+					.optimize()
+					.remap_vars(&unique_positions)
+					.expect("unique_positions covers all variables of the composition"),
+			);
+			let mut oracle_ids = composition
+				.vars_usage()
+				.iter()
+				.enumerate()
+				.filter(|(_, &used)| used)
+				.map(|(var, _)| unique_ids[var])
+				.collect::<Vec<_>>();
+
+			if oracle_ids.is_empty() {
+				if composition == ArithExpr::Const(F::ZERO)
+					&& matches!(constraint.predicate, ConstraintPredicate::Zero)
+				{
+					continue;
+				}
+				// Constant constraints still need an oracle to fix the number of variables.
+				oracle_ids.push(unique_ids[0]);
+			}
+
+			let var_positions = unique_ids
+				.iter()
+				.map(|id| oracle_ids.binary_search(id).unwrap_or(0))
+				.collect::<Vec<_>>();
+			let composition = canonicalize(
+				composition
+					.remap_vars(&var_positions)
+					.expect("var_positions covers all variables of the composition"),
+			);
+
+			let is_duplicate = constraints.iter().any(|other| {
+				other.oracle_ids == oracle_ids
+					&& other.composition == composition
+					&& same_predicate(&other.predicate, &constraint.predicate)
+			});
+			if !is_duplicate {
+				constraints.push(OracleConstraint {
+					name: constraint.name.clone(),
+					oracle_ids,
+					composition,
+					predicate: constraint.predicate.clone(),
+				});
+			}
+		}
+	}
+
+	let groups = connected_components(
+		&constraints
+			.iter()
+			.map(|constraint| constraint.oracle_ids.as_slice())
+			.collect::<Vec<_>>(),
+	);
+
+	let mut group_indices = HashMap::<OracleId, usize>::new();
+	let mut grouped_constraints = Vec::<Vec<OracleConstraint<F>>>::new();
+	for constraint in constraints {
+		let group = groups[constraint.oracle_ids[0]];
+		let index = *group_indices.entry(group).or_insert_with(|| {
+			grouped_constraints.push(Vec::new());
+			grouped_constraints.len() - 1
+		});
+		grouped_constraints[index].push(constraint);
+	}
+
+	grouped_constraints
+		.into_iter()
+		.map(|constraints| {
+			let mut oracle_ids = constraints
+				.iter()
+				.flat_map(|constraint| constraint.oracle_ids.iter().copied())
+				.collect::<Vec<_>>();
+			oracle_ids.sort_unstable();
+			oracle_ids.dedup();
+
+			let constraints = constraints
+				.into_iter()
+				.map(|constraint| {
+					let var_positions = constraint
+						.oracle_ids
+						.iter()
+						.map(|id| {
+							oracle_ids
+								.binary_search(id)
+								.expect("oracle_ids is a superset of constraint.oracle_ids")
+						})
+						.collect::<Vec<_>>();
+					Constraint {
+						name: constraint.name,
+						composition: constraint
+							.composition
+							.remap_vars(&var_positions)
+							.expect("var_positions covers all variables of the composition"),
+						predicate: constraint.predicate,
+					}
+				})
+				.collect();
+
+			ConstraintSet {
+				n_vars: oracles.n_vars(oracle_ids[0]),
+				oracle_ids,
+				constraints,
+			}
+		})
+		.collect()
+}
+
+/// Rewrites an expression into a canonical form, so that expressions that differ only in the
+/// order of the operands of additions and multiplications become equal.
+fn canonicalize<F: TowerField>(expr: ArithExpr<F>) -> ArithExpr<F> {
+	if let Ok(linear) = expr.linear_normal_form() {
+		let constant = (linear.constant != F::ZERO).then_some(ArithExpr::Const(linear.constant));
+		let terms = linear
+			.var_coeffs
+			.into_iter()
+			.enumerate()
+			.filter(|(_, coeff)| *coeff != F::ZERO)
+			.map(|(var, coeff)| {
+				if coeff == F::ONE {
+					ArithExpr::Var(var)
+				} else {
+					ArithExpr::Const(coeff) * ArithExpr::Var(var)
+				}
+			});
+		return constant
+			.into_iter()
+			.chain(terms)
+			.reduce(|acc, term| acc + term)
+			.unwrap_or(ArithExpr::Const(F::ZERO));
+	}
+
+	match expr {
+		ArithExpr::Add(_, _) | ArithExpr::Mul(_, _) => {
+			let is_add = matches!(expr, ArithExpr::Add(_, _));
+			let mut operands = Vec::new();
+			flatten_operands(expr, is_add, &mut operands);
+			let mut operands = operands.into_iter().map(canonicalize).collect::<Vec<_>>();
+			operands.sort_by(cmp_exprs);
+			operands
+				.into_iter()
+				.reduce(|acc, operand| if is_add { acc + operand } else { acc * operand })
+				.expect("sums and products have at least two operands")
+		}
+		ArithExpr::Pow(base, exp) => ArithExpr::Pow(Box::new(canonicalize(*base)), exp),
+		expr => expr,
+	}
+}
+
+/// Orders expressions by their node kinds, then by variable index, constant value or exponent,
+/// then by their operands.
+fn cmp_exprs<F: TowerField>(lhs: &ArithExpr<F>, rhs: &ArithExpr<F>) -> Ordering {
+	const fn kind<F: Field>(expr: &ArithExpr<F>) -> u8 {
+		match expr {
+			ArithExpr::Const(_) => 0,
+			ArithExpr::Var(_) => 1,
+			ArithExpr::Add(_, _) => 2,
+			ArithExpr::Mul(_, _) => 3,
+			ArithExpr::Pow(_, _) => 4,
+		}
+	}
+
+	match (lhs, rhs) {
+		(ArithExpr::Const(lhs), ArithExpr::Const(rhs)) => lhs.iter_bases().cmp(rhs.iter_bases()),
+		(ArithExpr::Var(lhs), ArithExpr::Var(rhs)) => lhs.cmp(rhs),
+		(ArithExpr::Add(lhs_left, lhs_right), ArithExpr::Add(rhs_left, rhs_right))
+		| (ArithExpr::Mul(lhs_left, lhs_right), ArithExpr::Mul(rhs_left, rhs_right)) => {
+			cmp_exprs(lhs_left, rhs_left).then_with(|| cmp_exprs(lhs_right, rhs_right))
+		}
+		(ArithExpr::Pow(lhs_base, lhs_exp), ArithExpr::Pow(rhs_base, rhs_exp)) => lhs_exp
+			.cmp(rhs_exp)
+			.then_with(|| cmp_exprs(lhs_base, rhs_base)),
+		_ => kind(lhs).cmp(&kind(rhs)),
+	}
+}
+
+fn flatten_operands<F: Field>(expr: ArithExpr<F>, is_add: bool, operands: &mut Vec<ArithExpr<F>>) {
+	match expr {
+		ArithExpr::Add(left, right) if is_add => {
+			flatten_operands(*left, is_add, operands);
+			flatten_operands(*right, is_add, operands);
+		}
+		ArithExpr::Mul(left, right) if !is_add => {
+			flatten_operands(*left, is_add, operands);
+			flatten_operands(*right, is_add, operands);
+		}
+		expr => operands.push(expr),
+	}
+}
+
+fn same_predicate<F: Field>(lhs: &ConstraintPredicate<F>, rhs: &ConstraintPredicate<F>) -> bool {
+	match (lhs, rhs) {
+		(ConstraintPredicate::Zero, ConstraintPredicate::Zero) => true,
+		(ConstraintPredicate::Sum(lhs), ConstraintPredicate::Sum(rhs)) => lhs == rhs,
+		_ => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+	use binius_macros::arith_expr;
+
+	use super::*;
+	use crate::oracle::ShiftVariant;
+
+	type F = BinaryField128b;
+
+	fn constraint_system(
+		oracles: MultilinearOracleSet<F>,
+		table_constraints: Vec<ConstraintSet<F>>,
+	) -> ConstraintSystem<F> {
+		ConstraintSystem {
+			oracles,
+			table_constraints,
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			exponents: vec![],
+			max_channel_id: 0,
+			permutations: vec![],
+		}
+	}
+
+	#[test]
+	fn test_merges_duplicates_and_removes_unreachable() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, 0);
+		let b = oracles.add_committed(4, 0);
+		let unused = oracles.add_committed(4, 0);
+		let shifted_1 = oracles
+			.add_shifted(a, 1, 4, ShiftVariant::CircularLeft)
+			.unwrap();
+		let shifted_2 = oracles
+			.add_shifted(a, 1, 4, ShiftVariant::CircularLeft)
+			.unwrap();
+		let _unused_shifted = oracles
+			.add_shifted(unused, 1, 4, ShiftVariant::CircularLeft)
+			.unwrap();
+
+		let system = constraint_system(
+			oracles,
+			vec![ConstraintSet {
+				n_vars: 4,
+				oracle_ids: vec![b, shifted_1, shifted_2],
+				constraints: vec![
+					Constraint {
+						name: "first".into(),
+						composition: arith_expr!(F[x, y, z] = x * y + z),
+						predicate: ConstraintPredicate::Zero,
+					},
+					Constraint {
+						name: "second".into(),
+						composition: arith_expr!(F[x, y, z] = y * x + z),
+						predicate: ConstraintPredicate::Zero,
+					},
+				],
+			}],
+		);
+
+		let (optimized, remapping) = system.optimize().unwrap();
+		assert_eq!(optimized.oracles.size(), 3);
+		assert_eq!(remapping.get(unused), None);
+		assert_eq!(remapping.get(shifted_1), remapping.get(shifted_2));
+		assert_eq!(optimized.table_constraints.len(), 1);
+
+		let constraint_set = &optimized.table_constraints[0];
+		assert_eq!(
+			constraint_set.oracle_ids,
+			vec![remapping.get(b).unwrap(), remapping.get(shifted_1).unwrap()]
+		);
+		assert_eq!(constraint_set.constraints.len(), 1);
+	}
+
+	#[test]
+	fn test_folds_linear_combinations() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let a = oracles.add_committed(4, 7);
+		let b = oracles.add_committed(4, 7);
+		let inner = oracles
+			.add_linear_combination_with_offset(4, F::new(1), [(a, F::new(2)), (b, F::new(3))])
+			.unwrap();
+		let outer = oracles
+			.add_linear_combination(4, [(inner, F::new(5)), (a, F::new(7))])
+			.unwrap();
+		let identity = oracles
+			.add_linear_combination(4, [(outer, F::ONE)])
+			.unwrap();
+
+		let system = constraint_system(
+			oracles,
+			vec![ConstraintSet {
+				n_vars: 4,
+				oracle_ids: vec![identity],
+				constraints: vec![Constraint {
+					name: "zero".into(),
+					composition: arith_expr!(F[x] = x),
+					predicate: ConstraintPredicate::Zero,
+				}],
+			}],
+		);
+
+		let (optimized, remapping) = system.optimize().unwrap();
+		assert_eq!(remapping.get(identity), remapping.get(outer));
+		assert_eq!(remapping.get(inner), None);
+
+		let MultilinearPolyVariant::LinearCombination(folded) = optimized
+			.oracles
+			.oracle(remapping.get(outer).unwrap())
+			.variant
+		else {
+			panic!("expected a linear combination");
+		};
+		assert_eq!(folded.offset(), F::new(5));
+		assert_eq!(
+			folded
+				.polys()
+				.zip(folded.coefficients())
+				.collect::<Vec<_>>(),
+			vec![
+				(remapping.get(a).unwrap(), F::new(5) * F::new(2) + F::new(7)),
+				(remapping.get(b).unwrap(), F::new(5) * F::new(3)),
+			]
+		);
+	}
+
+	#[test]
+	fn test_canonicalize_orders_operands_structurally() {
+		let x = || ArithExpr::<F>::Var(0);
+		let y = || ArithExpr::<F>::Var(1);
+		let z = || ArithExpr::<F>::Var(2);
+
+		let lhs = (x() * y() * z()).pow(2) + x() * (y() + z()) * ArithExpr::Const(F::new(3));
+		let rhs = ArithExpr::Const(F::new(3)) * (z() + y()) * x() + (z() * (y() * x())).pow(2);
+		assert_eq!(canonicalize(lhs.clone()), canonicalize(rhs));
+
+		let other = (x() * y() * z()).pow(2) + x() * (y() + z()) * ArithExpr::Const(F::new(5));
+		assert_ne!(canonicalize(lhs), canonicalize(other));
+	}
+}
@@ -0,0 +1,169 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Permutation constraints between tuples of oracles.
+//!
+//! A [`Permutation`] constrains the rows of the oracles in `a` to be a permutation of the rows of
+//! the oracles in `b`. Unlike a pair of flushes over a channel, a permutation constraint applies
+//! to every row of the oracles, so it needs no selector.
+//!
+//! All permutation constraints are proved together. The verifier samples a mixing challenge
+//! $\alpha$ and a shift challenge $\beta$ shared by all constraints, and for every constraint the
+//! prover claims the grand products
+//!
+//! $$
+//! \prod_x \left(\beta + \sum_j \alpha^j a_j(x)\right) = \prod_x \left(\beta + \sum_j \alpha^j b_j(x)\right),
+//! $$
+//!
+//! which are proved in the same batch as the other grand products with
+//! [`gkr_gpa`](crate::protocols::gkr_gpa).
+
+use std::collections::HashMap;
+
+use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, TowerField};
+use binius_macros::{DeserializeBytes, SerializeBytes};
+
+use super::error::{Error, VerificationError};
+use crate::{
+	oracle::{MultilinearOracleSet, OracleId},
+	witness::MultilinearExtensionIndex,
+};
+
+/// A constraint that the rows of the oracles in `a` are a permutation of the rows of the oracles
+/// in `b`.
+///
+/// Both sides must have the same, non-zero number of oracles, and all oracles must have the same
+/// number of variables.
+#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+pub struct Permutation {
+	pub a: Vec<OracleId>,
+	pub b: Vec<OracleId>,
+}
+
+impl Permutation {
+	/// Returns the number of variables of the permuted oracles.
+	pub fn n_vars<F: TowerField>(&self, oracles: &MultilinearOracleSet<F>) -> Result<usize, Error> {
+		if self.a.is_empty() || self.a.len() != self.b.len() {
+			return Err(Error::PermutationWidthMismatch {
+				a: self.a.len(),
+				b: self.b.len(),
+			});
+		}
+
+		let n_vars = oracles.n_vars(self.a[0]);
+		for &id in self.a.iter().chain(&self.b) {
+			let oracle_n_vars = oracles.n_vars(id);
+			if oracle_n_vars != n_vars {
+				return Err(Error::PermutationNvarsMismatch {
+					expected: n_vars,
+					got: oracle_n_vars,
+				});
+			}
+		}
+		Ok(n_vars)
+	}
+}
+
+/// Checks that the witness satisfies all permutation constraints.
+pub fn validate_witness<U, F>(
+	witness: &MultilinearExtensionIndex<U, F>,
+	oracles: &MultilinearOracleSet<F>,
+	permutations: &[Permutation],
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	for (index, permutation) in permutations.iter().enumerate() {
+		let n_vars = permutation.n_vars(oracles)?;
+
+		let mut multiplicities = HashMap::<Vec<F>, i64>::new();
+		for (side, sign) in [(&permutation.a, 1), (&permutation.b, -1)] {
+			let polys = side
+				.iter()
+				.map(|&id| witness.get_multilin_poly(id))
+				.collect::<Result<Vec<_>, _>>()?;
+			for i in 0..1 << n_vars {
+				let row = polys
+					.iter()
+					.map(|poly| poly.evaluate_on_hypercube(i))
+					.collect::<Result<Vec<_>, _>>()?;
+				*multiplicities.entry(row).or_default() += sign;
+			}
+		}
+
+		if multiplicities
+			.values()
+			.any(|&multiplicity| multiplicity != 0)
+		{
+			return Err(VerificationError::PermutationMismatch { index }.into());
+		}
+	}
+	Ok(())
+}
+
+/// Adds the oracles whose grand products prove the permutation constraints.
+///
+/// Returns the oracle ids of the `a` and `b` sides of every constraint, interleaved.
+pub fn make_permutation_oracles<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	permutations: &[Permutation],
+	mixing_challenge: F,
+	shift_challenge: F,
+) -> Result<Vec<OracleId>, Error> {
+	let max_width = permutations
+		.iter()
+		.map(|permutation| permutation.a.len())
+		.max()
+		.unwrap_or(0);
+	let mixing_powers =
+		std::iter::successors(Some(F::ONE), |power| Some(*power * mixing_challenge))
+			.take(max_width)
+			.collect::<Vec<_>>();
+
+	let mut ids = Vec::with_capacity(2 * permutations.len());
+	for (index, permutation) in permutations.iter().enumerate() {
+		let n_vars = permutation.n_vars(oracles)?;
+		for (side_name, side) in [("a", &permutation.a), ("b", &permutation.b)] {
+			let id = oracles
+				.add_named(format!("permutation index={index} side={side_name}"))
+				.linear_combination_with_offset(
+					n_vars,
+					shift_challenge,
+					side.iter().copied().zip(mixing_powers.iter().copied()),
+				)?;
+			ids.push(id);
+		}
+	}
+	Ok(ids)
+}
+
+/// Checks that the grand products of both sides of every permutation constraint are equal.
+///
+/// The products are interleaved in the order returned by [`make_permutation_oracles`].
+pub fn verify_permutation_products<F: TowerField>(products: &[F]) -> Result<(), Error> {
+	for (index, pair) in products.chunks_exact(2).enumerate() {
+		if pair[0] != pair[1] {
+			return Err(VerificationError::PermutationMismatch { index }.into());
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+
+	use super::*;
+
+	#[test]
+	fn test_verify_permutation_products() {
+		let products = [3, 3, 5, 5].map(BinaryField128b::new);
+		assert!(verify_permutation_products(&products).is_ok());
+
+		let products = [3, 3, 5, 6].map(BinaryField128b::new);
+		assert!(matches!(
+			verify_permutation_products(&products),
+			Err(Error::Verification(VerificationError::PermutationMismatch { index: 1 }))
+		));
+	}
+}
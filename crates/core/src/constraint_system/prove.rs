@@ -10,24 +10,25 @@ use binius_field::{
 	RepackedExtension, TowerField,
 };
 use binius_hal::ComputationBackend;
-use binius_hash::PseudoCompressionFunction;
+use binius_hash::{multi_digest::ParallelDigest, PseudoCompressionFunction};
 use binius_math::{
 	DefaultEvaluationDomainFactory, EvaluationDomainFactory, EvaluationOrder,
 	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension, MultilinearPoly,
 };
 use binius_maybe_rayon::prelude::*;
-use binius_utils::bail;
-use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+use binius_utils::{bail, memory, sparse_index::SparseIndex};
+use digest::{core_api::BlockSizeUser, FixedOutputReset, Output};
 use either::Either;
 use itertools::{chain, izip};
 use tracing::instrument;
 
 use super::{
-	channel::Boundary,
+	channel::{Boundary, Flush, FlushMultiplicity},
 	error::Error,
+	permutation::make_permutation_oracles,
 	verify::{
-		get_post_flush_sumcheck_eval_claims_without_eq, make_flush_oracles,
-		max_n_vars_and_skip_rounds, reorder_for_flushing_by_n_vars,
+		expand_flush_multiplicities, get_post_flush_sumcheck_eval_claims_without_eq,
+		make_flush_oracles, max_n_vars_and_skip_rounds, reorder_for_flushing_by_n_vars,
 	},
 	ConstraintSystem, Proof,
 };
@@ -40,9 +41,8 @@ use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeProver,
 	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
-	piop,
+	piop::{self, FRIPCSProver, PolynomialCommitmentProver},
 	protocols::{
-		fri::CommitOutput,
 		gkr_exp,
 		gkr_gpa::{self, GrandProductBatchProveOutput, GrandProductWitness, LayerClaim},
 		greedy_evalcheck,
@@ -51,7 +51,7 @@ use crate::{
 			prove::{
 				eq_ind::EqIndSumcheckProverBuilder, SumcheckProver, UnivariateZerocheckProver,
 			},
-			standard_switchover_heuristic, zerocheck,
+			standard_small_value_heuristic, standard_switchover_heuristic, zerocheck,
 		},
 	},
 	ring_switch,
@@ -60,22 +60,92 @@ use crate::{
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
+/// The maximum number of multilinear zerocheck rounds proven with the small-value optimization.
+///
+/// The accumulator precomputation grows exponentially with the number of rounds, and
+/// [`standard_small_value_heuristic`] picks fewer rounds for higher composition degrees.
+const MAX_SMALL_VALUE_ROUNDS: usize = 4;
+
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
-#[instrument("constraint_system::prove", skip_all, level = "debug")]
+///
+/// `Hash` is used to hash the Merkle tree leaves of the polynomial commitments. Any scalar digest
+/// can be used here, or a multi-lane digest such as
+/// [`binius_hash::groestl::Groestl256ParallelDigest`] that hashes several leaves at once. The
+/// proof only depends on the underlying scalar digest `Hash::Digest`.
 pub fn prove<U, Tower, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
 	security_bits: usize,
 	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	Compress: PseudoCompressionFunction<Output<Hash::Digest>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	prove_with_pcs::<U, Tower, _, Challenger_, Backend>(
+		constraint_system,
+		boundaries,
+		witness,
+		|commit_meta| {
+			let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
+			let pcs_prover = FRIPCSProver::<_, FDomain<Tower>, FEncode<Tower>, _, _>::new(
+				commit_meta,
+				merkle_prover,
+				DefaultEvaluationDomainFactory::default(),
+				security_bits,
+				log_inv_rate,
+			)?;
+			Ok(pcs_prover)
+		},
+		backend,
+	)
+}
+
+/// Generates a proof that a witness satisfies a constraint system with the given polynomial
+/// commitment scheme.
+///
+/// The PCS prover is created by `make_pcs_prover` once the shape of the committed batch is known.
+/// The proof must be verified with [`super::verify_with_pcs`] and the matching scheme.
+#[instrument(
+	"constraint_system::prove",
+	skip_all,
+	fields(peak_rss_kib = tracing::field::Empty),
+	level = "debug"
+)]
+pub fn prove_with_pcs<U, Tower, PCSProver, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	boundaries: &[Boundary<FExt<Tower>>],
 	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	make_pcs_prover: impl FnOnce(&piop::CommitMeta) -> Result<PCSProver, Error>,
 	backend: &Backend,
 ) -> Result<Proof, Error>
 where
 	U: ProverTowerUnderlier<Tower>,
 	Tower: ProverTowerFamily,
 	Tower::B128: PackedTop<Tower>,
-	Hash: Digest + BlockSizeUser + FixedOutputReset + Send + Sync + Clone,
-	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	PCSProver: PolynomialCommitmentProver<FExt<Tower>, PackedType<U, FExt<Tower>>>,
 	Challenger_: Challenger + Default,
 	Backend: ComputationBackend,
 	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
@@ -113,6 +183,7 @@ where
 		mut exponents,
 		non_zero_oracle_ids,
 		max_channel_id,
+		permutations,
 	} = constraint_system.clone();
 
 	exponents.sort_by_key(|b| std::cmp::Reverse(b.n_vars(&oracles)));
@@ -125,9 +196,6 @@ where
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
 
 	// Commit polynomials
-	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
-	let merkle_scheme = merkle_prover.scheme();
-
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
 	let committed_multilins = piop::collect_committed_witnesses(
 		&commit_meta,
@@ -136,22 +204,18 @@ where
 		&witness,
 	)?;
 
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
-	let CommitOutput {
-		commitment,
-		committed,
-		codeword,
-	} = piop::commit(&fri_params, &merkle_prover, &committed_multilins)?;
+	let pcs_prover = make_pcs_prover(&commit_meta)?;
+	let (commitment, committed) = pcs_prover.commit(&committed_multilins, backend)?;
 
 	// Observe polynomial commitment
 	let mut writer = transcript.message();
 	writer.write(&commitment);
 
+	// Move the committed witnesses into memory-mapped spill files. Later phases page in only what
+	// they read, and the pages are evicted again before the phases that do not read them.
+	drop(committed_multilins);
+	spill_committed_witnesses::<U, Tower>(&mut witness, &oracles, &oracle_to_commit_index)?;
+
 	// GKR exp
 	let exp_challenge = transcript.sample_vec(exp::max_n_vars(&exponents, &oracles));
 
@@ -191,7 +255,7 @@ where
 		make_fast_masked_flush_witnesses(&oracles, &witness, &non_zero_oracle_ids, None)?;
 	let non_zero_prodcheck_witnesses = non_zero_fast_witnesses
 		.into_par_iter()
-		.map(GrandProductWitness::new)
+		.map(|witness| GrandProductWitness::new(witness, backend))
 		.collect::<Result<Vec<_>, _>>()?;
 
 	let non_zero_products =
@@ -218,6 +282,9 @@ where
 	let permutation_challenges = transcript.sample_vec(max_channel_id + 1);
 
 	flushes.sort_by_key(|flush| flush.channel_id);
+	let unexpanded_flushes = flushes;
+	let flushes = expand_flush_multiplicities(&mut oracles, unexpanded_flushes.clone())?;
+	make_multiplicity_selector_witnesses(&mut witness, &unexpanded_flushes, &flushes)?;
 	let flush_oracle_ids =
 		make_flush_oracles(&mut oracles, &flushes, mixing_challenge, &permutation_challenges)?;
 	let flush_selectors = flushes
@@ -237,7 +304,7 @@ where
 	// This is important to do in parallel.
 	let flush_prodcheck_witnesses = flush_witnesses
 		.into_par_iter()
-		.map(GrandProductWitness::new)
+		.map(|witness| GrandProductWitness::new(witness, backend))
 		.collect::<Result<Vec<_>, _>>()?;
 	let flush_products = gkr_gpa::get_grand_products_from_witnesses(&flush_prodcheck_witnesses);
 
@@ -246,11 +313,49 @@ where
 	let flush_prodcheck_claims =
 		gkr_gpa::construct_grand_product_claims(&flush_oracle_ids, &oracles, &flush_products)?;
 
+	// Grand products for permutation constraints
+	let permutation_oracle_ids = if permutations.is_empty() {
+		Vec::new()
+	} else {
+		let mixing_challenge = transcript.sample();
+		let shift_challenge = transcript.sample();
+		make_permutation_oracles(&mut oracles, &permutations, mixing_challenge, shift_challenge)?
+	};
+
+	make_unmasked_flush_witnesses(&oracles, &mut witness, &permutation_oracle_ids)?;
+	let permutation_witnesses =
+		make_fast_masked_flush_witnesses(&oracles, &witness, &permutation_oracle_ids, None)?;
+	let permutation_prodcheck_witnesses = permutation_witnesses
+		.into_par_iter()
+		.map(|witness| GrandProductWitness::new(witness, backend))
+		.collect::<Result<Vec<_>, _>>()?;
+	let permutation_products =
+		gkr_gpa::get_grand_products_from_witnesses(&permutation_prodcheck_witnesses);
+
+	transcript
+		.message()
+		.write_scalar_slice(&permutation_products);
+
+	let permutation_prodcheck_claims = gkr_gpa::construct_grand_product_claims(
+		&permutation_oracle_ids,
+		&oracles,
+		&permutation_products,
+	)?;
+
+	// The grand product provers only read their own layers, not the committed witnesses
+	witness.evict_spilled()?;
+
 	// Prove grand products
-	let all_gpa_witnesses = [flush_prodcheck_witnesses, non_zero_prodcheck_witnesses].concat();
-	let all_gpa_claims = chain!(flush_prodcheck_claims, non_zero_prodcheck_claims)
-		.map(|claim| claim.isomorphic())
-		.collect::<Vec<_>>();
+	let all_gpa_witnesses = [
+		flush_prodcheck_witnesses,
+		permutation_prodcheck_witnesses,
+		non_zero_prodcheck_witnesses,
+	]
+	.concat();
+	let all_gpa_claims =
+		chain!(flush_prodcheck_claims, permutation_prodcheck_claims, non_zero_prodcheck_claims)
+			.map(|claim| claim.isomorphic())
+			.collect::<Vec<_>>();
 
 	let GrandProductBatchProveOutput { final_layer_claims } =
 		gkr_gpa::batch_prove::<FFastExt<Tower>, _, FFastExt<Tower>, _, _>(
@@ -268,12 +373,16 @@ where
 		.map(|layer_claim| layer_claim.isomorphic())
 		.collect::<Vec<_>>();
 
-	let non_zero_final_layer_claims = final_layer_claims.split_off(flush_oracle_ids.len());
+	let non_zero_final_layer_claims =
+		final_layer_claims.split_off(flush_oracle_ids.len() + permutation_oracle_ids.len());
+	let permutation_final_layer_claims = final_layer_claims.split_off(flush_oracle_ids.len());
 	let flush_final_layer_claims = final_layer_claims;
 
-	// Reduce non_zero_final_layer_claims to evalcheck claims
+	// Reduce non_zero_final_layer_claims and permutation_final_layer_claims to evalcheck claims
 	let non_zero_prodcheck_eval_claims =
 		gkr_gpa::make_eval_claims(non_zero_oracle_ids, non_zero_final_layer_claims)?;
+	let permutation_eval_claims =
+		gkr_gpa::make_eval_claims(permutation_oracle_ids, permutation_final_layer_claims)?;
 
 	// Reduce flush_final_layer_claims to sumcheck claims then evalcheck claims
 	let (flush_oracle_ids, flush_selectors, flush_final_layer_claims) =
@@ -448,11 +557,15 @@ where
 	let eval_claims = greedy_evalcheck::prove::<_, _, FDomain<Tower>, _, _>(
 		&mut oracles,
 		&mut witness,
-		[non_zero_prodcheck_eval_claims, flush_eval_claims]
-			.concat()
-			.into_iter()
-			.chain(zerocheck_eval_claims)
-			.chain(exp_eval_claims),
+		[
+			non_zero_prodcheck_eval_claims,
+			flush_eval_claims,
+			permutation_eval_claims,
+		]
+		.concat()
+		.into_iter()
+		.chain(zerocheck_eval_claims)
+		.chain(exp_eval_claims),
 		switchover_fn,
 		&mut transcript,
 		&domain_factory,
@@ -467,36 +580,53 @@ where
 		&eval_claims,
 	)?;
 
+	let committed_multilins = piop::collect_committed_witnesses(
+		&commit_meta,
+		&oracle_to_commit_index,
+		&oracles,
+		&witness,
+	)?;
+	let unpacked_multilins = system
+		.unpacked_ids
+		.iter()
+		.map(|&id| witness.get_multilin_poly(id))
+		.collect::<Result<Vec<_>, _>>()?;
+
 	let ring_switch::ReducedWitness {
 		transparents: transparent_multilins,
 		sumcheck_claims: piop_sumcheck_claims,
 	} = ring_switch::prove::<_, _, _, Tower, _, _>(
 		&system,
 		&committed_multilins,
+		&unpacked_multilins,
 		&mut transcript,
 		backend,
 	)?;
 
-	// Prove evaluation claims using PIOP compiler
-	piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _>(
-		&fri_params,
-		&merkle_prover,
-		domain_factory,
-		&commit_meta,
+	// Prove evaluation claims using the polynomial commitment scheme
+	pcs_prover.prove(
 		committed,
-		&codeword,
 		&committed_multilins,
 		&transparent_multilins,
 		&piop_sumcheck_claims,
 		&mut transcript,
-		&backend,
+		backend,
 	)?;
 
+	record_peak_rss();
 	Ok(Proof {
 		transcript: transcript.finalize(),
 	})
 }
 
+/// Records the peak resident set size of the process so far on the `peak_rss_kib` field of the
+/// current span.
+fn record_peak_rss() {
+	if let Some(peak_rss_kib) = memory::peak_rss_kib() {
+		tracing::Span::current().record("peak_rss_kib", peak_rss_kib);
+	}
+}
+
 type TypeErasedUnivariateZerocheck<'a, F> = Box<dyn UnivariateZerocheckProver<'a, F> + 'a>;
 type TypeErasedSumcheck<'a, F> = Box<dyn SumcheckProver<F> + 'a>;
 type TypeErasedProver<'a, F> =
@@ -546,6 +676,9 @@ where
 				self.backend,
 			)?;
 
+		let univariate_prover = univariate_prover
+			.with_small_value_heuristic(standard_small_value_heuristic(MAX_SMALL_VALUE_ROUNDS));
+
 		let type_erased_prover = if is_univariate(univariate_prover.n_vars()) {
 			let type_erased_univariate_prover =
 				Box::new(univariate_prover) as TypeErasedUnivariateZerocheck<'a, P::Scalar>;
@@ -563,6 +696,58 @@ where
 	}
 }
 
+/// Moves the committed witnesses into the spill directory of the witness index, if one is set.
+///
+/// The witnesses of other oracles that share the evaluations of a committed witness, such as
+/// packed views of it, are moved onto the same spill file, so that the evaluations are released
+/// from memory.
+#[instrument(skip_all, fields(peak_rss_kib = tracing::field::Empty), level = "debug")]
+fn spill_committed_witnesses<U, Tower>(
+	witness: &mut MultilinearExtensionIndex<U, FExt<Tower>>,
+	oracles: &MultilinearOracleSet<FExt<Tower>>,
+	oracle_to_commit_index: &SparseIndex<usize>,
+) -> Result<(), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+{
+	if witness.spill_dir().is_none() {
+		return Ok(());
+	}
+
+	for (oracle_id, _) in oracle_to_commit_index.iter() {
+		match witness.get_multilin_poly(oracle_id)?.log_extension_degree() {
+			7 => witness.spill::<Tower::B1>(oracle_id)?,
+			4 => witness.spill::<Tower::B8>(oracle_id)?,
+			3 => witness.spill::<Tower::B16>(oracle_id)?,
+			2 => witness.spill::<Tower::B32>(oracle_id)?,
+			1 => witness.spill::<Tower::B64>(oracle_id)?,
+			0 => witness.spill::<Tower::B128>(oracle_id)?,
+			_ => bail!(Error::IncorrectTowerLevel),
+		}
+	}
+
+	for oracle_id in 0..oracles.size() {
+		if oracle_to_commit_index.get(oracle_id).is_some() || !witness.has(oracle_id) {
+			continue;
+		}
+		match witness.get_multilin_poly(oracle_id)?.log_extension_degree() {
+			7 => witness.spill_alias::<Tower::B1>(oracle_id)?,
+			4 => witness.spill_alias::<Tower::B8>(oracle_id)?,
+			3 => witness.spill_alias::<Tower::B16>(oracle_id)?,
+			2 => witness.spill_alias::<Tower::B32>(oracle_id)?,
+			1 => witness.spill_alias::<Tower::B64>(oracle_id)?,
+			0 => witness.spill_alias::<Tower::B128>(oracle_id)?,
+			// Witnesses of other degrees cannot share the evaluations of a committed witness
+			_ => {}
+		}
+	}
+
+	// The peak so far includes the committed witnesses and their encoding
+	record_peak_rss();
+	Ok(())
+}
+
 #[instrument(skip_all, level = "debug")]
 fn make_unmasked_flush_witnesses<'a, U, Tower>(
 	oracles: &MultilinearOracleSet<FExt<Tower>>,
@@ -618,6 +803,65 @@ where
 	Ok(())
 }
 
+/// Computes the witnesses of the selectors that [`expand_flush_multiplicities`] adds for the bits
+/// of the flush multiplicities, each the product of a flush selector and a multiplicity bit.
+#[instrument(skip_all, level = "debug")]
+fn make_multiplicity_selector_witnesses<U, Tower>(
+	witness: &mut MultilinearExtensionIndex<U, FExt<Tower>>,
+	flushes: &[Flush],
+	expanded_flushes: &[Flush],
+) -> Result<(), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+{
+	let bit_ids = flushes.iter().flat_map(|flush| match &flush.multiplicity {
+		FlushMultiplicity::Constant(_) => vec![(flush.selector, None)],
+		FlushMultiplicity::Bits(bit_ids) => bit_ids
+			.iter()
+			.map(|&bit_id| (flush.selector, Some(bit_id)))
+			.collect(),
+	});
+	let products = izip!(bit_ids, expanded_flushes)
+		.filter_map(|((selector, bit_id), expanded_flush)| {
+			bit_id.map(|bit_id| (expanded_flush.selector, selector, bit_id))
+		})
+		.collect::<Vec<_>>();
+
+	let log_width = <PackedType<U, FExt<Tower>>>::LOG_WIDTH;
+	let selector_witnesses = products
+		.into_par_iter()
+		.map(|(product_id, selector, bit_id)| {
+			let selector = witness.get_multilin_poly(selector)?;
+			let bit = witness.get_multilin_poly(bit_id)?;
+			let n_vars = selector.n_vars();
+
+			let data = (0..1 << n_vars.saturating_sub(log_width))
+				.into_par_iter()
+				.map(|i| {
+					<PackedType<U, FExt<Tower>>>::from_fn(|j| {
+						let index = i << log_width | j;
+						let selector_eval = selector
+							.evaluate_on_hypercube(index)
+							.unwrap_or(<FExt<Tower>>::ZERO);
+						let bit_eval = bit
+							.evaluate_on_hypercube(index)
+							.unwrap_or(<FExt<Tower>>::ZERO);
+						selector_eval * bit_eval
+					})
+				})
+				.collect::<Vec<_>>();
+			let product = MultilinearExtension::new(n_vars, data)
+				.expect("data is constructed with the correct length with respect to n_vars");
+
+			Ok((product_id, MLEDirectAdapter::from(product).upcast_arc_dyn()))
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	witness.update_multilin_poly(selector_witnesses)?;
+	Ok(())
+}
+
 #[allow(clippy::type_complexity)]
 #[instrument(skip_all, level = "debug")]
 fn make_fast_masked_flush_witnesses<'a, U, Tower>(
@@ -714,6 +958,7 @@ pub struct FlushSumcheckProvers<Prover> {
 	flush_selectors_unique_by_claim: Vec<Vec<OracleId>>,
 }
 
+#[allow(clippy::needless_pass_by_ref_mut)]
 #[instrument(skip_all, level = "debug")]
 fn get_flush_sumcheck_provers<'a, 'b, U, Tower, FDomain, DomainFactory, Backend>(
 	oracles: &mut MultilinearOracleSet<Tower::B128>,
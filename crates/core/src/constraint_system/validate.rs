@@ -5,12 +5,12 @@ use binius_field::{
 };
 use binius_hal::ComputationBackendExt;
 use binius_math::MultilinearPoly;
-use binius_utils::bail;
+use binius_utils::{bail, checked_arithmetics::log2_strict_usize};
 
 use super::{
 	channel::{self, Boundary},
 	error::Error,
-	ConstraintSystem,
+	permutation, ConstraintSystem,
 };
 use crate::{
 	oracle::{
@@ -72,6 +72,13 @@ where
 		constraint_system.max_channel_id,
 	)?;
 
+	// Check that the permuted oracles contain the same rows
+	permutation::validate_witness(
+		witness,
+		&constraint_system.oracles,
+		&constraint_system.permutations,
+	)?;
+
 	// Check consistency of virtual oracle witnesses (eg. that shift polynomials are actually shifts).
 	for oracle in constraint_system.oracles.iter() {
 		validate_virtual_oracle_witness(oracle, &constraint_system.oracles, witness)?;
@@ -237,6 +244,51 @@ where
 				check_eval(oracle_label, i, F::ZERO, poly.evaluate_on_hypercube(i)?)?;
 			}
 		}
+		MultilinearPolyVariant::Interleaved(inner_ids) => {
+			let inner_polys = inner_ids
+				.iter()
+				.map(|&id| witness.get_multilin_poly(id))
+				.collect::<Result<Vec<_>, _>>()?;
+			let log_count = log2_strict_usize(inner_polys.len());
+			for i in 0..1 << n_vars {
+				check_eval(
+					oracle_label,
+					i,
+					inner_polys[i % (1 << log_count)].evaluate_on_hypercube(i >> log_count)?,
+					poly.evaluate_on_hypercube(i)?,
+				)?;
+			}
+		}
+		MultilinearPolyVariant::Strided(strided) => {
+			let unstrided_poly = witness.get_multilin_poly(strided.id())?;
+			for i in 0..1 << n_vars {
+				check_eval(
+					oracle_label,
+					i,
+					unstrided_poly
+						.evaluate_on_hypercube((i << strided.log_stride()) | strided.offset())?,
+					poly.evaluate_on_hypercube(i)?,
+				)?;
+			}
+		}
+		MultilinearPolyVariant::Concatenated(inner_ids) => {
+			let mut offset = 0;
+			for inner_id in inner_ids {
+				let piece_poly = witness.get_multilin_poly(inner_id)?;
+				for i in 0..1 << piece_poly.n_vars() {
+					check_eval(
+						oracle_label,
+						offset + i,
+						piece_poly.evaluate_on_hypercube(i)?,
+						poly.evaluate_on_hypercube(offset + i)?,
+					)?;
+				}
+				offset += 1 << piece_poly.n_vars();
+			}
+			for i in offset..1 << n_vars {
+				check_eval(oracle_label, i, F::ZERO, poly.evaluate_on_hypercube(i)?)?;
+			}
+		}
 		MultilinearPolyVariant::Packed(ref packed) => {
 			let expected = witness.get_multilin_poly(packed.id())?;
 			let got = witness.get_multilin_poly(oracle.id())?;
@@ -246,6 +298,15 @@ where
 				});
 			}
 		}
+		MultilinearPolyVariant::Unpacked(ref unpacked) => {
+			let expected = witness.get_multilin_poly(unpacked.id())?;
+			let got = witness.get_multilin_poly(oracle.id())?;
+			if expected.packed_evals() != got.packed_evals() {
+				return Err(Error::PackedUnderlierMismatch {
+					oracle: oracle_label.into(),
+				});
+			}
+		}
 		MultilinearPolyVariant::Composite(composite_mle) => {
 			let inner_polys = composite_mle
 				.polys()
@@ -13,18 +13,20 @@ use tracing::instrument;
 use super::{
 	channel::Boundary,
 	error::{Error, VerificationError},
-	exp, ConstraintSystem, Proof,
+	exp,
+	permutation::{make_permutation_oracles, verify_permutation_products},
+	ConstraintSystem, Proof,
 };
 use crate::{
 	composition::IndexComposition,
 	constraint_system::{
-		channel::{Flush, FlushDirection},
+		channel::{Flush, FlushDirection, FlushMultiplicity, MAX_MULTIPLICITY_BITS},
 		common::{FDomain, FEncode, FExt},
 	},
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeScheme,
 	oracle::{MultilinearOracleSet, OracleId},
-	piop,
+	piop::{self, PolynomialCommitmentScheme, FRIPCS},
 	polynomial::MultivariatePoly,
 	protocols::{
 		evalcheck::EvalcheckMultilinearClaim,
@@ -42,8 +44,7 @@ use crate::{
 	transparent::eq_ind::EqIndPartialEval,
 };
 
-/// Verifies a proof against a constraint system.
-#[instrument("constraint_system::verify", skip_all, level = "debug")]
+/// Verifies a proof against a constraint system with the standard FRI PCS.
 pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
@@ -58,6 +59,34 @@ where
 	Hash: Digest + BlockSizeUser,
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
+{
+	verify_with_pcs::<Tower, _, Challenger_>(constraint_system, boundaries, proof, |commit_meta| {
+		let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
+		let pcs = FRIPCS::<_, FEncode<Tower>, _>::new(
+			commit_meta,
+			merkle_scheme,
+			security_bits,
+			log_inv_rate,
+		)?;
+		Ok(pcs)
+	})
+}
+
+/// Verifies a proof against a constraint system with the given polynomial commitment scheme.
+///
+/// The scheme is created by `make_pcs` once the shape of the committed batch is known.
+#[instrument("constraint_system::verify", skip_all, level = "debug")]
+pub fn verify_with_pcs<Tower, PCS, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+	make_pcs: impl FnOnce(&piop::CommitMeta) -> Result<PCS, Error>,
+) -> Result<(), Error>
+where
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	PCS: PolynomialCommitmentScheme<FExt<Tower>>,
+	Challenger_: Challenger + Default,
 {
 	let ConstraintSystem {
 		mut oracles,
@@ -66,6 +95,7 @@ where
 		non_zero_oracle_ids,
 		max_channel_id,
 		mut exponents,
+		permutations,
 		..
 	} = constraint_system.clone();
 
@@ -77,18 +107,12 @@ where
 	let mut transcript = VerifierTranscript::<Challenger_>::new(transcript);
 	transcript.observe().write_slice(boundaries);
 
-	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
-	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
-		&commit_meta,
-		&merkle_scheme,
-		security_bits,
-		log_inv_rate,
-	)?;
+	let pcs = make_pcs(&commit_meta)?;
 
 	// Read polynomial commitment polynomials
 	let mut reader = transcript.message();
-	let commitment = reader.read::<Output<Hash>>()?;
+	let commitment = reader.read::<PCS::Commitment>()?;
 
 	// GKR exp multiplication
 	exponents.sort_by_key(|b| std::cmp::Reverse(b.n_vars(&oracles)));
@@ -130,6 +154,7 @@ where
 	let permutation_challenges = transcript.sample_vec(max_channel_id + 1);
 
 	flushes.sort_by_key(|flush| flush.channel_id);
+	let flushes = expand_flush_multiplicities(&mut oracles, flushes)?;
 	let flush_oracle_ids =
 		make_flush_oracles(&mut oracles, &flushes, mixing_challenge, &permutation_challenges)?;
 	let flush_selectors = flushes
@@ -151,19 +176,48 @@ where
 	let flush_prodcheck_claims =
 		gkr_gpa::construct_grand_product_claims(&flush_oracle_ids, &oracles, &flush_products)?;
 
+	// Grand products for permutation constraints
+	let permutation_oracle_ids = if permutations.is_empty() {
+		Vec::new()
+	} else {
+		let mixing_challenge = transcript.sample();
+		let shift_challenge = transcript.sample();
+		make_permutation_oracles(&mut oracles, &permutations, mixing_challenge, shift_challenge)?
+	};
+
+	let permutation_products = transcript
+		.message()
+		.read_scalar_slice(permutation_oracle_ids.len())?;
+	verify_permutation_products(&permutation_products)?;
+
+	let permutation_prodcheck_claims = gkr_gpa::construct_grand_product_claims(
+		&permutation_oracle_ids,
+		&oracles,
+		&permutation_products,
+	)?;
+
 	// Verify grand products
 	let mut final_layer_claims = gkr_gpa::batch_verify(
 		EvaluationOrder::LowToHigh,
-		[flush_prodcheck_claims, non_zero_prodcheck_claims].concat(),
+		[
+			flush_prodcheck_claims,
+			permutation_prodcheck_claims,
+			non_zero_prodcheck_claims,
+		]
+		.concat(),
 		&mut transcript,
 	)?;
 
-	let non_zero_final_layer_claims = final_layer_claims.split_off(flush_oracle_ids.len());
+	let non_zero_final_layer_claims =
+		final_layer_claims.split_off(flush_oracle_ids.len() + permutation_oracle_ids.len());
+	let permutation_final_layer_claims = final_layer_claims.split_off(flush_oracle_ids.len());
 	let flush_final_layer_claims = final_layer_claims;
 
-	// Reduce non_zero_final_layer_claims to evalcheck claims
+	// Reduce non_zero_final_layer_claims and permutation_final_layer_claims to evalcheck claims
 	let non_zero_prodcheck_eval_claims =
 		gkr_gpa::make_eval_claims(non_zero_oracle_ids, non_zero_final_layer_claims)?;
+	let permutation_eval_claims =
+		gkr_gpa::make_eval_claims(permutation_oracle_ids, permutation_final_layer_claims)?;
 
 	// Reduce flush_final_layer_claims to sumcheck claims then evalcheck claims
 	let (flush_oracle_ids, flush_selectors, flush_final_layer_claims) =
@@ -298,11 +352,15 @@ where
 	// Evalcheck
 	let eval_claims = greedy_evalcheck::verify(
 		&mut oracles,
-		[non_zero_prodcheck_eval_claims, flush_eval_claims]
-			.concat()
-			.into_iter()
-			.chain(zerocheck_eval_claims)
-			.chain(exp_eval_claims),
+		[
+			non_zero_prodcheck_eval_claims,
+			flush_eval_claims,
+			permutation_eval_claims,
+		]
+		.concat()
+		.into_iter()
+		.chain(zerocheck_eval_claims)
+		.chain(exp_eval_claims),
 		&mut transcript,
 	)?;
 
@@ -319,16 +377,8 @@ where
 		sumcheck_claims: piop_sumcheck_claims,
 	} = ring_switch::verify::<_, Tower, _>(&system, &mut transcript)?;
 
-	// Prove evaluation claims using PIOP compiler
-	piop::verify(
-		&commit_meta,
-		&merkle_scheme,
-		&fri_params,
-		&commitment,
-		&transparents,
-		&piop_sumcheck_claims,
-		&mut transcript,
-	)?;
+	// Verify evaluation claims using the polynomial commitment scheme
+	pcs.verify(&commitment, &transparents, &piop_sumcheck_claims, &mut transcript)?;
 
 	transcript.finalize()?;
 
@@ -431,8 +481,10 @@ fn verify_channels_balance<F: TowerField>(
 		let (pull_product, push_product) = flush_iter
 			.peeking_take_while(|(flush, _)| flush.channel_id == channel_id)
 			.fold(boundary_products, |(pull_product, push_product), (flush, flush_product)| {
-				let flush_product_with_multiplicity =
-					flush_product.pow_vartime([flush.multiplicity]);
+				let FlushMultiplicity::Constant(multiplicity) = flush.multiplicity else {
+					unreachable!("expand_flush_multiplicities leaves only constant multiplicities");
+				};
+				let flush_product_with_multiplicity = flush_product.pow_vartime([multiplicity]);
 				match flush.direction {
 					FlushDirection::Pull => {
 						(pull_product * flush_product_with_multiplicity, push_product)
@@ -450,6 +502,53 @@ fn verify_channels_balance<F: TowerField>(
 	Ok(())
 }
 
+/// Replaces every flush with a [`FlushMultiplicity::Bits`] multiplicity by one flush per bit,
+/// keeping the order of the flushes.
+///
+/// The flush for bit $k$ has the constant multiplicity $2^k$ and is selected by the product of
+/// the original selector and the bit oracle, which is added to the oracle set. The grand product
+/// of the expanded flushes raised to their multiplicities is then the product of
+/// $(\gamma + v)^m$ over the rows of the original flush.
+pub fn expand_flush_multiplicities<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	flushes: Vec<Flush>,
+) -> Result<Vec<Flush>, Error> {
+	let mut expanded = Vec::with_capacity(flushes.len());
+	for flush in flushes {
+		let FlushMultiplicity::Bits(ref bit_ids) = flush.multiplicity else {
+			expanded.push(flush);
+			continue;
+		};
+		if bit_ids.len() > MAX_MULTIPLICITY_BITS {
+			bail!(Error::TooManyMultiplicityBits {
+				max: MAX_MULTIPLICITY_BITS,
+				got: bit_ids.len(),
+			});
+		}
+		let n_vars = oracles.n_vars(flush.selector);
+		for (k, &bit_id) in bit_ids.iter().enumerate() {
+			if oracles.tower_level(bit_id) != 0 {
+				bail!(Error::NonBinaryMultiplicityBit { id: bit_id });
+			}
+			let selector = oracles
+				.add_named(format!("flush selector channel_id={} bit={k}", flush.channel_id))
+				.composite_mle(
+					n_vars,
+					[flush.selector, bit_id],
+					ArithExpr::Var(0) * ArithExpr::Var(1),
+				)?;
+			expanded.push(Flush {
+				oracles: flush.oracles.clone(),
+				channel_id: flush.channel_id,
+				direction: flush.direction,
+				selector,
+				multiplicity: FlushMultiplicity::Constant(1 << k),
+			});
+		}
+	}
+	Ok(expanded)
+}
+
 pub fn make_flush_oracles<F: TowerField>(
 	oracles: &mut MultilinearOracleSet<F>,
 	flushes: &[Flush],
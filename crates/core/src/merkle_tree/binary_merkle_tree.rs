@@ -1,10 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, mem::MaybeUninit};
+use std::{fmt::Debug, mem::MaybeUninit};
 
 use binius_field::TowerField;
+use binius_hal::ComputationBackend;
 use binius_hash::{multi_digest::ParallelDigest, PseudoCompressionFunction};
-use binius_maybe_rayon::{prelude::*, slice::ParallelSlice};
+use binius_maybe_rayon::prelude::*;
 use binius_utils::{bail, checked_arithmetics::log2_strict_usize};
 use digest::{crypto_common::BlockSizeUser, FixedOutputReset, Output};
 use tracing::instrument;
@@ -24,15 +25,17 @@ pub struct BinaryMerkleTree<D> {
 	pub inner_nodes: Vec<D>,
 }
 
-pub fn build<F, H, C>(
+pub fn build<F, H, C, Backend>(
 	compression: &C,
 	elements: &[F],
 	batch_size: usize,
+	backend: &Backend,
 ) -> Result<BinaryMerkleTree<Output<H::Digest>>, Error>
 where
 	F: TowerField,
 	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
 	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
+	Backend: ComputationBackend,
 {
 	if elements.len() % batch_size != 0 {
 		bail!(Error::IncorrectBatchSize);
@@ -48,20 +51,23 @@ where
 
 	internal_build(
 		compression,
-		|inner_nodes| hash_interleaved::<_, H>(elements, inner_nodes),
+		|inner_nodes| Ok(backend.merkle_hash_leaves::<_, H>(elements, inner_nodes)?),
 		log_len,
+		backend,
 	)
 }
 
-fn internal_build<Digest, C>(
+fn internal_build<Digest, C, Backend>(
 	compression: &C,
 	// Must either successfully initialize the passed in slice or return error
 	hash_leaves: impl FnOnce(&mut [MaybeUninit<Digest>]) -> Result<(), Error>,
 	log_len: usize,
+	backend: &Backend,
 ) -> Result<BinaryMerkleTree<Digest>, Error>
 where
-	Digest: Clone + Send + Sync,
+	Digest: Clone + PartialEq + Send + Sync,
 	C: PseudoCompressionFunction<Digest, 2> + Sync,
+	Backend: ComputationBackend,
 {
 	let total_length = (1 << (log_len + 1)) - 1;
 	let mut inner_nodes = Vec::with_capacity(total_length);
@@ -78,7 +84,7 @@ where
 		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_len - i));
 		remaining = next_remaining;
 
-		compress_layer(compression, prev_layer, next_layer);
+		backend.merkle_compress_layer(compression, prev_layer, next_layer)?;
 
 		prev_layer = unsafe {
 			// SAFETY: next_layer was just initialized by compress_layer
@@ -99,21 +105,26 @@ where
 }
 
 #[instrument("BinaryMerkleTree::build", skip_all, level = "debug")]
-pub fn build_from_iterator<F, H, C, ParIter>(
+pub fn build_from_iterator<F, H, C, ParIter, Backend>(
 	compression: &C,
 	iterated_chunks: ParIter,
 	log_len: usize,
+	backend: &Backend,
 ) -> Result<BinaryMerkleTree<Output<H::Digest>>, Error>
 where
 	F: TowerField,
 	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
 	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync,
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	Backend: ComputationBackend,
 {
+	// The leaves are produced by a CPU-side iterator, so only the inner layers are delegated to
+	// the backend.
 	internal_build(
 		compression,
 		|inner_nodes| hash_iterated::<F, H, _>(iterated_chunks, inner_nodes),
 		log_len,
+		backend,
 	)
 }
 
@@ -155,46 +166,6 @@ impl<D: Clone> BinaryMerkleTree<D> {
 	}
 }
 
-#[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
-fn compress_layer<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
-where
-	D: Clone + Send + Sync,
-	C: PseudoCompressionFunction<D, 2> + Sync,
-{
-	prev_layer
-		.par_chunks_exact(2)
-		.zip(next_layer.par_iter_mut())
-		.for_each(|(prev_pair, next_digest)| {
-			next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
-		})
-}
-
-/// Hashes the elements in chunks of a vector into digests.
-///
-/// Given a vector of elements and an output buffer of N hash digests, this splits the elements
-/// into N equal-sized chunks and hashes each chunks into the corresponding output digest. This
-/// returns the number of elements hashed into each digest.
-#[tracing::instrument("hash_interleaved", skip_all, level = "debug")]
-fn hash_interleaved<F, H>(
-	elems: &[F],
-	digests: &mut [MaybeUninit<Output<H::Digest>>],
-) -> Result<(), Error>
-where
-	F: TowerField,
-	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
-{
-	if elems.len() % digests.len() != 0 {
-		return Err(Error::IncorrectVectorLen {
-			expected: digests.len(),
-		});
-	}
-
-	let hash_data_iter = elems
-		.par_chunks(elems.len() / digests.len())
-		.map(|s| s.iter().copied());
-	hash_iterated::<_, H, _>(hash_data_iter, digests)
-}
-
 fn hash_iterated<F, H, ParIter>(
 	iterated_chunks: ParIter,
 	digests: &mut [MaybeUninit<Output<H::Digest>>],
@@ -14,6 +14,8 @@ pub enum Error {
 	PowerOfTwoLengthRequired,
 	#[error("The layer does not exist in the Merkle tree")]
 	IncorrectLayerDepth,
+	#[error("HAL error: {0}")]
+	Hal(#[from] binius_hal::Error),
 	#[error("transcript error: {0}")]
 	Transcript(#[from] transcript::Error),
 	#[error("verification failure: {0}")]
@@ -1,5 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use binius_hal::ComputationBackend;
 use binius_maybe_rayon::iter::IndexedParallelIterator;
 use bytes::{Buf, BufMut};
 
@@ -79,21 +80,24 @@ pub trait MerkleTreeProver<T>: Sync {
 
 	/// Commit a vector of values.
 	#[allow(clippy::type_complexity)]
-	fn commit(
+	fn commit<Backend: ComputationBackend>(
 		&self,
 		data: &[T],
 		batch_size: usize,
+		backend: &Backend,
 	) -> Result<(Commitment<<Self::Scheme as MerkleTreeScheme<T>>::Digest>, Self::Committed), Error>;
 
 	/// Commit interleaved elements from iterator by val
 	#[allow(clippy::type_complexity)]
-	fn commit_iterated<ParIter>(
+	fn commit_iterated<ParIter, Backend>(
 		&self,
 		iterated_chunks: ParIter,
 		log_len: usize,
+		backend: &Backend,
 	) -> Result<(Commitment<<Self::Scheme as MerkleTreeScheme<T>>::Digest>, Self::Committed), Error>
 	where
-		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = T>>;
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = T>>,
+		Backend: ComputationBackend;
 
 	/// Returns the internal digest layer at the given depth.
 	fn layer<'a>(
@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::TowerField;
+use binius_hal::ComputationBackend;
 use binius_hash::{multi_digest::ParallelDigest, PseudoCompressionFunction};
 use binius_maybe_rayon::iter::IndexedParallelIterator;
 use bytes::BufMut;
@@ -43,13 +44,18 @@ where
 		&self.scheme
 	}
 
-	fn commit(
+	fn commit<Backend: ComputationBackend>(
 		&self,
 		data: &[F],
 		batch_size: usize,
+		backend: &Backend,
 	) -> Result<(Commitment<Output<H::Digest>>, Self::Committed), Error> {
-		let tree =
-			binary_merkle_tree::build::<_, H, _>(self.scheme.compression(), data, batch_size)?;
+		let tree = binary_merkle_tree::build::<_, H, _, _>(
+			self.scheme.compression(),
+			data,
+			batch_size,
+			backend,
+		)?;
 
 		let commitment = Commitment {
 			root: tree.root(),
@@ -81,21 +87,24 @@ where
 
 	#[instrument(skip_all, level = "debug")]
 	#[allow(clippy::type_complexity)]
-	fn commit_iterated<ParIter>(
+	fn commit_iterated<ParIter, Backend>(
 		&self,
 		iterated_chunks: ParIter,
 		log_len: usize,
+		backend: &Backend,
 	) -> Result<
 		(Commitment<<Self::Scheme as super::MerkleTreeScheme<F>>::Digest>, Self::Committed),
 		Error,
 	>
 	where
 		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+		Backend: ComputationBackend,
 	{
-		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _>(
+		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _, _>(
 			self.scheme.compression(),
 			iterated_chunks,
 			log_len,
+			backend,
 		)?;
 
 		let commitment = Commitment {
@@ -4,7 +4,15 @@ use core::slice;
 use std::iter::repeat_with;
 
 use binius_field::{BinaryField16b, Field};
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hal::make_portable_backend;
+use binius_hash::{
+	blake3::{Blake3Compression, Blake3ParallelDigest},
+	groestl::{Groestl256, Groestl256ByteCompression, Groestl256ByteSliced16x},
+	keccak::{Keccak256Compression, Keccak256ParallelDigest},
+	multi_digest::{ParallelDigest, ParallelMulidigestImpl},
+	PseudoCompressionFunction,
+};
+use digest::{core_api::BlockSizeUser, FixedOutputReset, Output};
 use rand::{rngs::StdRng, SeedableRng};
 
 use super::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme};
@@ -19,7 +27,9 @@ fn test_binary_merkle_vcs_commit_prove_open_correctly() {
 	let data = repeat_with(|| Field::random(&mut rng))
 		.take(16)
 		.collect::<Vec<BinaryField16b>>();
-	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+	let (commitment, tree) = mr_prover
+		.commit(&data, 1, &make_portable_backend())
+		.unwrap();
 
 	assert_eq!(commitment.root, tree.root());
 
@@ -53,7 +63,9 @@ fn test_binary_merkle_vcs_commit_layer_prove_open_correctly() {
 	let data = repeat_with(|| Field::random(&mut rng))
 		.take(32)
 		.collect::<Vec<BinaryField16b>>();
-	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+	let (commitment, tree) = mr_prover
+		.commit(&data, 1, &make_portable_backend())
+		.unwrap();
 
 	assert_eq!(commitment.root, tree.root());
 	for layer_depth in 0..5 {
@@ -93,10 +105,72 @@ fn test_binary_merkle_vcs_verify_vector() {
 	let data = repeat_with(|| Field::random(&mut rng))
 		.take(4)
 		.collect::<Vec<BinaryField16b>>();
-	let (commitment, _) = mr_prover.commit(&data, 1).unwrap();
+	let (commitment, _) = mr_prover
+		.commit(&data, 1, &make_portable_backend())
+		.unwrap();
 
 	mr_prover
 		.scheme()
 		.verify_vector(&commitment.root, &data, 1)
 		.unwrap();
 }
+
+fn check_parallel_digest_commit_and_open<H, C>(compression: C)
+where
+	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset + Sync + Clone>,
+	C: PseudoCompressionFunction<Output<H::Digest>, 2> + Sync + Clone,
+{
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = BinaryMerkleTreeProver::<_, H, _>::new(compression.clone());
+	// The scalar digest must produce the same tree as the multi-lane digest.
+	let scalar_prover = BinaryMerkleTreeProver::<_, H::Digest, _>::new(compression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(64)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover
+		.commit(&data, 2, &make_portable_backend())
+		.unwrap();
+	let (scalar_commitment, _) = scalar_prover
+		.commit(&data, 2, &make_portable_backend())
+		.unwrap();
+	assert_eq!(commitment.root, scalar_commitment.root);
+
+	for (i, values) in data.chunks(2).enumerate() {
+		let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		mr_prover
+			.prove_opening(&tree, 0, i, &mut proof_writer.message())
+			.unwrap();
+
+		let mut proof_reader = proof_writer.into_verifier();
+		mr_prover
+			.scheme()
+			.verify_opening(
+				i,
+				values,
+				0,
+				5,
+				&[commitment.root.clone()],
+				&mut proof_reader.message(),
+			)
+			.unwrap();
+	}
+}
+
+#[test]
+fn test_binary_merkle_vcs_keccak256() {
+	check_parallel_digest_commit_and_open::<Keccak256ParallelDigest, _>(Keccak256Compression);
+}
+
+#[test]
+fn test_binary_merkle_vcs_blake3() {
+	check_parallel_digest_commit_and_open::<Blake3ParallelDigest, _>(Blake3Compression);
+}
+
+#[test]
+fn test_binary_merkle_vcs_groestl256_byte_sliced() {
+	check_parallel_digest_commit_and_open::<ParallelMulidigestImpl<Groestl256ByteSliced16x, 16>, _>(
+		Groestl256ByteCompression,
+	);
+}
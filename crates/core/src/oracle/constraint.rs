@@ -9,7 +9,9 @@ use binius_math::{ArithExpr, CompositionPoly};
 use binius_utils::bail;
 use itertools::Itertools;
 
-use super::{Error, MultilinearOracleSet, MultilinearPolyVariant, OracleId};
+use super::{
+	AuxiliaryOracle, DegreeReduction, Error, MultilinearOracleSet, MultilinearPolyVariant, OracleId,
+};
 
 /// Composition trait object that can be used to create lists of compositions of differing
 /// concrete types.
@@ -41,6 +43,7 @@ pub struct ConstraintSet<F: Field> {
 
 // A deferred constraint constructor that instantiates index composition after the superset of oracles is known
 #[allow(clippy::type_complexity)]
+#[derive(Clone)]
 struct UngroupedConstraint<F: Field> {
 	name: String,
 	oracle_ids: Vec<OracleId>,
@@ -256,6 +259,51 @@ impl<F: Field> ConstraintSetBuilder<F> {
 	}
 }
 
+impl<F: TowerField> ConstraintSetBuilder<F> {
+	/// Rewrites the constraints added so far to have compositions of at most the target degree.
+	///
+	/// The constraints are grouped into the constraint sets [`Self::build`] would produce, and
+	/// each set is reduced as a whole. Auxiliary oracles are added to `oracles` and the constraints
+	/// binding them are added to the builder. See [`ConstraintSet::reduce_degree`].
+	pub fn reduce_degree(
+		&mut self,
+		oracles: &mut MultilinearOracleSet<F>,
+		reduction: DegreeReduction,
+	) -> Result<Vec<AuxiliaryOracle<F>>, Error> {
+		let constraint_sets = Self {
+			constraints: self.constraints.clone(),
+		}
+		.build(oracles)?;
+
+		// Reduce each constraint set with a single reducer, so that subexpressions shared between
+		// its constraints are split off only once.
+		self.constraints.clear();
+		let mut auxiliary = Vec::new();
+		for mut constraint_set in constraint_sets {
+			let name = constraint_set.constraints[0].name.clone();
+			auxiliary.extend(constraint_set.reduce_degree(oracles, reduction, name)?);
+
+			let ConstraintSet {
+				oracle_ids,
+				constraints,
+				..
+			} = constraint_set;
+			self.constraints
+				.extend(
+					constraints
+						.into_iter()
+						.map(|constraint| UngroupedConstraint {
+							name: constraint.name,
+							oracle_ids: oracle_ids.clone(),
+							composition: constraint.composition,
+							predicate: constraint.predicate,
+						}),
+				);
+		}
+		Ok(auxiliary)
+	}
+}
+
 /// Find index of every subset element within the superset.
 /// If the superset contains duplicate elements the index of the first match is used
 ///
@@ -0,0 +1,518 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Degree reduction of constraint compositions.
+//!
+//! Zerocheck costs grow with the degree of the constraint compositions. This module rewrites
+//! compositions that exceed a target degree by splitting off subexpressions into auxiliary
+//! oracles, so that natural high-degree expressions, like an S-box inversion, can be written
+//! without splitting them by hand.
+
+use binius_field::{ExtensionField, TowerField};
+use binius_math::ArithExpr;
+use binius_utils::bail;
+
+use super::{
+	Constraint, ConstraintPredicate, ConstraintSet, Error, MultilinearOracleSet, OracleId,
+};
+use crate::{
+	tower::{TowerFamily, TowerUnderlier},
+	witness::{self, composite_witness, MultilinearExtensionIndex},
+};
+
+/// The kind of oracle introduced for a subexpression that is split off a composition.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AuxiliaryOracleKind {
+	/// A committed oracle, tied to the subexpression with an additional zerocheck constraint.
+	#[default]
+	Committed,
+	/// A virtual composite oracle over the inputs of the subexpression.
+	///
+	/// This introduces no new commitments or constraints, but every evaluation claim on the
+	/// oracle is reduced by an extra sumcheck of the subexpression's degree.
+	Composite,
+}
+
+/// Configuration of the degree reduction pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DegreeReduction {
+	/// The maximum total degree of the rewritten compositions. Must be at least 2.
+	pub max_degree: usize,
+	pub oracle_kind: AuxiliaryOracleKind,
+}
+
+impl DegreeReduction {
+	pub const fn new(max_degree: usize) -> Self {
+		Self {
+			max_degree,
+			oracle_kind: AuxiliaryOracleKind::Committed,
+		}
+	}
+
+	pub const fn with_oracle_kind(self, oracle_kind: AuxiliaryOracleKind) -> Self {
+		Self {
+			oracle_kind,
+			..self
+		}
+	}
+}
+
+/// An oracle introduced by degree reduction, whose values are a composition of other oracles.
+#[derive(Debug, Clone)]
+pub struct AuxiliaryOracle<F: TowerField> {
+	pub id: OracleId,
+	pub kind: AuxiliaryOracleKind,
+	/// The oracles the composition is evaluated over.
+	pub inner: Vec<OracleId>,
+	/// The composition over `inner` defining the oracle values.
+	pub composition: ArithExpr<F>,
+	/// Tower level of the field the oracle values lie in.
+	pub tower_level: usize,
+}
+
+impl<F: TowerField> AuxiliaryOracle<F> {
+	/// Computes the witness of the oracle from the witnesses of its inner oracles and adds it to
+	/// the witness index.
+	///
+	/// Auxiliary oracles may be composed of other auxiliary oracles, so the witnesses must be
+	/// populated in the order the oracles were introduced.
+	pub fn populate_witness<U, Tower>(
+		&self,
+		oracles: &MultilinearOracleSet<F>,
+		witness: &mut MultilinearExtensionIndex<U, F>,
+	) -> Result<(), witness::Error>
+	where
+		U: TowerUnderlier<Tower>,
+		Tower: TowerFamily<B128 = F>,
+		F: ExtensionField<Tower::B1>
+			+ ExtensionField<Tower::B8>
+			+ ExtensionField<Tower::B16>
+			+ ExtensionField<Tower::B32>
+			+ ExtensionField<Tower::B64>,
+	{
+		let n_vars = oracles.n_vars(self.id);
+		let inner = self
+			.inner
+			.iter()
+			.map(|&id| witness.get_multilin_poly(id))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let poly = match self.tower_level {
+			0 => composite_witness::<U, F, Tower::B1>(n_vars, inner, &self.composition)?,
+			3 => composite_witness::<U, F, Tower::B8>(n_vars, inner, &self.composition)?,
+			4 => composite_witness::<U, F, Tower::B16>(n_vars, inner, &self.composition)?,
+			5 => composite_witness::<U, F, Tower::B32>(n_vars, inner, &self.composition)?,
+			6 => composite_witness::<U, F, Tower::B64>(n_vars, inner, &self.composition)?,
+			7 => composite_witness::<U, F, Tower::B128>(n_vars, inner, &self.composition)?,
+			_ => unreachable!("auxiliary oracle tower levels are normalized to packable fields"),
+		};
+		witness.update_multilin_poly([(self.id, poly)])
+	}
+}
+
+impl<F: TowerField> ConstraintSet<F> {
+	/// Rewrites every constraint in the set to have a composition of at most the target degree.
+	///
+	/// Auxiliary oracles are added to `oracles` and appended to the oracle IDs of the set, along
+	/// with any zerocheck constraints binding them to the subexpressions they replace. The
+	/// returned auxiliary oracles describe how to generate their witnesses.
+	pub fn reduce_degree(
+		&mut self,
+		oracles: &mut MultilinearOracleSet<F>,
+		reduction: DegreeReduction,
+		name: impl ToString,
+	) -> Result<Vec<AuxiliaryOracle<F>>, Error> {
+		let mut reducer =
+			DegreeReducer::new(oracles, reduction, self.n_vars, name, &mut self.oracle_ids)?;
+		for constraint in &mut self.constraints {
+			constraint.composition = reducer.reduce(&constraint.composition)?;
+		}
+
+		let (auxiliary, auxiliary_constraints) = reducer.finish();
+		self.constraints.extend(auxiliary_constraints);
+		Ok(auxiliary)
+	}
+}
+
+/// Rewrites compositions over a variable space of oracle IDs, growing the space with auxiliary
+/// oracles as needed.
+struct DegreeReducer<'a, F: TowerField> {
+	oracles: &'a mut MultilinearOracleSet<F>,
+	reduction: DegreeReduction,
+	n_vars: usize,
+	name: String,
+	oracle_ids: &'a mut Vec<OracleId>,
+	// Subexpressions that have been split off, with the variable of their auxiliary oracle
+	split: Vec<(ArithExpr<F>, usize)>,
+	auxiliary: Vec<AuxiliaryOracle<F>>,
+	constraints: Vec<Constraint<F>>,
+}
+
+impl<'a, F: TowerField> DegreeReducer<'a, F> {
+	fn new(
+		oracles: &'a mut MultilinearOracleSet<F>,
+		reduction: DegreeReduction,
+		n_vars: usize,
+		name: impl ToString,
+		oracle_ids: &'a mut Vec<OracleId>,
+	) -> Result<Self, Error> {
+		if reduction.max_degree < 2 {
+			bail!(Error::DegreeReductionTargetTooLow {
+				max_degree: reduction.max_degree
+			});
+		}
+		Ok(Self {
+			oracles,
+			reduction,
+			n_vars,
+			name: name.to_string(),
+			oracle_ids,
+			split: Vec::new(),
+			auxiliary: Vec::new(),
+			constraints: Vec::new(),
+		})
+	}
+
+	/// Returns the auxiliary oracles and the constraints binding committed ones, in the order
+	/// they were introduced.
+	fn finish(self) -> (Vec<AuxiliaryOracle<F>>, Vec<Constraint<F>>) {
+		(self.auxiliary, self.constraints)
+	}
+
+	/// Returns an equivalent composition of at most the target degree.
+	fn reduce(&mut self, expr: &ArithExpr<F>) -> Result<ArithExpr<F>, Error> {
+		if expr.degree() <= self.reduction.max_degree {
+			return Ok(expr.clone());
+		}
+		self.rewrite(expr)
+	}
+
+	fn rewrite(&mut self, expr: &ArithExpr<F>) -> Result<ArithExpr<F>, Error> {
+		let rewritten = match expr {
+			ArithExpr::Const(_) | ArithExpr::Var(_) => expr.clone(),
+			ArithExpr::Add(left, right) => self.rewrite(left)? + self.rewrite(right)?,
+			ArithExpr::Mul(left, right) => {
+				let left = self.rewrite(left)?;
+				let right = self.rewrite(right)?;
+				self.reduce_product(left, right)?
+			}
+			ArithExpr::Pow(base, exp) => {
+				let base = self.rewrite(base)?;
+				self.reduce_pow(base, *exp)?
+			}
+		};
+		Ok(rewritten)
+	}
+
+	fn reduce_product(
+		&mut self,
+		left: ArithExpr<F>,
+		right: ArithExpr<F>,
+	) -> Result<ArithExpr<F>, Error> {
+		let max_degree = self.reduction.max_degree;
+		if left.degree() + right.degree() <= max_degree {
+			return Ok(left * right);
+		}
+
+		let (high, low) = if left.degree() >= right.degree() {
+			(left, right)
+		} else {
+			(right, left)
+		};
+		let high = self.split_off(high)?;
+		let low = if low.degree() + 1 > max_degree {
+			self.split_off(low)?
+		} else {
+			low
+		};
+		Ok(high * low)
+	}
+
+	fn reduce_pow(&mut self, base: ArithExpr<F>, exp: u64) -> Result<ArithExpr<F>, Error> {
+		let max_degree = self.reduction.max_degree;
+		if base.degree() * exp as usize <= max_degree {
+			return Ok(base.pow(exp));
+		}
+
+		let base = if base.degree() > 1 {
+			self.split_off(base)?
+		} else {
+			base
+		};
+		if exp as usize <= max_degree {
+			return Ok(base.pow(exp));
+		}
+
+		// base^exp = (base^max_degree)^quotient * base^remainder
+		let quotient = exp / max_degree as u64;
+		let remainder = exp % max_degree as u64;
+		let power = self.split_off(base.clone().pow(max_degree as u64))?;
+		let head = self.reduce_pow(power, quotient)?;
+		if remainder == 0 {
+			Ok(head)
+		} else {
+			self.reduce_product(head, base.pow(remainder))
+		}
+	}
+
+	/// Replaces a subexpression with a variable referring to an auxiliary oracle.
+	fn split_off(&mut self, expr: ArithExpr<F>) -> Result<ArithExpr<F>, Error> {
+		if let ArithExpr::Var(_) = expr {
+			return Ok(expr);
+		}
+		if let Some((_, var)) = self.split.iter().find(|(split, _)| *split == expr) {
+			return Ok(ArithExpr::Var(*var));
+		}
+
+		let vars_usage = expr.vars_usage();
+		let inner = vars_usage
+			.iter()
+			.enumerate()
+			.filter(|(_, &used)| used)
+			.map(|(var, _)| self.oracle_ids[var])
+			.collect::<Vec<_>>();
+		let mut indices = vec![0; vars_usage.len()];
+		for (index, var) in vars_usage
+			.iter()
+			.enumerate()
+			.filter(|(_, &used)| used)
+			.map(|(var, _)| var)
+			.enumerate()
+		{
+			indices[var] = index;
+		}
+		let composition = expr.clone().remap_vars(&indices)?;
+
+		let tower_level = inner
+			.iter()
+			.map(|&id| self.oracles.tower_level(id))
+			.chain([composition.binary_tower_level()])
+			.max()
+			.unwrap_or(0);
+		// Witnesses are packed over the fields of a tower family, which skips B2 and B4.
+		let tower_level = if tower_level == 0 {
+			0
+		} else {
+			tower_level.max(3)
+		};
+
+		let name = format!("{}_aux_{}", self.name, self.auxiliary.len());
+		let kind = self.reduction.oracle_kind;
+		let id = match kind {
+			AuxiliaryOracleKind::Committed => self
+				.oracles
+				.add_named(&name)
+				.committed(self.n_vars, tower_level),
+			AuxiliaryOracleKind::Composite => self.oracles.add_named(&name).composite_mle(
+				self.n_vars,
+				inner.iter().copied(),
+				composition.clone(),
+			)?,
+		};
+
+		let var = self.oracle_ids.len();
+		self.oracle_ids.push(id);
+		if kind == AuxiliaryOracleKind::Committed {
+			self.constraints.push(Constraint {
+				name,
+				composition: ArithExpr::Var(var) - expr.clone(),
+				predicate: ConstraintPredicate::Zero,
+			});
+		}
+		self.split.push((expr, var));
+		self.auxiliary.push(AuxiliaryOracle {
+			id,
+			kind,
+			inner,
+			composition,
+			tower_level,
+		});
+		Ok(ArithExpr::Var(var))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		as_packed_field::PackedType, packed::set_packed_slice, BinaryField128b, BinaryField8b,
+		Field, PackedField,
+	};
+	use binius_math::{ArithExpr, CompositionPoly, MultilinearExtension};
+
+	use super::*;
+	use crate::{
+		oracle::ConstraintSetBuilder, polynomial::ArithCircuitPoly, tower::CanonicalTowerFamily,
+		witness::MultilinearExtensionIndex,
+	};
+
+	type F = BinaryField128b;
+	type U = binius_field::arch::OptimalUnderlier;
+
+	fn eval(expr: &ArithExpr<F>, vars: &[F]) -> F {
+		ArithCircuitPoly::with_n_vars(vars.len(), expr.clone())
+			.unwrap()
+			.evaluate(vars)
+			.unwrap()
+	}
+
+	fn sbox_set(oracles: &mut MultilinearOracleSet<F>) -> ConstraintSet<F> {
+		let x = oracles.add_committed(4, 3);
+		let y = oracles.add_committed(4, 3);
+		// y = x^254, the inverse of x in B8
+		let composition = ArithExpr::Var(1) - ArithExpr::Var(0).pow(254);
+		ConstraintSet {
+			n_vars: 4,
+			oracle_ids: vec![x, y],
+			constraints: vec![Constraint {
+				name: "sbox".into(),
+				composition,
+				predicate: ConstraintPredicate::Zero,
+			}],
+		}
+	}
+
+	#[test]
+	fn test_reduce_degree_bounds_compositions() {
+		for oracle_kind in [
+			AuxiliaryOracleKind::Committed,
+			AuxiliaryOracleKind::Composite,
+		] {
+			let mut oracles = MultilinearOracleSet::new();
+			let mut set = sbox_set(&mut oracles);
+			let reduction = DegreeReduction::new(3).with_oracle_kind(oracle_kind);
+			let auxiliary = set.reduce_degree(&mut oracles, reduction, "sbox").unwrap();
+
+			assert!(!auxiliary.is_empty());
+			assert_eq!(set.oracle_ids.len(), 2 + auxiliary.len());
+			for constraint in &set.constraints {
+				assert!(constraint.composition.degree() <= 3);
+			}
+			for aux in &auxiliary {
+				assert!(aux.composition.degree() <= 3);
+				assert_eq!(aux.tower_level, 3);
+				assert_eq!(oracles.n_vars(aux.id), 4);
+			}
+			let n_constraints = match oracle_kind {
+				AuxiliaryOracleKind::Committed => 1 + auxiliary.len(),
+				AuxiliaryOracleKind::Composite => 1,
+			};
+			assert_eq!(set.constraints.len(), n_constraints);
+		}
+	}
+
+	#[test]
+	fn test_reduce_degree_keeps_low_degree_constraints() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut set = sbox_set(&mut oracles);
+		set.constraints[0].composition = ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::one();
+		let expected = set.constraints[0].composition.clone();
+
+		let auxiliary = set
+			.reduce_degree(&mut oracles, DegreeReduction::new(2), "inv")
+			.unwrap();
+		assert!(auxiliary.is_empty());
+		assert_eq!(set.oracle_ids.len(), 2);
+		assert_eq!(set.constraints[0].composition, expected);
+	}
+
+	#[test]
+	fn test_reduce_degree_rejects_linear_target() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut set = sbox_set(&mut oracles);
+		assert!(set
+			.reduce_degree(&mut oracles, DegreeReduction::new(1), "sbox")
+			.is_err());
+	}
+
+	#[test]
+	fn test_reduce_degree_witness_satisfies_constraints() {
+		let mut oracles = MultilinearOracleSet::new();
+		let mut set = sbox_set(&mut oracles);
+		let auxiliary = set
+			.reduce_degree(&mut oracles, DegreeReduction::new(3), "sbox")
+			.unwrap();
+
+		let xs = (0..16u8)
+			.map(|i| BinaryField8b::new(i.wrapping_mul(37)))
+			.collect::<Vec<_>>();
+		let ys = xs
+			.iter()
+			.map(|x| x.invert().unwrap_or(BinaryField8b::ZERO))
+			.collect::<Vec<_>>();
+		let mut witness = MultilinearExtensionIndex::<U, F>::new();
+		for (id, values) in [(set.oracle_ids[0], &xs), (set.oracle_ids[1], &ys)] {
+			let mut packed = vec![
+				PackedType::<U, BinaryField8b>::default();
+				1 << 4usize
+					.saturating_sub(PackedType::<U, BinaryField8b>::LOG_WIDTH)
+			];
+			for (i, &value) in values.iter().enumerate() {
+				set_packed_slice(&mut packed, i, value);
+			}
+			let mle = MultilinearExtension::new(4, packed).unwrap();
+			witness
+				.update_multilin_poly([(id, mle.specialize_arc_dyn())])
+				.unwrap();
+		}
+		for aux in &auxiliary {
+			aux.populate_witness::<U, CanonicalTowerFamily>(&oracles, &mut witness)
+				.unwrap();
+		}
+
+		let polys = set
+			.oracle_ids
+			.iter()
+			.map(|&id| witness.get_multilin_poly(id).unwrap())
+			.collect::<Vec<_>>();
+		for i in 0..16 {
+			let vars = polys
+				.iter()
+				.map(|poly| poly.evaluate_on_hypercube(i).unwrap())
+				.collect::<Vec<_>>();
+			for constraint in &set.constraints {
+				assert_eq!(eval(&constraint.composition, &vars), F::ZERO);
+			}
+		}
+	}
+
+	#[test]
+	fn test_builder_reduces_shared_subexpressions_once() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut single = sbox_set(&mut oracles);
+		let x = single.oracle_ids[0];
+		let y = single.oracle_ids[1];
+		let single_auxiliary = single
+			.reduce_degree(&mut oracles, DegreeReduction::new(3), "sbox")
+			.unwrap();
+
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("sbox", [x, y], ArithExpr::Var(1) - ArithExpr::Var(0).pow(254));
+		builder.add_zerocheck("sbox_twice", [y, x], ArithExpr::Var(0) - ArithExpr::Var(1).pow(254));
+		let auxiliary = builder
+			.reduce_degree(&mut oracles, DegreeReduction::new(3))
+			.unwrap();
+		assert_eq!(auxiliary.len(), single_auxiliary.len());
+
+		let sets = builder.build(&oracles).unwrap();
+		assert_eq!(sets.len(), 1);
+		for constraint in &sets[0].constraints {
+			assert!(constraint.composition.degree() <= 3);
+		}
+	}
+
+	#[test]
+	fn test_composite_witness_rejects_values_outside_subfield() {
+		let mle = MultilinearExtension::new(
+			4,
+			vec![
+				PackedType::<U, BinaryField8b>::one();
+				1 << 4usize.saturating_sub(PackedType::<U, BinaryField8b>::LOG_WIDTH)
+			],
+		)
+		.unwrap();
+		let inner = vec![mle.specialize_arc_dyn::<PackedType<U, F>>()];
+
+		let composition = ArithExpr::Var(0) * ArithExpr::Const(F::new(1 << 100));
+		assert!(composite_witness::<U, F, BinaryField8b>(4, inner.clone(), &composition).is_err());
+		assert!(composite_witness::<U, F, F>(4, inner, &composition).is_ok());
+	}
+}
@@ -18,6 +18,27 @@ pub enum Error {
 		"n_vars ({n_vars}) must be at least as big as the requested log_degree ({log_degree})"
 	)]
 	NotEnoughVarsForPacking { n_vars: usize, log_degree: usize },
+	#[error("cannot unpack oracle {id} with tower level {tower_level} by log_degree {log_degree}")]
+	NotEnoughTowerLevelsForUnpacking {
+		id: OracleId,
+		tower_level: usize,
+		log_degree: usize,
+	},
+	#[error("only committed oracles can be unpacked, but oracle {id} is not committed")]
+	UnpackingNonCommitted { id: OracleId },
+	#[error("the number of interleaved oracles ({count}) must be a power of two")]
+	InvalidInterleavedCount { count: usize },
+	#[error("invalid stride of an oracle with {n_vars} variables: log_stride={log_stride}, offset={offset}")]
+	InvalidStride {
+		n_vars: usize,
+		log_stride: usize,
+		offset: usize,
+	},
+	#[error("cannot concatenate oracles with {inner_n_vars:?} variables into an oracle with {n_vars} variables")]
+	InvalidConcatenation {
+		n_vars: usize,
+		inner_n_vars: Vec<usize>,
+	},
 	#[error("no oracle exists in this MultilinearOracleSet with id {0}")]
 	InvalidOracleId(OracleId),
 	#[error("tower_level ({tower_level}) exceeds maximum")]
@@ -26,4 +47,8 @@ pub enum Error {
 	EmptyConstraintSet,
 	#[error("expected constraint set to contain only constraints with n_vars={expected}, but found n_vars={got}")]
 	ConstraintSetNvarsMismatch { got: usize, expected: usize },
+	#[error("degree reduction requires a target degree of at least 2, got {max_degree}")]
+	DegreeReductionTargetTooLow { max_degree: usize },
+	#[error("math error: {0}")]
+	Math(#[from] binius_math::Error),
 }
@@ -8,10 +8,12 @@
 
 mod composite;
 mod constraint;
+mod degree_reduction;
 mod error;
 mod multilinear;
 
 pub use composite::*;
 pub use constraint::*;
+pub use degree_reduction::*;
 pub use error::Error;
 pub use multilinear::*;
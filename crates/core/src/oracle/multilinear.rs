@@ -5,7 +5,10 @@ use std::{array, fmt::Debug, sync::Arc};
 use binius_field::{BinaryField128b, Field, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
 use binius_math::ArithExpr;
-use binius_utils::{bail, DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
+use binius_utils::{
+	bail, checked_arithmetics::log2_strict_usize, DeserializeBytes, SerializationError,
+	SerializationMode, SerializeBytes,
+};
 use bytes::Buf;
 use getset::{CopyGetters, Getters};
 
@@ -158,6 +161,47 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
+	/// Views a committed oracle over a subfield of its tower, splitting each value into
+	/// $2^{\text{log\_degree}}$ coordinates.
+	///
+	/// This is the inverse of [`Self::packed`]: the resulting oracle has `log_degree` more
+	/// variables and `log_degree` fewer tower levels, and the value at index
+	/// $(i \ll \text{log\_degree}) | j$ is the $j$-th coordinate of the $i$-th inner value in the
+	/// tower basis. Evaluation claims on unpacked oracles are reduced by ring switching against the
+	/// inner commitment, so the inner oracle must be committed.
+	pub fn unpacked(self, inner_id: OracleId, log_degree: usize) -> Result<OracleId, Error> {
+		if inner_id >= self.mut_ref.oracles.len() {
+			bail!(Error::InvalidOracleId(inner_id));
+		}
+
+		let inner = self.mut_ref.get_from_set(inner_id);
+		if !matches!(inner.variant, MultilinearPolyVariant::Committed) {
+			bail!(Error::UnpackingNonCommitted { id: inner_id });
+		}
+		if log_degree > inner.tower_level {
+			bail!(Error::NotEnoughTowerLevelsForUnpacking {
+				id: inner_id,
+				tower_level: inner.tower_level,
+				log_degree,
+			});
+		}
+
+		let unpacked = Unpacked {
+			id: inner_id,
+			log_degree,
+		};
+
+		let oracle = |id: OracleId| MultilinearPolyOracle {
+			id,
+			n_vars: inner.n_vars + log_degree,
+			tower_level: inner.tower_level - log_degree,
+			name: self.name,
+			variant: MultilinearPolyVariant::Unpacked(unpacked),
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
 	pub fn projected(
 		self,
 		inner_id: OracleId,
@@ -297,6 +341,129 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
+	/// Interleaves several oracles with the same number of variables into one.
+	///
+	/// The number of inner oracles $k$ must be a power of two. The resulting oracle has
+	/// $\log_2 k$ additional low variables selecting the inner oracle, i.e. the value at hypercube
+	/// index $i k + j$ is the value of the $j$-th inner oracle at index $i$.
+	pub fn interleaved(self, inner: impl IntoIterator<Item = OracleId>) -> Result<OracleId, Error> {
+		let inner = inner.into_iter().collect::<Vec<_>>();
+		if !inner.len().is_power_of_two() {
+			bail!(Error::InvalidInterleavedCount { count: inner.len() });
+		}
+
+		for &inner_id in &inner {
+			if inner_id >= self.mut_ref.oracles.len() {
+				bail!(Error::InvalidOracleId(inner_id));
+			}
+		}
+
+		let n_vars = self.mut_ref.n_vars(inner[0]);
+		if inner
+			.iter()
+			.any(|&inner_id| self.mut_ref.n_vars(inner_id) != n_vars)
+		{
+			bail!(Error::IncorrectNumberOfVariables { expected: n_vars });
+		}
+
+		let tower_level = inner
+			.iter()
+			.map(|&inner_id| self.mut_ref.tower_level(inner_id))
+			.max()
+			.unwrap_or(0);
+
+		let oracle = |id: OracleId| MultilinearPolyOracle {
+			id,
+			n_vars: n_vars + log2_strict_usize(inner.len()),
+			tower_level,
+			name: self.name,
+			variant: MultilinearPolyVariant::Interleaved(inner),
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
+	/// Takes every $2^{\text{log\_stride}}$-th value of an oracle, starting at `offset`.
+	///
+	/// The resulting oracle has `log_stride` fewer variables than the inner one. Strided oracles
+	/// are the inverse of [`Self::interleaved`].
+	pub fn strided(
+		self,
+		inner_id: OracleId,
+		log_stride: usize,
+		offset: usize,
+	) -> Result<OracleId, Error> {
+		if inner_id >= self.mut_ref.oracles.len() {
+			bail!(Error::InvalidOracleId(inner_id));
+		}
+
+		let inner = self.mut_ref.get_from_set(inner_id);
+		let strided = Strided::new(&inner, log_stride, offset)?;
+
+		let oracle = |id: OracleId| MultilinearPolyOracle {
+			id,
+			n_vars: inner.n_vars - log_stride,
+			tower_level: inner.tower_level,
+			name: self.name,
+			variant: MultilinearPolyVariant::Strided(strided),
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
+	/// Concatenates the hypercube evaluations of several oracles into one oracle with `n_vars`
+	/// variables.
+	///
+	/// There must be at least one inner oracle. The inner oracles may have different numbers of
+	/// variables, but must be ordered from most variables to least, and their total size must fit
+	/// within `2^n_vars` values. The remaining values are zero. This is the piecewise multilinear
+	/// layout described in [`binius_math::evaluate_piecewise_multilinear`].
+	pub fn concatenated(
+		self,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+	) -> Result<OracleId, Error> {
+		let inner = inner.into_iter().collect::<Vec<_>>();
+		for &inner_id in &inner {
+			if inner_id >= self.mut_ref.oracles.len() {
+				bail!(Error::InvalidOracleId(inner_id));
+			}
+		}
+
+		let inner_n_vars = inner
+			.iter()
+			.map(|&inner_id| self.mut_ref.n_vars(inner_id))
+			.collect::<Vec<_>>();
+		let total_len = inner_n_vars
+			.iter()
+			.try_fold(0usize, |acc, &piece_n_vars| acc.checked_add(1 << piece_n_vars));
+		if inner_n_vars.is_empty()
+			|| inner_n_vars.windows(2).any(|pair| pair[0] < pair[1])
+			|| total_len.is_none_or(|total_len| total_len > 1 << n_vars)
+		{
+			bail!(Error::InvalidConcatenation {
+				n_vars,
+				inner_n_vars,
+			});
+		}
+
+		let tower_level = inner
+			.iter()
+			.map(|&inner_id| self.mut_ref.tower_level(inner_id))
+			.max()
+			.unwrap_or(0);
+
+		let oracle = |id: OracleId| MultilinearPolyOracle {
+			id,
+			n_vars,
+			tower_level,
+			name: self.name,
+			variant: MultilinearPolyVariant::Concatenated(inner),
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
 	fn add_committed_with_name(
 		&mut self,
 		n_vars: usize,
@@ -385,6 +552,13 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.oracles[id].clone()
 	}
 
+	/// Adds a copy of an oracle, assigning it the next ID in this set.
+	///
+	/// The inner oracle IDs of the variant must already refer to oracles in this set.
+	pub(crate) fn add_oracle(&mut self, oracle: MultilinearPolyOracle<F>) -> OracleId {
+		self.add_to_set(|id| MultilinearPolyOracle { id, ..oracle })
+	}
+
 	pub fn add_transparent(
 		&mut self,
 		poly: impl MultivariatePoly<F> + 'static,
@@ -422,6 +596,10 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().packed(id, log_degree)
 	}
 
+	pub fn add_unpacked(&mut self, id: OracleId, log_degree: usize) -> Result<OracleId, Error> {
+		self.add().unpacked(id, log_degree)
+	}
+
 	pub fn add_projected(
 		&mut self,
 		id: OracleId,
@@ -453,6 +631,30 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().zero_padded(id, n_vars)
 	}
 
+	pub fn add_interleaved(
+		&mut self,
+		inner: impl IntoIterator<Item = OracleId>,
+	) -> Result<OracleId, Error> {
+		self.add().interleaved(inner)
+	}
+
+	pub fn add_strided(
+		&mut self,
+		id: OracleId,
+		log_stride: usize,
+		offset: usize,
+	) -> Result<OracleId, Error> {
+		self.add().strided(id, log_stride, offset)
+	}
+
+	pub fn add_concatenated(
+		&mut self,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = OracleId>,
+	) -> Result<OracleId, Error> {
+		self.add().concatenated(n_vars, inner)
+	}
+
 	pub fn add_composite_mle(
 		&mut self,
 		n_vars: usize,
@@ -538,6 +740,10 @@ pub enum MultilinearPolyVariant<F: TowerField> {
 	LinearCombination(LinearCombination<F>),
 	ZeroPadded(OracleId),
 	Composite(CompositeMLE<F>),
+	Interleaved(Vec<OracleId>),
+	Strided(Strided),
+	Concatenated(Vec<OracleId>),
+	Unpacked(Unpacked),
 }
 
 impl DeserializeBytes for MultilinearPolyVariant<BinaryField128b> {
@@ -560,6 +766,10 @@ impl DeserializeBytes for MultilinearPolyVariant<BinaryField128b> {
 			5 => Self::Packed(DeserializeBytes::deserialize(buf, mode)?),
 			6 => Self::LinearCombination(DeserializeBytes::deserialize(buf, mode)?),
 			7 => Self::ZeroPadded(DeserializeBytes::deserialize(buf, mode)?),
+			9 => Self::Interleaved(DeserializeBytes::deserialize(buf, mode)?),
+			10 => Self::Strided(DeserializeBytes::deserialize(buf, mode)?),
+			11 => Self::Concatenated(DeserializeBytes::deserialize(buf, mode)?),
+			12 => Self::Unpacked(DeserializeBytes::deserialize(buf, mode)?),
 			variant_index => {
 				return Err(SerializationError::UnknownEnumVariant {
 					name: "MultilinearPolyVariant",
@@ -570,6 +780,54 @@ impl DeserializeBytes for MultilinearPolyVariant<BinaryField128b> {
 	}
 }
 
+impl<F: TowerField> MultilinearPolyVariant<F> {
+	/// Returns the IDs of the oracles this variant is derived from.
+	pub fn inner_ids(&self) -> Vec<OracleId> {
+		match self {
+			Self::Committed | Self::Transparent(_) => vec![],
+			Self::Repeating { id, .. } | Self::ZeroPadded(id) => vec![*id],
+			Self::Projected(projected) => vec![projected.id],
+			Self::Shifted(shifted) => vec![shifted.id],
+			Self::Packed(packed) => vec![packed.id],
+			Self::LinearCombination(linear_combination) => linear_combination.polys().collect(),
+			Self::Composite(composite) => composite.inner.clone(),
+			Self::Interleaved(inner) | Self::Concatenated(inner) => inner.clone(),
+			Self::Strided(strided) => vec![strided.id],
+			Self::Unpacked(unpacked) => vec![unpacked.id],
+		}
+	}
+
+	/// Returns a copy of the variant with every inner oracle ID replaced by `f(id)`.
+	pub fn map_inner_ids(&self, mut f: impl FnMut(OracleId) -> OracleId) -> Self {
+		let mut variant = self.clone();
+		match &mut variant {
+			Self::Committed | Self::Transparent(_) => {}
+			Self::Repeating { id, .. } | Self::ZeroPadded(id) => *id = f(*id),
+			Self::Projected(projected) => projected.id = f(projected.id),
+			Self::Shifted(shifted) => shifted.id = f(shifted.id),
+			Self::Packed(packed) => packed.id = f(packed.id),
+			Self::LinearCombination(linear_combination) => {
+				for (id, _) in &mut linear_combination.inner {
+					*id = f(*id);
+				}
+			}
+			Self::Composite(composite) => {
+				for id in &mut composite.inner {
+					*id = f(*id);
+				}
+			}
+			Self::Interleaved(inner) | Self::Concatenated(inner) => {
+				for id in inner {
+					*id = f(*id);
+				}
+			}
+			Self::Strided(strided) => strided.id = f(strided.id),
+			Self::Unpacked(unpacked) => unpacked.id = f(unpacked.id),
+		}
+		variant
+	}
+}
+
 /// A transparent multilinear polynomial oracle.
 ///
 /// See the [`MultilinearPolyOracle`] documentation for context.
@@ -727,6 +985,17 @@ pub struct Packed {
 	log_degree: usize,
 }
 
+/// A committed oracle viewed over a subfield, with each value split into its tower basis
+/// coordinates.
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, SerializeBytes, DeserializeBytes)]
+pub struct Unpacked {
+	#[get_copy = "pub"]
+	id: OracleId,
+	/// The number of tower levels decreased by the unpacking operation.
+	#[get_copy = "pub"]
+	log_degree: usize,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, SerializeBytes, DeserializeBytes)]
 pub struct LinearCombination<F: TowerField> {
 	#[get_copy = "pub"]
@@ -759,6 +1028,15 @@ impl<F: TowerField> LinearCombination<F> {
 		})
 	}
 
+	/// Creates a linear combination from its terms without checking the inner oracles.
+	pub(crate) const fn from_terms(n_vars: usize, offset: F, inner: Vec<(OracleId, F)>) -> Self {
+		Self {
+			n_vars,
+			offset,
+			inner,
+		}
+	}
+
 	pub fn n_polys(&self) -> usize {
 		self.inner.len()
 	}
@@ -772,6 +1050,57 @@ impl<F: TowerField> LinearCombination<F> {
 	}
 }
 
+/// A strided view of an oracle, selecting every $2^{\text{log\_stride}}$-th value starting at
+/// `offset`.
+///
+/// The value at hypercube index $i$ is the value of the inner oracle at index
+/// $i 2^{\text{log\_stride}} + \text{offset}$. Equivalently, the strided oracle is the inner oracle
+/// with its first `log_stride` variables fixed to the bits of `offset`.
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters, SerializeBytes, DeserializeBytes)]
+pub struct Strided {
+	#[get_copy = "pub"]
+	id: OracleId,
+	#[get_copy = "pub"]
+	log_stride: usize,
+	#[get_copy = "pub"]
+	offset: usize,
+}
+
+impl Strided {
+	fn new<F: TowerField>(
+		oracle: &MultilinearPolyOracle<F>,
+		log_stride: usize,
+		offset: usize,
+	) -> Result<Self, Error> {
+		if log_stride > oracle.n_vars() || offset >= 1 << log_stride {
+			bail!(Error::InvalidStride {
+				n_vars: oracle.n_vars(),
+				log_stride,
+				offset,
+			});
+		}
+		Ok(Self {
+			id: oracle.id(),
+			log_stride,
+			offset,
+		})
+	}
+
+	/// Returns the evaluation point of the inner oracle corresponding to `eval_point`.
+	pub fn inner_eval_point<F: Field>(&self, eval_point: &[F]) -> Vec<F> {
+		(0..self.log_stride)
+			.map(|i| {
+				if (self.offset >> i) & 1 == 0 {
+					F::ZERO
+				} else {
+					F::ONE
+				}
+			})
+			.chain(eval_point.iter().copied())
+			.collect()
+	}
+}
+
 /// MLE of a multivariate polynomial evaluated on multilinear oracles.
 ///
 /// i.e. the MLE of the evaluations of $C(M_1, M_2, \ldots, M_n)$ on $\{0, 1\}^\mu$ where:
@@ -849,6 +1178,10 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 			MultilinearPolyVariant::LinearCombination(_) => "LinearCombination",
 			MultilinearPolyVariant::ZeroPadded(_) => "ZeroPadded",
 			MultilinearPolyVariant::Composite(_) => "CompositeMLE",
+			MultilinearPolyVariant::Interleaved(_) => "Interleaved",
+			MultilinearPolyVariant::Strided(_) => "Strided",
+			MultilinearPolyVariant::Concatenated(_) => "Concatenated",
+			MultilinearPolyVariant::Unpacked(_) => "Unpacked",
 		}
 	}
 
@@ -33,6 +33,7 @@
 
 pub mod commit;
 mod error;
+mod pcs;
 mod prove;
 #[cfg(test)]
 mod tests;
@@ -41,5 +42,6 @@ mod verify;
 
 pub use commit::*;
 pub use error::*;
+pub use pcs::*;
 pub use prove::*;
 pub use verify::{make_commit_params_with_optimal_arity, verify, CommitMeta, PIOPSumcheckClaim};
@@ -0,0 +1,329 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Polynomial commitment scheme interface for the PIOP compiler.
+//!
+//! The PIOP reduces all evaluation claims on committed multilinears to sumcheck claims on products
+//! of a committed polynomial and a transparent polynomial, see [`PIOPSumcheckClaim`]. A
+//! [`PolynomialCommitmentScheme`] commits to a batch of multilinears with the shape described by a
+//! [`CommitMeta`] and proves a batch of such claims against the commitment. The FRI-Binius
+//! compiler from [DP24] is provided as [`FRIPCS`] and [`FRIPCSProver`].
+//!
+//! [DP24]: <https://eprint.iacr.org/2024/504>
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+use binius_field::{
+	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable,
+	TowerField,
+};
+use binius_hal::ComputationBackend;
+use binius_math::{EvaluationDomainFactory, MultilinearPoly};
+use binius_utils::{DeserializeBytes, SerializeBytes};
+use getset::Getters;
+
+use super::{
+	error::Error,
+	prove::{commit, prove},
+	verify::{make_commit_params_with_optimal_arity, verify, CommitMeta, PIOPSumcheckClaim},
+};
+use crate::{
+	fiat_shamir::Challenger,
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	polynomial::MultivariatePoly,
+	protocols::fri::{CommitOutput, FRIParams},
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// A polynomial commitment scheme for batches of multilinears over `F`.
+///
+/// A scheme instance is bound to the shape of one committed batch, given by a [`CommitMeta`], and
+/// verifies batched sumcheck claims that are products of committed and transparent multilinears.
+pub trait PolynomialCommitmentScheme<F: Field> {
+	/// The commitment that the prover sends to the verifier.
+	type Commitment: Clone + SerializeBytes + DeserializeBytes;
+
+	/// Returns the shape of the committed batch.
+	fn commit_meta(&self) -> &CommitMeta;
+
+	/// Verifies a batch of sumcheck claims against a commitment.
+	///
+	/// ## Arguments
+	///
+	/// * `commitment` - the commitment to the batch of multilinears
+	/// * `transparents` - a slice of transparent polynomials in ascending order by number of
+	///   variables
+	/// * `claims` - a batch of sumcheck claims referencing committed polynomials in the batch and
+	///   the transparent polynomials in `transparents`
+	/// * `transcript` - the verifier transcript
+	fn verify<'a, Challenger_: Challenger>(
+		&self,
+		commitment: &Self::Commitment,
+		transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error>;
+}
+
+/// A prover for a [`PolynomialCommitmentScheme`] with witness multilinears packed into `P`.
+///
+/// This is separate from [`PolynomialCommitmentScheme`] so that it may be implemented using a
+/// hardware-accelerated backend.
+pub trait PolynomialCommitmentProver<F: Field, P: PackedField<Scalar = F>> {
+	type Scheme: PolynomialCommitmentScheme<F>;
+	/// Data generated during commitment required to generate opening proofs.
+	type Committed;
+
+	/// Commits a batch of multilinears.
+	///
+	/// The multilinears must match the shape of the scheme's [`CommitMeta`], in ascending order by
+	/// the number of variables.
+	#[allow(clippy::type_complexity)]
+	fn commit<M, Backend>(
+		&self,
+		multilins: &[M],
+		backend: &Backend,
+	) -> Result<(<Self::Scheme as PolynomialCommitmentScheme<F>>::Commitment, Self::Committed), Error>
+	where
+		M: MultilinearPoly<P>,
+		Backend: ComputationBackend;
+
+	/// Proves a batch of sumcheck claims that are products of committed polynomials from the
+	/// batch and transparent polynomials.
+	///
+	/// The arguments corresponding to the committed multilinears must be the output of
+	/// [`Self::commit`].
+	fn prove<M, Challenger_, Backend>(
+		&self,
+		committed: Self::Committed,
+		committed_multilins: &[M],
+		transparent_multilins: &[M],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut ProverTranscript<Challenger_>,
+		backend: &Backend,
+	) -> Result<(), Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+		Challenger_: Challenger,
+		Backend: ComputationBackend;
+}
+
+/// The FRI-Binius polynomial commitment scheme.
+#[derive(Debug, Getters)]
+pub struct FRIPCS<F, FEncode, MTScheme>
+where
+	F: BinaryField,
+	FEncode: BinaryField,
+{
+	commit_meta: CommitMeta,
+	#[getset(get = "pub")]
+	fri_params: FRIParams<F, FEncode>,
+	#[getset(get = "pub")]
+	merkle_scheme: MTScheme,
+}
+
+impl<F, FEncode, MTScheme> FRIPCS<F, FEncode, MTScheme>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F>,
+{
+	/// Constructs the scheme with the FRI parameters selected by
+	/// [`make_commit_params_with_optimal_arity`].
+	pub fn new(
+		commit_meta: &CommitMeta,
+		merkle_scheme: MTScheme,
+		security_bits: usize,
+		log_inv_rate: usize,
+	) -> Result<Self, Error> {
+		let fri_params = make_commit_params_with_optimal_arity(
+			commit_meta,
+			&merkle_scheme,
+			security_bits,
+			log_inv_rate,
+		)?;
+		Ok(Self::with_params(commit_meta, merkle_scheme, fri_params))
+	}
+
+	/// Constructs the scheme with explicit FRI parameters.
+	pub fn with_params(
+		commit_meta: &CommitMeta,
+		merkle_scheme: MTScheme,
+		fri_params: FRIParams<F, FEncode>,
+	) -> Self {
+		Self {
+			commit_meta: commit_meta.clone(),
+			fri_params,
+			merkle_scheme,
+		}
+	}
+}
+
+impl<F, FEncode, MTScheme> PolynomialCommitmentScheme<F> for FRIPCS<F, FEncode, MTScheme>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes + DeserializeBytes>,
+{
+	type Commitment = MTScheme::Digest;
+
+	fn commit_meta(&self) -> &CommitMeta {
+		&self.commit_meta
+	}
+
+	fn verify<'a, Challenger_: Challenger>(
+		&self,
+		commitment: &Self::Commitment,
+		transparents: &[impl Borrow<dyn MultivariatePoly<F> + 'a>],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut VerifierTranscript<Challenger_>,
+	) -> Result<(), Error> {
+		verify(
+			&self.commit_meta,
+			&self.merkle_scheme,
+			&self.fri_params,
+			commitment,
+			transparents,
+			claims,
+			transcript,
+		)
+	}
+}
+
+/// The prover of the FRI-Binius polynomial commitment scheme.
+#[derive(Debug, Getters)]
+pub struct FRIPCSProver<F, FDomain, FEncode, DomainFactory, MTProver>
+where
+	F: BinaryField,
+	FEncode: BinaryField,
+{
+	commit_meta: CommitMeta,
+	#[getset(get = "pub")]
+	fri_params: FRIParams<F, FEncode>,
+	#[getset(get = "pub")]
+	merkle_prover: MTProver,
+	domain_factory: DomainFactory,
+	_fdomain_marker: PhantomData<FDomain>,
+}
+
+impl<F, FDomain, FEncode, DomainFactory, MTProver>
+	FRIPCSProver<F, FDomain, FEncode, DomainFactory, MTProver>
+where
+	F: BinaryField + ExtensionField<FEncode>,
+	FEncode: BinaryField,
+	MTProver: MerkleTreeProver<F>,
+{
+	/// Constructs the prover with the FRI parameters selected by
+	/// [`make_commit_params_with_optimal_arity`].
+	pub fn new(
+		commit_meta: &CommitMeta,
+		merkle_prover: MTProver,
+		domain_factory: DomainFactory,
+		security_bits: usize,
+		log_inv_rate: usize,
+	) -> Result<Self, Error> {
+		let fri_params = make_commit_params_with_optimal_arity(
+			commit_meta,
+			merkle_prover.scheme(),
+			security_bits,
+			log_inv_rate,
+		)?;
+		Ok(Self::with_params(commit_meta, merkle_prover, domain_factory, fri_params))
+	}
+
+	/// Constructs the prover with explicit FRI parameters.
+	pub fn with_params(
+		commit_meta: &CommitMeta,
+		merkle_prover: MTProver,
+		domain_factory: DomainFactory,
+		fri_params: FRIParams<F, FEncode>,
+	) -> Self {
+		Self {
+			commit_meta: commit_meta.clone(),
+			fri_params,
+			merkle_prover,
+			domain_factory,
+			_fdomain_marker: PhantomData,
+		}
+	}
+}
+
+/// The data retained by [`FRIPCSProver`] after committing a batch.
+#[derive(Debug)]
+pub struct FRICommitted<P, VCSCommitted> {
+	committed: VCSCommitted,
+	codeword: Vec<P>,
+}
+
+impl<F, FDomain, FEncode, P, DomainFactory, MTScheme, MTProver> PolynomialCommitmentProver<F, P>
+	for FRIPCSProver<F, FDomain, FEncode, DomainFactory, MTProver>
+where
+	F: TowerField + ExtensionField<FEncode>,
+	FDomain: Field,
+	FEncode: BinaryField,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<FDomain>
+		+ PackedExtension<FEncode>,
+	DomainFactory: EvaluationDomainFactory<FDomain> + Clone,
+	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes + DeserializeBytes>,
+	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+{
+	type Scheme = FRIPCS<F, FEncode, MTScheme>;
+	type Committed = FRICommitted<P, MTProver::Committed>;
+
+	fn commit<M, Backend>(
+		&self,
+		multilins: &[M],
+		backend: &Backend,
+	) -> Result<(MTScheme::Digest, Self::Committed), Error>
+	where
+		M: MultilinearPoly<P>,
+		Backend: ComputationBackend,
+	{
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = commit(&self.fri_params, &self.merkle_prover, multilins, backend)?;
+		Ok((
+			commitment,
+			FRICommitted {
+				committed,
+				codeword,
+			},
+		))
+	}
+
+	fn prove<M, Challenger_, Backend>(
+		&self,
+		committed: Self::Committed,
+		committed_multilins: &[M],
+		transparent_multilins: &[M],
+		claims: &[PIOPSumcheckClaim<F>],
+		transcript: &mut ProverTranscript<Challenger_>,
+		backend: &Backend,
+	) -> Result<(), Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+		Challenger_: Challenger,
+		Backend: ComputationBackend,
+	{
+		let FRICommitted {
+			committed,
+			codeword,
+		} = committed;
+		prove(
+			&self.fri_params,
+			&self.merkle_prover,
+			self.domain_factory.clone(),
+			&self.commit_meta,
+			committed,
+			&codeword,
+			committed_multilins,
+			transparent_multilins,
+			claims,
+			transcript,
+			backend,
+		)
+	}
+}
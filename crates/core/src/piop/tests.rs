@@ -19,11 +19,14 @@ use super::{
 	prove::commit,
 	verify,
 	verify::{make_commit_params_with_optimal_arity, CommitMeta},
-	PIOPSumcheckClaim,
+	FRIPCSProver, PIOPSumcheckClaim, PolynomialCommitmentProver, PolynomialCommitmentScheme,
+	FRIPCS,
 };
 use crate::{
 	fiat_shamir::HasherChallenger,
-	merkle_tree::{BinaryMerkleTreeProver, MerkleTreeProver, MerkleTreeScheme},
+	merkle_tree::{
+		BinaryMerkleTreeProver, BinaryMerkleTreeScheme, MerkleTreeProver, MerkleTreeScheme,
+	},
 	polynomial::MultivariatePoly,
 	protocols::fri::CommitOutput,
 	transcript::ProverTranscript,
@@ -133,7 +136,7 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 		commitment,
 		committed,
 		codeword,
-	} = commit(&fri_params, merkle_prover, &committed_multilins).unwrap();
+	} = commit(&fri_params, merkle_prover, &committed_multilins, &backend).unwrap();
 
 	let transparent_multilins_by_vars = commit_meta
 		.n_multilins_by_vars()
@@ -251,3 +254,84 @@ fn test_commit_prove_verify() {
 		log_inv_rate,
 	);
 }
+
+#[test]
+fn test_fri_pcs_commit_prove_verify() {
+	type F = <PackedBinaryField2x128b as PackedField>::Scalar;
+
+	let commit_meta = CommitMeta::with_vars([4, 4, 6, 7]);
+	let log_inv_rate = 1;
+
+	let pcs_prover = FRIPCSProver::<_, BinaryField8b, BinaryField16b, _, _>::new(
+		&commit_meta,
+		BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression),
+		DefaultEvaluationDomainFactory::default(),
+		SECURITY_BITS,
+		log_inv_rate,
+	)
+	.unwrap();
+	let pcs = FRIPCS::<F, BinaryField16b, _>::new(
+		&commit_meta,
+		BinaryMerkleTreeScheme::<_, Groestl256, _>::new(Groestl256ByteCompression),
+		SECURITY_BITS,
+		log_inv_rate,
+	)
+	.unwrap();
+
+	let backend = make_portable_backend();
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let committed_multilins =
+		generate_multilins::<PackedBinaryField2x128b>(commit_meta.n_multilins_by_vars(), &mut rng)
+			.into_iter()
+			.map(MLEDirectAdapter::from)
+			.collect::<Vec<_>>();
+	let (commitment, committed) = pcs_prover.commit(&committed_multilins, &backend).unwrap();
+
+	let transparent_mles = generate_multilins::<PackedBinaryField2x128b>(
+		&commit_meta
+			.n_multilins_by_vars()
+			.iter()
+			.map(|&n_committed| n_committed.min(1))
+			.collect::<Vec<_>>(),
+		&mut rng,
+	);
+	let transparent_multilins = transparent_mles
+		.iter()
+		.map(|mle| MLEDirectAdapter::from(mle.clone()))
+		.collect::<Vec<_>>();
+	let sumcheck_claims = make_sumcheck_claims(&committed_multilins, &transparent_multilins);
+
+	let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	proof.message().write(&commitment);
+	pcs_prover
+		.prove(
+			committed,
+			&committed_multilins,
+			&transparent_multilins,
+			&sumcheck_claims,
+			&mut proof,
+			&backend,
+		)
+		.unwrap();
+
+	let mut proof = proof.into_verifier();
+	let transparent_polys = transparent_mles
+		.iter()
+		.map(|mle| {
+			transparent::MultilinearExtensionTransparent::<_, PackedBinaryField2x128b>::from_values_and_mu(
+				mle.evals().to_vec(),
+				mle.n_vars(),
+			)
+			.unwrap()
+		})
+		.collect::<Vec<_>>();
+	let transparent_polys = transparent_polys
+		.iter()
+		.map(|poly| poly as &dyn MultivariatePoly<F>)
+		.collect::<Vec<_>>();
+
+	let commitment = proof.message().read().unwrap();
+	pcs.verify(&commitment, &transparent_polys, &sumcheck_claims, &mut proof)
+		.unwrap();
+}
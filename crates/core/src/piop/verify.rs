@@ -33,7 +33,7 @@ use crate::{
 /// interpolating them into a piecewise multilinear whose evaluations are the concatenation of the
 /// piecewise evaluations. This metadata captures the "shape" of the batch, meaning the number of
 /// variables of all polynomials in the batch.
-#[derive(Debug, CopyGetters)]
+#[derive(Debug, Clone, CopyGetters)]
 pub struct CommitMeta {
 	n_multilins_by_vars: Vec<usize>,
 	offsets_by_vars: Vec<usize>,
@@ -36,6 +36,9 @@ enum EvalcheckNumerics {
 	LinearCombination,
 	ZeroPadded,
 	CompositeMLE,
+	Interleaved,
+	Concatenated,
+	Unpacked,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,6 +53,13 @@ pub enum EvalcheckProof<F: Field> {
 	},
 	ZeroPadded(F, Box<EvalcheckProof<F>>),
 	CompositeMLE,
+	Interleaved {
+		subproofs: Vec<(F, EvalcheckProof<F>)>,
+	},
+	Concatenated {
+		subproofs: Vec<(F, EvalcheckProof<F>)>,
+	},
+	Unpacked,
 }
 
 impl<F: Field> EvalcheckProof<F> {
@@ -70,6 +80,19 @@ impl<F: Field> EvalcheckProof<F> {
 				EvalcheckProof::ZeroPadded(eval.into(), Box::new(proof.isomorphic()))
 			}
 			Self::CompositeMLE => EvalcheckProof::CompositeMLE,
+			Self::Interleaved { subproofs } => EvalcheckProof::Interleaved {
+				subproofs: subproofs
+					.into_iter()
+					.map(|(eval, proof)| (eval.into(), proof.isomorphic()))
+					.collect(),
+			},
+			Self::Concatenated { subproofs } => EvalcheckProof::Concatenated {
+				subproofs: subproofs
+					.into_iter()
+					.map(|(eval, proof)| (eval.into(), proof.isomorphic()))
+					.collect(),
+			},
+			Self::Unpacked => EvalcheckProof::Unpacked,
 		}
 	}
 }
@@ -85,6 +108,9 @@ impl EvalcheckNumerics {
 			6 => Ok(Self::LinearCombination),
 			7 => Ok(Self::ZeroPadded),
 			8 => Ok(Self::CompositeMLE),
+			9 => Ok(Self::Interleaved),
+			10 => Ok(Self::Concatenated),
+			11 => Ok(Self::Unpacked),
 			_ => Err(Error::EvalcheckSerializationError),
 		}
 	}
@@ -114,12 +140,7 @@ pub fn serialize_evalcheck_proof<B: BufMut, F: TowerField>(
 		}
 		EvalcheckProof::LinearCombination { subproofs } => {
 			transcript.write_bytes(&[EvalcheckNumerics::LinearCombination as u8]);
-			let len_u64 = subproofs.len() as u64;
-			transcript.write_bytes(&len_u64.to_le_bytes());
-			for (scalar, subproof) in subproofs {
-				transcript.write_scalar(*scalar);
-				serialize_evalcheck_proof(transcript, subproof)
-			}
+			serialize_evalcheck_subproofs(transcript, subproofs);
 		}
 		EvalcheckProof::ZeroPadded(val, subproof) => {
 			transcript.write_bytes(&[EvalcheckNumerics::ZeroPadded as u8]);
@@ -129,6 +150,29 @@ pub fn serialize_evalcheck_proof<B: BufMut, F: TowerField>(
 		EvalcheckProof::CompositeMLE => {
 			transcript.write_bytes(&[EvalcheckNumerics::CompositeMLE as u8]);
 		}
+		EvalcheckProof::Interleaved { subproofs } => {
+			transcript.write_bytes(&[EvalcheckNumerics::Interleaved as u8]);
+			serialize_evalcheck_subproofs(transcript, subproofs);
+		}
+		EvalcheckProof::Concatenated { subproofs } => {
+			transcript.write_bytes(&[EvalcheckNumerics::Concatenated as u8]);
+			serialize_evalcheck_subproofs(transcript, subproofs);
+		}
+		EvalcheckProof::Unpacked => {
+			transcript.write_bytes(&[EvalcheckNumerics::Unpacked as u8]);
+		}
+	}
+}
+
+fn serialize_evalcheck_subproofs<B: BufMut, F: TowerField>(
+	transcript: &mut TranscriptWriter<B>,
+	subproofs: &[(F, EvalcheckProof<F>)],
+) {
+	let len_u64 = subproofs.len() as u64;
+	transcript.write_bytes(&len_u64.to_le_bytes());
+	for (scalar, subproof) in subproofs {
+		transcript.write_scalar(*scalar);
+		serialize_evalcheck_proof(transcript, subproof)
 	}
 }
 
@@ -150,15 +194,7 @@ pub fn deserialize_evalcheck_proof<B: Buf, F: TowerField>(
 			Ok(EvalcheckProof::Repeating(Box::new(inner)))
 		}
 		EvalcheckNumerics::LinearCombination => {
-			let mut len = [0u8; 8];
-			transcript.read_bytes(&mut len)?;
-			let len = u64::from_le_bytes(len) as usize;
-			let mut subproofs: Vec<(F, EvalcheckProof<F>)> = Vec::new();
-			for _ in 0..len {
-				let scalar = transcript.read_scalar()?;
-				let subproof = deserialize_evalcheck_proof(transcript)?;
-				subproofs.push((scalar, subproof));
-			}
+			let subproofs = deserialize_evalcheck_subproofs(transcript)?;
 			Ok(EvalcheckProof::LinearCombination { subproofs })
 		}
 		EvalcheckNumerics::ZeroPadded => {
@@ -167,7 +203,31 @@ pub fn deserialize_evalcheck_proof<B: Buf, F: TowerField>(
 			Ok(EvalcheckProof::ZeroPadded(scalar, Box::new(subproof)))
 		}
 		EvalcheckNumerics::CompositeMLE => Ok(EvalcheckProof::CompositeMLE),
+		EvalcheckNumerics::Interleaved => {
+			let subproofs = deserialize_evalcheck_subproofs(transcript)?;
+			Ok(EvalcheckProof::Interleaved { subproofs })
+		}
+		EvalcheckNumerics::Concatenated => {
+			let subproofs = deserialize_evalcheck_subproofs(transcript)?;
+			Ok(EvalcheckProof::Concatenated { subproofs })
+		}
+		EvalcheckNumerics::Unpacked => Ok(EvalcheckProof::Unpacked),
+	}
+}
+
+fn deserialize_evalcheck_subproofs<B: Buf, F: TowerField>(
+	transcript: &mut TranscriptReader<B>,
+) -> Result<Vec<(F, EvalcheckProof<F>)>, Error> {
+	let mut len = [0u8; 8];
+	transcript.read_bytes(&mut len)?;
+	let len = u64::from_le_bytes(len) as usize;
+	let mut subproofs: Vec<(F, EvalcheckProof<F>)> = Vec::new();
+	for _ in 0..len {
+		let scalar = transcript.read_scalar()?;
+		let subproof = deserialize_evalcheck_proof(transcript)?;
+		subproofs.push((scalar, subproof));
 	}
+	Ok(subproofs)
 }
 
 pub struct EvalPointOracleIdMap<T: Clone, F: Field> {
@@ -8,6 +8,7 @@ use binius_field::{
 use binius_hal::ComputationBackend;
 use binius_math::MultilinearExtension;
 use binius_maybe_rayon::prelude::*;
+use binius_utils::checked_arithmetics::log2_strict_usize;
 use getset::{Getters, MutGetters};
 use itertools::izip;
 use tracing::instrument;
@@ -303,6 +304,14 @@ where
 				);
 			}
 
+			MultilinearPolyVariant::Unpacked(_) => {
+				self.finalized_proofs.insert(
+					multilinear_id,
+					eval_point,
+					(eval, EvalcheckProof::Unpacked),
+				);
+			}
+
 			MultilinearPolyVariant::Repeating { id, .. } => {
 				let n_vars = self.oracles.n_vars(id);
 				let inner_eval_point = eval_point.slice(0..n_vars);
@@ -401,6 +410,38 @@ where
 				self.incomplete_proof_claims
 					.insert(multilinear_id, eval_point, evalcheck_claim);
 			}
+
+			MultilinearPolyVariant::Interleaved(inner_ids) => {
+				let log_count = log2_strict_usize(inner_ids.len());
+				let inner_eval_point = eval_point.slice(log_count..eval_point.len());
+				for inner_id in inner_ids {
+					self.claims_without_evals
+						.push((self.oracles.oracle(inner_id), inner_eval_point.clone()));
+				}
+				self.incomplete_proof_claims
+					.insert(multilinear_id, eval_point, evalcheck_claim);
+			}
+
+			MultilinearPolyVariant::Strided(strided) => {
+				let subclaim = EvalcheckMultilinearClaim {
+					id: strided.id(),
+					eval_point: strided.inner_eval_point(&eval_point).into(),
+					eval,
+				};
+				self.incomplete_proof_claims
+					.insert(multilinear_id, eval_point, evalcheck_claim);
+				self.claims_queue.push(subclaim);
+			}
+
+			MultilinearPolyVariant::Concatenated(inner_ids) => {
+				for inner_id in inner_ids {
+					let inner = self.oracles.oracle(inner_id);
+					let inner_eval_point = eval_point.slice(0..inner.n_vars());
+					self.claims_without_evals.push((inner, inner_eval_point));
+				}
+				self.incomplete_proof_claims
+					.insert(multilinear_id, eval_point, evalcheck_claim);
+			}
 		};
 	}
 
@@ -479,6 +520,52 @@ where
 					})
 			}
 
+			MultilinearPolyVariant::Interleaved(inner_ids) => {
+				let log_count = log2_strict_usize(inner_ids.len());
+				let inner_eval_point = &evalcheck_claim.eval_point[log_count..];
+				inner_ids
+					.iter()
+					.map(|&inner_id| {
+						self.finalized_proofs
+							.get(inner_id, inner_eval_point)
+							.map(|(eval, subproof)| (*eval, subproof.clone()))
+					})
+					.collect::<Option<Vec<_>>>()
+					.map(|subproofs| {
+						self.finalized_proofs.insert(
+							evalcheck_claim.id,
+							eval_point,
+							(eval, EvalcheckProof::Interleaved { subproofs }),
+						);
+					})
+			}
+
+			MultilinearPolyVariant::Strided(strided) => self
+				.finalized_proofs
+				.get(strided.id(), &strided.inner_eval_point(&eval_point))
+				.map(|(_, subproof)| subproof.clone())
+				.map(|subproof| {
+					self.finalized_proofs
+						.insert(evalcheck_claim.id, eval_point, (eval, subproof));
+				}),
+
+			MultilinearPolyVariant::Concatenated(inner_ids) => inner_ids
+				.iter()
+				.map(|&inner_id| {
+					let inner_n_vars = self.oracles.n_vars(inner_id);
+					self.finalized_proofs
+						.get(inner_id, &evalcheck_claim.eval_point[..inner_n_vars])
+						.map(|(eval, subproof)| (*eval, subproof.clone()))
+				})
+				.collect::<Option<Vec<_>>>()
+				.map(|subproofs| {
+					self.finalized_proofs.insert(
+						evalcheck_claim.id,
+						eval_point,
+						(eval, EvalcheckProof::Concatenated { subproofs }),
+					);
+				}),
+
 			_ => unreachable!(),
 		};
 		res.is_some()
@@ -493,7 +580,7 @@ where
 
 		let multilinear = self.oracles.oracle(id);
 		match multilinear.variant {
-			MultilinearPolyVariant::Committed => {
+			MultilinearPolyVariant::Committed | MultilinearPolyVariant::Unpacked(_) => {
 				let subclaim = EvalcheckMultilinearClaim {
 					id: multilinear.id,
 					eval_point,
@@ -568,6 +655,45 @@ where
 				};
 				self.collect_projected_committed(subclaim);
 			}
+			MultilinearPolyVariant::Interleaved(inner_ids) => {
+				let log_count = log2_strict_usize(inner_ids.len());
+				let inner_eval_point = eval_point.slice(log_count..eval_point.len());
+				for id in inner_ids {
+					let (eval, _) = self
+						.finalized_proofs
+						.get(id, &inner_eval_point)
+						.expect("finalized_proofs contains all the proofs");
+					let subclaim = EvalcheckMultilinearClaim {
+						id,
+						eval_point: inner_eval_point.clone(),
+						eval: *eval,
+					};
+					self.collect_projected_committed(subclaim);
+				}
+			}
+			MultilinearPolyVariant::Strided(strided) => {
+				let subclaim = EvalcheckMultilinearClaim {
+					id: strided.id(),
+					eval_point: strided.inner_eval_point(&eval_point).into(),
+					eval,
+				};
+				self.collect_projected_committed(subclaim);
+			}
+			MultilinearPolyVariant::Concatenated(inner_ids) => {
+				for id in inner_ids {
+					let inner_eval_point = eval_point.slice(0..self.oracles.n_vars(id));
+					let (eval, _) = self
+						.finalized_proofs
+						.get(id, &inner_eval_point)
+						.expect("finalized_proofs contains all the proofs");
+					let subclaim = EvalcheckMultilinearClaim {
+						id,
+						eval_point: inner_eval_point,
+						eval: *eval,
+					};
+					self.collect_projected_committed(subclaim);
+				}
+			}
 			_ => {}
 		}
 	}
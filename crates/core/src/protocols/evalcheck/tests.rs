@@ -6,7 +6,7 @@ use assert_matches::assert_matches;
 use binius_field::{
 	packed::{get_packed_slice, len_packed_slice, set_packed_slice},
 	underlier::WithUnderlier,
-	BinaryField128b, Field, PackedBinaryField128x1b, PackedBinaryField16x8b,
+	BinaryField128b, BinaryField8b, Field, PackedBinaryField128x1b, PackedBinaryField16x8b,
 	PackedBinaryField1x128b, PackedField, TowerField,
 };
 use binius_hal::{make_portable_backend, ComputationBackendExt};
@@ -27,7 +27,9 @@ use crate::{
 		EvalcheckProof, EvalcheckProver, EvalcheckVerifier,
 	},
 	transparent::select_row::SelectRow,
-	witness::MultilinearExtensionIndex,
+	witness::{
+		concatenated_witness, interleaved_witness, strided_witness, MultilinearExtensionIndex,
+	},
 };
 
 type FExtension = BinaryField128b;
@@ -546,6 +548,280 @@ fn test_evalcheck_zero_padded() {
 	verifier_state.verify(vec![claim], proof).unwrap();
 }
 
+#[test]
+fn test_evalcheck_interleaved() {
+	type P = PackedBinaryField16x8b;
+
+	let inner_n_vars = 6;
+	let log_count = 2;
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let inner_ids = (0..1 << log_count)
+		.map(|_| oracles.add_committed(inner_n_vars, <P as PackedField>::Scalar::TOWER_LEVEL))
+		.collect::<Vec<_>>();
+	let interleaved_id = oracles.add_interleaved(inner_ids.clone()).unwrap();
+	assert_eq!(oracles.n_vars(interleaved_id), inner_n_vars + log_count);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let inner_witnesses = inner_ids
+		.iter()
+		.map(|_| {
+			MultilinearExtension::from_values(
+				repeat_with(|| P::random(&mut rng))
+					.take(1 << (inner_n_vars - P::LOG_WIDTH))
+					.collect(),
+			)
+			.unwrap()
+		})
+		.collect::<Vec<_>>();
+	let interleaved_poly = interleaved_witness(&inner_witnesses).unwrap();
+	assert_eq!(
+		get_packed_slice(interleaved_poly.evals(), (5 << log_count) | 3),
+		get_packed_slice(inner_witnesses[3].evals(), 5)
+	);
+
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(inner_n_vars + log_count)
+		.collect::<Vec<_>>();
+
+	let backend = make_portable_backend();
+	let query: MultilinearQuery<BinaryField128b, _> =
+		backend.multilinear_query(&eval_point).unwrap();
+	let eval = interleaved_poly.evaluate(&query).unwrap();
+
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	witness_index
+		.update_multilin_poly(
+			inner_ids
+				.iter()
+				.copied()
+				.zip(&inner_witnesses)
+				.map(|(id, witness)| (id, witness.to_ref().specialize_arc_dyn::<PExtension>()))
+				.chain([(
+					interleaved_id,
+					interleaved_poly.to_ref().specialize_arc_dyn::<PExtension>(),
+				)]),
+		)
+		.unwrap();
+
+	let claim = EvalcheckMultilinearClaim {
+		id: interleaved_id,
+		eval_point: eval_point.into(),
+		eval,
+	};
+
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index, &backend);
+	let proof = prover_state.prove(vec![claim.clone()]).unwrap();
+	assert_matches!(proof[0], EvalcheckProof::Interleaved { .. });
+	assert_eq!(prover_state.committed_eval_claims().len(), 1 << log_count);
+
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	verifier_state
+		.verify(vec![claim.clone()], proof.clone())
+		.unwrap();
+	assert_eq!(verifier_state.committed_eval_claims().len(), 1 << log_count);
+
+	let wrong_claim = EvalcheckMultilinearClaim {
+		eval: claim.eval + FExtension::ONE,
+		..claim
+	};
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	assert!(verifier_state.verify(vec![wrong_claim], proof).is_err());
+}
+
+#[test]
+fn test_evalcheck_strided() {
+	type P = PackedBinaryField16x8b;
+
+	let n_vars = 8;
+	let log_stride = 2;
+	let offset = 3;
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let poly_id = oracles.add_committed(n_vars, <P as PackedField>::Scalar::TOWER_LEVEL);
+	let strided_id = oracles.add_strided(poly_id, log_stride, offset).unwrap();
+	assert_eq!(oracles.n_vars(strided_id), n_vars - log_stride);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let poly_witness = MultilinearExtension::from_values(
+		repeat_with(|| P::random(&mut rng))
+			.take(1 << (n_vars - P::LOG_WIDTH))
+			.collect(),
+	)
+	.unwrap();
+	let strided_poly = strided_witness(&poly_witness, log_stride, offset).unwrap();
+	assert_eq!(
+		get_packed_slice(strided_poly.evals(), 7),
+		get_packed_slice(poly_witness.evals(), (7 << log_stride) | offset)
+	);
+
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars - log_stride)
+		.collect::<Vec<_>>();
+
+	let backend = make_portable_backend();
+	let query: MultilinearQuery<BinaryField128b, _> =
+		backend.multilinear_query(&eval_point).unwrap();
+	let eval = strided_poly.evaluate(&query).unwrap();
+
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	witness_index
+		.update_multilin_poly(vec![
+			(poly_id, poly_witness.to_ref().specialize_arc_dyn::<PExtension>()),
+			(strided_id, strided_poly.to_ref().specialize_arc_dyn::<PExtension>()),
+		])
+		.unwrap();
+
+	let claim = EvalcheckMultilinearClaim {
+		id: strided_id,
+		eval_point: eval_point.clone().into(),
+		eval,
+	};
+
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index, &backend);
+	let proof = prover_state.prove(vec![claim.clone()]).unwrap();
+	assert_eq!(prover_state.committed_eval_claims().len(), 1);
+
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	verifier_state.verify(vec![claim], proof).unwrap();
+
+	let committed_claim = &verifier_state.committed_eval_claims()[0];
+	assert_eq!(committed_claim.id, poly_id);
+	assert_eq!(committed_claim.eval_point[..log_stride], [FExtension::ONE, FExtension::ONE]);
+	assert_eq!(committed_claim.eval_point[log_stride..], eval_point);
+}
+
+#[test]
+fn test_evalcheck_unpacked() {
+	let n_vars = 6;
+	let log_degree = 3;
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let poly_id = oracles.add_committed(n_vars, BinaryField8b::TOWER_LEVEL);
+	let unpacked_id = oracles.add_unpacked(poly_id, log_degree).unwrap();
+	assert_eq!(oracles.n_vars(unpacked_id), n_vars + log_degree);
+	assert_eq!(oracles.tower_level(unpacked_id), 0);
+
+	assert!(oracles.add_unpacked(poly_id, log_degree + 1).is_err());
+	let shifted_id = oracles
+		.add_shifted(poly_id, 1, n_vars, ShiftVariant::CircularLeft)
+		.unwrap();
+	assert!(oracles.add_unpacked(shifted_id, log_degree).is_err());
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars + log_degree)
+		.collect::<Vec<_>>();
+	let claim = EvalcheckMultilinearClaim {
+		id: unpacked_id,
+		eval_point: eval_point.clone().into(),
+		eval: <FExtension as Field>::random(&mut rng),
+	};
+
+	let backend = make_portable_backend();
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index, &backend);
+	let proof = prover_state.prove(vec![claim.clone()]).unwrap();
+	assert_eq!(proof, vec![EvalcheckProof::Unpacked]);
+
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	verifier_state.verify(vec![claim], proof).unwrap();
+
+	// Unpacked claims are resolved by ring switching, not by reduction to the inner oracle.
+	let committed_claim = &verifier_state.committed_eval_claims()[0];
+	assert_eq!(committed_claim.id, unpacked_id);
+	assert_eq!(*committed_claim.eval_point, eval_point);
+}
+
+#[test]
+fn test_evalcheck_concatenated() {
+	type P = PackedBinaryField16x8b;
+
+	let n_vars = 8;
+	let inner_n_vars = [6, 5, 4, 4];
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let inner_ids = inner_n_vars.map(|inner_n_vars| {
+		oracles.add_committed(inner_n_vars, <P as PackedField>::Scalar::TOWER_LEVEL)
+	});
+	let concatenated_id = oracles.add_concatenated(n_vars, inner_ids).unwrap();
+
+	assert!(oracles
+		.add_concatenated(n_vars, [inner_ids[3], inner_ids[0]])
+		.is_err());
+	assert!(oracles.add_concatenated(6, inner_ids).is_err());
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let inner_witnesses = inner_n_vars.map(|inner_n_vars| {
+		MultilinearExtension::from_values(
+			repeat_with(|| P::random(&mut rng))
+				.take(1 << (inner_n_vars - P::LOG_WIDTH))
+				.collect(),
+		)
+		.unwrap()
+	});
+	let concatenated_poly = concatenated_witness(n_vars, &inner_witnesses).unwrap();
+	assert!(concatenated_witness(
+		n_vars,
+		&[inner_witnesses[3].to_ref(), inner_witnesses[0].to_ref()]
+	)
+	.is_err());
+	assert_eq!(
+		get_packed_slice(concatenated_poly.evals(), 64 + 32 + 3),
+		get_packed_slice(inner_witnesses[2].evals(), 3)
+	);
+	assert_eq!(get_packed_slice(concatenated_poly.evals(), 200), Field::ZERO);
+
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+
+	let backend = make_portable_backend();
+	let query: MultilinearQuery<BinaryField128b, _> =
+		backend.multilinear_query(&eval_point).unwrap();
+	let eval = concatenated_poly.evaluate(&query).unwrap();
+
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	witness_index
+		.update_multilin_poly(
+			inner_ids
+				.into_iter()
+				.zip(&inner_witnesses)
+				.map(|(id, witness)| (id, witness.to_ref().specialize_arc_dyn::<PExtension>()))
+				.chain([(
+					concatenated_id,
+					concatenated_poly
+						.to_ref()
+						.specialize_arc_dyn::<PExtension>(),
+				)]),
+		)
+		.unwrap();
+
+	let claim = EvalcheckMultilinearClaim {
+		id: concatenated_id,
+		eval_point: eval_point.into(),
+		eval,
+	};
+
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index, &backend);
+	let proof = prover_state.prove(vec![claim.clone()]).unwrap();
+	assert_matches!(proof[0], EvalcheckProof::Concatenated { .. });
+	assert_eq!(prover_state.committed_eval_claims().len(), inner_ids.len());
+
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	verifier_state
+		.verify(vec![claim.clone()], proof.clone())
+		.unwrap();
+	assert_eq!(verifier_state.committed_eval_claims().len(), inner_ids.len());
+
+	let wrong_claim = EvalcheckMultilinearClaim {
+		eval: claim.eval + FExtension::ONE,
+		..claim
+	};
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	assert!(verifier_state.verify(vec![wrong_claim], proof).is_err());
+}
+
 // Test evalcheck serialization
 #[test]
 fn test_evalcheck_serialization() {
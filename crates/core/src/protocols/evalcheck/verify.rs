@@ -3,7 +3,8 @@
 use std::mem;
 
 use binius_field::{util::inner_product_unchecked, TowerField};
-use binius_math::extrapolate_line_scalar;
+use binius_math::{eq_ind_partial_eval, evaluate_piecewise_multilinear, extrapolate_line_scalar};
+use binius_utils::checked_arithmetics::log2_strict_usize;
 use getset::{Getters, MutGetters};
 use tracing::instrument;
 
@@ -120,6 +121,20 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 				self.committed_eval_claims.push(claim);
 			}
 
+			MultilinearPolyVariant::Unpacked(_) => {
+				if evalcheck_proof != EvalcheckProof::Unpacked {
+					return Err(VerificationError::SubproofMismatch.into());
+				}
+
+				let claim = EvalcheckMultilinearClaim {
+					id: multilinear.id(),
+					eval_point,
+					eval,
+				};
+
+				self.committed_eval_claims.push(claim);
+			}
+
 			MultilinearPolyVariant::Repeating { id, .. } => {
 				let subproof = match evalcheck_proof {
 					EvalcheckProof::Repeating(subproof) => subproof,
@@ -241,6 +256,86 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 					subclaim_eval_point,
 				)?;
 			}
+			MultilinearPolyVariant::Interleaved(inner_ids) => {
+				let subproofs = match evalcheck_proof {
+					EvalcheckProof::Interleaved { subproofs } => subproofs,
+					_ => return Err(VerificationError::SubproofMismatch.into()),
+				};
+
+				if subproofs.len() != inner_ids.len() {
+					return Err(VerificationError::SubproofMismatch.into());
+				}
+
+				// The low variables select the inner oracle, so the evaluation is the inner
+				// evaluations weighted by the equality indicator at the low coordinates
+				let log_count = log2_strict_usize(inner_ids.len());
+				let (selector_point, subclaim_eval_point) = eval_point.split_at(log_count);
+				let actual_eval = inner_product_unchecked::<F, F>(
+					subproofs.iter().map(|(eval, _)| *eval),
+					eq_ind_partial_eval::<F>(selector_point),
+				);
+
+				if actual_eval != eval {
+					return Err(VerificationError::IncorrectEvaluation(multilinear.label()).into());
+				}
+
+				subproofs.into_iter().zip(inner_ids).try_for_each(
+					|((eval, subproof), suboracle_id)| {
+						self.verify_multilinear_subclaim(
+							eval,
+							subproof,
+							suboracle_id,
+							subclaim_eval_point,
+						)
+					},
+				)?;
+			}
+			MultilinearPolyVariant::Strided(strided) => {
+				let new_claim = EvalcheckMultilinearClaim {
+					id: strided.id(),
+					eval_point: strided.inner_eval_point(&eval_point).into(),
+					eval,
+				};
+
+				self.verify_multilinear(new_claim, evalcheck_proof)?;
+			}
+			MultilinearPolyVariant::Concatenated(inner_ids) => {
+				let subproofs = match evalcheck_proof {
+					EvalcheckProof::Concatenated { subproofs } => subproofs,
+					_ => return Err(VerificationError::SubproofMismatch.into()),
+				};
+
+				if subproofs.len() != inner_ids.len() {
+					return Err(VerificationError::SubproofMismatch.into());
+				}
+
+				let mut n_pieces_by_vars = vec![0; eval_point.len() + 1];
+				for &inner_id in &inner_ids {
+					n_pieces_by_vars[self.oracles.n_vars(inner_id)] += 1;
+				}
+				let mut piece_evals = subproofs.iter().map(|(eval, _)| *eval).collect::<Vec<_>>();
+				let actual_eval = evaluate_piecewise_multilinear(
+					&eval_point,
+					&n_pieces_by_vars,
+					&mut piece_evals,
+				)?;
+
+				if actual_eval != eval {
+					return Err(VerificationError::IncorrectEvaluation(multilinear.label()).into());
+				}
+
+				subproofs.into_iter().zip(inner_ids).try_for_each(
+					|((eval, subproof), suboracle_id)| {
+						let inner_n_vars = self.oracles.n_vars(suboracle_id);
+						self.verify_multilinear_subclaim(
+							eval,
+							subproof,
+							suboracle_id,
+							&eval_point[..inner_n_vars],
+						)
+					},
+				)?;
+			}
 			MultilinearPolyVariant::Composite(composition) => {
 				if evalcheck_proof != EvalcheckProof::CompositeMLE {
 					return Err(VerificationError::SubproofMismatch.into());
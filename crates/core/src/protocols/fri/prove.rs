@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::{BinaryField, ExtensionField, PackedExtension, PackedField, TowerField};
-use binius_hal::{make_portable_backend, ComputationBackend};
+use binius_hal::ComputationBackend;
 use binius_maybe_rayon::prelude::*;
 use binius_utils::{bail, SerializeBytes};
 use bytemuck::zeroed_vec;
@@ -17,53 +17,33 @@ use super::{
 use crate::{
 	fiat_shamir::{CanSampleBits, Challenger},
 	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
-	protocols::fri::common::{fold_chunk, fold_interleaved_chunk},
 	reed_solomon::reed_solomon::ReedSolomonCode,
 	transcript::{ProverTranscript, TranscriptWriter},
 };
 
 #[instrument(skip_all, level = "debug")]
-pub fn fold_codeword<F, FS>(
+pub fn fold_codeword<F, FS, Backend>(
 	rs_code: &ReedSolomonCode<FS>,
 	codeword: &[F],
 	// Round is the number of total folding challenges received so far.
 	round: usize,
 	folding_challenges: &[F],
-) -> Vec<F>
+	backend: &Backend,
+) -> Result<Vec<F>, Error>
 where
 	F: BinaryField + ExtensionField<FS>,
 	FS: BinaryField,
+	Backend: ComputationBackend,
 {
 	// Preconditions
 	assert_eq!(codeword.len() % (1 << folding_challenges.len()), 0);
 	assert!(round >= folding_challenges.len());
 	assert!(round <= rs_code.log_dim());
 
-	if folding_challenges.is_empty() {
-		return codeword.to_vec();
-	}
-
 	let start_round = round - folding_challenges.len();
-	let chunk_size = 1 << folding_challenges.len();
-
-	// For each chunk of size `2^chunk_size` in the codeword, fold it with the folding challenges
-	codeword
-		.par_chunks(chunk_size)
-		.enumerate()
-		.map_init(
-			|| vec![F::default(); chunk_size],
-			|scratch_buffer, (chunk_index, chunk)| {
-				fold_chunk(
-					rs_code,
-					start_round,
-					chunk_index,
-					chunk,
-					folding_challenges,
-					scratch_buffer,
-				)
-			},
-		)
-		.collect()
+	let folded =
+		backend.fri_fold_codeword(rs_code.get_ntt(), codeword, start_round, folding_challenges)?;
+	Ok(folded)
 }
 
 /// Fold the interleaved codeword into a single codeword with the same block length.
@@ -74,49 +54,26 @@ where
 /// * `codeword` - an interleaved codeword.
 /// * `challenges` - the folding challenges. The length must be at least `log_batch_size`.
 /// * `log_batch_size` - the base-2 logarithm of the batch size of the interleaved code.
+/// * `backend` - the computation backend performing the fold.
 #[instrument(skip_all, level = "debug")]
-fn fold_interleaved<F, FS>(
+fn fold_interleaved<F, FS, Backend>(
 	rs_code: &ReedSolomonCode<FS>,
 	codeword: &[F],
 	challenges: &[F],
 	log_batch_size: usize,
-) -> Vec<F>
+	backend: &Backend,
+) -> Result<Vec<F>, Error>
 where
 	F: BinaryField + ExtensionField<FS>,
 	FS: BinaryField,
+	Backend: ComputationBackend,
 {
 	assert_eq!(codeword.len(), 1 << (rs_code.log_len() + log_batch_size));
 	assert!(challenges.len() >= log_batch_size);
 
-	let backend = make_portable_backend();
-
-	let (interleave_challenges, fold_challenges) = challenges.split_at(log_batch_size);
-	let tensor = backend
-		.tensor_product_full_query(interleave_challenges)
-		.expect("number of challenges is less than 32");
-
-	// For each chunk of size `2^chunk_size` in the codeword, fold it with the folding challenges
-	let fold_chunk_size = 1 << fold_challenges.len();
-	let interleave_chunk_size = 1 << log_batch_size;
-	let chunk_size = fold_chunk_size * interleave_chunk_size;
-	codeword
-		.par_chunks(chunk_size)
-		.enumerate()
-		.map_init(
-			|| vec![F::default(); 2 * fold_chunk_size],
-			|scratch_buffer, (i, chunk)| {
-				fold_interleaved_chunk(
-					rs_code,
-					log_batch_size,
-					i,
-					chunk,
-					&tensor,
-					fold_challenges,
-					scratch_buffer,
-				)
-			},
-		)
-		.collect()
+	let folded =
+		backend.fri_fold_interleaved(rs_code.get_ntt(), codeword, challenges, log_batch_size)?;
+	Ok(folded)
 }
 
 #[derive(Debug)]
@@ -166,12 +123,14 @@ where
 /// * `params` - common FRI protocol parameters.
 /// * `merkle_prover` - the merke tree prover to use for committing
 /// * `message` - the interleaved message to encode and commit
+/// * `backend` - the computation backend to use for encoding
 #[instrument(skip_all, level = "debug")]
-pub fn commit_interleaved<F, FA, P, PA, MerkleProver, VCS>(
+pub fn commit_interleaved<F, FA, P, PA, MerkleProver, VCS, Backend>(
 	rs_code: &ReedSolomonCode<PA>,
 	params: &FRIParams<F, FA>,
 	merkle_prover: &MerkleProver,
 	message: &[P],
+	backend: &Backend,
 ) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
 where
 	F: BinaryField,
@@ -180,6 +139,7 @@ where
 	PA: PackedField<Scalar = FA>,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F>,
+	Backend: ComputationBackend,
 {
 	let n_elems = rs_code.dim() << params.log_batch_size();
 	if message.len() * P::WIDTH != n_elems {
@@ -188,25 +148,35 @@ where
 		));
 	}
 
-	commit_interleaved_with(rs_code, params, merkle_prover, move |buffer| {
-		buffer.copy_from_slice(message)
-	})
+	commit_interleaved_with(
+		rs_code,
+		params,
+		merkle_prover,
+		move |buffer| buffer.copy_from_slice(message),
+		backend,
+	)
 }
 
 /// Encodes and commits the input message with a closure for writing the message.
 ///
+/// The whole message must be written before encoding starts. It cannot be committed in row
+/// chunks as they are generated, because every coset block of the Reed–Solomon codeword is the
+/// additive NTT of the entire message, so no Merkle leaf is final until the last row is known.
+///
 /// ## Arguments
 ///
 /// * `rs_code` - the Reed-Solomon code to use for encoding
 /// * `params` - common FRI protocol parameters.
 /// * `merkle_prover` - the Merkle tree prover to use for committing
 /// * `message_writer` - a closure that writes the interleaved message to encode and commit
+/// * `backend` - the computation backend to use for encoding
 #[instrument(skip_all, level = "debug")]
-pub fn commit_interleaved_with<F, FA, P, PA, MerkleProver, VCS>(
+pub fn commit_interleaved_with<F, FA, P, PA, MerkleProver, VCS, Backend>(
 	rs_code: &ReedSolomonCode<PA>,
 	params: &FRIParams<F, FA>,
 	merkle_prover: &MerkleProver,
 	message_writer: impl FnOnce(&mut [P]),
+	backend: &Backend,
 ) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
 where
 	F: BinaryField,
@@ -215,6 +185,7 @@ where
 	PA: PackedField<Scalar = FA>,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F>,
+	Backend: ComputationBackend,
 {
 	let log_batch_size = params.log_batch_size();
 	let log_elems = rs_code.log_dim() + log_batch_size;
@@ -225,7 +196,7 @@ where
 	let mut encoded = tracing::debug_span!("allocate codeword")
 		.in_scope(|| zeroed_vec(1 << (log_elems - P::LOG_WIDTH + rs_code.log_inv_rate())));
 	message_writer(&mut encoded[..1 << (log_elems - P::LOG_WIDTH)]);
-	rs_code.encode_ext_batch_inplace(&mut encoded, log_batch_size)?;
+	rs_code.encode_ext_batch_inplace(backend, &mut encoded, log_batch_size)?;
 
 	// take the first arity as coset_log_len, or use log_inv_rate if arities are empty
 	let coset_log_len = params
@@ -240,13 +211,13 @@ where
 		let iterated_big_chunks = to_par_scalar_big_chunks(&encoded, 1 << coset_log_len);
 
 		merkle_prover
-			.commit_iterated(iterated_big_chunks, log_len)
+			.commit_iterated(iterated_big_chunks, log_len, backend)
 			.map_err(|err| Error::VectorCommit(Box::new(err)))?
 	} else {
 		let iterated_small_chunks = to_par_scalar_small_chunks(&encoded, 1 << coset_log_len);
 
 		merkle_prover
-			.commit_iterated(iterated_small_chunks, log_len)
+			.commit_iterated(iterated_small_chunks, log_len, backend)
 			.map_err(|err| Error::VectorCommit(Box::new(err)))?
 	};
 
@@ -263,12 +234,13 @@ pub enum FoldRoundOutput<VCSCommitment> {
 }
 
 /// A stateful prover for the FRI fold phase.
-pub struct FRIFolder<'a, F, FA, MerkleProver, VCS>
+pub struct FRIFolder<'a, F, FA, MerkleProver, VCS, Backend>
 where
 	FA: BinaryField,
 	F: BinaryField,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F>,
+	Backend: ComputationBackend,
 {
 	params: &'a FRIParams<F, FA>,
 	merkle_prover: &'a MerkleProver,
@@ -278,14 +250,16 @@ where
 	curr_round: usize,
 	next_commit_round: Option<usize>,
 	unprocessed_challenges: Vec<F>,
+	backend: &'a Backend,
 }
 
-impl<'a, F, FA, MerkleProver, VCS> FRIFolder<'a, F, FA, MerkleProver, VCS>
+impl<'a, F, FA, MerkleProver, VCS, Backend> FRIFolder<'a, F, FA, MerkleProver, VCS, Backend>
 where
 	F: TowerField + ExtensionField<FA>,
 	FA: BinaryField,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F, Digest: SerializeBytes>,
+	Backend: ComputationBackend,
 {
 	/// Constructs a new folder.
 	pub fn new(
@@ -293,6 +267,7 @@ where
 		merkle_prover: &'a MerkleProver,
 		committed_codeword: &'a [F],
 		committed: &'a MerkleProver::Committed,
+		backend: &'a Backend,
 	) -> Result<Self, Error> {
 		if committed_codeword.len() != 1 << params.log_len() {
 			bail!(Error::InvalidArgs(
@@ -310,6 +285,7 @@ where
 			curr_round: 0,
 			next_commit_round,
 			unprocessed_challenges: Vec::with_capacity(params.rs_code().log_dim()),
+			backend,
 		})
 	}
 
@@ -354,7 +330,8 @@ where
 					prev_codeword,
 					self.curr_round - self.params.log_batch_size(),
 					&self.unprocessed_challenges,
-				)
+					self.backend,
+				)?
 			}
 			None => {
 				// Fold the interleaved codeword that was originally committed into a single
@@ -365,7 +342,8 @@ where
 					self.codeword,
 					&self.unprocessed_challenges,
 					self.params.log_batch_size(),
-				)
+					self.backend,
+				)?
 			}
 		};
 		self.unprocessed_challenges.clear();
@@ -380,7 +358,7 @@ where
 
 		let (commitment, committed) = self
 			.merkle_prover
-			.commit(&folded_codeword, coset_size)
+			.commit(&folded_codeword, coset_size, self.backend)
 			.map_err(|err| Error::VectorCommit(Box::new(err)))?;
 
 		self.round_committed.push((folded_codeword, committed));
@@ -2,7 +2,8 @@
 
 use std::slice;
 
-use binius_field::{packed::get_packed_slice, Field, PackedField};
+use binius_field::{Field, PackedField};
+use binius_hal::ComputationBackend;
 use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
 use bytemuck::zeroed_vec;
@@ -38,7 +39,10 @@ pub struct GrandProductWitness<PW: PackedField> {
 
 impl<PW: PackedField> GrandProductWitness<PW> {
 	#[instrument(skip_all, level = "debug", name = "GrandProductWitness::new")]
-	pub fn new(poly: MultilinearWitness<PW>) -> Result<Self, Error> {
+	pub fn new(
+		poly: MultilinearWitness<PW>,
+		backend: &impl ComputationBackend,
+	) -> Result<Self, Error> {
 		// Compute the circuit layers from bottom to top
 		// TODO: Why does this fully copy the input layer?
 		let mut input_layer = zeroed_vec(1 << poly.n_vars().saturating_sub(PW::LOG_WIDTH));
@@ -66,33 +70,11 @@ impl<PW: PackedField> GrandProductWitness<PW> {
 		debug_span!("constructing_layers").in_scope(|| {
 			for curr_n_vars in (0..poly.n_vars()).rev() {
 				let layer_below = all_layers.last().expect("layers is not empty by invariant");
-				let mut new_layer = zeroed_vec(1 << curr_n_vars.saturating_sub(PW::LOG_WIDTH));
-
-				if curr_n_vars >= PW::LOG_WIDTH {
-					let (left_half, right_half) =
-						layer_below.split_at(1 << (curr_n_vars - PW::LOG_WIDTH));
-
-					new_layer
-						.par_iter_mut()
-						.zip(left_half.par_iter().zip(right_half.par_iter()))
-						.for_each(|(out_i, (left_i, right_i))| {
-							*out_i = *left_i * *right_i;
-						});
-				} else {
-					let new_layer = &mut new_layer[0];
-					let len = 1 << curr_n_vars;
-					for i in 0..len {
-						new_layer.set(
-							i,
-							get_packed_slice(layer_below, i)
-								* get_packed_slice(layer_below, len + i),
-						);
-					}
-				}
-
+				let new_layer = backend.gkr_product_layer(curr_n_vars, layer_below)?;
 				all_layers.push(new_layer);
 			}
-		});
+			Ok::<_, Error>(())
+		})?;
 
 		// Reverse the layers
 		all_layers.reverse();
@@ -7,6 +7,7 @@ use binius_field::{
 	underlier::UnderlierType,
 	Field, PackedField, TowerField,
 };
+use binius_hal::ComputationBackend;
 use binius_utils::bail;
 use tracing::instrument;
 
@@ -21,6 +22,7 @@ use crate::{
 pub fn construct_grand_product_witnesses<U, F>(
 	ids: &[OracleId],
 	witness_index: &MultilinearExtensionIndex<U, F>,
+	backend: &impl ComputationBackend,
 ) -> Result<Vec<GrandProductWitness<PackedType<U, F>>>, Error>
 where
 	U: UnderlierType + PackScalar<F>,
@@ -31,7 +33,7 @@ where
 			witness_index
 				.get_multilin_poly(*id)
 				.map_err(|e| e.into())
-				.and_then(GrandProductWitness::new)
+				.and_then(|witness| GrandProductWitness::new(witness, backend))
 		})
 		.collect::<Result<Vec<_>, _>>()
 }
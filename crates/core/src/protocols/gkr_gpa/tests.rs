@@ -97,7 +97,8 @@ fn create_claims_witnesses_helper<
 		let witness_poly = witness_index
 			.get_multilin_poly(multilin_oracles[index].id())
 			.unwrap();
-		let witness = GrandProductWitness::new(witness_poly).unwrap();
+		let witness =
+			GrandProductWitness::new(witness_poly, &binius_hal::make_portable_backend()).unwrap();
 		new_claims.push(claim);
 		new_witnesses.push(witness);
 	});
@@ -241,6 +241,35 @@ pub const fn immediate_switchover_heuristic(_extension_degree: usize) -> usize {
 	0
 }
 
+/// Constructs a small-value heuristic that returns the number of initial multilinear rounds (at most
+/// `max_rounds`) to be proven with the small-value optimization, given the extension degree of the
+/// large field over the base field of the witness and the maximal composition degree.
+///
+/// Each small-value round grows the precomputation by a factor of $(d + 1) / 2$; rounds are added
+/// while the total growth stays within the extension degree, which stands in for the relative cost
+/// of an extension field multiplication.
+pub fn standard_small_value_heuristic(max_rounds: usize) -> impl Fn(usize, usize) -> usize + Copy {
+	move |extension_degree: usize, composition_degree: usize| {
+		if extension_degree <= 1 {
+			return 0;
+		}
+
+		let log_growth_per_round = ((composition_degree.max(1) + 1) as f64).log2() - 1.0;
+		let max_log_growth = (extension_degree as f64).log2();
+		(1..=max_rounds)
+			.take_while(|&rounds| rounds as f64 * log_growth_per_round <= max_log_growth)
+			.count()
+	}
+}
+
+/// Small-value heuristic that never uses the small-value optimization.
+pub const fn no_small_value_heuristic(
+	_extension_degree: usize,
+	_composition_degree: usize,
+) -> usize {
+	0
+}
+
 /// Check that all multilinears in a slice are of the same size.
 pub fn equal_n_vars_check<'a, P, M>(
 	multilinears: impl IntoIterator<Item = &'a M>,
@@ -93,6 +93,10 @@ pub enum Error {
 	LagrangeRoundEvalsSizeMismatch,
 	#[error("length of the zero prefix does not match the expected value")]
 	IncorrectZerosPrefixLen,
+	#[error("cannot have more small-value rounds than the total number of variables")]
+	TooManySmallValueRounds,
+	#[error("small-value accumulators do not match the eq-ind sumcheck instance")]
+	IncorrectSmallValueAccumulators,
 	#[error("oracle error: {0}")]
 	Oracle(#[from] OracleError),
 	#[error("witness error: {0}")]
@@ -22,8 +22,8 @@ use crate::{
 			interpolation_domains_for_composition_degrees, RoundCoeffs,
 		},
 		prove::{
-			common::fold_partial_eq_ind, MultilinearInput, ProverState, SumcheckInterpolator,
-			SumcheckProver,
+			common::fold_partial_eq_ind, MultilinearInput, ProverState, SmallValueAccumulators,
+			SumcheckInterpolator, SumcheckProver,
 		},
 		CompositeSumClaim, Error,
 	},
@@ -98,6 +98,7 @@ where
 	nonzero_scalars_prefixes: Option<Vec<usize>>,
 	first_round_eval_1s: Option<Vec<P::Scalar>>,
 	const_eval_suffix: (usize, P::Scalar, P::Scalar),
+	small_value_accumulators: Option<SmallValueAccumulators<P::Scalar>>,
 	backend: &'a Backend,
 }
 
@@ -114,6 +115,7 @@ where
 			nonzero_scalars_prefixes: None,
 			first_round_eval_1s: None,
 			const_eval_suffix: Default::default(),
+			small_value_accumulators: None,
 		}
 	}
 
@@ -160,6 +162,18 @@ where
 		self
 	}
 
+	/// Specify precomputed small-value accumulators, which replace round evaluation in the first
+	/// [`SmallValueAccumulators::n_rounds`] rounds.
+	///
+	/// Only low-to-high evaluation order is supported.
+	pub fn with_small_value_accumulators(
+		mut self,
+		small_value_accumulators: SmallValueAccumulators<F>,
+	) -> Self {
+		self.small_value_accumulators = Some(small_value_accumulators);
+		self
+	}
+
 	#[instrument(skip_all, level = "debug", name = "EqIndSumcheckProverBuilder::build")]
 	pub fn build<FDomain, Composition, M>(
 		self,
@@ -243,6 +257,20 @@ where
 
 		let nontrivial_evaluation_points = get_nontrivial_evaluation_points(&domains)?;
 
+		let small_value_rounds = if let Some(ref accumulators) = self.small_value_accumulators {
+			let degrees = compositions.iter().map(|composition| composition.degree());
+			if evaluation_order != EvaluationOrder::LowToHigh
+				|| accumulators.n_vars() != n_vars
+				|| !accumulators.degrees().iter().copied().eq(degrees)
+			{
+				bail!(Error::IncorrectSmallValueAccumulators);
+			}
+
+			accumulators.n_rounds()
+		} else {
+			0
+		};
+
 		let multilinears_input = self
 			.nonzero_scalars_prefixes
 			.unwrap_or_else(|| vec![1 << n_vars; multilinears.len()])
@@ -259,13 +287,15 @@ where
 			multilinears_input,
 			claimed_sums,
 			nontrivial_evaluation_points,
-			switchover_fn,
+			// Multilinears are not accessed during small-value rounds, no need to fold them early.
+			|extension_degree| switchover_fn(extension_degree).max(small_value_rounds),
 			backend,
 		)?;
 
 		let eq_ind_prefix_eval = F::ONE;
 		let eq_ind_challenges = eq_ind_challenges.to_vec();
 		let first_round_eval_1s = self.first_round_eval_1s;
+		let small_value_accumulators = self.small_value_accumulators;
 
 		Ok(EqIndSumcheckProver {
 			n_vars,
@@ -279,6 +309,8 @@ where
 			compositions,
 			domains,
 			first_round_eval_1s,
+			small_value_accumulators,
+			small_value_challenges: Vec::new(),
 			backend: PhantomData,
 		})
 	}
@@ -303,6 +335,8 @@ where
 	compositions: Vec<Composition>,
 	domains: Vec<InterpolationDomain<FDomain>>,
 	first_round_eval_1s: Option<Vec<P::Scalar>>,
+	small_value_accumulators: Option<SmallValueAccumulators<P::Scalar>>,
+	small_value_challenges: Vec<P::Scalar>,
 	backend: PhantomData<Backend>,
 }
 
@@ -333,6 +367,12 @@ where
 		}
 	}
 
+	fn is_small_value_round(&self) -> bool {
+		self.small_value_accumulators
+			.as_ref()
+			.is_some_and(|accumulators| self.round() < accumulators.n_rounds())
+	}
+
 	fn update_eq_ind_prefix_eval(&mut self, challenge: F) {
 		// Update the running eq ind evaluation.
 		self.eq_ind_prefix_eval *= eq(self.eq_ind_round_challenge(), challenge);
@@ -398,26 +438,6 @@ where
 			}
 		};
 
-		let evaluators = self
-			.compositions
-			.iter()
-			.map(|composition| {
-				let composition_at_infinity =
-					ArithCircuitPoly::new(composition.expression().leading_term());
-
-				Evaluator {
-					n_rounds_remaining,
-					composition,
-					composition_at_infinity,
-					have_first_round_eval_1s,
-					eq_ind_challenges,
-					eq_ind_partial_evals,
-					suffix_value,
-					suffix_value_at_inf,
-				}
-			})
-			.collect::<Vec<_>>();
-
 		let interpolators = self
 			.domains
 			.iter()
@@ -438,10 +458,37 @@ where
 				.min(1 << n_rounds_remaining.saturating_sub(1)),
 		};
 
-		let round_evals = self.state.calculate_round_evals(
-			(1 << n_rounds_remaining.saturating_sub(1)) - self.eval_prefix,
-			&evaluators,
-		)?;
+		let round_evals = if self.is_small_value_round() {
+			self.small_value_accumulators
+				.as_ref()
+				.expect("is_small_value_round checks accumulators presence")
+				.round_evals(&self.small_value_challenges, have_first_round_eval_1s)?
+		} else {
+			let evaluators = self
+				.compositions
+				.iter()
+				.map(|composition| {
+					let composition_at_infinity =
+						ArithCircuitPoly::new(composition.expression().leading_term());
+
+					Evaluator {
+						n_rounds_remaining,
+						composition,
+						composition_at_infinity,
+						have_first_round_eval_1s,
+						eq_ind_challenges,
+						eq_ind_partial_evals,
+						suffix_value,
+						suffix_value_at_inf,
+					}
+				})
+				.collect::<Vec<_>>();
+
+			self.state.calculate_round_evals(
+				(1 << n_rounds_remaining.saturating_sub(1)) - self.eval_prefix,
+				&evaluators,
+			)?
+		};
 
 		let prime_coeffs = self.state.calculate_round_coeffs_from_evals(
 			&interpolators,
@@ -470,6 +517,10 @@ where
 
 	#[instrument(skip_all, name = "EqIndSumcheckProver::fold", level = "debug")]
 	fn fold(&mut self, challenge: F) -> Result<(), Error> {
+		if self.is_small_value_round() {
+			self.small_value_challenges.push(challenge);
+		}
+
 		self.update_eq_ind_prefix_eval(challenge);
 
 		let evaluation_order = self.state.evaluation_order();
@@ -0,0 +1,650 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::mem;
+
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	util::inner_product_unchecked,
+	ExtensionField, Field, PackedExtension, PackedField, PackedSubfield, TowerField,
+};
+use binius_hal::RoundEvals;
+use binius_math::{eq_ind_partial_eval, CompositionPoly, EvaluationDomainFactory, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use bytemuck::zeroed_vec;
+use itertools::izip;
+use stackalloc::stackalloc_with_iter;
+use tracing::instrument;
+
+use super::univariate::univariate_skip_domains;
+use crate::{
+	polynomial::ArithCircuitPoly,
+	protocols::sumcheck::{
+		common::{equal_n_vars_check, small_field_embedding_degree_check},
+		univariate_zerocheck::domain_size,
+		Error,
+	},
+};
+
+/// Precomputed accumulators of the small-value optimization for the first rounds of an eq-ind
+/// sumcheck.
+///
+/// The prime polynomial of round $i$ (see [`EqIndSumcheckProver`]) is a linear combination of the
+/// round $i$ accumulators with Lagrange coefficients at the challenges of the previous rounds. The
+/// accumulators are sums of composition evaluations over a small grid in the first variables,
+/// which are computed upfront in the witness base field, and scaled by the equality indicator
+/// only at the very end. See [BDT25], Section 4 for details.
+///
+/// [`EqIndSumcheckProver`]: super::eq_ind::EqIndSumcheckProver
+/// [BDT25]: <https://eprint.iacr.org/2025/1117>
+#[derive(Debug, Clone)]
+pub struct SmallValueAccumulators<F: Field> {
+	n_vars: usize,
+	degrees: Vec<usize>,
+	finite_points: Vec<F>,
+	// Indexed by round, then by composition. Each innermost vector is indexed by the evaluation
+	// points of the previous rounds and the current round in mixed radix, with the earliest round
+	// being the least significant digit.
+	accumulators: Vec<Vec<Vec<F>>>,
+}
+
+impl<F: Field> SmallValueAccumulators<F> {
+	/// The number of variables of the sumcheck instance.
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// The number of initial rounds covered by the accumulators.
+	pub fn n_rounds(&self) -> usize {
+		self.accumulators.len()
+	}
+
+	/// Degrees of the compositions, in claim order.
+	pub fn degrees(&self) -> &[usize] {
+		&self.degrees
+	}
+
+	/// Computes the prime polynomial round evaluations at the `SumcheckEvaluator` evaluation points
+	/// (one, Karatsuba infinity, then nontrivial points), given the challenges of all the previous
+	/// rounds.
+	pub fn round_evals(
+		&self,
+		challenges: &[F],
+		skip_eval_one: bool,
+	) -> Result<Vec<RoundEvals<F>>, Error> {
+		let Some(round_accumulators) = self.accumulators.get(challenges.len()) else {
+			bail!(Error::IncorrectNumberOfChallenges);
+		};
+
+		let round_evals = izip!(round_accumulators, &self.degrees)
+			.map(|(accumulators, &degree)| {
+				let radix = grid_radix(degree);
+				let weights = challenges.iter().fold(vec![F::ONE], |weights, &challenge| {
+					lagrange_coeffs(&self.finite_points, radix, challenge)
+						.into_iter()
+						.flat_map(|coeff| weights.iter().map(move |&weight| weight * coeff))
+						.collect()
+				});
+
+				let start_index = if skip_eval_one { 2 } else { 1 };
+				let evals = (start_index..=degree)
+					.map(|index| {
+						let accumulators = &accumulators[index * weights.len()..][..weights.len()];
+						inner_product_unchecked(
+							weights.iter().copied(),
+							accumulators.iter().copied(),
+						)
+					})
+					.collect();
+
+				RoundEvals(evals)
+			})
+			.collect();
+
+		Ok(round_evals)
+	}
+}
+
+/// Precomputes the small-value accumulators for the first `n_rounds` rounds of an eq-ind sumcheck
+/// in low-to-high evaluation order.
+///
+/// Multilinears should be embeddable into `FBase`, and compositions are evaluated over `FBase`.
+/// Round evaluation points are taken from the same domains as the ones used by the eq-ind prover.
+#[instrument(skip_all, level = "debug")]
+pub fn precompute_small_value_accumulators<F, FDomain, FBase, P, Composition, M>(
+	n_rounds: usize,
+	multilinears: &[M],
+	compositions: &[Composition],
+	eq_ind_challenges: &[F],
+	domain_factory: impl EvaluationDomainFactory<FDomain>,
+) -> Result<SmallValueAccumulators<F>, Error>
+where
+	F: TowerField + ExtensionField<FDomain> + ExtensionField<FBase>,
+	FDomain: Field,
+	FBase: TowerField + ExtensionField<FDomain>,
+	P: PackedExtension<FBase, Scalar = F>,
+	Composition: CompositionPoly<PackedSubfield<P, FBase>>,
+	M: MultilinearPoly<P> + Send + Sync,
+{
+	let skipped_prefix = SkippedPrefix {
+		skip_rounds: 0,
+		extrapolation: vec![vec![FDomain::ONE]],
+		weights: vec![F::ONE],
+	};
+
+	precompute_accumulators::<_, _, FBase, _, _, _>(
+		n_rounds,
+		multilinears,
+		compositions,
+		eq_ind_challenges,
+		domain_factory,
+		skipped_prefix,
+	)
+}
+
+/// Precomputes the small-value accumulators for the first `n_rounds` multilinear rounds that
+/// follow a univariate skip over the first `skip_rounds` variables.
+///
+/// After the skip, the multilinears are folded with Lagrange coefficients at the extension field
+/// `univariate_challenge` and are thus no longer small. The accumulators are instead computed from
+/// the unfolded `multilinears`: the skipped variables are extrapolated to the univariate round
+/// domain in `FBase`, and the per-point sums are combined with the Lagrange coefficients of that
+/// domain at `univariate_challenge`. The composition restricted to the univariate round domain has
+/// degree at most `d * (2^skip_rounds - 1)`, hence the combination is exact.
+///
+/// `eq_ind_challenges` are the zerocheck challenges of the rounds after the skip.
+#[instrument(skip_all, level = "debug")]
+pub fn precompute_small_value_accumulators_after_skip<F, FDomain, FBase, P, Composition, M>(
+	n_rounds: usize,
+	skip_rounds: usize,
+	univariate_challenge: F,
+	multilinears: &[M],
+	compositions: &[Composition],
+	eq_ind_challenges: &[F],
+	domain_factory: impl EvaluationDomainFactory<FDomain>,
+) -> Result<SmallValueAccumulators<F>, Error>
+where
+	F: TowerField + ExtensionField<FDomain> + ExtensionField<FBase>,
+	FDomain: TowerField,
+	FBase: TowerField + ExtensionField<FDomain>,
+	P: PackedExtension<FBase, Scalar = F>,
+	Composition: CompositionPoly<PackedSubfield<P, FBase>>,
+	M: MultilinearPoly<P> + Send + Sync,
+{
+	let max_degree = compositions
+		.iter()
+		.map(|composition| composition.degree())
+		.max()
+		.unwrap_or(0);
+	let (subcube_domain, max_domain) =
+		univariate_skip_domains::<FDomain>(skip_rounds, domain_size(max_degree, skip_rounds))?;
+
+	let skipped_prefix = SkippedPrefix {
+		skip_rounds,
+		extrapolation: max_domain
+			.finite_points()
+			.iter()
+			.map(|&point| subcube_domain.lagrange_evals(point))
+			.collect(),
+		weights: max_domain.lagrange_evals(univariate_challenge),
+	};
+
+	precompute_accumulators::<_, _, FBase, _, _, _>(
+		n_rounds,
+		multilinears,
+		compositions,
+		eq_ind_challenges,
+		domain_factory,
+		skipped_prefix,
+	)
+}
+
+fn precompute_accumulators<F, FDomain, FBase, P, Composition, M>(
+	n_rounds: usize,
+	multilinears: &[M],
+	compositions: &[Composition],
+	eq_ind_challenges: &[F],
+	domain_factory: impl EvaluationDomainFactory<FDomain>,
+	skipped_prefix: SkippedPrefix<FDomain, F>,
+) -> Result<SmallValueAccumulators<F>, Error>
+where
+	F: TowerField + ExtensionField<FDomain> + ExtensionField<FBase>,
+	FDomain: Field,
+	FBase: TowerField + ExtensionField<FDomain>,
+	P: PackedExtension<FBase, Scalar = F>,
+	Composition: CompositionPoly<PackedSubfield<P, FBase>>,
+	M: MultilinearPoly<P> + Send + Sync,
+{
+	let SkippedPrefix {
+		skip_rounds,
+		extrapolation,
+		weights,
+	} = skipped_prefix;
+
+	let n_vars = equal_n_vars_check(multilinears)?
+		.checked_sub(skip_rounds)
+		.ok_or(Error::TooManySkippedRounds)?;
+	let n_multilinears = multilinears.len();
+
+	if n_rounds > n_vars {
+		bail!(Error::TooManySmallValueRounds);
+	}
+
+	if eq_ind_challenges.len() != n_vars {
+		bail!(Error::IncorrectEqIndChallengesLength);
+	}
+
+	for composition in compositions {
+		if composition.n_vars() != n_multilinears {
+			bail!(Error::InvalidComposition {
+				actual: composition.n_vars(),
+				expected: n_multilinears,
+			});
+		}
+	}
+
+	small_field_embedding_degree_check::<_, FBase, P, _>(multilinears)?;
+
+	let degrees = compositions
+		.iter()
+		.map(|composition| composition.degree())
+		.collect::<Vec<_>>();
+
+	// All compositions share the grid of the highest degree one, as lower degree domains are
+	// prefixes of the higher degree ones.
+	let max_radix = degrees.iter().copied().map(grid_radix).max().unwrap_or(2);
+	let domain = domain_factory.create(max_radix)?;
+	let finite_points = domain.finite_points().to_vec();
+	let grid_points = (0..max_radix)
+		.map(|index| grid_point(&finite_points, max_radix, index).map(|point| FBase::from(point)))
+		.collect::<Vec<_>>();
+
+	let compositions_at_infinity = compositions
+		.iter()
+		.map(|composition| ArithCircuitPoly::new(composition.expression().leading_term()))
+		.collect::<Vec<_>>();
+
+	// For each round and composition, enumerate the grid points contributing to the accumulators:
+	// arbitrary points in the previous rounds, all but zero in the current round, and hypercube
+	// vertices in the later small-value rounds.
+	let accumulator_points = (0..n_rounds)
+		.map(|round| {
+			degrees
+				.iter()
+				.map(|&degree| {
+					let radix = grid_radix(degree);
+					let prefix_len = radix.pow(round as u32 + 1);
+					(0..prefix_len << (n_rounds - round - 1))
+						.filter(|index| index % prefix_len >= radix.pow(round as u32))
+						.map(|index| {
+							let mut grid_index = 0;
+							let mut is_infinity_point = false;
+							let mut stride = 1;
+							for var in 0..n_rounds {
+								let digit = if var <= round {
+									(index / radix.pow(var as u32)) % radix
+								} else {
+									((index / prefix_len) >> (var - round - 1)) & 1
+								};
+								is_infinity_point |=
+									grid_point(&finite_points, radix, digit).is_none();
+								grid_index += digit * stride;
+								stride *= max_radix;
+							}
+							AccumulatorPoint {
+								index,
+								grid_index,
+								is_infinity_point,
+							}
+						})
+						.collect::<Vec<_>>()
+				})
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+
+	// Rows of the remaining variables are processed in batches, with each batch being evaluated
+	// over the whole grid in the first variables at once.
+	const MAX_LOG_BATCH: usize = 8;
+	let suffix_vars = n_vars - n_rounds;
+	let log_batch = suffix_vars.min(MAX_LOG_BATCH);
+	let prefix_vars = skip_rounds + n_rounds;
+	let subcube_vars = prefix_vars + log_batch;
+	let log_embedding_degree = <F as ExtensionField<FBase>>::LOG_DEGREE;
+	let column_len = 1 << log_batch.saturating_sub(<PackedSubfield<P, FBase>>::LOG_WIDTH);
+
+	let eq_ind_suffix = eq_ind_partial_eval::<F>(&eq_ind_challenges[n_rounds..]);
+
+	let sums = (0..1 << (suffix_vars - log_batch))
+		.into_par_iter()
+		.try_fold(
+			|| {
+				ParFoldStates::<FBase, P>::new(
+					n_multilinears,
+					subcube_vars,
+					log_embedding_degree,
+					column_len,
+					&accumulator_points,
+				)
+			},
+			|mut par_fold_states, batch_index| -> Result<_, Error> {
+				let ParFoldStates {
+					evals,
+					prefix_grids,
+					grids,
+					scratch,
+					composition_evals,
+					sums,
+				} = &mut par_fold_states;
+
+				for (multilinear, prefix_grid) in izip!(multilinears, prefix_grids.iter_mut()) {
+					multilinear.subcube_evals(
+						subcube_vars,
+						batch_index,
+						log_embedding_degree,
+						evals.as_mut_slice(),
+					)?;
+
+					// Transpose the subcube into one column of rows per vertex of the hypercube
+					// in the skipped and the small-value variables.
+					let evals_base = <P as PackedExtension<FBase>>::cast_bases(evals.as_slice());
+					prefix_grid.resize(column_len << prefix_vars, Default::default());
+					for vertex in 0..1 << prefix_vars {
+						let column = &mut prefix_grid[vertex * column_len..][..column_len];
+						for row in 0..1 << log_batch {
+							let eval = get_packed_slice(evals_base, vertex | row << prefix_vars);
+							set_packed_slice(column, row, eval);
+						}
+					}
+				}
+
+				let eq_ind_batch = &eq_ind_suffix[batch_index << log_batch..][..1 << log_batch];
+
+				for (skip_coeffs, &weight) in izip!(&extrapolation, &weights) {
+					for (prefix_grid, grid) in izip!(prefix_grids.iter(), grids.iter_mut()) {
+						extrapolate_skipped_vars(
+							prefix_grid,
+							grid,
+							skip_rounds,
+							n_rounds,
+							column_len,
+							skip_coeffs,
+						);
+						extrapolate_grid(grid, scratch, n_rounds, column_len, &grid_points);
+					}
+
+					for (round_points, round_sums) in izip!(&accumulator_points, sums.iter_mut()) {
+						for (composition, composition_at_infinity, points, sums) in izip!(
+							compositions,
+							&compositions_at_infinity,
+							round_points,
+							round_sums.iter_mut()
+						) {
+							for &AccumulatorPoint {
+								index,
+								grid_index,
+								is_infinity_point,
+							} in points
+							{
+								let batch_query = grids
+									.iter()
+									.map(|grid| &grid[grid_index * column_len..][..column_len]);
+
+								stackalloc_with_iter(n_multilinears, batch_query, |batch_query| {
+									if is_infinity_point {
+										composition_at_infinity
+											.batch_evaluate(batch_query, composition_evals)
+									} else {
+										composition.batch_evaluate(batch_query, composition_evals)
+									}
+								})?;
+
+								// Only here small field evaluations meet the extension field.
+								let sum = izip!(
+									PackedField::iter_slice(composition_evals.as_slice()),
+									eq_ind_batch
+								)
+								.map(|(eval, &eq_ind)| eq_ind * eval)
+								.sum::<F>();
+								sums[index] += weight * sum;
+							}
+						}
+					}
+				}
+
+				Ok(par_fold_states)
+			},
+		)
+		.map(|states| -> Result<_, Error> { Ok(states?.sums) })
+		.try_reduce(
+			|| zero_sums(&accumulator_points),
+			|mut overall_sums, partial_sums| {
+				for (overall, partial) in
+					izip!(overall_sums.iter_mut().flatten(), partial_sums.iter().flatten())
+				{
+					for (overall, &partial) in izip!(overall, partial) {
+						*overall += partial;
+					}
+				}
+				Ok(overall_sums)
+			},
+		)?;
+
+	// Sum over the hypercube vertices in the later small-value rounds, weighted by the equality
+	// indicator.
+	let accumulators = izip!(0..n_rounds, sums)
+		.map(|(round, round_sums)| {
+			let eq_ind_vertices = eq_ind_partial_eval::<F>(&eq_ind_challenges[round + 1..n_rounds]);
+
+			izip!(round_sums, &degrees)
+				.map(|(sums, &degree)| {
+					let prefix_len = grid_radix(degree).pow(round as u32 + 1);
+					(0..prefix_len)
+						.map(|index| {
+							let sums = sums[index..].iter().step_by(prefix_len).copied();
+							inner_product_unchecked(eq_ind_vertices.iter().copied(), sums)
+						})
+						.collect()
+				})
+				.collect()
+		})
+		.collect();
+
+	let finite_points = finite_points.into_iter().map(F::from).collect();
+
+	Ok(SmallValueAccumulators {
+		n_vars,
+		degrees,
+		finite_points,
+		accumulators,
+	})
+}
+
+// The number of evaluation points per variable for a composition of a given degree: zero, one,
+// and for degrees above one, Karatsuba infinity followed by nontrivial finite points.
+fn grid_radix(degree: usize) -> usize {
+	degree.max(1) + 1
+}
+
+// Maps an evaluation point index into a finite domain point, or `None` for Karatsuba infinity.
+// This is the order in which `SumcheckEvaluator` evaluation points are laid out.
+fn grid_point<F: Field>(finite_points: &[F], radix: usize, index: usize) -> Option<F> {
+	match index {
+		0 | 1 => Some(finite_points[index]),
+		2 if radix > 2 => None,
+		_ => Some(finite_points[index - 1]),
+	}
+}
+
+// Evaluations of the Lagrange basis for grid points of a given radix at `x`. Karatsuba infinity
+// corresponds to the leading coefficient, and thus its basis polynomial vanishes on the finite
+// points.
+fn lagrange_coeffs<F: Field>(finite_points: &[F], radix: usize, x: F) -> Vec<F> {
+	let n_finite_points = if radix > 2 { radix - 1 } else { radix };
+	let finite_points = &finite_points[..n_finite_points];
+
+	(0..radix)
+		.map(|index| match grid_point(finite_points, radix, index) {
+			Some(point) => {
+				let (numerator, denominator) = finite_points
+					.iter()
+					.filter(|&&other| other != point)
+					.fold((F::ONE, F::ONE), |(numerator, denominator), &other| {
+						(numerator * (x - other), denominator * (point - other))
+					});
+				numerator * denominator.invert_or_zero()
+			}
+			None => finite_points.iter().map(|&point| x - point).product(),
+		})
+		.collect()
+}
+
+// Extrapolates the columns over the hypercube in the skipped and the small-value variables to a
+// single point of the univariate round domain in the skipped variables, given by the Lagrange
+// coefficients `skip_coeffs` of the skipped subcube at that point.
+fn extrapolate_skipped_vars<FDomain, P>(
+	prefix_grid: &[P],
+	grid: &mut Vec<P>,
+	skip_rounds: usize,
+	n_rounds: usize,
+	column_len: usize,
+	skip_coeffs: &[FDomain],
+) where
+	FDomain: Field,
+	P: PackedField<Scalar: ExtensionField<FDomain>>,
+{
+	grid.clear();
+	grid.resize(column_len << n_rounds, P::zero());
+
+	for (subcube_index, &coeff) in skip_coeffs.iter().enumerate() {
+		// The coefficients are unit vectors at the points of the skipped subcube.
+		if coeff == FDomain::ZERO {
+			continue;
+		}
+
+		let coeff = P::broadcast(P::Scalar::from(coeff));
+		for vertex in 0..1 << n_rounds {
+			let src =
+				&prefix_grid[(subcube_index | vertex << skip_rounds) * column_len..][..column_len];
+			let dest = &mut grid[vertex * column_len..][..column_len];
+			for (dest, &src) in izip!(dest, src) {
+				*dest += src * coeff;
+			}
+		}
+	}
+}
+
+// Extends evaluations over the hypercube in the first `n_vars` variables, laid out as columns of
+// `column_len` packed elements, to the grid of `grid_points` in each variable.
+fn extrapolate_grid<P: PackedField>(
+	grid: &mut Vec<P>,
+	scratch: &mut Vec<P>,
+	n_vars: usize,
+	column_len: usize,
+	grid_points: &[Option<P::Scalar>],
+) {
+	let radix = grid_points.len();
+
+	for var in 0..n_vars {
+		let prefix_len = radix.pow(var as u32);
+		let suffix_len = 1 << (n_vars - var - 1);
+		scratch.resize(prefix_len * radix * suffix_len * column_len, P::zero());
+
+		for suffix in 0..suffix_len {
+			for prefix in 0..prefix_len {
+				let column_0 =
+					&grid[(prefix + prefix_len * 2 * suffix) * column_len..][..column_len];
+				let column_1 =
+					&grid[(prefix + prefix_len * (2 * suffix + 1)) * column_len..][..column_len];
+
+				for (index, &point) in grid_points.iter().enumerate() {
+					let dest = prefix + prefix_len * index + prefix_len * radix * suffix;
+					let dest = &mut scratch[dest * column_len..][..column_len];
+
+					for (dest, &eval_0, &eval_1) in izip!(dest, column_0, column_1) {
+						*dest = match (index, point) {
+							(0, _) => eval_0,
+							(1, _) => eval_1,
+							(_, None) => eval_1 - eval_0,
+							(_, Some(point)) => eval_0 + (eval_1 - eval_0) * P::broadcast(point),
+						};
+					}
+				}
+			}
+		}
+
+		mem::swap(grid, scratch);
+	}
+}
+
+// The variables skipped by a univariate round, which precede the small-value rounds.
+struct SkippedPrefix<FDomain, F> {
+	skip_rounds: usize,
+	// Lagrange coefficients of the skipped subcube, at each point of the univariate round domain.
+	extrapolation: Vec<Vec<FDomain>>,
+	// Lagrange coefficients of the univariate round domain at the univariate round challenge.
+	weights: Vec<F>,
+}
+
+// A grid point contributing to an accumulator.
+#[derive(Debug, Clone, Copy)]
+struct AccumulatorPoint {
+	// Index of the accumulator sum.
+	index: usize,
+	// Index of the point in the grid of all multilinear extrapolations.
+	grid_index: usize,
+	// Whether any of the coordinates is Karatsuba infinity.
+	is_infinity_point: bool,
+}
+
+fn zero_sums<F: Field>(accumulator_points: &[Vec<Vec<AccumulatorPoint>>]) -> Vec<Vec<Vec<F>>> {
+	accumulator_points
+		.iter()
+		.map(|round_points| {
+			round_points
+				.iter()
+				.map(|points| {
+					let len = points.last().map_or(0, |point| point.index + 1);
+					vec![F::ZERO; len]
+				})
+				.collect()
+		})
+		.collect()
+}
+
+/// Parallel fold state, consisting of scratch areas and result accumulators.
+struct ParFoldStates<FBase: Field, P: PackedExtension<FBase>> {
+	/// Evals of a single multilinear over a subcube.
+	evals: Vec<P>,
+	/// Evals of each multilinear over the skipped and the small-value variables.
+	prefix_grids: Vec<Vec<PackedSubfield<P, FBase>>>,
+	/// Grid extrapolations of each multilinear.
+	grids: Vec<Vec<PackedSubfield<P, FBase>>>,
+	/// Scratch space for grid extrapolation.
+	scratch: Vec<PackedSubfield<P, FBase>>,
+	/// Evals of a single composition at a single grid point.
+	composition_evals: Vec<PackedSubfield<P, FBase>>,
+	/// Unweighted accumulator sums, per round and composition.
+	sums: Vec<Vec<Vec<P::Scalar>>>,
+}
+
+impl<FBase: Field, P: PackedExtension<FBase>> ParFoldStates<FBase, P> {
+	fn new(
+		n_multilinears: usize,
+		subcube_vars: usize,
+		log_embedding_degree: usize,
+		column_len: usize,
+		accumulator_points: &[Vec<Vec<AccumulatorPoint>>],
+	) -> Self {
+		Self {
+			evals: zeroed_vec(
+				1 << subcube_vars.saturating_sub(P::LOG_WIDTH + log_embedding_degree),
+			),
+			prefix_grids: vec![Vec::new(); n_multilinears],
+			grids: vec![Vec::new(); n_multilinears],
+			scratch: Vec::new(),
+			composition_evals: zeroed_vec(column_len),
+			sums: zero_sums(accumulator_points),
+		}
+	}
+}
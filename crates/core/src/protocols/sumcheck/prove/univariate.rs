@@ -138,6 +138,8 @@ struct ParFoldStates<FBase: Field, P: PackedExtension<FBase>> {
 	extrapolated_evals: Vec<Vec<PackedSubfield<P, FBase>>>,
 	/// Evals of a single composition over extrapolated multilinears. Scratch space.
 	composition_evals: Vec<PackedSubfield<P, FBase>>,
+	/// Whether each multilinear is zero on the current subcube.
+	zero_multilinears: Vec<bool>,
 	/// Round evals accumulators, per multilinear.
 	round_evals: Vec<Vec<P::Scalar>>,
 }
@@ -180,6 +182,7 @@ impl<FBase: Field, P: PackedExtension<FBase>> ParFoldStates<FBase, P> {
 			interleaved_evals,
 			extrapolated_evals,
 			composition_evals,
+			zero_multilinears: vec![false; n_multilinears],
 			round_evals,
 		}
 	}
@@ -239,22 +242,11 @@ where
 			mut partial_eq_ind_evals,
 		} = self;
 
-		// REVIEW: consider using novel basis for the univariate round representation
-		//         (instead of Lagrange)
-		let max_dim = log2_ceil_usize(max_domain_size);
-		let subspace =
-			BinarySubspace::<FDomain::Canonical>::with_dim(max_dim)?.isomorphic::<FDomain>();
-		let max_domain = EvaluationDomain::from_points(
-			subspace.iter().take(max_domain_size).collect::<Vec<_>>(),
-			false,
-		)?;
+		let (subcube_domain, max_domain) =
+			univariate_skip_domains::<FDomain>(skip_rounds, max_domain_size)?;
 
 		// Lagrange extrapolation over skipped subcube
-		let subcube_lagrange_coeffs = EvaluationDomain::from_points(
-			subspace.reduce_dim(skip_rounds)?.iter().collect::<Vec<_>>(),
-			false,
-		)?
-		.lagrange_evals(challenge);
+		let subcube_lagrange_coeffs = subcube_domain.lagrange_evals(challenge);
 
 		// Zerocheck tensor expansion for the reduced zerocheck should be one variable less
 		fold_partial_eq_ind::<P, Backend>(
@@ -287,6 +279,27 @@ where
 	}
 }
 
+/// Returns the evaluation domains of a univariate skip over `skip_rounds` variables: the skipped
+/// subcube, and the univariate round domain of `max_domain_size` points which extends it.
+pub(super) fn univariate_skip_domains<FDomain: TowerField>(
+	skip_rounds: usize,
+	max_domain_size: usize,
+) -> Result<(EvaluationDomain<FDomain>, EvaluationDomain<FDomain>), Error> {
+	// REVIEW: consider using novel basis for the univariate round representation
+	//         (instead of Lagrange)
+	let max_dim = log2_ceil_usize(max_domain_size);
+	let subspace = BinarySubspace::<FDomain::Canonical>::with_dim(max_dim)?.isomorphic::<FDomain>();
+	let max_domain = EvaluationDomain::from_points(
+		subspace.iter().take(max_domain_size).collect::<Vec<_>>(),
+		false,
+	)?;
+	let subcube_domain = EvaluationDomain::from_points(
+		subspace.reduce_dim(skip_rounds)?.iter().collect::<Vec<_>>(),
+		false,
+	)?;
+	Ok((subcube_domain, max_domain))
+}
+
 /// Compute univariate skip round evaluations for zerocheck.
 ///
 /// When all witness multilinear hypercube evaluations can be embedded into a small field
@@ -405,6 +418,17 @@ where
 	let subcube_vars = log_batch + skip_rounds;
 	let log_subcube_count = n_vars - subcube_vars;
 
+	// Compositions that are zero whenever some of their multilinears are zero contribute nothing
+	// on subcubes where those multilinears are zero, so such subcubes are skipped. Extrapolations
+	// of zero subcubes are zero as well.
+	let expressions = compositions
+		.iter()
+		.map(|composition| composition.expression())
+		.collect::<Vec<_>>();
+	let skip_zero_subcubes = expressions
+		.iter()
+		.any(|expression| expression.vanishes_when_zero(&vec![true; n_multilinears]));
+
 	// NB: we avoid evaluation on the first 2^skip_rounds points because honest
 	// prover would always evaluate to zero there; we also factor out first
 	// skip_rounds terms of the equality indicator and apply them pointwise to
@@ -428,13 +452,13 @@ where
 					interleaved_evals,
 					extrapolated_evals,
 					composition_evals,
+					zero_multilinears,
 					round_evals,
-					..
 				} = &mut par_fold_states;
 
 				// Interpolate multilinear evals for each multilinear
-				for (multilinear, extrapolated_evals) in
-					izip!(multilinears, extrapolated_evals.iter_mut())
+				for (multilinear, extrapolated_evals, is_zero) in
+					izip!(multilinears, extrapolated_evals.iter_mut(), zero_multilinears.iter_mut())
 				{
 					// Sample evals subcube from a multilinear poly
 					multilinear.subcube_evals(
@@ -444,6 +468,14 @@ where
 						evals.as_mut_slice(),
 					)?;
 
+					if skip_zero_subcubes {
+						*is_zero = evals.iter().all(|&eval| eval == P::zero());
+						if *is_zero {
+							extrapolated_evals.fill(PackedSubfield::<P, FBase>::zero());
+							continue;
+						}
+					}
+
 					// Use the PackedExtension bound to cast evals to base field.
 					let evals_base =
 						<P as PackedExtension<FBase>>::cast_bases_mut(evals.as_mut_slice());
@@ -499,9 +531,13 @@ where
 					&partial_eq_ind_evals_scalars[subcube_index << log_batch..][..1 << log_batch];
 
 				// Evaluate the compositions and accumulate round results
-				for (composition, round_evals, &pbase_prefix_len) in
-					izip!(compositions, round_evals, &pbase_prefix_lens)
+				for (composition, expression, round_evals, &pbase_prefix_len) in
+					izip!(compositions, &expressions, round_evals, &pbase_prefix_lens)
 				{
+					if skip_zero_subcubes && expression.vanishes_when_zero(zero_multilinears) {
+						continue;
+					}
+
 					let extrapolated_evals_iter = extrapolated_evals
 						.iter()
 						.map(|evals| &evals[..pbase_prefix_len]);
@@ -24,6 +24,9 @@ use crate::{
 		common::{equal_n_vars_check, CompositeSumClaim},
 		prove::{
 			eq_ind::EqIndSumcheckProverBuilder,
+			small_value::{
+				precompute_small_value_accumulators, precompute_small_value_accumulators_after_skip,
+			},
 			univariate::{
 				zerocheck_univariate_evals, ZerocheckUnivariateEvalsOutput,
 				ZerocheckUnivariateFoldResult,
@@ -110,6 +113,7 @@ pub struct UnivariateZerocheck<
 	zerocheck_challenges: Vec<P::Scalar>,
 	domain_factory: DomainFactory,
 	switchover_fn: SwitchoverFn,
+	small_value_rounds: usize,
 	backend: &'a Backend,
 	univariate_evals_output: Option<ZerocheckUnivariateEvalsOutput<P::Scalar, P, Backend>>,
 	_p_base_marker: PhantomData<FBase>,
@@ -196,6 +200,7 @@ where
 			zerocheck_challenges,
 			domain_factory,
 			switchover_fn,
+			small_value_rounds: 0,
 			backend,
 			univariate_evals_output: None,
 			_p_base_marker: PhantomData,
@@ -203,9 +208,35 @@ where
 		})
 	}
 
+	/// Use the small-value optimization in the first multilinear rounds, either those of
+	/// [`Self::into_regular_zerocheck`] or those following the univariate round.
+	///
+	/// The number of rounds is chosen by `small_value_fn` from the extension degree of `F` over
+	/// `FBase` and the maximal composition degree, see [`standard_small_value_heuristic`].
+	///
+	/// [`standard_small_value_heuristic`]: crate::protocols::sumcheck::standard_small_value_heuristic
+	pub fn with_small_value_heuristic(
+		mut self,
+		small_value_fn: impl Fn(usize, usize) -> usize,
+	) -> Self {
+		let max_degree = self
+			.compositions
+			.iter()
+			.map(|(_, composition_base, _)| composition_base.degree())
+			.max()
+			.unwrap_or(0);
+		let extension_degree = 1 << <F as ExtensionField<FBase>>::LOG_DEGREE;
+		self.small_value_rounds = small_value_fn(extension_degree, max_degree).min(self.n_vars);
+		self
+	}
+
 	#[instrument(skip_all, level = "debug")]
 	#[allow(clippy::type_complexity)]
-	pub fn into_regular_zerocheck(self) -> Result<Box<dyn SumcheckProver<F> + 'a>, Error> {
+	pub fn into_regular_zerocheck(self) -> Result<Box<dyn SumcheckProver<F> + 'a>, Error>
+	where
+		F: ExtensionField<FBase>,
+		FBase: TowerField,
+	{
 		if self.univariate_evals_output.is_some() {
 			bail!(Error::ExpectedFold);
 		}
@@ -220,6 +251,25 @@ where
 			validate_witness(&self.multilinears, &compositions)?;
 		}
 
+		// Small-value rounds evaluate base field compositions, before the witness is folded.
+		let small_value_accumulators = (self.small_value_rounds > 0)
+			.then(|| {
+				let compositions_base = self
+					.compositions
+					.iter()
+					.map(|(_, composition_base, _)| composition_base)
+					.collect::<Vec<_>>();
+
+				precompute_small_value_accumulators::<_, _, FBase, _, _, _>(
+					self.small_value_rounds,
+					&self.multilinears,
+					&compositions_base,
+					&self.zerocheck_challenges,
+					self.domain_factory.clone(),
+				)
+			})
+			.transpose()?;
+
 		let composite_claims = self
 			.compositions
 			.into_iter()
@@ -231,16 +281,21 @@ where
 
 		let first_round_eval_1s = composite_claims.iter().map(|_| F::ZERO).collect::<Vec<_>>();
 
-		let prover = EqIndSumcheckProverBuilder::new(self.backend)
-			.with_first_round_eval_1s(&first_round_eval_1s)
-			.build(
-				EvaluationOrder::LowToHigh,
-				self.multilinears,
-				&self.zerocheck_challenges,
-				composite_claims,
-				self.domain_factory,
-				self.switchover_fn,
-			)?;
+		let mut builder = EqIndSumcheckProverBuilder::new(self.backend)
+			.with_first_round_eval_1s(&first_round_eval_1s);
+
+		if let Some(small_value_accumulators) = small_value_accumulators {
+			builder = builder.with_small_value_accumulators(small_value_accumulators);
+		}
+
+		let prover = builder.build(
+			EvaluationOrder::LowToHigh,
+			self.multilinears,
+			&self.zerocheck_challenges,
+			composite_claims,
+			self.domain_factory,
+			self.switchover_fn,
+		)?;
 
 		Ok(Box::new(prover) as Box<dyn SumcheckProver<F> + 'a>)
 	}
@@ -274,7 +329,7 @@ impl<
 where
 	F: TowerField,
 	FDomain: TowerField,
-	FBase: ExtensionField<FDomain>,
+	FBase: TowerField + ExtensionField<FDomain>,
 	P: PackedFieldIndexable<Scalar = F>
 		+ PackedExtension<F, PackedSubfield = P>
 		+ PackedExtension<FBase, PackedSubfield: PackedFieldIndexable>
@@ -318,7 +373,7 @@ where
 
 		// Output contains values that are needed for computations that happen after
 		// the round challenge has been sampled
-		let univariate_evals_output = zerocheck_univariate_evals::<_, _, FBase, _, _, _, _>(
+		let univariate_evals_output = zerocheck_univariate_evals::<_, FDomain, FBase, _, _, _, _>(
 			&self.multilinears,
 			&compositions_base,
 			&self.zerocheck_challenges,
@@ -374,6 +429,28 @@ where
 			.expect("validated to be Some")
 			.fold::<FDomain>(challenge)?;
 
+		// Small-value rounds after the skip are precomputed from the unfolded witness.
+		let small_value_rounds = self.small_value_rounds.min(self.zerocheck_challenges.len());
+		let small_value_accumulators = (small_value_rounds > 0)
+			.then(|| {
+				let compositions_base = self
+					.compositions
+					.iter()
+					.map(|(_, composition_base, _)| composition_base)
+					.collect::<Vec<_>>();
+
+				precompute_small_value_accumulators_after_skip::<_, _, FBase, _, _, _>(
+					small_value_rounds,
+					skip_rounds,
+					challenge,
+					&self.multilinears,
+					&compositions_base,
+					&self.zerocheck_challenges,
+					self.domain_factory.clone(),
+				)
+			})
+			.transpose()?;
+
 		// For each subcube of size 2**skip_rounds, we need to compute its
 		// inner product with Lagrange coefficients at challenge point in order
 		// to obtain the witness for the remaining multilinear rounds.
@@ -406,18 +483,21 @@ where
 
 		// The remaining non-univariate zerocheck rounds are an instance of EqIndSumcheck,
 		// due to the number of zerocheck challenges being equal to the number of remaining rounds.
-		let regular_prover = EqIndSumcheckProverBuilder::new(self.backend)
-			.with_eq_ind_partial_evals(partial_eq_ind_evals)
-			.build(
-				EvaluationOrder::LowToHigh,
-				partial_low_multilinears,
-				&self.zerocheck_challenges,
-				composite_claims,
-				self.domain_factory,
-				|extension_degree| {
-					(self.switchover_fn)(extension_degree).saturating_sub(skip_rounds)
-				},
-			)?;
+		let mut builder = EqIndSumcheckProverBuilder::new(self.backend)
+			.with_eq_ind_partial_evals(partial_eq_ind_evals);
+
+		if let Some(small_value_accumulators) = small_value_accumulators {
+			builder = builder.with_small_value_accumulators(small_value_accumulators);
+		}
+
+		let regular_prover = builder.build(
+			EvaluationOrder::LowToHigh,
+			partial_low_multilinears,
+			&self.zerocheck_challenges,
+			composite_claims,
+			self.domain_factory,
+			|extension_degree| (self.switchover_fn)(extension_degree).saturating_sub(skip_rounds),
+		)?;
 
 		Ok(Box::new(regular_prover) as Box<dyn SumcheckProver<F> + 'a>)
 	}
@@ -222,14 +222,17 @@ mod tests {
 	use binius_field::{
 		arch::{OptimalUnderlier128b, OptimalUnderlier512b},
 		as_packed_field::{PackScalar, PackedType},
+		packed::pack_slice,
 		underlier::UnderlierType,
 		AESTowerField128b, AESTowerField16b, AESTowerField8b, BinaryField128b, BinaryField16b,
-		Field, PackedBinaryField1x128b, PackedBinaryField4x32b, PackedFieldIndexable, TowerField,
+		Field, PackedBinaryField1x128b, PackedBinaryField4x32b, PackedField, PackedFieldIndexable,
+		TowerField,
 	};
 	use binius_hal::ComputationBackend;
 	use binius_hash::groestl::Groestl256;
 	use binius_math::{
-		BinarySubspace, CompositionPoly, EvaluationOrder, IsomorphicEvaluationDomainFactory,
+		ArithExpr, BinarySubspace, CompositionPoly, EvaluationOrder,
+		IsomorphicEvaluationDomainFactory, MLEEmbeddingAdapter, MultilinearExtension,
 		MultilinearPoly,
 	};
 	use rand::{prelude::StdRng, SeedableRng};
@@ -576,4 +579,276 @@ mod tests {
 			verifier_proof.finalize().unwrap()
 		}
 	}
+
+	/// Hides the structure of a composition from the prover, which prevents it from skipping
+	/// subcubes where the composition vanishes.
+	#[derive(Debug)]
+	struct OpaqueComposition<C>(C);
+
+	impl<P: PackedField, C: CompositionPoly<P>> CompositionPoly<P> for OpaqueComposition<C> {
+		fn n_vars(&self) -> usize {
+			self.0.n_vars()
+		}
+
+		fn degree(&self) -> usize {
+			self.0.degree()
+		}
+
+		fn binary_tower_level(&self) -> usize {
+			self.0.binary_tower_level()
+		}
+
+		fn expression(&self) -> ArithExpr<P::Scalar> {
+			// 1 + 1 is zero in characteristic 2, but not structurally.
+			self.0.expression()
+				+ ArithExpr::Const(P::Scalar::ONE)
+				+ ArithExpr::Const(P::Scalar::ONE)
+		}
+
+		fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+			self.0.evaluate(query)
+		}
+
+		fn batch_evaluate(
+			&self,
+			batch_query: &[&[P]],
+			evals: &mut [P],
+		) -> Result<(), binius_math::Error> {
+			self.0.batch_evaluate(batch_query, evals)
+		}
+	}
+
+	#[test]
+	fn test_univariatized_zerocheck_sparse_witness_same_proof() {
+		type U = OptimalUnderlier128b;
+		type FI = AESTowerField128b;
+		type FDomain = AESTowerField16b;
+		type FBase = AESTowerField16b;
+		type FWitness = AESTowerField8b;
+
+		let n_vars = 8;
+		let n_multilinears = 3;
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+		let switchover_fn = standard_switchover_heuristic(-2);
+		let mut rng = StdRng::seed_from_u64(0);
+
+		// All multilinears are zero on the padding rows, and the first one acts as a selector
+		// which is additionally unset on a run of rows.
+		let padding_start = 3 << (n_vars - 2);
+		let unset_selector_rows = 1 << (n_vars - 3)..1 << (n_vars - 1);
+		let multilinear_values = (0..n_multilinears)
+			.map(|j| {
+				(0..1 << n_vars)
+					.map(|i| {
+						if i >= padding_start
+							|| (j == 0 && unset_selector_rows.contains(&i))
+							|| i % n_multilinears == j
+						{
+							FWitness::ZERO
+						} else {
+							<FWitness as Field>::random(&mut rng)
+						}
+					})
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let prove = |skip_rounds: usize,
+		             composition_base: Arc<dyn CompositionPoly<PackedType<U, FBase>>>,
+		             composition: Arc<dyn CompositionPoly<PackedType<U, FI>>>| {
+			let multilinears = multilinear_values
+				.iter()
+				.map(|values| {
+					let mle = MultilinearExtension::from_values(pack_slice::<
+						PackedType<U, FWitness>,
+					>(values))
+					.unwrap();
+					MLEEmbeddingAdapter::<_, PackedType<U, FI>>::from(mle)
+				})
+				.collect::<Vec<_>>();
+
+			let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			let zerocheck_challenges: Vec<FI> = proof.sample_vec(n_vars - skip_rounds);
+
+			let prover =
+				UnivariateZerocheck::<FDomain, FBase, PackedType<U, FI>, _, _, _, _, _, _>::new(
+					multilinears,
+					[("product".to_string(), composition_base, composition)],
+					&zerocheck_challenges,
+					domain_factory.clone(),
+					switchover_fn,
+					&backend,
+				)
+				.unwrap();
+
+			let prover_univariate_output =
+				batch_prove_zerocheck_univariate_round(vec![prover], skip_rounds, &mut proof)
+					.unwrap();
+			batch_prove_with_start(
+				prover_univariate_output.batch_prove_start,
+				Vec::<Box<dyn SumcheckProver<_>>>::new(),
+				&mut proof,
+			)
+			.unwrap();
+
+			proof.finalize()
+		};
+
+		let product = Arc::new(ProductComposition::<3> {});
+		let opaque_product = Arc::new(OpaqueComposition(product.clone()));
+
+		for skip_rounds in 0..=n_vars {
+			assert_eq!(
+				prove(skip_rounds, product.clone(), product.clone()),
+				prove(skip_rounds, opaque_product.clone(), opaque_product.clone())
+			);
+		}
+	}
+
+	#[test]
+	fn test_zerocheck_small_value_rounds_same_proof() {
+		type U = OptimalUnderlier512b;
+		type FI = AESTowerField128b;
+		type FDomain = AESTowerField16b;
+		type FBase = AESTowerField16b;
+		type FWitness = AESTowerField8b;
+		type P = PackedType<U, FI>;
+
+		let n_vars = 7;
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+		let switchover_fn = standard_switchover_heuristic(-2);
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let mut multilinears =
+			generate_zero_product_multilinears::<PackedType<U, FWitness>, P>(&mut rng, n_vars, 2);
+		multilinears.extend(generate_zero_product_multilinears(&mut rng, n_vars, 3));
+		multilinears.extend(generate_zero_product_multilinears(&mut rng, n_vars, 4));
+
+		let pair = Arc::new(IndexComposition::new(9, [0, 1], ProductComposition::<2> {}).unwrap());
+		let triple =
+			Arc::new(IndexComposition::new(9, [2, 3, 4], ProductComposition::<3> {}).unwrap());
+		let quad =
+			Arc::new(IndexComposition::new(9, [5, 6, 7, 8], ProductComposition::<4> {}).unwrap());
+
+		let prover_compositions = [
+			(
+				"pair".into(),
+				pair.clone() as Arc<dyn CompositionPoly<PackedType<U, FBase>>>,
+				pair as Arc<dyn CompositionPoly<P>>,
+			),
+			(
+				"triple".into(),
+				triple.clone() as Arc<dyn CompositionPoly<PackedType<U, FBase>>>,
+				triple as Arc<dyn CompositionPoly<P>>,
+			),
+			(
+				"quad".into(),
+				quad.clone() as Arc<dyn CompositionPoly<PackedType<U, FBase>>>,
+				quad as Arc<dyn CompositionPoly<P>>,
+			),
+		];
+
+		let prove = |small_value_rounds: usize| {
+			let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			let zerocheck_challenges: Vec<FI> = proof.sample_vec(n_vars);
+
+			let prover = UnivariateZerocheck::<FDomain, FBase, P, _, _, _, _, _, _>::new(
+				multilinears.clone(),
+				prover_compositions.to_vec(),
+				&zerocheck_challenges,
+				domain_factory.clone(),
+				switchover_fn,
+				&backend,
+			)
+			.unwrap()
+			.with_small_value_heuristic(|_, _| small_value_rounds)
+			.into_regular_zerocheck()
+			.unwrap();
+
+			batch_prove(vec![prover], &mut proof).unwrap();
+			proof.finalize()
+		};
+
+		let expected_proof = prove(0);
+		for small_value_rounds in 1..=n_vars {
+			assert_eq!(prove(small_value_rounds), expected_proof);
+		}
+	}
+
+	#[test]
+	fn test_univariatized_zerocheck_small_value_rounds_same_proof() {
+		type U = OptimalUnderlier512b;
+		type FI = AESTowerField128b;
+		type FDomain = AESTowerField16b;
+		type FBase = AESTowerField16b;
+		type FWitness = AESTowerField8b;
+		type P = PackedType<U, FI>;
+
+		let n_vars = 7;
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+		let switchover_fn = standard_switchover_heuristic(-2);
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let mut multilinears =
+			generate_zero_product_multilinears::<PackedType<U, FWitness>, P>(&mut rng, n_vars, 2);
+		multilinears.extend(generate_zero_product_multilinears(&mut rng, n_vars, 3));
+
+		let pair = Arc::new(IndexComposition::new(5, [0, 1], ProductComposition::<2> {}).unwrap());
+		let triple =
+			Arc::new(IndexComposition::new(5, [2, 3, 4], ProductComposition::<3> {}).unwrap());
+
+		let prover_compositions = [
+			(
+				"pair".into(),
+				pair.clone() as Arc<dyn CompositionPoly<PackedType<U, FBase>>>,
+				pair as Arc<dyn CompositionPoly<P>>,
+			),
+			(
+				"triple".into(),
+				triple.clone() as Arc<dyn CompositionPoly<PackedType<U, FBase>>>,
+				triple as Arc<dyn CompositionPoly<P>>,
+			),
+		];
+
+		let prove = |skip_rounds: usize, small_value_rounds: usize| {
+			let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			let zerocheck_challenges: Vec<FI> = proof.sample_vec(n_vars - skip_rounds);
+
+			let prover = UnivariateZerocheck::<FDomain, FBase, P, _, _, _, _, _, _>::new(
+				multilinears.clone(),
+				prover_compositions.to_vec(),
+				&zerocheck_challenges,
+				domain_factory.clone(),
+				switchover_fn,
+				&backend,
+			)
+			.unwrap()
+			.with_small_value_heuristic(|_, _| small_value_rounds);
+
+			let prover_univariate_output =
+				batch_prove_zerocheck_univariate_round(vec![prover], skip_rounds, &mut proof)
+					.unwrap();
+			batch_prove_with_start(
+				prover_univariate_output.batch_prove_start,
+				Vec::<Box<dyn SumcheckProver<_>>>::new(),
+				&mut proof,
+			)
+			.unwrap();
+
+			proof.finalize()
+		};
+
+		for skip_rounds in 0..=3 {
+			let expected_proof = prove(skip_rounds, 0);
+			for small_value_rounds in 1..=n_vars - skip_rounds {
+				assert_eq!(prove(skip_rounds, small_value_rounds), expected_proof);
+			}
+		}
+	}
 }
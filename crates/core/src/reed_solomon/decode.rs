@@ -0,0 +1,312 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Decoding of [`ReedSolomonCode`] codewords.
+//!
+//! Codewords are the evaluations over the NTT domain of polynomials of degree less than the code
+//! dimension, with the message as coefficients in the novel polynomial basis. The decoders
+//! recover the polynomial in the monomial basis with [`PolyOps`] and convert it back into a
+//! message. Codes over a base field are decoded directly over any extension field, which is the
+//! same as decoding each component of the extension separately.
+//!
+//! Error correction uses Gao's algorithm, see [Gao03].
+//!
+//! [Gao03]: <https://www.math.clemson.edu/~sgao/papers/RS.pdf>
+
+use binius_field::{BinaryField, ExtensionField, PackedField};
+use binius_ntt::{poly_ops::PolyOps, AdditiveNTT, SingleThreadedNTT};
+use binius_utils::bail;
+use tracing::instrument;
+
+use super::{error::Error, reed_solomon::ReedSolomonCode};
+
+impl<P> ReedSolomonCode<P>
+where
+	P: PackedField<Scalar: BinaryField>,
+{
+	/// Recovers the message of a codeword from which some symbols have been erased.
+	///
+	/// `received` holds one entry per codeword position, with `None` marking an erasure. The
+	/// message is interpolated from the first [`Self::dim`] known symbols, and all other known
+	/// symbols are checked against its encoding.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::NotEnoughSymbols`] if fewer than [`Self::dim`] symbols are known.
+	/// * [`Error::InconsistentSymbols`] if the known symbols are not all from one codeword.
+	#[instrument(skip_all, level = "debug")]
+	pub fn decode_erasures<FE>(&self, received: &[Option<FE>]) -> Result<Vec<FE>, Error>
+	where
+		FE: BinaryField + ExtensionField<P::Scalar>,
+	{
+		if received.len() != self.len() {
+			bail!(Error::IncorrectReceivedWordLength {
+				expected: self.len()
+			});
+		}
+
+		let known = received
+			.iter()
+			.enumerate()
+			.filter_map(|(i, symbol)| symbol.map(|symbol| (i, symbol)))
+			.collect::<Vec<_>>();
+		if known.len() < self.dim() {
+			bail!(Error::NotEnoughSymbols {
+				dim: self.dim(),
+				n_known: known.len(),
+			});
+		}
+
+		let ntt = self.extension_ntt::<FE>()?;
+		let poly_ops = PolyOps::new(&ntt)?;
+		let domain = ntt.subspace(0);
+
+		let (points, values): (Vec<_>, Vec<_>) = known[..self.dim()]
+			.iter()
+			.map(|&(i, symbol)| (domain.get(i), symbol))
+			.unzip();
+		let mut message = poly_ops.interpolate(&points, &values)?;
+		poly_ops.monomial_to_novel(&mut message)?;
+
+		if known.len() > self.dim() {
+			let codeword = self.encode_message(&ntt, &message)?;
+			if known[self.dim()..]
+				.iter()
+				.any(|&(i, symbol)| codeword[i] != symbol)
+			{
+				bail!(Error::InconsistentSymbols);
+			}
+		}
+
+		Ok(message)
+	}
+
+	/// Recovers the message of a codeword with up to `(len() - dim()) / 2` corrupted symbols.
+	///
+	/// Uses Gao's algorithm: the partial extended Euclidean algorithm on the vanishing polynomial
+	/// of the domain and the interpolant of the received word yields the error locator and the
+	/// message polynomial times the error locator. The Euclidean algorithm takes quadratic time in
+	/// the block length.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::TooManyErrors`] if no codeword is within the unique decoding radius.
+	#[instrument(skip_all, level = "debug")]
+	pub fn decode<FE>(&self, received: &[FE]) -> Result<Vec<FE>, Error>
+	where
+		FE: BinaryField + ExtensionField<P::Scalar>,
+	{
+		if received.len() != self.len() {
+			bail!(Error::IncorrectReceivedWordLength {
+				expected: self.len()
+			});
+		}
+
+		let ntt = self.extension_ntt::<FE>()?;
+		let poly_ops = PolyOps::new(&ntt)?;
+
+		let mut interpolant = received.to_vec();
+		ntt.inverse_transform(&mut interpolant, 0, 0, self.log_len())?;
+		if interpolant[self.dim()..]
+			.iter()
+			.all(|&coeff| coeff == FE::ZERO)
+		{
+			interpolant.truncate(self.dim());
+			return Ok(interpolant);
+		}
+		poly_ops.novel_to_monomial(&mut interpolant)?;
+
+		let domain = ntt.subspace(0);
+		let points = (0..self.len()).map(|i| domain.get(i)).collect::<Vec<_>>();
+		let vanishing = poly_ops.vanishing_polynomial(&points)?;
+
+		// Run the extended Euclidean algorithm on (vanishing, interpolant) until the remainder
+		// has degree less than (len + dim) / 2, tracking the cofactor of the interpolant.
+		let (mut r_prev, mut r) = (vanishing, trim_zeros(interpolant));
+		let (mut t_prev, mut t) = (Vec::new(), vec![FE::ONE]);
+		while 2 * r.len() > self.len() + self.dim() {
+			let (quotient, remainder) = poly_ops.div_rem(&r_prev, &r)?;
+			let t_next = add(&t_prev, &poly_ops.mul(&quotient, &t)?);
+			(r_prev, r) = (r, trim_zeros(remainder));
+			(t_prev, t) = (t, t_next);
+		}
+
+		// The message polynomial is the remainder divided by the error locator.
+		let (message_poly, remainder) = poly_ops.div_rem(&r, &t)?;
+		let message_poly = trim_zeros(message_poly);
+		if remainder.iter().any(|&coeff| coeff != FE::ZERO) || message_poly.len() > self.dim() {
+			bail!(Error::TooManyErrors);
+		}
+
+		let mut message = message_poly;
+		message.resize(self.dim(), FE::ZERO);
+		poly_ops.monomial_to_novel(&mut message)?;
+		Ok(message)
+	}
+
+	/// An NTT over the extension field whose domain contains the evaluation domain of the code as
+	/// its first half.
+	///
+	/// The domain has one more dimension than the code so that products with the vanishing
+	/// polynomial of the code domain, which has `len() + 1` coefficients, fit into it.
+	fn extension_ntt<FE>(&self) -> Result<SingleThreadedNTT<FE>, binius_ntt::Error>
+	where
+		FE: BinaryField + ExtensionField<P::Scalar>,
+	{
+		SingleThreadedNTT::with_domain_field::<P::Scalar>(self.log_len() + 1)
+	}
+
+	fn encode_message<FE>(
+		&self,
+		ntt: &SingleThreadedNTT<FE>,
+		message: &[FE],
+	) -> Result<Vec<FE>, binius_ntt::Error>
+	where
+		FE: BinaryField,
+	{
+		let mut codeword = message.repeat(self.inv_rate());
+		for (coset, chunk) in codeword.chunks_exact_mut(self.dim()).enumerate() {
+			ntt.forward_transform(chunk, coset as u32, 0, self.log_dim())?;
+		}
+		Ok(codeword)
+	}
+}
+
+fn trim_zeros<F: BinaryField>(mut coeffs: Vec<F>) -> Vec<F> {
+	while coeffs.last() == Some(&F::ZERO) {
+		coeffs.pop();
+	}
+	coeffs
+}
+
+fn add<F: BinaryField>(a: &[F], b: &[F]) -> Vec<F> {
+	let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+	let mut sum = long.to_vec();
+	for (coeff, &b_i) in sum.iter_mut().zip(short) {
+		*coeff += b_i;
+	}
+	sum
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField128b, BinaryField16b, Field, PackedExtension};
+	use binius_hal::make_portable_backend;
+	use binius_ntt::NTTOptions;
+	use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+	use super::*;
+
+	type P = <FE as PackedExtension<BinaryField16b>>::PackedSubfield;
+	type FE = BinaryField128b;
+
+	fn encode_random(
+		rs_code: &ReedSolomonCode<P>,
+		log_batch_size: usize,
+		rng: &mut StdRng,
+	) -> (Vec<Vec<FE>>, Vec<Vec<FE>>) {
+		let batch_size = 1 << log_batch_size;
+		let mut buffer = vec![FE::ZERO; rs_code.len() << log_batch_size];
+		for elem in &mut buffer[..rs_code.dim() << log_batch_size] {
+			*elem = <FE as Field>::random(&mut *rng);
+		}
+		let interleaved_messages = buffer[..rs_code.dim() << log_batch_size].to_vec();
+		rs_code
+			.encode_ext_batch_inplace(&make_portable_backend(), &mut buffer, log_batch_size)
+			.unwrap();
+
+		let deinterleave = |interleaved: &[FE]| {
+			(0..batch_size)
+				.map(|b| {
+					interleaved
+						.iter()
+						.skip(b)
+						.step_by(batch_size)
+						.copied()
+						.collect()
+				})
+				.collect()
+		};
+		(deinterleave(&interleaved_messages), deinterleave(&buffer))
+	}
+
+	#[test]
+	fn test_decode_erasures() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let rs_code = ReedSolomonCode::<P>::new(5, 2, &NTTOptions::default()).unwrap();
+		let (messages, codewords) = encode_random(&rs_code, 2, &mut rng);
+
+		for (message, codeword) in messages.iter().zip(&codewords) {
+			for n_known in [rs_code.dim(), rs_code.dim() + 10, rs_code.len()] {
+				let known = sample(&mut rng, rs_code.len(), n_known);
+				let mut received = vec![None; rs_code.len()];
+				for i in known.iter() {
+					received[i] = Some(codeword[i]);
+				}
+				assert_eq!(&rs_code.decode_erasures(&received).unwrap(), message);
+			}
+		}
+	}
+
+	#[test]
+	fn test_decode_erasures_failures() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let rs_code = ReedSolomonCode::<P>::new(4, 1, &NTTOptions::default()).unwrap();
+		let (_, codewords) = encode_random(&rs_code, 0, &mut rng);
+
+		let mut received = codewords[0].iter().copied().map(Some).collect::<Vec<_>>();
+		for symbol in &mut received[..rs_code.len() - rs_code.dim() + 1] {
+			*symbol = None;
+		}
+		assert!(matches!(
+			rs_code.decode_erasures(&received),
+			Err(Error::NotEnoughSymbols {
+				dim: 16,
+				n_known: 15
+			})
+		));
+
+		let mut received = codewords[0].iter().copied().map(Some).collect::<Vec<_>>();
+		received[rs_code.len() - 1] = Some(<FE as Field>::random(&mut rng));
+		assert!(matches!(rs_code.decode_erasures(&received), Err(Error::InconsistentSymbols)));
+
+		assert!(matches!(
+			rs_code.decode_erasures(&received[1..]),
+			Err(Error::IncorrectReceivedWordLength { expected: 32 })
+		));
+	}
+
+	#[test]
+	fn test_decode_errors() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let rs_code = ReedSolomonCode::<P>::new(5, 2, &NTTOptions::default()).unwrap();
+		let (messages, codewords) = encode_random(&rs_code, 1, &mut rng);
+		let max_errors = (rs_code.len() - rs_code.dim()) / 2;
+
+		for (message, codeword) in messages.iter().zip(&codewords) {
+			for n_errors in [0, 1, max_errors / 2, max_errors] {
+				let mut received = codeword.clone();
+				for i in sample(&mut rng, rs_code.len(), n_errors).iter() {
+					received[i] += repeat_with(|| <FE as Field>::random(&mut rng))
+						.find(|&e| e != FE::ZERO)
+						.unwrap();
+				}
+				assert_eq!(&rs_code.decode(&received).unwrap(), message);
+			}
+		}
+	}
+
+	#[test]
+	fn test_decode_too_many_errors() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let rs_code = ReedSolomonCode::<P>::new(5, 2, &NTTOptions::default()).unwrap();
+		let (_, codewords) = encode_random(&rs_code, 0, &mut rng);
+
+		let mut received = codewords[0].clone();
+		for i in sample(&mut rng, rs_code.len(), rs_code.len() - rs_code.dim()).iter() {
+			received[i] = <FE as Field>::random(&mut rng);
+		}
+		assert!(matches!(rs_code.decode(&received), Err(Error::TooManyErrors)));
+	}
+}
@@ -0,0 +1,15 @@
+// Copyright 2025 Irreducible Inc.
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("the received word must have exactly {expected} symbols")]
+	IncorrectReceivedWordLength { expected: usize },
+	#[error("erasure decoding requires at least {dim} known symbols, got {n_known}")]
+	NotEnoughSymbols { dim: usize, n_known: usize },
+	#[error("the known symbols do not agree with any codeword")]
+	InconsistentSymbols,
+	#[error("the received word has more errors than can be uniquely decoded")]
+	TooManyErrors,
+	#[error("NTT error: {0}")]
+	NTT(#[from] binius_ntt::Error),
+}
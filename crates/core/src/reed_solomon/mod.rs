@@ -1,3 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod decode;
+mod error;
 pub mod reed_solomon;
+
+pub use error::Error;
@@ -13,9 +13,8 @@
 use std::marker::PhantomData;
 
 use binius_field::{BinaryField, ExtensionField, PackedField, RepackedExtension};
-use binius_maybe_rayon::prelude::*;
+use binius_hal::ComputationBackend;
 use binius_ntt::{AdditiveNTT, DynamicDispatchNTT, Error, NTTOptions, ThreadingSettings};
-use binius_utils::bail;
 use getset::CopyGetters;
 use tracing::instrument;
 
@@ -29,7 +28,6 @@ where
 	log_dimension: usize,
 	#[getset(get_copy = "pub")]
 	log_inv_rate: usize,
-	multithreaded: bool,
 	_p_marker: PhantomData<P>,
 }
 
@@ -54,17 +52,14 @@ where
 					log_threads: ntt_log_threads,
 				},
 				precompute_twiddles: ntt_options.precompute_twiddles,
+				kernel: ntt_options.kernel,
 			},
 		)?;
 
-		let multithreaded =
-			!matches!(ntt_options.thread_settings, ThreadingSettings::SingleThreaded);
-
 		Ok(Self {
 			ntt,
 			log_dimension,
 			log_inv_rate,
-			multithreaded,
 			_p_marker: PhantomData,
 		})
 	}
@@ -112,44 +107,19 @@ where
 	///
 	/// * If the `code` buffer does not have capacity for `len() << log_batch_size` field
 	///   elements.
-	fn encode_batch_inplace(&self, code: &mut [P], log_batch_size: usize) -> Result<(), Error> {
-		let _scope = tracing::trace_span!(
-			"Reed–Solomon encode",
-			log_len = self.log_len(),
-			log_batch_size = log_batch_size,
-			symbol_bits = P::Scalar::N_BITS,
+	fn encode_batch_inplace(
+		&self,
+		backend: &impl ComputationBackend,
+		code: &mut [P],
+		log_batch_size: usize,
+	) -> Result<(), binius_hal::Error> {
+		backend.reed_solomon_encode_batch(
+			&self.ntt,
+			self.log_dim(),
+			self.log_inv_rate,
+			log_batch_size,
+			code,
 		)
-		.entered();
-		if (code.len() << log_batch_size) < self.len() {
-			bail!(Error::BufferTooSmall {
-				log_code_len: self.len(),
-			});
-		}
-		if self.dim() % P::WIDTH != 0 {
-			bail!(Error::PackingWidthMustDivideDimension);
-		}
-
-		let msgs_len = (self.dim() / P::WIDTH) << log_batch_size;
-		for i in 1..(1 << self.log_inv_rate) {
-			code.copy_within(0..msgs_len, i * msgs_len);
-		}
-
-		if self.multithreaded {
-			(0..(1 << self.log_inv_rate))
-				.into_par_iter()
-				.zip(code.par_chunks_exact_mut(msgs_len))
-				.try_for_each(|(i, data)| {
-					self.ntt
-						.forward_transform(data, i, log_batch_size, self.log_dim())
-				})
-		} else {
-			(0..(1 << self.log_inv_rate))
-				.zip(code.chunks_exact_mut(msgs_len))
-				.try_for_each(|(i, data)| {
-					self.ntt
-						.forward_transform(data, i, log_batch_size, self.log_dim())
-				})
-		}
 	}
 
 	/// Encode a batch of interleaved messages of extension field elements in-place in a provided
@@ -168,9 +138,14 @@ where
 	#[instrument(skip_all, level = "debug")]
 	pub fn encode_ext_batch_inplace<PE: RepackedExtension<P>>(
 		&self,
+		backend: &impl ComputationBackend,
 		code: &mut [PE],
 		log_batch_size: usize,
-	) -> Result<(), Error> {
-		self.encode_batch_inplace(PE::cast_bases_mut(code), log_batch_size + PE::Scalar::LOG_DEGREE)
+	) -> Result<(), binius_hal::Error> {
+		self.encode_batch_inplace(
+			backend,
+			PE::cast_bases_mut(code),
+			log_batch_size + PE::Scalar::LOG_DEGREE,
+		)
 	}
 }
@@ -7,7 +7,7 @@ use binius_utils::sparse_index::SparseIndex;
 
 use super::error::Error;
 use crate::{
-	oracle::{MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant},
+	oracle::{MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant, OracleId},
 	piop::CommitMeta,
 	protocols::evalcheck::EvalcheckMultilinearClaim,
 };
@@ -42,6 +42,9 @@ pub struct EvalClaimSuffixDesc<F: Field> {
 pub struct PIOPSumcheckClaimDesc<'a, F: Field> {
 	/// Index of the committed multilinear, referencing the commit metadata.
 	pub committed_idx: usize,
+	/// Index of the unpacked oracle the claim is on, referencing the slice in an
+	/// [`EvalClaimSystem`], or `None` if the claim is directly on the committed multilinear.
+	pub unpacked_idx: Option<usize>,
 	/// Index of the suffix descriptor, referencing the slice in an [`EvalClaimSystem`].
 	pub suffix_desc_idx: usize,
 	pub eval_claim: &'a EvalcheckMultilinearClaim<F>,
@@ -58,6 +61,11 @@ pub struct EvalClaimSystem<'a, F: Field> {
 	pub suffix_descs: Vec<EvalClaimSuffixDesc<F>>,
 	pub sumcheck_claim_descs: Vec<PIOPSumcheckClaimDesc<'a, F>>,
 	pub eval_claim_to_prefix_desc_index: Vec<usize>,
+	/// IDs of the unpacked oracles with evaluation claims.
+	///
+	/// An unpacked oracle shares the packed evaluations of its inner committed multilinear, but
+	/// the prover needs its witness to compute partial evaluations over the smaller field.
+	pub unpacked_ids: Vec<OracleId>,
 }
 
 impl<'a, F: TowerField> EvalClaimSystem<'a, F> {
@@ -68,7 +76,8 @@ impl<'a, F: TowerField> EvalClaimSystem<'a, F> {
 	/// * `commit_meta` - metadata about the polynomial commitment.
 	/// * `oracle_to_commit_index` - a sparse index mapping oracle IDs to IDs in the commit
 	///   metadata.
-	/// * `eval_claims` - the evaluation claims on committed multilinear polynomials.
+	/// * `eval_claims` - the evaluation claims on committed multilinear polynomials, or on unpacked
+	///   views of them.
 	pub fn new(
 		oracles: &MultilinearOracleSet<F>,
 		commit_meta: &'a CommitMeta,
@@ -85,7 +94,7 @@ impl<'a, F: TowerField> EvalClaimSystem<'a, F> {
 			MultilinearPolyOracle {
 				n_vars,
 				tower_level,
-				variant: MultilinearPolyVariant::Committed,
+				variant: MultilinearPolyVariant::Committed | MultilinearPolyVariant::Unpacked(_),
 				..
 			} => n_vars + tower_level,
 			// Ignore any non-committed oracles for now, they'll be caught later in a context where
@@ -100,21 +109,34 @@ impl<'a, F: TowerField> EvalClaimSystem<'a, F> {
 			eval_claim_to_suffix_desc_index,
 		) = group_claims_by_eval_point(oracles, &eval_claims)?;
 
+		let mut unpacked_ids = Vec::new();
 		let sumcheck_claim_descs = eval_claims
 			.into_iter()
 			.enumerate()
 			.map(|(i, eval_claim)| {
 				let oracle = oracles.oracle(eval_claim.id);
-				if !matches!(oracle.variant, MultilinearPolyVariant::Committed) {
-					return Err(Error::EvalcheckClaimForDerivedPoly { id: eval_claim.id });
-				}
+				let (committed_id, unpacked_idx) = match &oracle.variant {
+					MultilinearPolyVariant::Committed => (oracle.id(), None),
+					MultilinearPolyVariant::Unpacked(unpacked) => {
+						let unpacked_idx = unpacked_ids
+							.iter()
+							.position(|&id| id == oracle.id())
+							.unwrap_or_else(|| {
+								unpacked_ids.push(oracle.id());
+								unpacked_ids.len() - 1
+							});
+						(unpacked.id(), Some(unpacked_idx))
+					}
+					_ => return Err(Error::EvalcheckClaimForDerivedPoly { id: eval_claim.id }),
+				};
 				let committed_idx = oracle_to_commit_index
-					.get(oracle.id())
+					.get(committed_id)
 					.copied()
-					.ok_or_else(|| Error::OracleToCommitIndexMissingEntry { id: eval_claim.id })?;
+					.ok_or_else(|| Error::OracleToCommitIndexMissingEntry { id: committed_id })?;
 				let suffix_desc_idx = eval_claim_to_suffix_desc_index[i];
 				Ok(PIOPSumcheckClaimDesc {
 					committed_idx,
+					unpacked_idx,
 					suffix_desc_idx,
 					eval_claim,
 				})
@@ -127,6 +149,7 @@ impl<'a, F: TowerField> EvalClaimSystem<'a, F> {
 			suffix_descs,
 			sumcheck_claim_descs,
 			eval_claim_to_prefix_desc_index,
+			unpacked_ids,
 		})
 	}
 
@@ -153,7 +176,7 @@ fn group_claims_by_eval_point<F: TowerField>(
 		let MultilinearPolyOracle {
 			id,
 			tower_level,
-			variant: MultilinearPolyVariant::Committed,
+			variant: MultilinearPolyVariant::Committed | MultilinearPolyVariant::Unpacked(_),
 			..
 		} = oracles.oracle(claim.id)
 		else {
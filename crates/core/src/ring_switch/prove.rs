@@ -36,15 +36,15 @@ pub struct ReducedWitness<P: PackedField> {
 pub fn prove<F, P, M, Tower, Challenger_, Backend>(
 	system: &EvalClaimSystem<F>,
 	witnesses: &[M],
+	unpacked_witnesses: &[M],
 	transcript: &mut ProverTranscript<Challenger_>,
 	backend: &Backend,
 ) -> Result<ReducedWitness<P>, Error>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	P: PackedFieldIndexable<Scalar = F>,
 	M: MultilinearPoly<P> + Sync,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 	Challenger_: Challenger,
 	Backend: ComputationBackend,
 {
@@ -53,6 +53,11 @@ where
 			"witness length does not match the number of multilinears".into(),
 		));
 	}
+	if unpacked_witnesses.len() != system.unpacked_ids.len() {
+		return Err(Error::InvalidWitness(
+			"witness length does not match the number of unpacked multilinears".into(),
+		));
+	}
 
 	// Sample enough randomness to batch tensor elements corresponding to claims that share an
 	// evaluation point prefix.
@@ -61,7 +66,8 @@ where
 	let mixing_coeffs = MultilinearQuery::expand(&mixing_challenges).into_expansion();
 
 	// For each evaluation point prefix, send one batched partial evaluation.
-	let tensor_elems = compute_partial_evals::<_, _, _, Tower, _>(system, witnesses, backend)?;
+	let tensor_elems =
+		compute_partial_evals::<_, _, _, Tower, _>(system, witnesses, unpacked_witnesses, backend)?;
 	let scaled_tensor_elems = scale_tensor_elems(tensor_elems, &mixing_coeffs);
 	let mixed_tensor_elems = mix_tensor_elems_for_prefixes(
 		&scaled_tensor_elems,
@@ -80,8 +86,11 @@ where
 		MultilinearQuery::<F, _>::expand(&row_batch_challenges).into_expansion(),
 	));
 
-	let row_batched_evals =
-		compute_row_batched_sumcheck_evals(scaled_tensor_elems, row_batch_coeffs.coeffs());
+	let row_batched_evals = compute_row_batched_sumcheck_evals(
+		scaled_tensor_elems,
+		row_batch_coeffs.coeffs(),
+		backend,
+	)?;
 	transcript.message().write_scalar_slice(&row_batched_evals);
 
 	// Create the reduced PIOP sumcheck witnesses.
@@ -114,6 +123,7 @@ where
 fn compute_partial_evals<F, P, M, Tower, Backend>(
 	system: &EvalClaimSystem<F>,
 	witnesses: &[M],
+	unpacked_witnesses: &[M],
 	backend: &Backend,
 ) -> Result<Vec<TowerTensorAlgebra<Tower>>, Error>
 where
@@ -135,13 +145,17 @@ where
 		.map(
 			|PIOPSumcheckClaimDesc {
 			     committed_idx,
+			     unpacked_idx,
 			     suffix_desc_idx,
 			     eval_claim: _,
 			 }| {
 				let suffix = &system.suffix_descs[*suffix_desc_idx];
 				let suffix_query = &suffix_queries[*suffix_desc_idx];
-				let partial_eval =
-					witnesses[*committed_idx].evaluate_partial_high(suffix_query.to_ref())?;
+				let witness = match unpacked_idx {
+					Some(unpacked_idx) => &unpacked_witnesses[*unpacked_idx],
+					None => &witnesses[*committed_idx],
+				};
+				let partial_eval = backend.evaluate_partial_high(witness, suffix_query.to_ref())?;
 				TowerTensorAlgebra::new(
 					suffix.kappa,
 					PackedField::iter_slice(partial_eval.evals())
@@ -200,18 +214,19 @@ where
 }
 
 #[instrument(skip_all)]
-fn compute_row_batched_sumcheck_evals<F, Tower>(
+fn compute_row_batched_sumcheck_evals<F, Tower, Backend>(
 	tensor_elems: Vec<TowerTensorAlgebra<Tower>>,
 	row_batch_coeffs: &[F],
-) -> Vec<F>
+	backend: &Backend,
+) -> Result<Vec<F>, Error>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
+	Backend: ComputationBackend,
 {
 	tensor_elems
 		.into_par_iter()
-		.map(|tensor_elem| tensor_elem.fold_vertical(row_batch_coeffs))
+		.map(|tensor_elem| tensor_elem.fold_vertical_on_backend(row_batch_coeffs, backend))
 		.collect()
 }
 
@@ -223,10 +238,9 @@ fn make_ring_switch_eq_inds<F, P, Tower>(
 	mixing_coeffs: &[F],
 ) -> Result<Vec<MultilinearWitness<'static, P>>, Error>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	P: PackedFieldIndexable<Scalar = F>,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 {
 	sumcheck_claim_descs
 		.par_iter()
@@ -240,7 +240,7 @@ fn test_prove_verify_claim_reduction_with_naive_validation() {
 		let ReducedWitness {
 			transparents: transparent_witnesses,
 			sumcheck_claims: prover_sumcheck_claims,
-		} = prove::<_, _, _, Tower, _, _>(&system, &witnesses, &mut proof, &backend).unwrap();
+		} = prove::<_, _, _, Tower, _, _>(&system, &witnesses, &[], &mut proof, &backend).unwrap();
 
 		let mut proof = proof.into_verifier();
 		let ReducedClaim {
@@ -293,11 +293,12 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 	)
 	.unwrap();
 
+	let backend = make_portable_backend();
 	let CommitOutput {
 		commitment,
 		committed,
 		codeword,
-	} = piop::commit(&fri_params, merkle_prover, &committed_multilins).unwrap();
+	} = piop::commit(&fri_params, merkle_prover, &committed_multilins, &backend).unwrap();
 
 	let eval_claims = setup_test_eval_claims(&mut rng, oracles, &witness_index);
 
@@ -309,11 +310,11 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 	let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
 	proof.message().write(&commitment);
 
-	let backend = make_portable_backend();
 	let ReducedWitness {
 		transparents: transparent_multilins,
 		sumcheck_claims,
-	} = prove::<_, _, _, Tower, _, _>(&system, &committed_multilins, &mut proof, &backend).unwrap();
+	} = prove::<_, _, _, Tower, _, _>(&system, &committed_multilins, &[], &mut proof, &backend)
+		.unwrap();
 
 	let domain_factory = DefaultEvaluationDomainFactory::<Tower::B8>::default();
 	piop::prove(
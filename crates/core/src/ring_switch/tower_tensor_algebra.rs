@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use binius_hal::ComputationBackend;
+
 use super::error::Error;
 use crate::{
 	tensor_algebra::TensorAlgebra,
@@ -124,4 +126,33 @@ where
 			Self::B128(elem) => elem.fold_vertical(coeffs),
 		}
 	}
+
+	/// Fold the tensor algebra element into a field element like [`Self::fold_vertical`], on the
+	/// given backend.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::HAL`] if `coeffs` has fewer than $2^\kappa$ elements
+	pub fn fold_vertical_on_backend(
+		&self,
+		coeffs: &[FExt<Tower>],
+		backend: &impl ComputationBackend,
+	) -> Result<FExt<Tower>, Error> {
+		let folded =
+			match self {
+				Self::B1(elem) => backend
+					.tensor_algebra_fold_vertical::<Tower::B1, _>(elem.vertical_elems(), coeffs),
+				Self::B8(elem) => backend
+					.tensor_algebra_fold_vertical::<Tower::B8, _>(elem.vertical_elems(), coeffs),
+				Self::B16(elem) => backend
+					.tensor_algebra_fold_vertical::<Tower::B16, _>(elem.vertical_elems(), coeffs),
+				Self::B32(elem) => backend
+					.tensor_algebra_fold_vertical::<Tower::B32, _>(elem.vertical_elems(), coeffs),
+				Self::B64(elem) => backend
+					.tensor_algebra_fold_vertical::<Tower::B64, _>(elem.vertical_elems(), coeffs),
+				Self::B128(elem) => backend
+					.tensor_algebra_fold_vertical::<Tower::B128, _>(elem.vertical_elems(), coeffs),
+			}?;
+		Ok(folded)
+	}
 }
@@ -0,0 +1,422 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+mod spill;
+
+use std::{
+	fmt::Debug,
+	ops::Deref,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	packed::{get_packed_slice, set_packed_slice},
+	underlier::{UnderlierType, WithUnderlier},
+	ExtensionField, Field, PackedExtension, PackedField, TowerField,
+};
+use binius_math::{
+	ArithExpr, MLEEmbeddingAdapter, MultilinearExtension, MultilinearExtensionBorrowed,
+	MultilinearPoly, PackingDeref,
+};
+use binius_utils::bail;
+pub use spill::SpilledEvals;
+
+use crate::{
+	oracle::OracleId,
+	polynomial::{ArithCircuitPoly, Error as PolynomialError, MultilinearComposite},
+};
+
+pub type MultilinearWitness<'a, P> = Arc<dyn MultilinearPoly<P> + Send + Sync + 'a>;
+
+/// Data structure that indexes multilinear extensions by oracle ID.
+///
+/// A [`crate::oracle::MultilinearOracleSet`] indexes multilinear polynomial oracles by assigning
+/// unique, sequential oracle IDs. The caller can get the [`MultilinearExtension`] defined natively
+/// over a subfield. This is possible because the [`MultilinearExtensionIndex::get`] method is
+/// generic over the subfield type and the struct itself only stores the underlying data.
+///
+/// When a spill directory is set with [`MultilinearExtensionIndex::set_spill_dir`], the prover
+/// moves the committed witnesses out of memory into memory-mapped files in that directory once
+/// they have been committed, see [`MultilinearExtensionIndex::spill`] and
+/// [`MultilinearExtensionIndex::spill_alias`].
+#[derive(Default, Debug)]
+pub struct MultilinearExtensionIndex<'a, U, FW>
+where
+	U: UnderlierType + PackScalar<FW>,
+	FW: Field,
+{
+	entries: Vec<Option<MultilinearWitness<'a, PackedType<U, FW>>>>,
+	spill_dir: Option<PathBuf>,
+	spilled: Vec<SpilledWitness<U>>,
+}
+
+/// A witness that has been moved into a spill file, together with the address its evaluations had
+/// in memory, which identifies the witnesses that alias them.
+#[derive(Debug)]
+struct SpilledWitness<U> {
+	source: usize,
+	evals: SpilledEvals<U>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("witness not found for oracle {id}")]
+	MissingWitness { id: OracleId },
+	#[error("witness for oracle id {id} does not have an explicit backing multilinear")]
+	NoExplicitBackingMultilinearExtension { id: OracleId },
+	#[error("log degree mismatch for oracle id {oracle_id}. field_log_extension_degree = {field_log_extension_degree} entry_log_extension_degree = {entry_log_extension_degree}")]
+	OracleExtensionDegreeMismatch {
+		oracle_id: OracleId,
+		field_log_extension_degree: usize,
+		entry_log_extension_degree: usize,
+	},
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] PolynomialError),
+	#[error("HAL error: {0}")]
+	HalError(#[from] binius_hal::Error),
+	#[error("cannot concatenate witnesses with {inner_n_vars:?} variables, they must be ordered from most variables to least")]
+	UnorderedConcatenation { inner_n_vars: Vec<usize> },
+	#[error("composite value at hypercube index {index} does not lie in the subfield of tower level {tower_level}")]
+	CompositeValueNotInSubfield { index: usize, tower_level: usize },
+	#[error("Math error: {0}")]
+	MathError(#[from] binius_math::Error),
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
+}
+
+impl<'a, U, FW> MultilinearExtensionIndex<'a, U, FW>
+where
+	U: UnderlierType + PackScalar<FW>,
+	FW: Field,
+{
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn get_multilin_poly(
+		&self,
+		id: OracleId,
+	) -> Result<MultilinearWitness<'a, PackedType<U, FW>>, Error> {
+		let entry = self
+			.entries
+			.get(id)
+			.ok_or(Error::MissingWitness { id })?
+			.as_ref()
+			.ok_or(Error::MissingWitness { id })?;
+		Ok(entry.clone())
+	}
+
+	/// Whether has data for the given oracle id.
+	pub fn has(&self, id: OracleId) -> bool {
+		self.entries.get(id).is_some_and(Option::is_some)
+	}
+
+	pub fn update_multilin_poly(
+		&mut self,
+		witnesses: impl IntoIterator<Item = (OracleId, MultilinearWitness<'a, PackedType<U, FW>>)>,
+	) -> Result<(), Error> {
+		for (id, witness) in witnesses {
+			if id >= self.entries.len() {
+				self.entries.resize_with(id + 1, || None);
+			}
+			self.entries[id] = Some(witness);
+		}
+		Ok(())
+	}
+
+	/// Sets the directory that committed witnesses are spilled to during proving.
+	pub fn set_spill_dir(&mut self, dir: impl Into<PathBuf>) {
+		self.spill_dir = Some(dir.into());
+	}
+
+	/// The directory that committed witnesses are spilled to during proving, if any.
+	pub fn spill_dir(&self) -> Option<&Path> {
+		self.spill_dir.as_deref()
+	}
+
+	/// Moves the witness for the given oracle id out of memory into a file in the spill
+	/// directory.
+	///
+	/// The entry is replaced with a multilinear extension over [`SpilledEvals`], which maps the
+	/// file into memory and pages the evaluations in as they are accessed. Memory is only released
+	/// once all other references to the original evaluations have been dropped, including the
+	/// witnesses that alias them, see [`Self::spill_alias`]. Does nothing if no spill directory is
+	/// set.
+	pub fn spill<FS>(&mut self, id: OracleId) -> Result<(), Error>
+	where
+		FS: TowerField,
+		FW: ExtensionField<FS>,
+		U: PackScalar<FS>,
+	{
+		let Some(dir) = &self.spill_dir else {
+			return Ok(());
+		};
+
+		let entry = self.get_multilin_poly(id)?;
+		let underliers = explicit_underliers::<U, FS, FW>(id, &*entry)?;
+		let evals = SpilledEvals::write(underliers, dir)?;
+		self.entries[id] = Some(spilled_witness::<U, FS, FW>(entry.n_vars(), evals.clone())?);
+		self.spilled.push(SpilledWitness {
+			source: underliers.as_ptr() as usize,
+			evals,
+		});
+		Ok(())
+	}
+
+	/// Moves the witness for the given oracle id onto the spill file of a spilled witness whose
+	/// evaluations it shares, such as a packed view of a committed witness.
+	///
+	/// Otherwise the witness keeps the evaluations of the spilled witness in memory. Does nothing
+	/// if the witness does not share the evaluations of a spilled witness. Spilled witnesses are
+	/// recognized by the address their evaluations had in memory, so this must be called before
+	/// new witnesses are added to the index.
+	pub fn spill_alias<FS>(&mut self, id: OracleId) -> Result<(), Error>
+	where
+		FS: TowerField,
+		FW: ExtensionField<FS>,
+		U: PackScalar<FS>,
+	{
+		let entry = self.get_multilin_poly(id)?;
+		let Some(evals) = entry.packed_evals() else {
+			return Ok(());
+		};
+		let underliers = <PackedType<U, FW>>::to_underliers_ref(evals);
+		let Some(spilled) = self.spilled.iter().find(|spilled| {
+			spilled.source == underliers.as_ptr() as usize
+				&& underliers.len() <= spilled.evals.len()
+		}) else {
+			return Ok(());
+		};
+
+		explicit_underliers::<U, FS, FW>(id, &*entry)?;
+		let evals = spilled.evals.prefix(underliers.len());
+		self.entries[id] = Some(spilled_witness::<U, FS, FW>(entry.n_vars(), evals)?);
+		Ok(())
+	}
+
+	/// Releases the resident pages of all spilled witnesses.
+	///
+	/// The prover calls this before phases that do not read the committed witnesses, so that the
+	/// pages faulted in by earlier phases do not add to the memory used by later ones.
+	pub fn evict_spilled(&self) -> Result<(), Error> {
+		for spilled in &self.spilled {
+			spilled.evals.evict()?;
+		}
+		Ok(())
+	}
+
+	/// TODO: Remove once PCS no longer needs this
+	pub fn get<FS>(
+		&self,
+		id: OracleId,
+	) -> Result<MultilinearExtensionBorrowed<PackedType<U, FS>>, Error>
+	where
+		FS: TowerField,
+		FW: ExtensionField<FS>,
+		U: PackScalar<FS>,
+	{
+		let entry = self
+			.entries
+			.get(id)
+			.ok_or(Error::MissingWitness { id })?
+			.as_ref()
+			.ok_or(Error::MissingWitness { id })?;
+
+		if entry.log_extension_degree() != FW::LOG_DEGREE {
+			bail!(Error::OracleExtensionDegreeMismatch {
+				oracle_id: id,
+				field_log_extension_degree: FW::LOG_DEGREE,
+				entry_log_extension_degree: entry.log_extension_degree()
+			})
+		}
+
+		let evals = entry
+			.packed_evals()
+			.map(<PackedType<U, FW>>::cast_bases)
+			.ok_or(Error::NoExplicitBackingMultilinearExtension { id })?;
+
+		Ok(MultilinearExtension::from_values_slice(evals)?)
+	}
+}
+
+/// The underliers of the evaluations backing the witness for oracle `id`, whose evaluations must
+/// lie in `FS`.
+fn explicit_underliers<U, FS, FW>(
+	id: OracleId,
+	witness: &dyn MultilinearPoly<PackedType<U, FW>>,
+) -> Result<&[U], Error>
+where
+	U: UnderlierType + PackScalar<FS> + PackScalar<FW>,
+	FS: TowerField,
+	FW: ExtensionField<FS>,
+{
+	if witness.log_extension_degree() != FW::LOG_DEGREE {
+		bail!(Error::OracleExtensionDegreeMismatch {
+			oracle_id: id,
+			field_log_extension_degree: FW::LOG_DEGREE,
+			entry_log_extension_degree: witness.log_extension_degree(),
+		});
+	}
+
+	let evals = witness
+		.packed_evals()
+		.ok_or(Error::NoExplicitBackingMultilinearExtension { id })?;
+	Ok(<PackedType<U, FW>>::to_underliers_ref(evals))
+}
+
+/// The witness over spilled evaluations in `FS`, embedded into `FW`.
+fn spilled_witness<'a, U, FS, FW>(
+	n_vars: usize,
+	evals: SpilledEvals<U>,
+) -> Result<MultilinearWitness<'a, PackedType<U, FW>>, Error>
+where
+	U: UnderlierType + PackScalar<FS> + PackScalar<FW>,
+	FS: TowerField,
+	FW: ExtensionField<FS>,
+{
+	let mle = MultilinearExtension::new(n_vars, PackingDeref::<U, FS, _>::new(evals))?;
+	Ok(MLEEmbeddingAdapter::<_, PackedType<U, FW>, _>::from(mle).upcast_arc_dyn())
+}
+
+/// Materializes the witness of an interleaved oracle from the witnesses of its inner oracles.
+///
+/// See [`MultilinearOracleSetAddition::interleaved`](crate::oracle::MultilinearOracleSetAddition::interleaved)
+/// for the layout.
+pub fn interleaved_witness<P, Data>(
+	inner: &[MultilinearExtension<P, Data>],
+) -> Result<MultilinearExtension<P>, Error>
+where
+	P: PackedField,
+	Data: Deref<Target = [P]>,
+{
+	if !inner.len().is_power_of_two() {
+		bail!(binius_math::Error::PowerOfTwoLengthRequired);
+	}
+
+	let inner_n_vars = inner[0].n_vars();
+	if let Some(mismatch) = inner.iter().find(|poly| poly.n_vars() != inner_n_vars) {
+		bail!(binius_math::Error::IncorrectNumberOfVariables {
+			expected: inner_n_vars,
+			actual: mismatch.n_vars(),
+		});
+	}
+
+	let log_count = inner.len().ilog2() as usize;
+	let n_vars = inner_n_vars + log_count;
+	let mut evals = vec![P::default(); 1 << n_vars.saturating_sub(P::LOG_WIDTH)];
+	for (j, poly) in inner.iter().enumerate() {
+		for i in 0..1 << inner_n_vars {
+			set_packed_slice(&mut evals, (i << log_count) | j, get_packed_slice(poly.evals(), i));
+		}
+	}
+	Ok(MultilinearExtension::new(n_vars, evals)?)
+}
+
+/// Materializes the witness of a strided oracle from the witness of its inner oracle.
+///
+/// See [`MultilinearOracleSetAddition::strided`](crate::oracle::MultilinearOracleSetAddition::strided)
+/// for the layout.
+pub fn strided_witness<P, Data>(
+	inner: &MultilinearExtension<P, Data>,
+	log_stride: usize,
+	offset: usize,
+) -> Result<MultilinearExtension<P>, Error>
+where
+	P: PackedField,
+	Data: Deref<Target = [P]>,
+{
+	if log_stride > inner.n_vars() || offset >= 1 << log_stride {
+		bail!(binius_math::Error::ArgumentRangeError {
+			arg: "offset".into(),
+			range: 0..1 << log_stride.min(inner.n_vars()),
+		});
+	}
+
+	let n_vars = inner.n_vars() - log_stride;
+	let mut evals = vec![P::default(); 1 << n_vars.saturating_sub(P::LOG_WIDTH)];
+	for i in 0..1 << n_vars {
+		set_packed_slice(
+			&mut evals,
+			i,
+			get_packed_slice(inner.evals(), (i << log_stride) | offset),
+		);
+	}
+	Ok(MultilinearExtension::new(n_vars, evals)?)
+}
+
+/// Materializes the witness of a concatenated oracle with `n_vars` variables from the witnesses of
+/// its inner oracles.
+///
+/// See [`MultilinearOracleSetAddition::concatenated`](crate::oracle::MultilinearOracleSetAddition::concatenated)
+/// for the layout. The inner witnesses must be ordered from most variables to least.
+pub fn concatenated_witness<P, Data>(
+	n_vars: usize,
+	inner: &[MultilinearExtension<P, Data>],
+) -> Result<MultilinearExtension<P>, Error>
+where
+	P: PackedField,
+	Data: Deref<Target = [P]>,
+{
+	if inner
+		.windows(2)
+		.any(|pair| pair[0].n_vars() < pair[1].n_vars())
+	{
+		bail!(Error::UnorderedConcatenation {
+			inner_n_vars: inner.iter().map(|poly| poly.n_vars()).collect(),
+		});
+	}
+
+	let total_len = inner.iter().map(|poly| 1 << poly.n_vars()).sum::<usize>();
+	if total_len > 1 << n_vars {
+		bail!(binius_math::Error::PiecewiseMultilinearTooLong {
+			total_length: total_len,
+			total_n_vars: n_vars,
+		});
+	}
+
+	let mut evals = vec![P::default(); 1 << n_vars.saturating_sub(P::LOG_WIDTH)];
+	let mut offset = 0;
+	for poly in inner {
+		for i in 0..1 << poly.n_vars() {
+			set_packed_slice(&mut evals, offset + i, get_packed_slice(poly.evals(), i));
+		}
+		offset += 1 << poly.n_vars();
+	}
+	Ok(MultilinearExtension::new(n_vars, evals)?)
+}
+
+/// Materializes the values of a composition of the witnesses of its inner oracles, packed over the
+/// subfield `FS`.
+///
+/// Fails if the composition does not map the inner values into `FS`.
+pub fn composite_witness<'a, U, FW, FS>(
+	n_vars: usize,
+	inner: Vec<MultilinearWitness<'a, PackedType<U, FW>>>,
+	composition: &ArithExpr<FW>,
+) -> Result<MultilinearWitness<'a, PackedType<U, FW>>, Error>
+where
+	U: UnderlierType + PackScalar<FW> + PackScalar<FS>,
+	FW: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let composition = ArithCircuitPoly::with_n_vars(inner.len(), composition.clone())?;
+	let composite = MultilinearComposite::new(n_vars, composition, inner)?;
+
+	let mut evals = vec![
+		PackedType::<U, FS>::default();
+		1 << n_vars.saturating_sub(PackedType::<U, FS>::LOG_WIDTH)
+	];
+	for i in 0..1 << n_vars {
+		let value = composite
+			.evaluate_on_hypercube(i)?
+			.try_into()
+			.map_err(|_| Error::CompositeValueNotInSubfield {
+				index: i,
+				tower_level: FS::TOWER_LEVEL,
+			})?;
+		set_packed_slice(&mut evals, i, value);
+	}
+	let mle = MultilinearExtension::new(n_vars, evals)?;
+	Ok(MLEEmbeddingAdapter::from(mle).upcast_arc_dyn())
+}
@@ -0,0 +1,356 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	fs::{self, File},
+	io::Write,
+	marker::PhantomData,
+	mem,
+	ops::Deref,
+	path::{Path, PathBuf},
+	process, slice,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+};
+
+use binius_field::underlier::UnderlierType;
+use memmap2::Mmap;
+
+use super::Error;
+
+/// Counter used to give every spill file created by this process a unique name.
+static SPILL_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The underliers of witness evaluations that have been moved out of memory into a file.
+///
+/// The file is memory-mapped read-only and dereferences as a slice of underliers, which the OS
+/// pages in as they are accessed. Resident pages are clean and file-backed: the OS may reclaim
+/// them under memory pressure, and [`Self::evict`] releases them explicitly. Clones share the
+/// mapping, and the file is deleted when the last clone is dropped.
+#[derive(Debug, Clone)]
+pub struct SpilledEvals<U> {
+	file: Arc<SpillFile>,
+	len: usize,
+	_marker: PhantomData<U>,
+}
+
+#[derive(Debug)]
+struct SpillFile {
+	path: PathBuf,
+	mmap: Mmap,
+}
+
+impl<U: UnderlierType> SpilledEvals<U> {
+	/// Writes the underliers into a new file in `dir` and maps it into memory.
+	#[tracing::instrument(
+		"SpilledEvals::write",
+		skip_all,
+		fields(bytes = mem::size_of_val(underliers)),
+		level = "debug"
+	)]
+	pub(super) fn write(underliers: &[U], dir: &Path) -> Result<Self, Error> {
+		let path = dir.join(format!(
+			"binius-witness-{}-{}.bin",
+			process::id(),
+			SPILL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+		));
+		let mut file = File::create(&path)?;
+		file.write_all(bytemuck::cast_slice(underliers))?;
+		file.sync_all()?;
+		drop(file);
+
+		// SAFETY: the file has a unique name in a directory owned by the prover and is never
+		// written to again while it is mapped.
+		let mmap = unsafe { Mmap::map(&File::open(&path)?)? };
+		// Mappings are page-aligned, which satisfies the alignment of any underlier type.
+		debug_assert_eq!(mmap.as_ptr().align_offset(mem::align_of::<U>()), 0);
+		debug_assert_eq!(mmap.len(), mem::size_of_val(underliers));
+
+		Ok(Self {
+			file: Arc::new(SpillFile { path, mmap }),
+			len: underliers.len(),
+			_marker: PhantomData,
+		})
+	}
+
+	/// Returns the first `len` underliers, sharing the mapping.
+	pub(super) fn prefix(&self, len: usize) -> Self {
+		assert!(len <= self.len);
+		Self {
+			file: self.file.clone(),
+			len,
+			_marker: PhantomData,
+		}
+	}
+
+	/// The path of the file holding the evaluations.
+	pub fn path(&self) -> &Path {
+		&self.file.path
+	}
+
+	/// Releases the pages of the mapping that are currently resident.
+	///
+	/// The evaluations stay accessible; they are read back from the file on the next access.
+	#[cfg(unix)]
+	pub fn evict(&self) -> Result<(), Error> {
+		// SAFETY: the mapping is read-only and backed by a file that is not modified, so dropped
+		// pages are transparently re-read with the same contents.
+		unsafe {
+			self.file
+				.mmap
+				.unchecked_advise(memmap2::UncheckedAdvice::DontNeed)?
+		};
+		Ok(())
+	}
+
+	/// Releases the pages of the mapping that are currently resident.
+	///
+	/// This is a no-op on platforms without `madvise`, where the OS reclaims the pages on its own.
+	#[cfg(not(unix))]
+	pub fn evict(&self) -> Result<(), Error> {
+		Ok(())
+	}
+}
+
+impl<U: UnderlierType> Deref for SpilledEvals<U> {
+	type Target = [U];
+
+	fn deref(&self) -> &[U] {
+		// SAFETY: the mapping is aligned for `U` and holds at least `len` values, whose bytes were
+		// written from valid `U` values by `write`.
+		unsafe { slice::from_raw_parts(self.file.mmap.as_ptr().cast::<U>(), self.len) }
+	}
+}
+
+impl Drop for SpillFile {
+	fn drop(&mut self) {
+		if let Err(err) = fs::remove_file(&self.path) {
+			tracing::warn!(path = %self.path.display(), %err, "failed to remove witness spill file");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{iter::repeat_with, sync::Arc};
+
+	use binius_field::{
+		arch::OptimalUnderlier, as_packed_field::PackedType, underlier::WithUnderlier,
+		BinaryField128b, BinaryField32b, BinaryField8b, PackedField,
+	};
+	use binius_math::{MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly};
+	use binius_utils::memory::peak_rss_kib;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::witness::MultilinearExtensionIndex;
+
+	type U = OptimalUnderlier;
+	type FW = BinaryField128b;
+	type FS = BinaryField8b;
+
+	#[test]
+	fn test_spill_and_page_in() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 10;
+		let evals = repeat_with(|| PackedType::<U, FS>::random(&mut rng))
+			.take(1 << (n_vars - PackedType::<U, FS>::LOG_WIDTH))
+			.collect::<Vec<_>>();
+		let mle = MultilinearExtension::new(n_vars, evals.clone()).unwrap();
+		let original = MLEEmbeddingAdapter::<_, PackedType<U, FW>, _>::from(mle).upcast_arc_dyn();
+
+		let dir = std::env::temp_dir().join(format!("binius-spill-test-{}", process::id()));
+		fs::create_dir_all(&dir).unwrap();
+
+		let mut witness = MultilinearExtensionIndex::<U, FW>::new();
+		witness
+			.update_multilin_poly([(0, original.clone())])
+			.unwrap();
+
+		// Without a spill directory spilling does nothing
+		witness.spill::<FS>(0).unwrap();
+		assert!(Arc::ptr_eq(&witness.get_multilin_poly(0).unwrap(), &original));
+
+		witness.set_spill_dir(&dir);
+		witness.spill::<FS>(0).unwrap();
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+		let spilled = witness.get_multilin_poly(0).unwrap();
+		assert_eq!(spilled.n_vars(), n_vars);
+		assert_eq!(spilled.log_extension_degree(), original.log_extension_degree());
+		for index in 0..1 << n_vars {
+			assert_eq!(
+				spilled.evaluate_on_hypercube(index).unwrap(),
+				original.evaluate_on_hypercube(index).unwrap()
+			);
+		}
+		assert_eq!(witness.get::<FS>(0).unwrap().evals(), evals.as_slice());
+
+		// Evicted pages are read back from the file on the next access
+		witness.evict_spilled().unwrap();
+		assert_eq!(witness.get::<FS>(0).unwrap().evals(), evals.as_slice());
+
+		drop(spilled);
+		drop(witness);
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+		fs::remove_dir(&dir).unwrap();
+	}
+
+	#[test]
+	fn test_spill_alias() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 12;
+		let underliers = repeat_with(|| PackedType::<U, FS>::random(&mut rng))
+			.take(1 << (n_vars - PackedType::<U, FS>::LOG_WIDTH))
+			.map(WithUnderlier::to_underlier)
+			.collect::<Vec<U>>();
+
+		// A witness over 8-bit evaluations and a packed view of the same memory over 32-bit ones
+		let unpacked = MultilinearExtension::new(
+			n_vars,
+			PackedType::<U, FS>::from_underliers_ref(&underliers),
+		)
+		.unwrap();
+		let packed = MultilinearExtension::new(
+			n_vars - 2,
+			PackedType::<U, BinaryField32b>::from_underliers_ref(&underliers),
+		)
+		.unwrap();
+		let unrelated = MultilinearExtension::new(n_vars, unpacked.evals().to_vec()).unwrap();
+
+		let dir = std::env::temp_dir().join(format!("binius-spill-alias-test-{}", process::id()));
+		fs::create_dir_all(&dir).unwrap();
+
+		let mut witness = MultilinearExtensionIndex::<U, FW>::new();
+		witness
+			.update_multilin_poly([
+				(0, MLEEmbeddingAdapter::from(unpacked).upcast_arc_dyn()),
+				(1, MLEEmbeddingAdapter::from(packed).upcast_arc_dyn()),
+				(2, MLEEmbeddingAdapter::from(unrelated).upcast_arc_dyn()),
+			])
+			.unwrap();
+		let originals = (0..3)
+			.map(|id| witness.get_multilin_poly(id).unwrap())
+			.collect::<Vec<_>>();
+
+		witness.set_spill_dir(&dir);
+		witness.spill::<FS>(0).unwrap();
+		witness.spill_alias::<BinaryField32b>(1).unwrap();
+		witness.spill_alias::<FS>(2).unwrap();
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+		let spilled_evals = witness
+			.get_multilin_poly(0)
+			.unwrap()
+			.packed_evals()
+			.unwrap()
+			.as_ptr();
+		assert_eq!(
+			witness
+				.get_multilin_poly(1)
+				.unwrap()
+				.packed_evals()
+				.unwrap()
+				.as_ptr(),
+			spilled_evals
+		);
+		assert!(Arc::ptr_eq(&witness.get_multilin_poly(2).unwrap(), &originals[2]));
+		for (id, original) in originals.iter().enumerate() {
+			let entry = witness.get_multilin_poly(id).unwrap();
+			assert_eq!(entry.n_vars(), original.n_vars());
+			assert_eq!(entry.log_extension_degree(), original.log_extension_degree());
+			for index in 0..1 << entry.n_vars() {
+				assert_eq!(
+					entry.evaluate_on_hypercube(index).unwrap(),
+					original.evaluate_on_hypercube(index).unwrap()
+				);
+			}
+		}
+
+		drop(originals);
+		drop(witness);
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+		fs::remove_dir(&dir).unwrap();
+	}
+
+	const PEAK_RSS_MODE_VAR: &str = "BINIUS_SPILL_PEAK_RSS_MODE";
+
+	/// Commits a 64 MiB witness, reads it once, then runs a phase that allocates another 64 MiB
+	/// without reading the witness, and prints the peak RSS of the process.
+	#[cfg(target_os = "linux")]
+	fn run_peak_rss_scenario(spill: bool) {
+		let n_vars = 22;
+		let evals =
+			vec![PackedType::<U, FW>::one(); 1 << (n_vars - PackedType::<U, FW>::LOG_WIDTH)];
+		let mle = MultilinearExtension::new(n_vars, evals).unwrap();
+
+		let dir = std::env::temp_dir().join(format!("binius-spill-rss-{}", process::id()));
+		fs::create_dir_all(&dir).unwrap();
+
+		let mut witness = MultilinearExtensionIndex::<U, FW>::new();
+		witness
+			.update_multilin_poly([(0, MLEEmbeddingAdapter::from(mle).upcast_arc_dyn())])
+			.unwrap();
+		if spill {
+			witness.set_spill_dir(&dir);
+			witness.spill::<FW>(0).unwrap();
+		}
+
+		let evals = witness.get::<FW>(0).unwrap();
+		let n_ones = evals
+			.evals()
+			.iter()
+			.filter(|&&packed| packed == PackedType::<U, FW>::one())
+			.count();
+		assert_eq!(n_ones, evals.evals().len());
+		witness.evict_spilled().unwrap();
+
+		let phase_buffer = std::hint::black_box(vec![1u8; 64 << 20]);
+		println!("peak_rss_kib={}", peak_rss_kib().unwrap());
+
+		drop(phase_buffer);
+		drop(witness);
+		fs::remove_dir(&dir).unwrap();
+	}
+
+	#[test]
+	#[cfg(target_os = "linux")]
+	fn test_spill_reduces_peak_rss() {
+		if let Ok(mode) = std::env::var(PEAK_RSS_MODE_VAR) {
+			run_peak_rss_scenario(mode == "spill");
+			return;
+		}
+
+		// Peak RSS is tracked per process, so each scenario runs in a fresh copy of this test
+		let peak_rss_kib = |mode: &str| -> usize {
+			let output = process::Command::new(std::env::current_exe().unwrap())
+				.args([
+					"witness::spill::tests::test_spill_reduces_peak_rss",
+					"--exact",
+					"--nocapture",
+					"--test-threads=1",
+				])
+				.env(PEAK_RSS_MODE_VAR, mode)
+				.output()
+				.unwrap();
+			assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+			String::from_utf8(output.stdout)
+				.unwrap()
+				.lines()
+				.find_map(|line| Some(line.split_once("peak_rss_kib=")?.1))
+				.expect("scenario prints its peak RSS")
+				.parse()
+				.unwrap()
+		};
+
+		let resident_peak = peak_rss_kib("resident");
+		let spilled_peak = peak_rss_kib("spill");
+		// The witness and the phase buffer take 64 MiB each. With spilling they are never
+		// resident at the same time.
+		assert!(
+			spilled_peak + (32 << 10) < resident_peak,
+			"spilled peak {spilled_peak} KiB, resident peak {resident_peak} KiB"
+		);
+	}
+}
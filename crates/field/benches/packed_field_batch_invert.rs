@@ -0,0 +1,74 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	util::{batch_invert_or_zero, batch_invert_or_zero_packed},
+	BinaryField128b, BinaryField32b, Field, PackedBinaryField16x32b, PackedBinaryField4x128b,
+	PackedField,
+};
+use criterion::{
+	criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion, Throughput,
+};
+
+const COUNTS: [usize; 3] = [1 << 10, 1 << 14, 1 << 20];
+
+fn bench_scalar<F: Field>(group: &mut BenchmarkGroup<WallTime>, name: &str) {
+	let mut rng = rand::thread_rng();
+	for count in COUNTS {
+		let values = repeat_with(|| F::random(&mut rng))
+			.take(count)
+			.collect::<Vec<_>>();
+		group.throughput(Throughput::Elements(count as u64));
+		group.bench_function(format!("{name}/batch/{count}"), |bench| {
+			bench.iter_batched_ref(
+				|| values.clone(),
+				|values| batch_invert_or_zero(values),
+				criterion::BatchSize::LargeInput,
+			);
+		});
+		group.bench_function(format!("{name}/elementwise/{count}"), |bench| {
+			bench.iter_batched_ref(
+				|| values.clone(),
+				|values| values.iter_mut().for_each(|v| *v = v.invert_or_zero()),
+				criterion::BatchSize::LargeInput,
+			);
+		});
+	}
+}
+
+fn bench_packed<P: PackedField>(group: &mut BenchmarkGroup<WallTime>, name: &str) {
+	let mut rng = rand::thread_rng();
+	for count in COUNTS {
+		let values = repeat_with(|| P::random(&mut rng))
+			.take(count >> P::LOG_WIDTH)
+			.collect::<Vec<_>>();
+		group.throughput(Throughput::Elements(count as u64));
+		group.bench_function(format!("{name}/batch/{count}"), |bench| {
+			bench.iter_batched_ref(
+				|| values.clone(),
+				|values| batch_invert_or_zero_packed(values),
+				criterion::BatchSize::LargeInput,
+			);
+		});
+		group.bench_function(format!("{name}/elementwise/{count}"), |bench| {
+			bench.iter_batched_ref(
+				|| values.clone(),
+				|values| values.iter_mut().for_each(|v| *v = v.invert_or_zero()),
+				criterion::BatchSize::LargeInput,
+			);
+		});
+	}
+}
+
+fn batch_invert(c: &mut Criterion) {
+	let mut group = c.benchmark_group("batch_invert");
+	bench_scalar::<BinaryField32b>(&mut group, "32b");
+	bench_scalar::<BinaryField128b>(&mut group, "128b");
+	bench_packed::<PackedBinaryField16x32b>(&mut group, "16x32b");
+	bench_packed::<PackedBinaryField4x128b>(&mut group, "4x128b");
+	group.finish();
+}
+
+criterion_group!(packed_field_batch_invert, batch_invert);
+criterion_main!(packed_field_batch_invert);
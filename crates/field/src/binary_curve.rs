@@ -0,0 +1,495 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Ordinary elliptic curves over binary fields.
+//!
+//! An ordinary binary curve is given by the Weierstrass equation
+//! $y^2 + xy = x^3 + ax^2 + b$ with $b \neq 0$. The group law is computed in López–Dahab
+//! projective coordinates, where $(X, Y, Z)$ represents the affine point $(X/Z, Y/Z^2)$, so that
+//! no field inversions are needed until a point is normalized. See [HMV04], section 3.2.3, for
+//! the formulas.
+//!
+//! [HMV04]: <https://link.springer.com/book/10.1007/b97644>
+
+use binius_utils::{
+	bytes::{Buf, BufMut},
+	DeserializeBytes, SerializationError, SerializationMode, SerializeBytes,
+};
+
+use crate::{error::Error, BinaryField, BinaryField1b, BinaryFieldOps, ExtensionField, Field};
+
+/// Tag byte of the encoding of the point at infinity.
+const INFINITY_TAG: u8 = 0x00;
+/// Tag byte of the encoding of a finite point, or'ed with the bit selecting the $y$ coordinate.
+const COMPRESSED_TAG: u8 = 0x02;
+
+/// An ordinary binary curve $y^2 + xy = x^3 + ax^2 + b$.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryCurve<F> {
+	a: F,
+	b: F,
+}
+
+/// A finite point in affine coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AffinePoint<F> {
+	pub x: F,
+	pub y: F,
+}
+
+/// A point in López–Dahab coordinates $(X, Y, Z)$, representing $(X/Z, Y/Z^2)$.
+///
+/// The point at infinity is any point with $Z = 0$.
+#[derive(Debug, Clone, Copy)]
+pub struct LopezDahabPoint<F> {
+	pub x: F,
+	pub y: F,
+	pub z: F,
+}
+
+impl<F: Field> LopezDahabPoint<F> {
+	/// The point at infinity, which is the identity of the group.
+	pub const INFINITY: Self = Self {
+		x: F::ONE,
+		y: F::ZERO,
+		z: F::ZERO,
+	};
+
+	pub fn is_infinity(&self) -> bool {
+		self.z == F::ZERO
+	}
+
+	/// Returns the point in affine coordinates, or `None` for the point at infinity.
+	pub fn to_affine(&self) -> Option<AffinePoint<F>> {
+		let z_inv = self.z.invert()?;
+		Some(AffinePoint {
+			x: self.x * z_inv,
+			y: self.y * z_inv.square(),
+		})
+	}
+}
+
+impl<F: Field> From<AffinePoint<F>> for LopezDahabPoint<F> {
+	fn from(point: AffinePoint<F>) -> Self {
+		Self {
+			x: point.x,
+			y: point.y,
+			z: F::ONE,
+		}
+	}
+}
+
+impl<F: Field> PartialEq for LopezDahabPoint<F> {
+	fn eq(&self, other: &Self) -> bool {
+		// $(X_1, Y_1, Z_1) \sim (X_2, Y_2, Z_2)$ iff $X_1 Z_2 = X_2 Z_1$ and
+		// $Y_1 Z_2^2 = Y_2 Z_1^2$, unless either is the point at infinity.
+		match (self.is_infinity(), other.is_infinity()) {
+			(true, true) => true,
+			(false, false) => {
+				self.x * other.z == other.x * self.z
+					&& self.y * other.z.square() == other.y * self.z.square()
+			}
+			_ => false,
+		}
+	}
+}
+
+impl<F: Field> Eq for LopezDahabPoint<F> {}
+
+impl<F: BinaryField> BinaryCurve<F> {
+	/// Constructs the curve $y^2 + xy = x^3 + ax^2 + b$.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::SingularCurve`] if `b` is zero.
+	pub fn new(a: F, b: F) -> Result<Self, Error> {
+		if b == F::ZERO {
+			return Err(Error::SingularCurve);
+		}
+		Ok(Self { a, b })
+	}
+
+	pub const fn a(&self) -> F {
+		self.a
+	}
+
+	pub const fn b(&self) -> F {
+		self.b
+	}
+
+	/// Returns whether the affine point satisfies the curve equation.
+	pub fn is_on_curve(&self, point: &AffinePoint<F>) -> bool {
+		let AffinePoint { x, y } = *point;
+		let x_sq = x.square();
+		y.square() + x * y == x_sq * (x + self.a) + self.b
+	}
+
+	/// Returns the finite point with the given $x$ coordinate whose $y$ coordinate is selected by
+	/// `y_bit`, if the curve has a point with this $x$ coordinate.
+	///
+	/// The two points with coordinate $x \neq 0$ are $(x, xz)$ and $(x, x(z + 1))$, where
+	/// $z^2 + z = x + a + b/x^2$, and `y_bit` selects the one for which $z$ has the given
+	/// [`Self::y_bit`]. The only point with $x = 0$ is $(0, \sqrt{b})$, which requires `y_bit` to
+	/// be zero.
+	pub fn lift_x(&self, x: F, y_bit: bool) -> Option<AffinePoint<F>> {
+		let Some(x_inv) = x.invert() else {
+			return (!y_bit).then(|| AffinePoint {
+				x,
+				y: self.b.sqrt(),
+			});
+		};
+
+		let mut z = (x + self.a + self.b * x_inv.square()).solve_quadratic()?;
+		if z_bit(z) != y_bit {
+			z += F::ONE;
+		}
+		Some(AffinePoint { x, y: x * z })
+	}
+
+	/// Returns the bit that selects the $y$ coordinate of a finite point among the two points with
+	/// the same $x$ coordinate, see [`Self::lift_x`].
+	pub fn y_bit(&self, point: &AffinePoint<F>) -> bool {
+		match point.x.invert() {
+			Some(x_inv) => z_bit(point.y * x_inv),
+			None => false,
+		}
+	}
+
+	/// Returns $-P$, which is $(X, XZ + Y, Z)$.
+	pub fn neg(&self, point: &LopezDahabPoint<F>) -> LopezDahabPoint<F> {
+		LopezDahabPoint {
+			x: point.x,
+			y: point.x * point.z + point.y,
+			z: point.z,
+		}
+	}
+
+	/// Returns $2P$.
+	pub fn double(&self, point: &LopezDahabPoint<F>) -> LopezDahabPoint<F> {
+		let LopezDahabPoint { x, y, z } = *point;
+
+		let x_sq = x.square();
+		let z_sq = z.square();
+		let b_z4 = self.b * z_sq.square();
+
+		let z3 = x_sq * z_sq;
+		let x3 = x_sq.square() + b_z4;
+		let y3 = b_z4 * z3 + x3 * (self.a * z3 + y.square() + b_z4);
+		LopezDahabPoint {
+			x: x3,
+			y: y3,
+			z: z3,
+		}
+	}
+
+	/// Returns $P + Q$ for an affine point $Q$, using mixed López–Dahab-affine addition.
+	pub fn add_affine(&self, lhs: &LopezDahabPoint<F>, rhs: &AffinePoint<F>) -> LopezDahabPoint<F> {
+		if lhs.is_infinity() {
+			return (*rhs).into();
+		}
+
+		let LopezDahabPoint {
+			x: x1,
+			y: y1,
+			z: z1,
+		} = *lhs;
+		let AffinePoint { x: x2, y: y2 } = *rhs;
+
+		let z1_sq = z1.square();
+		let a = y2 * z1_sq + y1;
+		let b = x2 * z1 + x1;
+		if b == F::ZERO {
+			// The points have the same $x$ coordinate, so they are either equal or opposite.
+			return if a == F::ZERO {
+				self.double(lhs)
+			} else {
+				LopezDahabPoint::INFINITY
+			};
+		}
+
+		let c = z1 * b;
+		let d = b.square() * (c + self.a * z1_sq);
+		let z3 = c.square();
+		let e = a * c;
+		let x3 = a.square() + d + e;
+		let f = x3 + x2 * z3;
+		let g = (x2 + y2) * z3.square();
+		let y3 = (e + z3) * f + g;
+		LopezDahabPoint {
+			x: x3,
+			y: y3,
+			z: z3,
+		}
+	}
+
+	/// Returns $P + Q$.
+	///
+	/// The right hand side is normalized to affine coordinates, which takes one inversion.
+	pub fn add(&self, lhs: &LopezDahabPoint<F>, rhs: &LopezDahabPoint<F>) -> LopezDahabPoint<F> {
+		match rhs.to_affine() {
+			Some(rhs) => self.add_affine(lhs, &rhs),
+			None => *lhs,
+		}
+	}
+
+	/// Returns $kP$, where `scalar` is a little-endian order integer $k$.
+	///
+	/// # Guarantees
+	///
+	/// **This operation is variable time**, and must not be used with secret scalars when timing
+	/// side channels are a concern.
+	pub fn scalar_mul_vartime<S: AsRef<[u64]>>(
+		&self,
+		point: &AffinePoint<F>,
+		scalar: S,
+	) -> LopezDahabPoint<F> {
+		let mut res = LopezDahabPoint::INFINITY;
+		for limb in scalar.as_ref().iter().rev() {
+			for i in (0..64).rev() {
+				res = self.double(&res);
+				if (limb >> i) & 1 == 1 {
+					res = self.add_affine(&res, point);
+				}
+			}
+		}
+		res
+	}
+
+	/// Writes the compressed encoding of a point.
+	///
+	/// The point at infinity is encoded as a single zero byte. A finite point is encoded as the tag
+	/// byte `0x02 | y_bit` followed by the encoding of its $x$ coordinate, see [`Self::y_bit`].
+	pub fn encode_point(
+		&self,
+		point: Option<&AffinePoint<F>>,
+		mut write_buf: impl BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError>
+	where
+		F: SerializeBytes,
+	{
+		match point {
+			None => INFINITY_TAG.serialize(&mut write_buf, mode),
+			Some(point) => {
+				(COMPRESSED_TAG | self.y_bit(point) as u8).serialize(&mut write_buf, mode)?;
+				point.x.serialize(&mut write_buf, mode)
+			}
+		}
+	}
+
+	/// Reads a point written by [`Self::encode_point`].
+	///
+	/// ## Throws
+	///
+	/// * [`SerializationError::InvalidConstruction`] if the encoding is not that of a point on the
+	///   curve.
+	pub fn decode_point(
+		&self,
+		mut read_buf: impl Buf,
+		mode: SerializationMode,
+	) -> Result<Option<AffinePoint<F>>, SerializationError>
+	where
+		F: DeserializeBytes,
+	{
+		let invalid = SerializationError::InvalidConstruction {
+			name: "BinaryCurve point",
+		};
+		match u8::deserialize(&mut read_buf, mode)? {
+			INFINITY_TAG => Ok(None),
+			tag if tag & !1 == COMPRESSED_TAG => {
+				let x = F::deserialize(&mut read_buf, mode)?;
+				self.lift_x(x, tag & 1 == 1).map(Some).ok_or(invalid)
+			}
+			_ => Err(invalid),
+		}
+	}
+}
+
+/// Returns the coordinate of $z$ that distinguishes it from $z + 1$, which is the coordinate of
+/// the first basis element in which one has a non-zero coordinate.
+fn z_bit<F: BinaryField>(z: F) -> bool {
+	let (z_i, _) = ExtensionField::<BinaryField1b>::iter_bases(&z)
+		.zip(ExtensionField::<BinaryField1b>::iter_bases(&F::ONE))
+		.find(|&(_, one_i)| one_i == BinaryField1b::ONE)
+		.expect("one is non-zero");
+	z_i == BinaryField1b::ONE
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use proptest::prelude::*;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::{AESTowerField64b, BinaryField128b, BinaryField128bPolyval, BinaryField8b};
+
+	fn random_curve<F: BinaryField>(rng: &mut StdRng) -> BinaryCurve<F> {
+		let b = repeat_with(|| <F as Field>::random(&mut *rng))
+			.find(|&b| b != F::ZERO)
+			.unwrap();
+		BinaryCurve::new(<F as Field>::random(&mut *rng), b).unwrap()
+	}
+
+	fn random_point<F: BinaryField>(curve: &BinaryCurve<F>, rng: &mut StdRng) -> AffinePoint<F> {
+		repeat_with(|| curve.lift_x(<F as Field>::random(&mut *rng), rng.gen()))
+			.flatten()
+			.next()
+			.unwrap()
+	}
+
+	/// The affine group law, as a reference for the López–Dahab formulas.
+	fn add_reference<F: BinaryField>(
+		curve: &BinaryCurve<F>,
+		p: &AffinePoint<F>,
+		q: &AffinePoint<F>,
+	) -> Option<AffinePoint<F>> {
+		let (x1, y1, x2, y2) = (p.x, p.y, q.x, q.y);
+		let (lambda, x3) = if x1 != x2 {
+			let lambda = (y1 + y2) * (x1 + x2).invert().unwrap();
+			(lambda, lambda.square() + lambda + x1 + x2 + curve.a())
+		} else if y1 == y2 && x1 != F::ZERO {
+			let lambda = x1 + y1 * x1.invert().unwrap();
+			(lambda, lambda.square() + lambda + curve.a())
+		} else {
+			return None;
+		};
+		Some(AffinePoint {
+			x: x3,
+			y: lambda * (x1 + x3) + x3 + y1,
+		})
+	}
+
+	fn check_group_law<F: BinaryField>(rng: &mut StdRng) {
+		let curve = random_curve::<F>(rng);
+		let p = random_point(&curve, rng);
+		let q = random_point(&curve, rng);
+		let r = random_point(&curve, rng);
+		assert!(curve.is_on_curve(&p));
+
+		let p_ld = LopezDahabPoint::from(p);
+		let q_ld = LopezDahabPoint::from(q);
+		let r_ld = LopezDahabPoint::from(r);
+
+		let sum = curve.add_affine(&p_ld, &q);
+		assert_eq!(sum.to_affine(), add_reference(&curve, &p, &q));
+		assert_eq!(curve.double(&p_ld).to_affine(), add_reference(&curve, &p, &p));
+		assert_eq!(curve.add_affine(&p_ld, &p), curve.double(&p_ld));
+
+		assert_eq!(curve.add(&p_ld, &q_ld), curve.add(&q_ld, &p_ld));
+		assert_eq!(
+			curve.add(&curve.add(&p_ld, &q_ld), &r_ld),
+			curve.add(&p_ld, &curve.add(&q_ld, &r_ld))
+		);
+		assert_eq!(curve.add(&p_ld, &LopezDahabPoint::INFINITY), p_ld);
+		assert_eq!(curve.add(&LopezDahabPoint::INFINITY, &p_ld), p_ld);
+		assert!(curve.add(&p_ld, &curve.neg(&p_ld)).is_infinity());
+
+		// Points in projective coordinates with $Z \neq 1$.
+		if let Some(sum_affine) = sum.to_affine() {
+			assert!(curve.is_on_curve(&sum_affine));
+			assert_eq!(curve.add(&sum, &sum), curve.double(&sum));
+			assert_eq!(
+				curve.double(&sum).to_affine(),
+				add_reference(&curve, &sum_affine, &sum_affine)
+			);
+		}
+	}
+
+	fn check_scalar_mul<F: BinaryField>(rng: &mut StdRng) {
+		let curve = random_curve::<F>(rng);
+		let p = random_point(&curve, rng);
+
+		let k1 = rng.gen::<u32>() as u64;
+		let k2 = rng.gen::<u32>() as u64;
+		let k1_p = curve.scalar_mul_vartime(&p, [k1]);
+		let k2_p = curve.scalar_mul_vartime(&p, [k2]);
+		assert_eq!(curve.scalar_mul_vartime(&p, [k1 + k2]), curve.add(&k1_p, &k2_p));
+		assert_eq!(
+			curve.scalar_mul_vartime(&k1_p.to_affine().unwrap(), [k2]),
+			curve.scalar_mul_vartime(&p, [k1 * k2])
+		);
+
+		let mut multiple = LopezDahabPoint::INFINITY;
+		for k in 0..8u64 {
+			assert_eq!(curve.scalar_mul_vartime(&p, [k]), multiple);
+			multiple = curve.add_affine(&multiple, &p);
+		}
+		assert_eq!(curve.scalar_mul_vartime(&p, [3, 0]), curve.scalar_mul_vartime(&p, [3]));
+	}
+
+	fn check_encoding<F: BinaryField + SerializeBytes + DeserializeBytes>(rng: &mut StdRng) {
+		let curve = random_curve::<F>(rng);
+		let p = random_point(&curve, rng);
+		let mode = SerializationMode::Native;
+
+		for point in [
+			Some(p),
+			Some(curve.neg(&p.into()).to_affine().unwrap()),
+			None,
+		] {
+			let mut buf = Vec::new();
+			curve.encode_point(point.as_ref(), &mut buf, mode).unwrap();
+			assert_eq!(curve.decode_point(buf.as_slice(), mode).unwrap(), point);
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn test_group_law(seed in any::<u64>()) {
+			let mut rng = StdRng::seed_from_u64(seed);
+			check_group_law::<BinaryField128b>(&mut rng);
+			check_group_law::<BinaryField8b>(&mut rng);
+			check_group_law::<AESTowerField64b>(&mut rng);
+			check_group_law::<BinaryField128bPolyval>(&mut rng);
+		}
+
+		#[test]
+		fn test_scalar_mul(seed in any::<u64>()) {
+			let mut rng = StdRng::seed_from_u64(seed);
+			check_scalar_mul::<BinaryField128b>(&mut rng);
+			check_scalar_mul::<BinaryField128bPolyval>(&mut rng);
+		}
+
+		#[test]
+		fn test_encoding(seed in any::<u64>()) {
+			let mut rng = StdRng::seed_from_u64(seed);
+			check_encoding::<BinaryField128b>(&mut rng);
+			check_encoding::<AESTowerField64b>(&mut rng);
+			check_encoding::<BinaryField128bPolyval>(&mut rng);
+		}
+	}
+
+	#[test]
+	fn test_group_order_exhaustive() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let curve = random_curve::<BinaryField8b>(&mut rng);
+
+		let points = (0..=u8::MAX)
+			.flat_map(|x| [false, true].map(|y_bit| curve.lift_x(BinaryField8b::new(x), y_bit)))
+			.flatten()
+			.collect::<Vec<_>>();
+		for point in &points {
+			assert!(curve.is_on_curve(point));
+		}
+
+		// Every point is annihilated by the group order, which is within the Hasse bound.
+		let order = points.len() as u64 + 1;
+		assert!(order.abs_diff(257) <= 32);
+		for point in &points {
+			assert!(curve.scalar_mul_vartime(point, [order]).is_infinity());
+		}
+
+		let invalid_x = (0..=u8::MAX)
+			.map(BinaryField8b::new)
+			.find(|&x| curve.lift_x(x, false).is_none())
+			.unwrap();
+		let mut buf = vec![COMPRESSED_TAG];
+		invalid_x
+			.serialize(&mut buf, SerializationMode::Native)
+			.unwrap();
+		assert!(curve
+			.decode_point(buf.as_slice(), SerializationMode::Native)
+			.is_err());
+	}
+}
@@ -0,0 +1,291 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Field-theoretic operations on binary fields.
+//!
+//! Every binary field $\mathbb{F}_{2^n}$ is a Galois extension of its subfields, and the
+//! operations here are all derived from the Frobenius automorphism $x \mapsto x^2$: square roots,
+//! absolute and relative traces, solutions of $x^2 + x = c$ and minimal polynomials. They are
+//! provided for every [`BinaryField`], which includes the tower, AES and POLYVAL fields, and the
+//! lane-wise operations additionally for packed binary fields.
+
+use crate::{BinaryField, BinaryField1b, ExtensionField, Field, PackedField};
+
+/// Frobenius-derived operations on a binary field.
+pub trait BinaryFieldOps: BinaryField {
+	/// Applies the Frobenius automorphism `k` times, ie. returns $x^{2^k}$.
+	fn frobenius(self, k: usize) -> Self {
+		(0..k % Self::N_BITS).fold(self, |x, _| x.square())
+	}
+
+	/// Returns the unique square root, which is the inverse of the Frobenius automorphism.
+	fn sqrt(self) -> Self {
+		self.frobenius(Self::N_BITS - 1)
+	}
+
+	/// Returns the absolute trace $\sum_{i=0}^{n-1} x^{2^i}$ into $\mathbb{F}_2$.
+	fn trace(self) -> BinaryField1b {
+		self.relative_trace()
+	}
+
+	/// Returns the trace relative to the subfield `FSub`, $\sum_{i=0}^{d-1} x^{q^i}$, where $q$ is
+	/// the order of `FSub` and $d$ the degree of the extension.
+	fn relative_trace<FSub>(self) -> FSub
+	where
+		FSub: BinaryField,
+		Self: ExtensionField<FSub>,
+	{
+		let mut conjugate = self;
+		let mut trace = self;
+		for _ in 1..<Self as ExtensionField<FSub>>::DEGREE {
+			conjugate = conjugate.frobenius(FSub::N_BITS);
+			trace += conjugate;
+		}
+		match trace.try_into() {
+			Ok(trace) => trace,
+			Err(_) => unreachable!("the relative trace is fixed by the Frobenius of FSub"),
+		}
+	}
+
+	/// Returns an element of absolute trace one.
+	///
+	/// The trace is a nonzero $\mathbb{F}_2$-linear map, so some element of the basis over
+	/// $\mathbb{F}_2$ has trace one.
+	fn trace_one() -> Self {
+		(0..Self::N_BITS)
+			.map(|i| match <Self as ExtensionField<BinaryField1b>>::basis(i) {
+				Ok(basis) => basis,
+				Err(_) => unreachable!("i is less than the degree over BinaryField1b"),
+			})
+			.find(|basis| basis.trace() == BinaryField1b::ONE)
+			.expect("the trace is surjective onto BinaryField1b")
+	}
+
+	/// Returns a solution $x$ of $x^2 + x = c$ for `self` $= c$, if one exists.
+	///
+	/// A solution exists if and only if the absolute trace of $c$ is zero, in which case the
+	/// solutions are $x$ and $x + 1$.
+	fn solve_quadratic(self) -> Option<Self> {
+		// IEEE 1363-2000, A.4.7: with $\tau$ of trace one, the solution is
+		// $x = \sum_{i=0}^{n-2} c^{2^i} \sum_{j=i+1}^{n-1} \tau^{2^j}$, accumulated Horner-style.
+		let tau = Self::trace_one();
+
+		let mut x = Self::ZERO;
+		let mut tau_sum = tau;
+		for _ in 1..Self::N_BITS {
+			x = x.square() + tau_sum.square() * self;
+			tau_sum = tau_sum.square() + tau;
+		}
+
+		(x.square() + x == self).then_some(x)
+	}
+
+	/// Returns the coefficients of the minimal polynomial over the subfield `FSub`, in ascending
+	/// order of degree.
+	///
+	/// The minimal polynomial is monic and its roots are the distinct conjugates $x^{q^i}$, where
+	/// $q$ is the order of `FSub`.
+	fn minimal_polynomial<FSub>(self) -> Vec<FSub>
+	where
+		FSub: BinaryField,
+		Self: ExtensionField<FSub>,
+	{
+		let mut coeffs = vec![Self::ONE];
+		let mut conjugate = self;
+		loop {
+			// Multiply by $X + x^{q^i}$.
+			coeffs.push(Self::ZERO);
+			for i in (0..coeffs.len() - 1).rev() {
+				let coeff = coeffs[i];
+				coeffs[i + 1] += coeff;
+				coeffs[i] = coeff * conjugate;
+			}
+
+			conjugate = conjugate.frobenius(FSub::N_BITS);
+			if conjugate == self {
+				break;
+			}
+		}
+
+		coeffs
+			.into_iter()
+			.map(|coeff| match coeff.try_into() {
+				Ok(coeff) => coeff,
+				Err(_) => unreachable!("the minimal polynomial has coefficients in the subfield"),
+			})
+			.collect()
+	}
+}
+
+impl<F: BinaryField> BinaryFieldOps for F {}
+
+/// Lane-wise Frobenius-derived operations on a packed binary field.
+///
+/// The methods carry a `_lanes` suffix because scalar binary fields are also packed fields of
+/// width one, so the names must not collide with those of [`BinaryFieldOps`].
+pub trait PackedBinaryFieldOps: PackedField<Scalar: BinaryField> {
+	/// Applies the Frobenius automorphism `k` times to every lane.
+	fn frobenius_lanes(self, k: usize) -> Self {
+		(0..k % Self::Scalar::N_BITS).fold(self, |x, _| x.square())
+	}
+
+	/// Returns the square root of every lane.
+	fn sqrt_lanes(self) -> Self {
+		self.frobenius_lanes(Self::Scalar::N_BITS - 1)
+	}
+
+	/// Returns the absolute trace of every lane, which is either zero or one.
+	fn trace_lanes(self) -> Self {
+		let mut conjugate = self;
+		let mut trace = self;
+		for _ in 1..Self::Scalar::N_BITS {
+			conjugate = conjugate.square();
+			trace += conjugate;
+		}
+		trace
+	}
+}
+
+impl<P: PackedField<Scalar: BinaryField>> PackedBinaryFieldOps for P {}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField8b,
+		BinaryField128b, BinaryField128bPolyval, BinaryField16b, BinaryField2b, BinaryField32b,
+		BinaryField4b, BinaryField64b, BinaryField8b, PackedAESBinaryField16x8b,
+		PackedBinaryField4x32b, PackedBinaryPolyval1x128b,
+	};
+
+	fn check_field_ops<F: BinaryField>(x: F, y: F) {
+		assert_eq!(F::trace_one().trace(), BinaryField1b::ONE);
+
+		assert_eq!(x.sqrt().square(), x);
+		assert_eq!(x.square().sqrt(), x);
+		assert_eq!(x.frobenius(F::N_BITS), x);
+		assert_eq!(x.frobenius(3), x.square().square().square());
+
+		assert_eq!((x + y).trace(), x.trace() + y.trace());
+		assert_eq!(x.square().trace(), x.trace());
+
+		// x^2 + x has trace zero, and its solutions are x and x + 1.
+		let c = x.square() + x;
+		assert_eq!(c.trace(), BinaryField1b::ZERO);
+		let solution = c.solve_quadratic().unwrap();
+		assert!(solution == x || solution == x + F::ONE);
+
+		let minimal_polynomial = x.minimal_polynomial::<BinaryField1b>();
+		assert_eq!(minimal_polynomial.last(), Some(&BinaryField1b::ONE));
+		assert_eq!(F::N_BITS % (minimal_polynomial.len() - 1), 0);
+		let eval = minimal_polynomial
+			.iter()
+			.rev()
+			.fold(F::ZERO, |eval, &coeff| eval * x + coeff);
+		assert_eq!(eval, F::ZERO);
+	}
+
+	fn check_relative_ops<F, FSub>(x: F)
+	where
+		F: BinaryField + ExtensionField<FSub>,
+		FSub: BinaryField,
+	{
+		// The trace is transitive through the subfield.
+		let relative_trace = x.relative_trace::<FSub>();
+		assert_eq!(relative_trace.trace(), x.trace());
+
+		let minimal_polynomial = x.minimal_polynomial::<FSub>();
+		assert_eq!(minimal_polynomial.last(), Some(&FSub::ONE));
+		assert_eq!(<F as ExtensionField<FSub>>::DEGREE % (minimal_polynomial.len() - 1), 0);
+		let eval = minimal_polynomial
+			.iter()
+			.rev()
+			.fold(F::ZERO, |eval, &coeff| eval * x + coeff);
+		assert_eq!(eval, F::ZERO);
+
+		// Subfield elements have linear minimal polynomials.
+		let sub = F::from(relative_trace);
+		assert_eq!(sub.minimal_polynomial::<FSub>(), vec![relative_trace, FSub::ONE]);
+	}
+
+	fn check_packed_ops<P: PackedField<Scalar: BinaryField>>(x: P) {
+		let sqrt = x.sqrt_lanes();
+		let frobenius = x.frobenius_lanes(5);
+		let trace = x.trace_lanes();
+		for i in 0..P::WIDTH {
+			assert_eq!(sqrt.get(i), x.get(i).sqrt());
+			assert_eq!(frobenius.get(i), x.get(i).frobenius(5));
+			assert_eq!(trace.get(i), x.get(i).trace().into());
+		}
+	}
+
+	macro_rules! field_ops_tests {
+		($($name:ident: $field:ty,)*) => {
+			proptest! {
+				$(
+					#[test]
+					fn $name(seed in any::<u64>()) {
+						let mut rng = StdRng::seed_from_u64(seed);
+						check_field_ops(<$field as Field>::random(&mut rng), <$field as Field>::random(&mut rng));
+					}
+				)*
+			}
+		};
+	}
+
+	field_ops_tests! {
+		test_field_ops_1b: BinaryField1b,
+		test_field_ops_2b: BinaryField2b,
+		test_field_ops_4b: BinaryField4b,
+		test_field_ops_8b: BinaryField8b,
+		test_field_ops_16b: BinaryField16b,
+		test_field_ops_32b: BinaryField32b,
+		test_field_ops_64b: BinaryField64b,
+		test_field_ops_128b: BinaryField128b,
+		test_field_ops_aes_8b: AESTowerField8b,
+		test_field_ops_aes_16b: AESTowerField16b,
+		test_field_ops_aes_32b: AESTowerField32b,
+		test_field_ops_aes_64b: AESTowerField64b,
+		test_field_ops_aes_128b: AESTowerField128b,
+		test_field_ops_polyval_128b: BinaryField128bPolyval,
+	}
+
+	proptest! {
+		#[test]
+		fn test_relative_ops(seed in any::<u64>()) {
+			let mut rng = StdRng::seed_from_u64(seed);
+			check_relative_ops::<BinaryField128b, BinaryField8b>(<BinaryField128b as Field>::random(&mut rng));
+			check_relative_ops::<BinaryField128b, BinaryField64b>(<BinaryField128b as Field>::random(&mut rng));
+			check_relative_ops::<BinaryField32b, BinaryField2b>(<BinaryField32b as Field>::random(&mut rng));
+			check_relative_ops::<AESTowerField128b, AESTowerField8b>(<AESTowerField128b as Field>::random(&mut rng));
+			check_relative_ops::<AESTowerField64b, AESTowerField32b>(<AESTowerField64b as Field>::random(&mut rng));
+		}
+
+		#[test]
+		fn test_packed_ops(seed in any::<u64>()) {
+			let mut rng = StdRng::seed_from_u64(seed);
+			check_packed_ops(PackedBinaryField4x32b::random(&mut rng));
+			check_packed_ops(PackedAESBinaryField16x8b::random(&mut rng));
+			check_packed_ops(PackedBinaryPolyval1x128b::random(&mut rng));
+		}
+	}
+
+	#[test]
+	fn test_solve_quadratic_exhaustive() {
+		let mut n_solvable = 0;
+		for c in 0..=u8::MAX {
+			let c = BinaryField8b::new(c);
+			match c.solve_quadratic() {
+				Some(x) => {
+					assert_eq!(x.square() + x, c);
+					assert_eq!(c.trace(), BinaryField1b::ZERO);
+					n_solvable += 1;
+				}
+				None => assert_eq!(c.trace(), BinaryField1b::ONE),
+			}
+		}
+		assert_eq!(n_solvable, 128);
+	}
+}
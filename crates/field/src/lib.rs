@@ -18,8 +18,10 @@ pub mod aes_field;
 pub mod arch;
 pub mod arithmetic_traits;
 pub mod as_packed_field;
+pub mod binary_curve;
 pub mod binary_field;
 mod binary_field_arithmetic;
+pub mod binary_field_ops;
 pub mod byte_iteration;
 pub mod error;
 pub mod extension;
@@ -43,7 +45,9 @@ pub mod util;
 
 pub use aes_field::*;
 pub use arch::byte_sliced::*;
+pub use binary_curve::*;
 pub use binary_field::*;
+pub use binary_field_ops::*;
 pub use error::*;
 pub use extension::*;
 pub use field::Field;
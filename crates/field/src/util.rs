@@ -84,10 +84,95 @@ pub fn powers<F: Field>(val: F) -> impl Iterator<Item = F> {
 	iter::successors(Some(F::ONE), move |&power| Some(power * val))
 }
 
+/// The number of elements that share one field inversion in the batch inversions.
+///
+/// Every chunk of this size is inverted independently, which bounds the scratch memory and lets
+/// the chunks be processed in parallel.
+const BATCH_INVERT_CHUNK_SIZE: usize = 1 << 10;
+
+/// Replaces every element of the slice with its inverse, or zero if the element is zero.
+///
+/// Uses Montgomery's trick, computing a single field inversion and three multiplications per
+/// element for every chunk of elements. The chunks are inverted in parallel.
+pub fn batch_invert_or_zero<F: Field>(values: &mut [F]) {
+	values
+		.par_chunks_mut(BATCH_INVERT_CHUNK_SIZE)
+		.for_each(|chunk| {
+			let mut prefix_products = Vec::with_capacity(chunk.len());
+			let mut product = F::ONE;
+			for &value in chunk.iter() {
+				prefix_products.push(product);
+				if value != F::ZERO {
+					product *= value;
+				}
+			}
+
+			let mut inverse = product.invert_or_zero();
+			for (value, &prefix_product) in chunk.iter_mut().zip(&prefix_products).rev() {
+				if *value != F::ZERO {
+					let value_inverse = inverse * prefix_product;
+					inverse *= *value;
+					*value = value_inverse;
+				}
+			}
+		});
+}
+
+/// Replaces every scalar of the packed slice with its inverse, or zero if the scalar is zero.
+///
+/// This is the packed version of [`batch_invert_or_zero`], running Montgomery's trick on all
+/// lanes at once. A chunk with zero scalars has its zero lanes temporarily replaced by one.
+pub fn batch_invert_or_zero_packed<P: PackedField>(values: &mut [P]) {
+	values
+		.par_chunks_mut(BATCH_INVERT_CHUNK_SIZE)
+		.for_each(|chunk| {
+			let mut prefix_products = Vec::with_capacity(chunk.len());
+			let mut product = P::one();
+			for &value in chunk.iter() {
+				prefix_products.push(product);
+				product *= value;
+			}
+
+			// The product of a lane is zero if and only if the lane holds a zero scalar somewhere
+			// in the chunk. This is rare, so only then are the zeros located and replaced.
+			let mut zero_lanes = Vec::new();
+			if product.iter().any(|scalar| scalar == P::Scalar::ZERO) {
+				product = P::one();
+				for (i, (value, prefix_product)) in
+					chunk.iter_mut().zip(&mut prefix_products).enumerate()
+				{
+					for lane in 0..P::WIDTH {
+						if value.get(lane) == P::Scalar::ZERO {
+							value.set(lane, P::Scalar::ONE);
+							zero_lanes.push((i, lane));
+						}
+					}
+					*prefix_product = product;
+					product *= *value;
+				}
+			}
+
+			let mut inverse = product.invert_or_zero();
+			for (value, &prefix_product) in chunk.iter_mut().zip(&prefix_products).rev() {
+				let value_inverse = inverse * prefix_product;
+				inverse *= *value;
+				*value = value_inverse;
+			}
+
+			for (i, lane) in zero_lanes {
+				chunk[i].set(lane, P::Scalar::ZERO);
+			}
+		});
+}
+
 #[cfg(test)]
 mod tests {
+	use std::iter::repeat_with;
+
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
 	use super::*;
-	use crate::PackedBinaryField4x32b;
+	use crate::{BinaryField128b, PackedBinaryField4x32b};
 
 	type P = PackedBinaryField4x32b;
 	type F = <P as PackedField>::Scalar;
@@ -170,4 +255,72 @@ mod tests {
 
 		assert_eq!(result, expected);
 	}
+
+	fn random_with_zeros<T: Copy>(
+		rng: &mut StdRng,
+		len: usize,
+		zero: T,
+		mut random: impl FnMut(&mut StdRng) -> T,
+	) -> Vec<T> {
+		repeat_with(|| if rng.gen_bool(0.1) { zero } else { random(rng) })
+			.take(len)
+			.collect()
+	}
+
+	#[test]
+	fn test_batch_invert_or_zero() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for len in [
+			0,
+			1,
+			7,
+			BATCH_INVERT_CHUNK_SIZE,
+			3 * BATCH_INVERT_CHUNK_SIZE + 5,
+		] {
+			let values = random_with_zeros(&mut rng, len, BinaryField128b::ZERO, |rng| {
+				<BinaryField128b as Field>::random(rng)
+			});
+			let mut inverses = values.clone();
+			batch_invert_or_zero(&mut inverses);
+			for (value, inverse) in values.into_iter().zip(inverses) {
+				assert_eq!(inverse, value.invert_or_zero());
+			}
+		}
+	}
+
+	#[test]
+	fn test_batch_invert_or_zero_packed() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for len in [
+			0,
+			1,
+			7,
+			BATCH_INVERT_CHUNK_SIZE,
+			3 * BATCH_INVERT_CHUNK_SIZE + 5,
+		] {
+			// Zero lanes are scattered among non-zero lanes of the same packed elements.
+			let values = repeat_with(|| {
+				P::from_scalars(random_with_zeros(&mut rng, P::WIDTH, F::ZERO, |rng| {
+					<F as Field>::random(rng)
+				}))
+			})
+			.take(len)
+			.collect::<Vec<_>>();
+			let mut inverses = values.clone();
+			batch_invert_or_zero_packed(&mut inverses);
+			for (value, inverse) in values.into_iter().zip(inverses) {
+				assert_eq!(inverse, value.invert_or_zero());
+			}
+		}
+
+		// Without any zeros
+		let values = repeat_with(|| P::from_fn(|_| F::new(rng.gen_range(1..=u32::MAX))))
+			.take(100)
+			.collect::<Vec<_>>();
+		let mut inverses = values.clone();
+		batch_invert_or_zero_packed(&mut inverses);
+		for (value, inverse) in values.into_iter().zip(inverses) {
+			assert_eq!(inverse, value.invert_or_zero());
+		}
+	}
 }
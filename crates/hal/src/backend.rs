@@ -2,15 +2,19 @@
 
 use std::{
 	fmt::Debug,
+	mem::MaybeUninit,
 	ops::{Deref, DerefMut},
 };
 
-use binius_field::{Field, PackedExtension, PackedField};
+use binius_field::{BinaryField, ExtensionField, Field, PackedExtension, PackedField, TowerField};
+use binius_hash::{multi_digest::ParallelDigest, PseudoCompressionFunction};
 use binius_math::{
 	CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly, MultilinearQuery,
 	MultilinearQueryRef,
 };
 use binius_maybe_rayon::iter::FromParallelIterator;
+use binius_ntt::AdditiveNTT;
+use digest::{core_api::BlockSizeUser, FixedOutputReset, Output};
 use tracing::instrument;
 
 use crate::{Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
@@ -81,6 +85,99 @@ pub trait ComputationBackend: Send + Sync + Debug {
 		multilinear: &impl MultilinearPoly<P>,
 		query_expansion: MultilinearQueryRef<P>,
 	) -> Result<MultilinearExtension<P>, Error>;
+	/// Encodes a batch of interleaved messages in-place with a Reed–Solomon code.
+	///
+	/// The first `2^(log_dim + log_batch_size)` scalars of `code` are the interleaved messages,
+	/// interpreted as coefficients in the novel polynomial basis of `ntt`. When the method
+	/// completes, `code` holds the `2^log_inv_rate` encoded cosets.
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync;
+
+	/// Hashes equal-sized chunks of `elements` into the Merkle tree leaf digests.
+	fn merkle_hash_leaves<F, H>(
+		&self,
+		elements: &[F],
+		digests: &mut [MaybeUninit<Output<H::Digest>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>;
+
+	/// Compresses pairs of adjacent digests of a Merkle tree layer into the next layer.
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [MaybeUninit<D>],
+	) -> Result<(), Error>
+	where
+		D: Clone + PartialEq + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync;
+
+	/// FRI-folds a codeword with consecutive folding challenges, starting at fold round
+	/// `start_round`.
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync;
+
+	/// Folds an interleaved codeword into a single codeword.
+	///
+	/// The first `log_batch_size` challenges mix the interleaved codewords, the remaining ones
+	/// FRI-fold the mixed codeword starting at fold round 0.
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		challenges: &[F],
+		log_batch_size: usize,
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync;
+
+	/// Computes the layer of a GKR grand product circuit with `n_vars` variables from the
+	/// `2^(n_vars + 1)` values of the layer below.
+	///
+	/// Each output value is the product of the values at the same index in the first and second
+	/// halves of `layer_below`.
+	fn gkr_product_layer<P: PackedField>(
+		&self,
+		n_vars: usize,
+		layer_below: &[P],
+	) -> Result<Vec<P>, Error>;
+
+	/// Folds a ring-switching tensor algebra element over `FE / F` into a field element by
+	/// scaling the rows with `coeffs` and accumulating.
+	///
+	/// The element is given by its `FE::DEGREE` vertical subring elements. `coeffs` must have at
+	/// least as many elements, only the first `FE::DEGREE` are used.
+	fn tensor_algebra_fold_vertical<F, FE>(
+		&self,
+		vertical_elems: &[FE],
+		coeffs: &[FE],
+	) -> Result<FE, Error>
+	where
+		F: Field,
+		FE: ExtensionField<F> + PackedExtension<F>;
 }
 
 /// Makes it unnecessary to clone backends.
@@ -160,6 +257,96 @@ where
 	) -> Result<MultilinearExtension<P>, Error> {
 		T::evaluate_partial_high(self, multilinear, query_expansion)
 	}
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync,
+	{
+		T::reed_solomon_encode_batch(self, ntt, log_dim, log_inv_rate, log_batch_size, code)
+	}
+
+	fn merkle_hash_leaves<F, H>(
+		&self,
+		elements: &[F],
+		digests: &mut [MaybeUninit<Output<H::Digest>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	{
+		T::merkle_hash_leaves::<F, H>(self, elements, digests)
+	}
+
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [MaybeUninit<D>],
+	) -> Result<(), Error>
+	where
+		D: Clone + PartialEq + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		T::merkle_compress_layer(self, compression, prev_layer, next_layer)
+	}
+
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		T::fri_fold_codeword(self, ntt, codeword, start_round, folding_challenges)
+	}
+
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		challenges: &[F],
+		log_batch_size: usize,
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		T::fri_fold_interleaved(self, ntt, codeword, challenges, log_batch_size)
+	}
+
+	fn gkr_product_layer<P: PackedField>(
+		&self,
+		n_vars: usize,
+		layer_below: &[P],
+	) -> Result<Vec<P>, Error> {
+		T::gkr_product_layer(self, n_vars, layer_below)
+	}
+
+	fn tensor_algebra_fold_vertical<F, FE>(
+		&self,
+		vertical_elems: &[FE],
+		coeffs: &[FE],
+	) -> Result<FE, Error>
+	where
+		F: Field,
+		FE: ExtensionField<F> + PackedExtension<F>,
+	{
+		T::tensor_algebra_fold_vertical::<F, FE>(self, vertical_elems, coeffs)
+	}
 }
 
 pub trait ComputationBackendExt: ComputationBackend {
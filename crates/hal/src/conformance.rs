@@ -0,0 +1,518 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Conformance checks that every [`ComputationBackend`] implementation must pass.
+//!
+//! Each check runs a backend operation on random inputs and compares the result against a simple,
+//! unoptimized reference implementation. The checks panic on mismatch, so a backend crate can run
+//! them from its own tests with [`check_backend_conformance`].
+
+use std::{iter::repeat_with, mem::MaybeUninit, ops::Range};
+
+use binius_field::{
+	BinaryField, BinaryField128b, BinaryField32b, BinaryField8b, ExtensionField, Field,
+	PackedBinaryField4x32b, PackedExtension, PackedField,
+};
+use binius_hash::{
+	groestl::{Groestl256, Groestl256ByteCompression},
+	PseudoCompressionFunction,
+};
+use binius_math::{
+	extrapolate_line_scalar, ArithExpr, CompositionPoly, EvaluationOrder, MLEDirectAdapter,
+	MultilinearExtension, MultilinearQuery,
+};
+use binius_ntt::{AdditiveNTT, SingleThreadedNTT};
+use binius_utils::{SerializationMode, SerializeBytes};
+use digest::{Digest, Output};
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{ComputationBackend, SumcheckEvaluator, SumcheckMultilinear};
+
+type F = BinaryField128b;
+type FS = BinaryField32b;
+
+/// Runs all conformance checks against the backend.
+pub fn check_backend_conformance(backend: &impl ComputationBackend) {
+	check_tensor_product_full_query(backend);
+	check_sumcheck_compute_round_evals(backend);
+	check_evaluate_partial_high(backend);
+	check_reed_solomon_encode_batch(backend);
+	check_merkle_tree_layers(backend);
+	check_fri_fold_codeword(backend);
+	check_fri_fold_interleaved(backend);
+	check_gkr_product_layer(backend);
+	check_tensor_algebra_fold_vertical(backend);
+}
+
+/// Checks [`ComputationBackend::tensor_product_full_query`] against the product formula of the
+/// equality indicator.
+pub fn check_tensor_product_full_query(backend: &impl ComputationBackend) {
+	let mut rng = StdRng::seed_from_u64(0);
+	for n_vars in 0..6 {
+		let query = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let tensor = backend.tensor_product_full_query::<F>(&query).unwrap();
+		assert_eq!(tensor.len(), 1 << n_vars);
+		for (i, &value) in tensor.iter().enumerate() {
+			let expected = query
+				.iter()
+				.enumerate()
+				.map(|(j, &r)| if (i >> j) & 1 == 1 { r } else { F::ONE - r })
+				.product::<F>();
+			assert_eq!(value, expected);
+		}
+	}
+}
+
+/// Checks [`ComputationBackend::sumcheck_compute_round_evals`] on a product of multilinears
+/// against summing the product of the interpolated multilinear values over the hypercube.
+pub fn check_sumcheck_compute_round_evals(backend: &impl ComputationBackend) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let n_multilinears = 3;
+	let evaluator = ProductEvaluator {
+		composition: ProductComposition {
+			n_vars: n_multilinears,
+		},
+	};
+	// The round evaluations at 0, 1, infinity and one nontrivial point.
+	let nontrivial_point = <F as Field>::random(&mut rng);
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		for n_vars in 1..6 {
+			let evals = repeat_with(|| {
+				repeat_with(|| <F as Field>::random(&mut rng))
+					.take(1 << n_vars)
+					.collect::<Vec<_>>()
+			})
+			.take(n_multilinears)
+			.collect::<Vec<_>>();
+			// Mix transparent and folded multilinears to cover both access paths.
+			let multilinears = evals
+				.iter()
+				.enumerate()
+				.map(|(i, evals)| {
+					if i % 2 == 0 {
+						SumcheckMultilinear::Transparent {
+							multilinear: MLEDirectAdapter::from(
+								MultilinearExtension::from_values_slice(evals).unwrap(),
+							),
+							switchover_round: n_vars,
+							zero_scalars_suffix: 0,
+						}
+					} else {
+						SumcheckMultilinear::Folded {
+							large_field_folded_evals: evals.clone(),
+						}
+					}
+				})
+				.collect::<Vec<_>>();
+
+			let round_evals = backend
+				.sumcheck_compute_round_evals(
+					evaluation_order,
+					n_vars,
+					0,
+					None,
+					&multilinears,
+					&[evaluator.clone()],
+					&[nontrivial_point],
+				)
+				.unwrap();
+			assert_eq!(round_evals.len(), 1);
+
+			let (evals_0, evals_1): (Vec<_>, Vec<_>) = evals
+				.iter()
+				.map(|evals| {
+					let half = 1 << (n_vars - 1);
+					match evaluation_order {
+						EvaluationOrder::LowToHigh => (
+							evals.iter().step_by(2).copied().collect::<Vec<_>>(),
+							evals.iter().skip(1).step_by(2).copied().collect::<Vec<_>>(),
+						),
+						EvaluationOrder::HighToLow => {
+							(evals[..half].to_vec(), evals[half..].to_vec())
+						}
+					}
+				})
+				.unzip();
+			let round_eval = |value: &dyn Fn(F, F) -> F| {
+				(0..1 << (n_vars - 1))
+					.map(|x| {
+						(0..n_multilinears)
+							.map(|j| value(evals_0[j][x], evals_1[j][x]))
+							.product::<F>()
+					})
+					.sum::<F>()
+			};
+			let expected = [
+				round_eval(&|eval_0, _| eval_0),
+				round_eval(&|_, eval_1| eval_1),
+				// The leading coefficient of the round polynomial.
+				round_eval(&|eval_0, eval_1| eval_1 - eval_0),
+				round_eval(&|eval_0, eval_1| {
+					extrapolate_line_scalar(eval_0, eval_1, nontrivial_point)
+				}),
+			];
+			assert_eq!(round_evals[0].0, expected);
+		}
+	}
+}
+
+/// Checks [`ComputationBackend::evaluate_partial_high`] against summing the evaluations over the
+/// high variables weighted by the query expansion.
+pub fn check_evaluate_partial_high(backend: &impl ComputationBackend) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let n_vars = 6;
+	for n_query_vars in 0..=n_vars {
+		let evals = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		let query = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(n_query_vars)
+			.collect::<Vec<_>>();
+		let multilinear =
+			MLEDirectAdapter::from(MultilinearExtension::from_values_slice(&evals).unwrap());
+		let query_expansion = MultilinearQuery::<F>::expand(&query);
+
+		let result = backend
+			.evaluate_partial_high(&multilinear, query_expansion.to_ref())
+			.unwrap();
+		assert_eq!(result.n_vars(), n_vars - n_query_vars);
+
+		let tensor = backend.tensor_product_full_query::<F>(&query).unwrap();
+		let expected = (0..1 << (n_vars - n_query_vars))
+			.map(|i| {
+				tensor
+					.iter()
+					.enumerate()
+					.map(|(j, &t)| evals[i + (j << (n_vars - n_query_vars))] * t)
+					.sum::<F>()
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(result.evals(), expected.as_slice());
+	}
+}
+
+/// Checks [`ComputationBackend::reed_solomon_encode_batch`] against a coset-by-coset forward NTT.
+pub fn check_reed_solomon_encode_batch(backend: &impl ComputationBackend) {
+	type P = PackedBinaryField4x32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let log_dim = 6;
+	for log_inv_rate in 0..3 {
+		for log_batch_size in 0..3 {
+			let ntt = SingleThreadedNTT::<FS>::new(log_dim + log_inv_rate).unwrap();
+			let msgs_len = 1 << (log_dim + log_batch_size - P::LOG_WIDTH);
+			let msgs = repeat_with(|| <P as PackedField>::random(&mut rng))
+				.take(msgs_len)
+				.collect::<Vec<_>>();
+
+			let mut code = vec![P::zero(); msgs_len << log_inv_rate];
+			code[..msgs_len].copy_from_slice(&msgs);
+			backend
+				.reed_solomon_encode_batch(&ntt, log_dim, log_inv_rate, log_batch_size, &mut code)
+				.unwrap();
+
+			for (coset, coset_code) in code.chunks_exact(msgs_len).enumerate() {
+				let mut expected = msgs.clone();
+				ntt.forward_transform(&mut expected, coset as u32, log_batch_size, log_dim)
+					.unwrap();
+				assert_eq!(coset_code, expected.as_slice());
+			}
+		}
+	}
+}
+
+/// Checks [`ComputationBackend::merkle_hash_leaves`] and
+/// [`ComputationBackend::merkle_compress_layer`] against sequential hashing of the serialized
+/// leaves.
+pub fn check_merkle_tree_layers(backend: &impl ComputationBackend) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let compression = Groestl256ByteCompression;
+	let log_len = 5;
+	for batch_size in [1, 3, 8] {
+		let elements = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(batch_size << log_len)
+			.collect::<Vec<_>>();
+
+		let mut leaves = zeroed_digests(1 << log_len);
+		backend
+			.merkle_hash_leaves::<_, Groestl256>(&elements, &mut leaves)
+			.unwrap();
+		let mut layer = into_initialized(leaves);
+
+		for (chunk, leaf) in elements.chunks(batch_size).zip(&layer) {
+			let mut buffer = Vec::new();
+			for elem in chunk {
+				elem.serialize(&mut buffer, SerializationMode::CanonicalTower)
+					.unwrap();
+			}
+			assert_eq!(*leaf, Groestl256::digest(&buffer));
+		}
+
+		while layer.len() > 1 {
+			let mut next_layer = zeroed_digests(layer.len() / 2);
+			backend
+				.merkle_compress_layer(&compression, &layer, &mut next_layer)
+				.unwrap();
+			let next_layer = into_initialized(next_layer);
+
+			for (pair, node) in layer.chunks_exact(2).zip(&next_layer) {
+				assert_eq!(*node, compression.compress([pair[0], pair[1]]));
+			}
+			layer = next_layer;
+		}
+	}
+}
+
+/// Checks [`ComputationBackend::fri_fold_codeword`] against folding with one challenge at a time.
+pub fn check_fri_fold_codeword(backend: &impl ComputationBackend) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let log_len = 8;
+	let ntt = SingleThreadedNTT::<FS>::new(log_len).unwrap();
+	for start_round in 0..3 {
+		for n_challenges in 0..4 {
+			let codeword = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(1 << (log_len - start_round))
+				.collect::<Vec<_>>();
+			let challenges = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(n_challenges)
+				.collect::<Vec<_>>();
+
+			let folded = backend
+				.fri_fold_codeword(&ntt, &codeword, start_round, &challenges)
+				.unwrap();
+			let expected = challenges
+				.iter()
+				.enumerate()
+				.fold(codeword, |codeword: Vec<F>, (i, &r)| {
+					reference_fold(&ntt, &codeword, start_round + i, r)
+				});
+			assert_eq!(folded, expected);
+		}
+	}
+}
+
+/// Checks [`ComputationBackend::fri_fold_interleaved`] against mixing the interleaved codewords
+/// with the tensor expansion and then folding with one challenge at a time.
+pub fn check_fri_fold_interleaved(backend: &impl ComputationBackend) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let log_len = 6;
+	let ntt = SingleThreadedNTT::<FS>::new(log_len).unwrap();
+	for log_batch_size in 0..3 {
+		for n_fold_challenges in 0..3 {
+			let codeword = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(1 << (log_len + log_batch_size))
+				.collect::<Vec<_>>();
+			let challenges = repeat_with(|| <F as Field>::random(&mut rng))
+				.take(log_batch_size + n_fold_challenges)
+				.collect::<Vec<_>>();
+
+			let folded = backend
+				.fri_fold_interleaved(&ntt, &codeword, &challenges, log_batch_size)
+				.unwrap();
+
+			let (interleave_challenges, fold_challenges) = challenges.split_at(log_batch_size);
+			let tensor = backend
+				.tensor_product_full_query::<F>(interleave_challenges)
+				.unwrap();
+			let mixed = codeword
+				.chunks(1 << log_batch_size)
+				.map(|values| values.iter().zip(tensor.iter()).map(|(&v, &t)| v * t).sum())
+				.collect::<Vec<F>>();
+			let expected = fold_challenges
+				.iter()
+				.enumerate()
+				.fold(mixed, |codeword: Vec<F>, (i, &r)| reference_fold(&ntt, &codeword, i, r));
+			assert_eq!(folded, expected);
+		}
+	}
+}
+
+/// Checks [`ComputationBackend::gkr_product_layer`] against multiplying the halves of the layer
+/// below element by element.
+pub fn check_gkr_product_layer(backend: &impl ComputationBackend) {
+	type P = PackedBinaryField4x32b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	for n_vars in 0..6usize {
+		let layer_below = repeat_with(|| <P as PackedField>::random(&mut rng))
+			.take(1 << (n_vars + 1).saturating_sub(P::LOG_WIDTH))
+			.collect::<Vec<_>>();
+		let layer = backend.gkr_product_layer(n_vars, &layer_below).unwrap();
+		assert_eq!(layer.len(), 1 << n_vars.saturating_sub(P::LOG_WIDTH));
+
+		let below = P::iter_slice(&layer_below).collect::<Vec<_>>();
+		let expected = (0..1 << n_vars)
+			.map(|i| below[i] * below[(1 << n_vars) + i])
+			.collect::<Vec<_>>();
+		let layer = P::iter_slice(&layer).take(1 << n_vars).collect::<Vec<_>>();
+		assert_eq!(layer, expected);
+	}
+}
+
+/// Checks [`ComputationBackend::tensor_algebra_fold_vertical`] against folding the horizontal
+/// subring elements, read off the subfield coordinates of the vertical ones.
+pub fn check_tensor_algebra_fold_vertical(backend: &impl ComputationBackend) {
+	check_tensor_algebra_fold_vertical_over::<BinaryField8b>(backend);
+	check_tensor_algebra_fold_vertical_over::<FS>(backend);
+	check_tensor_algebra_fold_vertical_over::<F>(backend);
+}
+
+fn check_tensor_algebra_fold_vertical_over<FSub>(backend: &impl ComputationBackend)
+where
+	FSub: Field,
+	F: ExtensionField<FSub> + PackedExtension<FSub>,
+{
+	let mut rng = StdRng::seed_from_u64(0);
+	let degree = <F as ExtensionField<FSub>>::DEGREE;
+	let vertical_elems = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(degree)
+		.collect::<Vec<_>>();
+	let coeffs = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(degree)
+		.collect::<Vec<_>>();
+
+	let result = backend
+		.tensor_algebra_fold_vertical::<FSub, F>(&vertical_elems, &coeffs)
+		.unwrap();
+	let expected = (0..degree)
+		.map(|i| {
+			let horizontal = <F as ExtensionField<FSub>>::from_bases(
+				vertical_elems
+					.iter()
+					.map(|elem| <F as ExtensionField<FSub>>::get_base(elem, i)),
+			)
+			.unwrap();
+			horizontal * coeffs[i]
+		})
+		.sum::<F>();
+	assert_eq!(result, expected);
+}
+
+/// The product of all variables.
+#[derive(Debug, Clone)]
+struct ProductComposition {
+	n_vars: usize,
+}
+
+impl CompositionPoly<F> for ProductComposition {
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn degree(&self) -> usize {
+		self.n_vars
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+
+	fn expression(&self) -> ArithExpr<F> {
+		(0..self.n_vars)
+			.map(ArithExpr::Var)
+			.reduce(|acc, var| acc * var)
+			.unwrap_or(ArithExpr::Const(F::ONE))
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, binius_math::Error> {
+		Ok(query.iter().product())
+	}
+}
+
+/// Sums a [`ProductComposition`] over the hypercube at the points 0, 1, infinity and one
+/// nontrivial point.
+///
+/// The composition is homogeneous, so evaluating it on the differences of the multilinears gives
+/// the leading coefficient of the round polynomial at the infinity point.
+#[derive(Debug, Clone)]
+struct ProductEvaluator {
+	composition: ProductComposition,
+}
+
+impl SumcheckEvaluator<F, ProductComposition> for ProductEvaluator {
+	fn eval_point_indices(&self) -> Range<usize> {
+		0..4
+	}
+
+	fn process_subcube_at_eval_point(
+		&self,
+		subcube_vars: usize,
+		_subcube_index: usize,
+		_is_infinity_point: bool,
+		batch_query: &[&[F]],
+	) -> F {
+		(0..1 << subcube_vars)
+			.map(|i| batch_query.iter().map(|evals| evals[i]).product::<F>())
+			.sum()
+	}
+
+	fn composition(&self) -> &ProductComposition {
+		&self.composition
+	}
+
+	fn eq_ind_partial_eval(&self) -> Option<&[F]> {
+		None
+	}
+}
+
+/// Folds a codeword once with the FRI folding map of [DP24], Def. 3.6.
+///
+/// [DP24]: <https://eprint.iacr.org/2024/504>
+fn reference_fold<FE, FB>(
+	ntt: &impl AdditiveNTT<FB>,
+	codeword: &[FE],
+	round: usize,
+	r: FE,
+) -> Vec<FE>
+where
+	FE: BinaryField + ExtensionField<FB>,
+	FB: BinaryField,
+{
+	codeword
+		.chunks_exact(2)
+		.enumerate()
+		.map(|(index, pair)| {
+			let t = ntt.get_subspace_eval(round, index);
+			let v = pair[0] + pair[1];
+			let u = pair[0] + v * t;
+			extrapolate_line_scalar(u, v, r)
+		})
+		.collect()
+}
+
+/// Returns output buffers holding zeroed digests.
+///
+/// The buffers are initialized before the call so that reading them back does not rely on the
+/// backend under test writing every element.
+fn zeroed_digests(len: usize) -> Vec<MaybeUninit<Output<Groestl256>>> {
+	vec![MaybeUninit::new(Output::<Groestl256>::default()); len]
+}
+
+fn into_initialized(values: Vec<MaybeUninit<Output<Groestl256>>>) -> Vec<Output<Groestl256>> {
+	values
+		.into_iter()
+		.map(|value| {
+			// SAFETY: the buffers are created initialized by `zeroed_digests`, and the backend
+			// methods only write initialized digests into them.
+			unsafe { value.assume_init() }
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::make_portable_backend;
+
+	#[test]
+	fn test_cpu_backend_conformance() {
+		check_backend_conformance(&make_portable_backend());
+	}
+
+	#[test]
+	fn test_cpu_backend_ref_conformance() {
+		let backend = make_portable_backend();
+		check_backend_conformance(&&backend);
+	}
+}
@@ -1,16 +1,25 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::fmt::Debug;
+use std::{fmt::Debug, mem::MaybeUninit};
 
-use binius_field::{Field, PackedExtension, PackedField};
+use binius_field::{BinaryField, ExtensionField, Field, PackedExtension, PackedField, TowerField};
+use binius_hash::{multi_digest::ParallelDigest, PseudoCompressionFunction};
 use binius_math::{
 	eq_ind_partial_eval, CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly,
 	MultilinearQueryRef,
 };
+use binius_ntt::AdditiveNTT;
+use digest::{core_api::BlockSizeUser, FixedOutputReset, Output};
 use tracing::instrument;
 
 use crate::{
-	sumcheck_folding::fold_multilinears, sumcheck_round_calculation::calculate_round_evals,
+	fri_folding::{fri_fold_codeword, fri_fold_interleaved},
+	gkr::gkr_product_layer,
+	merkle_tree::{merkle_compress_layer, merkle_hash_leaves},
+	reed_solomon::reed_solomon_encode_batch,
+	sumcheck_folding::fold_multilinears,
+	sumcheck_round_calculation::calculate_round_evals,
+	tensor_algebra::tensor_algebra_fold_vertical,
 	ComputationBackend, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
 };
 
@@ -88,4 +97,97 @@ impl ComputationBackend for CpuBackend {
 	) -> Result<MultilinearExtension<P>, Error> {
 		Ok(multilinear.evaluate_partial_high(query_expansion)?)
 	}
+
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync,
+	{
+		reed_solomon_encode_batch(ntt, log_dim, log_inv_rate, log_batch_size, code)
+	}
+
+	fn merkle_hash_leaves<F, H>(
+		&self,
+		elements: &[F],
+		digests: &mut [MaybeUninit<Output<H::Digest>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	{
+		merkle_hash_leaves::<F, H>(elements, digests)
+	}
+
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [MaybeUninit<D>],
+	) -> Result<(), Error>
+	where
+		D: Clone + PartialEq + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		merkle_compress_layer(compression, prev_layer, next_layer)
+	}
+
+	#[instrument(skip_all, name = "CpuBackend::fri_fold_codeword", level = "debug")]
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		fri_fold_codeword(ntt, codeword, start_round, folding_challenges)
+	}
+
+	#[instrument(skip_all, name = "CpuBackend::fri_fold_interleaved", level = "debug")]
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		challenges: &[F],
+		log_batch_size: usize,
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		fri_fold_interleaved(ntt, codeword, challenges, log_batch_size)
+	}
+
+	fn gkr_product_layer<P: PackedField>(
+		&self,
+		n_vars: usize,
+		layer_below: &[P],
+	) -> Result<Vec<P>, Error> {
+		gkr_product_layer(n_vars, layer_below)
+	}
+
+	fn tensor_algebra_fold_vertical<F, FE>(
+		&self,
+		vertical_elems: &[FE],
+		coeffs: &[FE],
+	) -> Result<FE, Error>
+	where
+		F: Field,
+		FE: ExtensionField<F> + PackedExtension<F>,
+	{
+		tensor_algebra_fold_vertical::<F, FE>(vertical_elems, coeffs)
+	}
 }
@@ -17,6 +17,10 @@ pub enum Error {
 	NoScratchSpace,
 	#[error("incorrect multilinear access destination slice lengths")]
 	IncorrectDestSliceLengths,
+	#[error("the input slice must have length {expected}")]
+	IncorrectInputSliceLength { expected: usize },
 	#[error("{0}")]
 	FieldError(#[from] binius_field::Error),
+	#[error("{0}")]
+	NttError(#[from] binius_ntt::Error),
 }
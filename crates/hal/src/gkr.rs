@@ -0,0 +1,41 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{packed::get_packed_slice, PackedField};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use bytemuck::zeroed_vec;
+
+use crate::Error;
+
+/// Computes the layer of a GKR grand product circuit with `n_vars` variables from the layer below.
+///
+/// Each output value is the product of the values at the same index in the first and second
+/// halves of `layer_below`.
+#[tracing::instrument("gkr_product_layer", skip_all, level = "debug")]
+pub(crate) fn gkr_product_layer<P: PackedField>(
+	n_vars: usize,
+	layer_below: &[P],
+) -> Result<Vec<P>, Error> {
+	let expected = 1 << (n_vars + 1).saturating_sub(P::LOG_WIDTH);
+	if layer_below.len() != expected {
+		bail!(Error::IncorrectInputSliceLength { expected });
+	}
+
+	let mut layer = zeroed_vec(1 << n_vars.saturating_sub(P::LOG_WIDTH));
+	if n_vars >= P::LOG_WIDTH {
+		let (left_half, right_half) = layer_below.split_at(1 << (n_vars - P::LOG_WIDTH));
+		layer
+			.par_iter_mut()
+			.zip(left_half.par_iter().zip(right_half.par_iter()))
+			.for_each(|(out_i, (left_i, right_i))| {
+				*out_i = *left_i * *right_i;
+			});
+	} else {
+		let layer = &mut layer[0];
+		let len = 1 << n_vars;
+		for i in 0..len {
+			layer.set(i, get_packed_slice(layer_below, i) * get_packed_slice(layer_below, len + i));
+		}
+	}
+	Ok(layer)
+}
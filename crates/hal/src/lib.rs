@@ -10,15 +10,24 @@
 
 mod backend;
 mod common;
+pub mod conformance;
 mod cpu;
 mod error;
+mod fri_folding;
+mod gkr;
+mod merkle_tree;
+mod recording;
+mod reed_solomon;
 mod sumcheck_evaluator;
 mod sumcheck_folding;
 mod sumcheck_multilinear;
 mod sumcheck_round_calculation;
+mod tensor_algebra;
 
 pub use backend::*;
 pub use cpu::*;
 pub use error::*;
+pub use fri_folding::{fri_fold_chunk, fri_fold_interleaved_chunk};
+pub use recording::*;
 pub use sumcheck_evaluator::*;
 pub use sumcheck_multilinear::*;
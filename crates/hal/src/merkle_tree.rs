@@ -0,0 +1,59 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, mem::MaybeUninit};
+
+use binius_field::TowerField;
+use binius_hash::{multi_digest::ParallelDigest, PseudoCompressionFunction};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use digest::{core_api::BlockSizeUser, FixedOutputReset, Output};
+
+use crate::Error;
+
+/// Hashes the elements in chunks of a vector into digests.
+///
+/// Given a vector of elements and an output buffer of N hash digests, this splits the elements
+/// into N equal-sized chunks and hashes each chunk into the corresponding output digest.
+#[tracing::instrument("hash_interleaved", skip_all, level = "debug")]
+pub(crate) fn merkle_hash_leaves<F, H>(
+	elements: &[F],
+	digests: &mut [MaybeUninit<Output<H::Digest>>],
+) -> Result<(), Error>
+where
+	F: TowerField,
+	H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+{
+	if digests.is_empty() || elements.len() % digests.len() != 0 {
+		bail!(Error::IncorrectDestSliceLengths);
+	}
+
+	let hash_data_iter = elements
+		.par_chunks(elements.len() / digests.len())
+		.map(|s| s.iter().copied());
+	H::new().digest(hash_data_iter, digests);
+	Ok(())
+}
+
+/// Compresses adjacent pairs of digests in `prev_layer` into the next layer of a Merkle tree.
+#[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
+pub(crate) fn merkle_compress_layer<D, C>(
+	compression: &C,
+	prev_layer: &[D],
+	next_layer: &mut [MaybeUninit<D>],
+) -> Result<(), Error>
+where
+	D: Clone + Send + Sync,
+	C: PseudoCompressionFunction<D, 2> + Sync,
+{
+	if prev_layer.len() != 2 * next_layer.len() {
+		bail!(Error::IncorrectDestSliceLengths);
+	}
+
+	prev_layer
+		.par_chunks_exact(2)
+		.zip(next_layer.par_iter_mut())
+		.for_each(|(prev_pair, next_digest)| {
+			next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
+		});
+	Ok(())
+}
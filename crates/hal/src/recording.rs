@@ -0,0 +1,916 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	collections::BTreeMap,
+	fmt::Debug,
+	io,
+	mem::MaybeUninit,
+	sync::{Mutex, PoisonError},
+	time::{Duration, Instant},
+};
+
+use binius_field::{BinaryField, ExtensionField, Field, PackedExtension, PackedField, TowerField};
+use binius_hash::{multi_digest::ParallelDigest, PseudoCompressionFunction};
+use binius_math::{
+	CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
+};
+use binius_ntt::AdditiveNTT;
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use digest::{core_api::BlockSizeUser, FixedOutputReset, Output};
+
+use crate::{ComputationBackend, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+
+/// An operation of the [`ComputationBackend`] trait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Operation {
+	TensorProductFullQuery,
+	SumcheckComputeRoundEvals,
+	SumcheckFoldMultilinears,
+	EvaluatePartialHigh,
+	ReedSolomonEncodeBatch,
+	MerkleHashLeaves,
+	MerkleCompressLayer,
+	FriFoldCodeword,
+	FriFoldInterleaved,
+	GkrProductLayer,
+	TensorAlgebraFoldVertical,
+}
+
+/// A single backend call recorded by [`RecordingBackend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallRecord {
+	pub operation: Operation,
+	/// Base-2 logarithm of the size of the data the operation works on, ie. the number of
+	/// variables of the multilinears or the log length of the codeword or Merkle layer.
+	pub n_vars: usize,
+	/// The number of multilinears (or interleaved codewords) processed by the call.
+	pub n_multilinears: Option<usize>,
+	/// The maximum degree of the compositions evaluated by the call.
+	pub degree: Option<usize>,
+	/// Wall-clock time spent in the wrapped backend.
+	pub elapsed: Duration,
+}
+
+/// Aggregated statistics of all recorded calls to one [`Operation`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationStats {
+	pub calls: usize,
+	pub elapsed: Duration,
+}
+
+/// A backend wrapper that records every call made to the wrapped backend.
+///
+/// Each call is logged with the operation, its input shape and the time spent in the wrapped
+/// backend. This is meant for profiling alternative backends on real proving workloads.
+#[derive(Debug)]
+pub struct RecordingBackend<Backend> {
+	inner: Backend,
+	records: Mutex<Vec<CallRecord>>,
+}
+
+impl<Backend: ComputationBackend> RecordingBackend<Backend> {
+	pub const fn new(inner: Backend) -> Self {
+		Self {
+			inner,
+			records: Mutex::new(Vec::new()),
+		}
+	}
+
+	/// Returns the wrapped backend.
+	pub const fn inner(&self) -> &Backend {
+		&self.inner
+	}
+
+	/// Returns a copy of the call log, in call order.
+	pub fn records(&self) -> Vec<CallRecord> {
+		self.lock_records().clone()
+	}
+
+	/// Empties the call log.
+	pub fn clear(&self) {
+		self.lock_records().clear();
+	}
+
+	/// Returns the number of calls and the total time per operation.
+	pub fn operation_stats(&self) -> BTreeMap<Operation, OperationStats> {
+		let mut stats = BTreeMap::<_, OperationStats>::new();
+		for record in self.lock_records().iter() {
+			let entry = stats.entry(record.operation).or_default();
+			entry.calls += 1;
+			entry.elapsed += record.elapsed;
+		}
+		stats
+	}
+
+	/// Writes the call log, one call per line.
+	pub fn write_call_log(&self, mut writer: impl io::Write) -> io::Result<()> {
+		let display =
+			|value: Option<usize>| value.map_or_else(|| "-".to_string(), |v| v.to_string());
+		for record in self.lock_records().iter() {
+			writeln!(
+				writer,
+				"{:?} n_vars={} n_multilinears={} degree={} elapsed={:?}",
+				record.operation,
+				record.n_vars,
+				display(record.n_multilinears),
+				display(record.degree),
+				record.elapsed,
+			)?;
+		}
+		Ok(())
+	}
+
+	fn lock_records(&self) -> std::sync::MutexGuard<'_, Vec<CallRecord>> {
+		// The log is only appended to, so it stays consistent even if a call panicked.
+		self.records.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	fn record<R>(
+		&self,
+		operation: Operation,
+		n_vars: usize,
+		n_multilinears: Option<usize>,
+		degree: Option<usize>,
+		call: impl FnOnce(&Backend) -> R,
+	) -> R {
+		let start = Instant::now();
+		let result = call(&self.inner);
+		let elapsed = start.elapsed();
+		self.lock_records().push(CallRecord {
+			operation,
+			n_vars,
+			n_multilinears,
+			degree,
+			elapsed,
+		});
+		result
+	}
+}
+
+impl<Backend: ComputationBackend> ComputationBackend for RecordingBackend<Backend> {
+	type Vec<P: Send + Sync + Debug + 'static> = Backend::Vec<P>;
+
+	fn to_hal_slice<P: Debug + Send + Sync>(v: Vec<P>) -> Self::Vec<P> {
+		Backend::to_hal_slice(v)
+	}
+
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, Error> {
+		self.record(Operation::TensorProductFullQuery, query.len(), None, None, |backend| {
+			backend.tensor_product_full_query(query)
+		})
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		const_eval_suffix: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		evaluators: &[Evaluator],
+		nontrivial_evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPoly<P>,
+	{
+		let degree = evaluators
+			.iter()
+			.map(|evaluator| evaluator.composition().degree())
+			.max();
+		self.record(
+			Operation::SumcheckComputeRoundEvals,
+			n_vars,
+			Some(multilinears.len()),
+			degree,
+			|backend| {
+				backend.sumcheck_compute_round_evals(
+					evaluation_order,
+					n_vars,
+					const_eval_suffix,
+					tensor_query,
+					multilinears,
+					evaluators,
+					nontrivial_evaluation_points,
+				)
+			},
+		)
+	}
+
+	fn sumcheck_fold_multilinears<P, M>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		multilinears: &mut [SumcheckMultilinear<P, M>],
+		challenge: P::Scalar,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+	) -> Result<bool, Error>
+	where
+		P: PackedField,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		let n_multilinears = multilinears.len();
+		self.record(
+			Operation::SumcheckFoldMultilinears,
+			n_vars,
+			Some(n_multilinears),
+			None,
+			|backend| {
+				backend.sumcheck_fold_multilinears(
+					evaluation_order,
+					n_vars,
+					multilinears,
+					challenge,
+					tensor_query,
+				)
+			},
+		)
+	}
+
+	fn evaluate_partial_high<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.record(
+			Operation::EvaluatePartialHigh,
+			multilinear.n_vars(),
+			Some(1),
+			None,
+			|backend| backend.evaluate_partial_high(multilinear, query_expansion),
+		)
+	}
+
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync,
+	{
+		self.record(
+			Operation::ReedSolomonEncodeBatch,
+			log_dim + log_inv_rate,
+			Some(1 << log_batch_size),
+			None,
+			|backend| {
+				backend.reed_solomon_encode_batch(ntt, log_dim, log_inv_rate, log_batch_size, code)
+			},
+		)
+	}
+
+	fn merkle_hash_leaves<F, H>(
+		&self,
+		elements: &[F],
+		digests: &mut [MaybeUninit<Output<H::Digest>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	{
+		self.record(
+			Operation::MerkleHashLeaves,
+			log2_ceil_usize(digests.len()),
+			None,
+			None,
+			|backend| backend.merkle_hash_leaves::<F, H>(elements, digests),
+		)
+	}
+
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [MaybeUninit<D>],
+	) -> Result<(), Error>
+	where
+		D: Clone + PartialEq + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		self.record(
+			Operation::MerkleCompressLayer,
+			log2_ceil_usize(prev_layer.len()),
+			None,
+			None,
+			|backend| backend.merkle_compress_layer(compression, prev_layer, next_layer),
+		)
+	}
+
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		self.record(
+			Operation::FriFoldCodeword,
+			log2_ceil_usize(codeword.len()),
+			None,
+			None,
+			|backend| backend.fri_fold_codeword(ntt, codeword, start_round, folding_challenges),
+		)
+	}
+
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		challenges: &[F],
+		log_batch_size: usize,
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		self.record(
+			Operation::FriFoldInterleaved,
+			log2_ceil_usize(codeword.len()).saturating_sub(log_batch_size),
+			Some(1 << log_batch_size),
+			None,
+			|backend| backend.fri_fold_interleaved(ntt, codeword, challenges, log_batch_size),
+		)
+	}
+
+	fn gkr_product_layer<P: PackedField>(
+		&self,
+		n_vars: usize,
+		layer_below: &[P],
+	) -> Result<Vec<P>, Error> {
+		self.record(Operation::GkrProductLayer, n_vars, None, None, |backend| {
+			backend.gkr_product_layer(n_vars, layer_below)
+		})
+	}
+
+	fn tensor_algebra_fold_vertical<F, FE>(
+		&self,
+		vertical_elems: &[FE],
+		coeffs: &[FE],
+	) -> Result<FE, Error>
+	where
+		F: Field,
+		FE: ExtensionField<F> + PackedExtension<F>,
+	{
+		self.record(Operation::TensorAlgebraFoldVertical, FE::LOG_DEGREE, None, None, |backend| {
+			backend.tensor_algebra_fold_vertical::<F, FE>(vertical_elems, coeffs)
+		})
+	}
+}
+
+/// A backend that runs every operation on two backends and asserts that the results agree.
+///
+/// The results of the `primary` backend are returned, the `reference` backend (typically
+/// [`crate::CpuBackend`]) only serves for comparison. Any mismatch panics, which makes it possible
+/// to check an alternative backend against the reference on full proving runs.
+#[derive(Debug)]
+pub struct DifferentialBackend<Primary, Reference> {
+	primary: Primary,
+	reference: Reference,
+}
+
+impl<Primary, Reference> DifferentialBackend<Primary, Reference>
+where
+	Primary: ComputationBackend,
+	Reference: ComputationBackend,
+{
+	pub const fn new(primary: Primary, reference: Reference) -> Self {
+		Self { primary, reference }
+	}
+
+	pub const fn primary(&self) -> &Primary {
+		&self.primary
+	}
+
+	pub const fn reference(&self) -> &Reference {
+		&self.reference
+	}
+}
+
+impl<Primary, Reference> ComputationBackend for DifferentialBackend<Primary, Reference>
+where
+	Primary: ComputationBackend,
+	Reference: ComputationBackend,
+{
+	type Vec<P: Send + Sync + Debug + 'static> = Primary::Vec<P>;
+
+	fn to_hal_slice<P: Debug + Send + Sync>(v: Vec<P>) -> Self::Vec<P> {
+		Primary::to_hal_slice(v)
+	}
+
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, Error> {
+		let result = self.primary.tensor_product_full_query::<P>(query)?;
+		let expected = self.reference.tensor_product_full_query::<P>(query)?;
+		assert_eq!(*result, *expected, "tensor_product_full_query mismatch");
+		Ok(result)
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		const_eval_suffix: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		evaluators: &[Evaluator],
+		nontrivial_evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPoly<P>,
+	{
+		let result = self.primary.sumcheck_compute_round_evals(
+			evaluation_order,
+			n_vars,
+			const_eval_suffix,
+			tensor_query,
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+		)?;
+		let expected = self.reference.sumcheck_compute_round_evals(
+			evaluation_order,
+			n_vars,
+			const_eval_suffix,
+			tensor_query,
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+		)?;
+		assert!(
+			result.len() == expected.len()
+				&& result
+					.iter()
+					.zip(&expected)
+					.all(|(result, expected)| result.0 == expected.0),
+			"sumcheck_compute_round_evals mismatch: {result:?} != {expected:?}"
+		);
+		Ok(result)
+	}
+
+	fn sumcheck_fold_multilinears<P, M>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		multilinears: &mut [SumcheckMultilinear<P, M>],
+		challenge: P::Scalar,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+	) -> Result<bool, Error>
+	where
+		P: PackedField,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		// The reference backend folds a copy of the multilinears which borrows the transparent
+		// ones, so the folded evaluations are extracted before the primary backend mutates them.
+		let mut reference_multilinears = multilinears
+			.iter()
+			.map(|multilinear| match multilinear {
+				SumcheckMultilinear::Transparent {
+					multilinear,
+					switchover_round,
+					zero_scalars_suffix,
+				} => SumcheckMultilinear::Transparent {
+					multilinear,
+					switchover_round: *switchover_round,
+					zero_scalars_suffix: *zero_scalars_suffix,
+				},
+				SumcheckMultilinear::Folded {
+					large_field_folded_evals,
+				} => SumcheckMultilinear::Folded {
+					large_field_folded_evals: large_field_folded_evals.clone(),
+				},
+			})
+			.collect::<Vec<_>>();
+		let expected = self.reference.sumcheck_fold_multilinears(
+			evaluation_order,
+			n_vars,
+			&mut reference_multilinears,
+			challenge,
+			tensor_query,
+		)?;
+		let expected_folded = reference_multilinears
+			.into_iter()
+			.map(|multilinear| match multilinear {
+				SumcheckMultilinear::Transparent { .. } => None,
+				SumcheckMultilinear::Folded {
+					large_field_folded_evals,
+				} => Some(large_field_folded_evals),
+			})
+			.collect::<Vec<_>>();
+
+		let result = self.primary.sumcheck_fold_multilinears(
+			evaluation_order,
+			n_vars,
+			multilinears,
+			challenge,
+			tensor_query,
+		)?;
+		assert_eq!(result, expected, "sumcheck_fold_multilinears return value mismatch");
+		for (i, (multilinear, expected_folded)) in
+			multilinears.iter().zip(expected_folded).enumerate()
+		{
+			match (multilinear, expected_folded) {
+				(SumcheckMultilinear::Transparent { .. }, None) => {}
+				(
+					SumcheckMultilinear::Folded {
+						large_field_folded_evals,
+					},
+					Some(expected_evals),
+				) => assert_eq!(
+					*large_field_folded_evals, expected_evals,
+					"sumcheck_fold_multilinears mismatch in multilinear {i}"
+				),
+				_ => panic!("sumcheck_fold_multilinears switchover mismatch in multilinear {i}"),
+			}
+		}
+		Ok(result)
+	}
+
+	fn evaluate_partial_high<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		let result = self
+			.primary
+			.evaluate_partial_high(multilinear, query_expansion)?;
+		let expected = self
+			.reference
+			.evaluate_partial_high(multilinear, query_expansion)?;
+		assert_eq!(result.evals(), expected.evals(), "evaluate_partial_high mismatch");
+		Ok(result)
+	}
+
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync,
+	{
+		let mut expected = code.to_vec();
+		self.reference.reed_solomon_encode_batch(
+			ntt,
+			log_dim,
+			log_inv_rate,
+			log_batch_size,
+			&mut expected,
+		)?;
+		self.primary
+			.reed_solomon_encode_batch(ntt, log_dim, log_inv_rate, log_batch_size, code)?;
+		assert_eq!(code, expected.as_slice(), "reed_solomon_encode_batch mismatch");
+		Ok(())
+	}
+
+	fn merkle_hash_leaves<F, H>(
+		&self,
+		elements: &[F],
+		digests: &mut [MaybeUninit<Output<H::Digest>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+	{
+		let mut expected = Vec::with_capacity(digests.len());
+		expected.resize_with(digests.len(), MaybeUninit::uninit);
+		self.reference
+			.merkle_hash_leaves::<F, H>(elements, &mut expected)?;
+		self.primary.merkle_hash_leaves::<F, H>(elements, digests)?;
+		for (i, (digest, expected)) in digests.iter().zip(&expected).enumerate() {
+			// SAFETY: both backends returned successfully, so all digests are initialized
+			let (digest, expected) =
+				unsafe { (digest.assume_init_ref(), expected.assume_init_ref()) };
+			assert_eq!(digest, expected, "merkle_hash_leaves mismatch at leaf {i}");
+		}
+		Ok(())
+	}
+
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [MaybeUninit<D>],
+	) -> Result<(), Error>
+	where
+		D: Clone + PartialEq + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		let mut expected = Vec::with_capacity(next_layer.len());
+		expected.resize_with(next_layer.len(), MaybeUninit::uninit);
+		self.reference
+			.merkle_compress_layer(compression, prev_layer, &mut expected)?;
+		self.primary
+			.merkle_compress_layer(compression, prev_layer, next_layer)?;
+		for (i, (digest, expected)) in next_layer.iter().zip(&expected).enumerate() {
+			// SAFETY: both backends returned successfully, so all digests are initialized
+			let (digest, expected) =
+				unsafe { (digest.assume_init_ref(), expected.assume_init_ref()) };
+			assert!(digest == expected, "merkle_compress_layer mismatch at node {i}");
+		}
+		Ok(())
+	}
+
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		let result =
+			self.primary
+				.fri_fold_codeword(ntt, codeword, start_round, folding_challenges)?;
+		let expected =
+			self.reference
+				.fri_fold_codeword(ntt, codeword, start_round, folding_challenges)?;
+		assert_eq!(result, expected, "fri_fold_codeword mismatch");
+		Ok(result)
+	}
+
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		challenges: &[F],
+		log_batch_size: usize,
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		let result =
+			self.primary
+				.fri_fold_interleaved(ntt, codeword, challenges, log_batch_size)?;
+		let expected =
+			self.reference
+				.fri_fold_interleaved(ntt, codeword, challenges, log_batch_size)?;
+		assert_eq!(result, expected, "fri_fold_interleaved mismatch");
+		Ok(result)
+	}
+
+	fn gkr_product_layer<P: PackedField>(
+		&self,
+		n_vars: usize,
+		layer_below: &[P],
+	) -> Result<Vec<P>, Error> {
+		let result = self.primary.gkr_product_layer(n_vars, layer_below)?;
+		let expected = self.reference.gkr_product_layer(n_vars, layer_below)?;
+		assert_eq!(result, expected, "gkr_product_layer mismatch");
+		Ok(result)
+	}
+
+	fn tensor_algebra_fold_vertical<F, FE>(
+		&self,
+		vertical_elems: &[FE],
+		coeffs: &[FE],
+	) -> Result<FE, Error>
+	where
+		F: Field,
+		FE: ExtensionField<F> + PackedExtension<F>,
+	{
+		let result = self
+			.primary
+			.tensor_algebra_fold_vertical::<F, FE>(vertical_elems, coeffs)?;
+		let expected = self
+			.reference
+			.tensor_algebra_fold_vertical::<F, FE>(vertical_elems, coeffs)?;
+		assert_eq!(result, expected, "tensor_algebra_fold_vertical mismatch");
+		Ok(result)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+	use digest::Digest;
+
+	use super::*;
+	use crate::{make_portable_backend, CpuBackend};
+
+	/// Delegates to [`CpuBackend`], except that Merkle layer compression swaps the digests of
+	/// each pair.
+	#[derive(Debug)]
+	struct FaultyMerkleBackend;
+
+	impl ComputationBackend for FaultyMerkleBackend {
+		type Vec<P: Send + Sync + Debug + 'static> = Vec<P>;
+
+		fn to_hal_slice<P: Debug + Send + Sync + 'static>(v: Vec<P>) -> Self::Vec<P> {
+			v
+		}
+
+		fn tensor_product_full_query<P: PackedField>(
+			&self,
+			query: &[P::Scalar],
+		) -> Result<Self::Vec<P>, Error> {
+			CpuBackend.tensor_product_full_query(query)
+		}
+
+		fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+			&self,
+			evaluation_order: EvaluationOrder,
+			n_vars: usize,
+			const_eval_suffix: usize,
+			tensor_query: Option<MultilinearQueryRef<P>>,
+			multilinears: &[SumcheckMultilinear<P, M>],
+			evaluators: &[Evaluator],
+			nontrivial_evaluation_points: &[FDomain],
+		) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+		where
+			FDomain: Field,
+			P: PackedExtension<FDomain>,
+			M: MultilinearPoly<P> + Send + Sync,
+			Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+			Composition: CompositionPoly<P>,
+		{
+			CpuBackend.sumcheck_compute_round_evals(
+				evaluation_order,
+				n_vars,
+				const_eval_suffix,
+				tensor_query,
+				multilinears,
+				evaluators,
+				nontrivial_evaluation_points,
+			)
+		}
+
+		fn sumcheck_fold_multilinears<P, M>(
+			&self,
+			evaluation_order: EvaluationOrder,
+			n_vars: usize,
+			multilinears: &mut [SumcheckMultilinear<P, M>],
+			challenge: P::Scalar,
+			tensor_query: Option<MultilinearQueryRef<P>>,
+		) -> Result<bool, Error>
+		where
+			P: PackedField,
+			M: MultilinearPoly<P> + Send + Sync,
+		{
+			CpuBackend.sumcheck_fold_multilinears(
+				evaluation_order,
+				n_vars,
+				multilinears,
+				challenge,
+				tensor_query,
+			)
+		}
+
+		fn evaluate_partial_high<P: PackedField>(
+			&self,
+			multilinear: &impl MultilinearPoly<P>,
+			query_expansion: MultilinearQueryRef<P>,
+		) -> Result<MultilinearExtension<P>, Error> {
+			CpuBackend.evaluate_partial_high(multilinear, query_expansion)
+		}
+
+		fn reed_solomon_encode_batch<F, P, NTT>(
+			&self,
+			ntt: &NTT,
+			log_dim: usize,
+			log_inv_rate: usize,
+			log_batch_size: usize,
+			code: &mut [P],
+		) -> Result<(), Error>
+		where
+			F: BinaryField,
+			P: PackedField<Scalar = F>,
+			NTT: AdditiveNTT<F> + Sync,
+		{
+			CpuBackend.reed_solomon_encode_batch(ntt, log_dim, log_inv_rate, log_batch_size, code)
+		}
+
+		fn merkle_hash_leaves<F, H>(
+			&self,
+			elements: &[F],
+			digests: &mut [MaybeUninit<Output<H::Digest>>],
+		) -> Result<(), Error>
+		where
+			F: TowerField,
+			H: ParallelDigest<Digest: BlockSizeUser + FixedOutputReset>,
+		{
+			CpuBackend.merkle_hash_leaves::<F, H>(elements, digests)
+		}
+
+		fn merkle_compress_layer<D, C>(
+			&self,
+			compression: &C,
+			prev_layer: &[D],
+			next_layer: &mut [MaybeUninit<D>],
+		) -> Result<(), Error>
+		where
+			D: Clone + PartialEq + Send + Sync,
+			C: PseudoCompressionFunction<D, 2> + Sync,
+		{
+			for (pair, node) in prev_layer.chunks_exact(2).zip(next_layer) {
+				node.write(compression.compress([pair[1].clone(), pair[0].clone()]));
+			}
+			Ok(())
+		}
+
+		fn fri_fold_codeword<F, FS, NTT>(
+			&self,
+			ntt: &NTT,
+			codeword: &[F],
+			start_round: usize,
+			folding_challenges: &[F],
+		) -> Result<Vec<F>, Error>
+		where
+			F: BinaryField + ExtensionField<FS>,
+			FS: BinaryField,
+			NTT: AdditiveNTT<FS> + Sync,
+		{
+			CpuBackend.fri_fold_codeword(ntt, codeword, start_round, folding_challenges)
+		}
+
+		fn fri_fold_interleaved<F, FS, NTT>(
+			&self,
+			ntt: &NTT,
+			codeword: &[F],
+			challenges: &[F],
+			log_batch_size: usize,
+		) -> Result<Vec<F>, Error>
+		where
+			F: BinaryField + ExtensionField<FS>,
+			FS: BinaryField,
+			NTT: AdditiveNTT<FS> + Sync,
+		{
+			CpuBackend.fri_fold_interleaved(ntt, codeword, challenges, log_batch_size)
+		}
+
+		fn gkr_product_layer<P: PackedField>(
+			&self,
+			n_vars: usize,
+			layer_below: &[P],
+		) -> Result<Vec<P>, Error> {
+			CpuBackend.gkr_product_layer(n_vars, layer_below)
+		}
+
+		fn tensor_algebra_fold_vertical<F, FE>(
+			&self,
+			vertical_elems: &[FE],
+			coeffs: &[FE],
+		) -> Result<FE, Error>
+		where
+			F: Field,
+			FE: ExtensionField<F> + PackedExtension<F>,
+		{
+			CpuBackend.tensor_algebra_fold_vertical::<F, FE>(vertical_elems, coeffs)
+		}
+	}
+
+	fn compress_layer(backend: &impl ComputationBackend) {
+		let layer = (0..8u8)
+			.map(|i| <Groestl256 as Digest>::digest([i]))
+			.collect::<Vec<_>>();
+		let mut next_layer = vec![MaybeUninit::uninit(); layer.len() / 2];
+		backend
+			.merkle_compress_layer(&Groestl256ByteCompression, &layer, &mut next_layer)
+			.unwrap();
+	}
+
+	#[test]
+	fn test_differential_merkle_compress_layer() {
+		compress_layer(&DifferentialBackend::new(make_portable_backend(), make_portable_backend()));
+	}
+
+	#[test]
+	#[should_panic(expected = "merkle_compress_layer mismatch")]
+	fn test_differential_merkle_compress_layer_detects_mismatch() {
+		compress_layer(&DifferentialBackend::new(FaultyMerkleBackend, make_portable_backend()));
+	}
+}
@@ -0,0 +1,54 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{BinaryField, PackedField};
+use binius_maybe_rayon::prelude::*;
+use binius_ntt::AdditiveNTT;
+use binius_utils::bail;
+
+use crate::Error;
+
+/// Encodes a batch of interleaved messages in-place with the additive NTT.
+///
+/// The first `2^(log_dim + log_batch_size)` scalars of `code` hold the interleaved messages. Each
+/// of the `2^log_inv_rate` cosets of the evaluation domain is encoded independently from a copy
+/// of the messages.
+pub(crate) fn reed_solomon_encode_batch<F, P, NTT>(
+	ntt: &NTT,
+	log_dim: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	code: &mut [P],
+) -> Result<(), Error>
+where
+	F: BinaryField,
+	P: PackedField<Scalar = F>,
+	NTT: AdditiveNTT<F> + Sync,
+{
+	let _scope = tracing::trace_span!(
+		"Reed–Solomon encode",
+		log_len = log_dim + log_inv_rate,
+		log_batch_size = log_batch_size,
+		symbol_bits = F::N_BITS,
+	)
+	.entered();
+
+	if log_dim < P::LOG_WIDTH {
+		bail!(binius_ntt::Error::PackingWidthMustDivideDimension);
+	}
+	let msgs_len = 1 << (log_dim + log_batch_size - P::LOG_WIDTH);
+	if code.len() < msgs_len << log_inv_rate {
+		bail!(binius_ntt::Error::BufferTooSmall {
+			log_code_len: log_dim + log_inv_rate,
+		});
+	}
+
+	for i in 1..(1 << log_inv_rate) {
+		code.copy_within(0..msgs_len, i * msgs_len);
+	}
+
+	(0..(1 << log_inv_rate))
+		.into_par_iter()
+		.zip(code.par_chunks_exact_mut(msgs_len))
+		.try_for_each(|(i, data)| ntt.forward_transform(data, i, log_batch_size, log_dim))?;
+	Ok(())
+}
@@ -165,16 +165,37 @@ where
 		bail!(Error::IncorrectNontrivialEvalPointsLength);
 	}
 
+	// Compositions that are zero whenever some of their multilinears are zero contribute nothing
+	// on subcubes where those multilinears are zero, like padding rows or rows with an unset
+	// selector. Such subcubes are skipped, which leaves the round evaluations unchanged.
+	let expressions = evaluators
+		.iter()
+		.map(|evaluator| evaluator.composition().expression())
+		.collect::<Vec<_>>();
+	let skip_zero_subcubes = expressions
+		.iter()
+		.any(|expression| expression.vanishes_when_zero(&vec![true; n_multilinears]));
+
 	let index_vars = n_vars - 1 - subcube_vars;
 	let subcube_count = ((1 << (n_vars - 1)) - const_eval_suffix).div_ceil(1 << subcube_vars);
 	let packed_accumulators = (0..subcube_count)
 		.into_par_iter()
 		.try_fold(
-			|| ParFoldStates::new(access, n_multilinears, n_round_evals.clone(), subcube_vars),
+			|| {
+				ParFoldStates::new(
+					access,
+					n_multilinears,
+					evaluators.len(),
+					n_round_evals.clone(),
+					subcube_vars,
+				)
+			},
 			|mut par_fold_states, subcube_index| {
 				let ParFoldStates {
 					multilinear_evals,
 					scratch_space,
+					zero_multilinears,
+					active_evaluators,
 					round_evals,
 				} = &mut par_fold_states;
 
@@ -190,6 +211,20 @@ where
 					)?;
 				}
 
+				if skip_zero_subcubes {
+					for (is_zero, evals) in izip!(zero_multilinears.iter_mut(), &*multilinear_evals)
+					{
+						*is_zero = evals.is_zero();
+					}
+					for (is_active, expression) in izip!(active_evaluators.iter_mut(), &expressions)
+					{
+						*is_active = !expression.vanishes_when_zero(zero_multilinears);
+					}
+					if !active_evaluators.contains(&true) {
+						return Ok(par_fold_states);
+					}
+				}
+
 				// Proceed by evaluation point first to share interpolation work between evaluators.
 				for eval_point_index in eval_point_indices.clone() {
 					// Infinity point requires special evaluation rules
@@ -243,11 +278,11 @@ where
 							});
 
 					stackalloc_with_iter(n_multilinears, evals_z_iter, |evals_z| {
-						for (evaluator, round_evals) in
-							iter::zip(evaluators, round_evals.iter_mut())
+						for (evaluator, round_evals, &is_active) in
+							izip!(evaluators, round_evals.iter_mut(), &*active_evaluators)
 						{
 							let eval_point_indices = evaluator.eval_point_indices();
-							if !eval_point_indices.contains(&eval_point_index) {
+							if !is_active || !eval_point_indices.contains(&eval_point_index) {
 								continue;
 							}
 
@@ -330,6 +365,15 @@ impl<P: PackedField> MultilinearEvals<P> {
 			evals_z: zeroed_vec(len),
 		}
 	}
+
+	// Whether the multilinear is zero on the subcube, and thus at every interpolated point too.
+	fn is_zero(&self) -> bool {
+		let zero = P::zero();
+		self.evals_0
+			.iter()
+			.chain(&self.evals_1)
+			.all(|&eval| eval == zero)
+	}
 }
 
 /// Parallel fold state, consisting of scratch area and result accumulator.
@@ -341,6 +385,12 @@ struct ParFoldStates<P: PackedField> {
 	// Additional scratch space.
 	scratch_space: Option<Vec<P>>,
 
+	// Whether each multilinear is zero on the current subcube.
+	zero_multilinears: Vec<bool>,
+
+	// Whether each evaluator may have a nonzero contribution on the current subcube.
+	active_evaluators: Vec<bool>,
+
 	// Accumulated sums of evaluations over univariate domain.
 	//
 	// Each element of the outer vector corresponds to one composite polynomial. Each element of
@@ -352,6 +402,7 @@ impl<P: PackedField> ParFoldStates<P> {
 	fn new(
 		access: &impl SumcheckMultilinearAccess<P>,
 		n_multilinears: usize,
+		n_evaluators: usize,
 		n_round_evals: impl Iterator<Item = usize>,
 		subcube_vars: usize,
 	) -> Self {
@@ -362,6 +413,8 @@ impl<P: PackedField> ParFoldStates<P> {
 			scratch_space: access
 				.scratch_space_len(subcube_vars)
 				.map(|len| zeroed_vec(len)),
+			zero_multilinears: vec![false; n_multilinears],
+			active_evaluators: vec![true; n_evaluators],
 			round_evals: n_round_evals
 				.map(|n_round_evals| zeroed_vec(n_round_evals))
 				.collect(),
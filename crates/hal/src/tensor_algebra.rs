@@ -0,0 +1,38 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{
+	square_transpose, util::inner_product_unchecked, ExtensionField, Field, PackedExtension,
+};
+use binius_utils::bail;
+
+use crate::Error;
+
+/// Folds a tensor algebra element, given by its vertical subring elements, into a field element by
+/// scaling the rows with `coeffs` and accumulating.
+///
+/// Only the first `FE::DEGREE` coefficients are used.
+pub(crate) fn tensor_algebra_fold_vertical<F, FE>(
+	vertical_elems: &[FE],
+	coeffs: &[FE],
+) -> Result<FE, Error>
+where
+	F: Field,
+	FE: ExtensionField<F> + PackedExtension<F>,
+{
+	if vertical_elems.len() != FE::DEGREE {
+		bail!(Error::IncorrectInputSliceLength {
+			expected: FE::DEGREE
+		});
+	}
+	if coeffs.len() < FE::DEGREE {
+		bail!(Error::IncorrectQuerySize {
+			expected: FE::DEGREE
+		});
+	}
+
+	// Transposing swaps the vertical and horizontal subrings, so the rows become the elements.
+	let mut elems = vertical_elems.to_vec();
+	square_transpose(FE::LOG_DEGREE, FE::cast_bases_mut(&mut elems))
+		.expect("transpose dimensions are square by the length check");
+	Ok(inner_product_unchecked::<FE, _>(elems, coeffs[..FE::DEGREE].iter().copied()))
+}
@@ -3,8 +3,17 @@
 use std::{array, mem::MaybeUninit};
 
 use binius_hash::{
-	groestl::Groestl256, multi_digest::MultiDigest, VisionHasherDigest,
-	VisionHasherDigestByteSliced,
+	blake3::{Blake3MultiDigest, BLAKE3_PARALLEL_LANES},
+	groestl::{
+		Groestl256, Groestl256ByteSliced16x, Groestl256ByteSliced32x, Groestl256ByteSliced64x,
+		Groestl256MultiDigest,
+	},
+	keccak::{Keccak256MultiDigest, KECCAK256_PARALLEL_LANES},
+	multi_digest::MultiDigest,
+	poseidon2::{
+		Poseidon2HasherDigest, Poseidon2HasherDigestPacked, POSEIDON2_HASHES_PER_PACKED_PERMUTATION,
+	},
+	VisionHasherDigest, VisionHasherDigestByteSliced,
 };
 use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 use digest::Digest;
@@ -27,6 +36,26 @@ fn bench_groestl(c: &mut Criterion) {
 		bench.iter(|| groestl_crypto::Groestl256::digest(data));
 	});
 
+	fn bench_multi<D: MultiDigest<LANES, Digest = Groestl256>, const LANES: usize>(
+		group: &mut criterion::BenchmarkGroup<criterion::measurement::WallTime>,
+		name: &str,
+		data: &[u8; N],
+	) {
+		group.bench_function(format!("Groestl256-{name}{LANES}"), |bench| {
+			bench.iter(|| {
+				let mut out = [MaybeUninit::<digest::Output<Groestl256>>::uninit(); LANES];
+				D::digest(array::from_fn(|i| &data[i * N / LANES..(i + 1) * N / LANES]), &mut out);
+
+				out
+			})
+		});
+	}
+	bench_multi::<Groestl256MultiDigest<4>, 4>(&mut group, "Interleaved", &data);
+	bench_multi::<Groestl256MultiDigest<8>, 8>(&mut group, "Interleaved", &data);
+	bench_multi::<Groestl256ByteSliced16x, 16>(&mut group, "ByteSliced", &data);
+	bench_multi::<Groestl256ByteSliced32x, 32>(&mut group, "ByteSliced", &data);
+	bench_multi::<Groestl256ByteSliced64x, 64>(&mut group, "ByteSliced", &data);
+
 	group.finish()
 }
 
@@ -57,5 +86,98 @@ fn bench_vision32(c: &mut Criterion) {
 	group.finish()
 }
 
-criterion_group!(hash, bench_groestl, bench_vision32);
+fn bench_poseidon2(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Poseidon2-b32");
+
+	let mut rng = thread_rng();
+
+	const N: usize = 1 << 16;
+	let mut data = [0u8; N];
+	rng.fill_bytes(&mut data);
+
+	group.throughput(Throughput::Bytes(N as u64));
+	group.bench_function("Poseidon2-Single", |bench| {
+		bench.iter(|| Poseidon2HasherDigest::digest(data))
+	});
+
+	const LANES: usize = POSEIDON2_HASHES_PER_PACKED_PERMUTATION;
+	group.bench_function("Poseidon2-Parallel16", |bench| {
+		bench.iter(|| {
+			let mut out = [MaybeUninit::<digest::Output<Poseidon2HasherDigest>>::uninit(); LANES];
+			Poseidon2HasherDigestPacked::digest(
+				array::from_fn(|i| &data[i * N / LANES..(i + 1) * N / LANES]),
+				&mut out,
+			);
+
+			out
+		})
+	});
+
+	group.finish()
+}
+
+fn bench_keccak256(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Keccak-256");
+
+	let mut rng = thread_rng();
+
+	const N: usize = 1 << 16;
+	let mut data = [0u8; N];
+	rng.fill_bytes(&mut data);
+
+	group.throughput(Throughput::Bytes(N as u64));
+	group.bench_function("Keccak256-Single", |bench| bench.iter(|| sha3::Keccak256::digest(data)));
+
+	const LANES: usize = KECCAK256_PARALLEL_LANES;
+	group.bench_function("Keccak256-Parallel4", |bench| {
+		bench.iter(|| {
+			let mut out = [MaybeUninit::<digest::Output<sha3::Keccak256>>::uninit(); LANES];
+			Keccak256MultiDigest::<LANES>::digest(
+				array::from_fn(|i| &data[i * N / LANES..(i + 1) * N / LANES]),
+				&mut out,
+			);
+
+			out
+		})
+	});
+
+	group.finish()
+}
+
+fn bench_blake3(c: &mut Criterion) {
+	let mut group = c.benchmark_group("BLAKE3");
+
+	let mut rng = thread_rng();
+
+	const N: usize = 1 << 16;
+	let mut data = [0u8; N];
+	rng.fill_bytes(&mut data);
+
+	group.throughput(Throughput::Bytes(N as u64));
+	group.bench_function("BLAKE3-Single", |bench| bench.iter(|| blake3::hash(&data)));
+
+	const LANES: usize = BLAKE3_PARALLEL_LANES;
+	group.bench_function("BLAKE3-Parallel8", |bench| {
+		bench.iter(|| {
+			let mut out = [MaybeUninit::<digest::Output<blake3::Hasher>>::uninit(); LANES];
+			Blake3MultiDigest::<LANES>::digest(
+				array::from_fn(|i| &data[i * N / LANES..(i + 1) * N / LANES]),
+				&mut out,
+			);
+
+			out
+		})
+	});
+
+	group.finish()
+}
+
+criterion_group!(
+	hash,
+	bench_groestl,
+	bench_vision32,
+	bench_poseidon2,
+	bench_keccak256,
+	bench_blake3
+);
 criterion_main!(hash);
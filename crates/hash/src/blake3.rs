@@ -0,0 +1,449 @@
+// Copyright 2025 Irreducible Inc.
+
+//! BLAKE3 compression and a multi-lane BLAKE3 digest.
+//!
+//! BLAKE3 is one of the fastest cryptographic hash functions on modern CPUs, which makes it a good
+//! choice for proofs that are verified natively.
+
+use std::{array, mem::MaybeUninit};
+
+use blake3::Hasher as Blake3;
+use digest::Output;
+use stackalloc::helpers::slice_assume_init_mut;
+
+use crate::{
+	multi_digest::{MultiDigest, ParallelMulidigestImpl},
+	CompressionFunction, PseudoCompressionFunction,
+};
+
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+
+const IV: [u32; 8] = [
+	0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+
+const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// The number of hashes computed in parallel by [`Blake3ParallelDigest`].
+pub const BLAKE3_PARALLEL_LANES: usize = 8;
+
+/// A two-to-one compression function for BLAKE3 digests.
+///
+/// The compression is `BLAKE3(left || right)`. The 64-byte input fits into a single BLAKE3 block,
+/// so this costs exactly one invocation of the BLAKE3 compression function.
+#[derive(Debug, Default, Clone)]
+pub struct Blake3Compression;
+
+impl PseudoCompressionFunction<Output<Blake3>, 2> for Blake3Compression {
+	fn compress(&self, input: [Output<Blake3>; 2]) -> Output<Blake3> {
+		let mut hasher = Blake3::new();
+		hasher.update(input[0].as_slice());
+		hasher.update(input[1].as_slice());
+		(*hasher.finalize().as_bytes()).into()
+	}
+}
+
+impl CompressionFunction<Output<Blake3>, 2> for Blake3Compression {}
+
+/// `N` interleaved 32-bit words, one per hash instance.
+type Words<const N: usize> = [u32; N];
+
+/// Computes `N` BLAKE3 hashes of equal-length messages at once.
+///
+/// Since all messages have the same length, the BLAKE3 chunk and tree structure is identical
+/// across instances, and only the message words differ. The state is stored lane-interleaved so
+/// that the compression function is a straight-line computation over `N` independent values that
+/// the compiler lowers to SIMD instructions.
+#[derive(Clone)]
+pub struct Blake3MultiDigest<const N: usize> {
+	/// Chaining value of the current chunk.
+	chunk_cv: [Words<N>; 8],
+	/// Index of the current chunk.
+	chunk_counter: u64,
+	/// Number of blocks of the current chunk that have been compressed.
+	blocks_compressed: usize,
+	/// The current, possibly partial, block of each instance.
+	block: [[u8; BLOCK_LEN]; N],
+	block_len: usize,
+	/// Chaining values of the completed subtrees.
+	cv_stack: Vec<[Words<N>; 8]>,
+}
+
+impl<const N: usize> Default for Blake3MultiDigest<N> {
+	fn default() -> Self {
+		Self {
+			chunk_cv: IV.map(|word| [word; N]),
+			chunk_counter: 0,
+			blocks_compressed: 0,
+			block: [[0; BLOCK_LEN]; N],
+			block_len: 0,
+			cv_stack: Vec::new(),
+		}
+	}
+}
+
+impl<const N: usize> Blake3MultiDigest<N> {
+	fn chunk_len(&self) -> usize {
+		self.blocks_compressed * BLOCK_LEN + self.block_len
+	}
+
+	fn start_flag(&self) -> u32 {
+		if self.blocks_compressed == 0 {
+			CHUNK_START
+		} else {
+			0
+		}
+	}
+
+	/// Compresses the full buffered block into the chunk chaining value.
+	fn compress_block(&mut self) {
+		debug_assert_eq!(self.block_len, BLOCK_LEN);
+
+		let flags = self.start_flag();
+		let block_words = block_words(&self.block);
+		self.chunk_cv = first_8_words(compress(
+			&self.chunk_cv,
+			&block_words,
+			self.chunk_counter,
+			BLOCK_LEN as u32,
+			flags,
+		));
+		self.blocks_compressed += 1;
+		self.block_len = 0;
+	}
+
+	/// Finishes the current chunk and merges its chaining value into the subtree stack.
+	fn finish_chunk(&mut self) {
+		debug_assert_eq!(self.chunk_len(), CHUNK_LEN);
+
+		let mut cv = self.chunk_output().chaining_value();
+		let mut total_chunks = self.chunk_counter + 1;
+		while total_chunks & 1 == 0 {
+			let left = self
+				.cv_stack
+				.pop()
+				.expect("stack holds one entry per 1-bit");
+			cv = parent_output(left, cv).chaining_value();
+			total_chunks >>= 1;
+		}
+		self.cv_stack.push(cv);
+
+		self.chunk_cv = IV.map(|word| [word; N]);
+		self.chunk_counter += 1;
+		self.blocks_compressed = 0;
+		self.block_len = 0;
+	}
+
+	fn chunk_output(&self) -> CompressionInput<N> {
+		let mut block = self.block;
+		for row in &mut block {
+			row[self.block_len..].fill(0);
+		}
+		let block_words = block_words(&block);
+
+		CompressionInput {
+			cv: self.chunk_cv,
+			block_words,
+			counter: self.chunk_counter,
+			block_len: self.block_len as u32,
+			flags: self.start_flag() | CHUNK_END,
+		}
+	}
+
+	fn finalize(&self, out: &mut [MaybeUninit<Output<Blake3>>; N]) {
+		let mut output = self.chunk_output();
+		for &left in self.cv_stack.iter().rev() {
+			output = parent_output(left, output.chaining_value());
+		}
+
+		let root =
+			compress(&output.cv, &output.block_words, 0, output.block_len, output.flags | ROOT);
+
+		let out: &mut [Output<Blake3>; N] = unsafe { slice_assume_init_mut(out) }
+			.try_into()
+			.expect("array has N elements");
+		for (i, word) in root[..8].iter().enumerate() {
+			for (out, lane_word) in out.iter_mut().zip(word) {
+				out[i * 4..i * 4 + 4].copy_from_slice(&lane_word.to_le_bytes());
+			}
+		}
+	}
+}
+
+impl<const N: usize> MultiDigest<N> for Blake3MultiDigest<N> {
+	type Digest = Blake3;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: [&[u8]; N]) {
+		for row in 1..N {
+			debug_assert_eq!(data[row].len(), data[0].len());
+		}
+
+		let mut offset = 0;
+		while offset < data[0].len() {
+			// A full block is only compressed once more input arrives, since the last block of a
+			// chunk must be compressed with the CHUNK_END flag.
+			if self.block_len == BLOCK_LEN {
+				if self.chunk_len() == CHUNK_LEN {
+					self.finish_chunk();
+				} else {
+					self.compress_block();
+				}
+			}
+
+			let to_copy = std::cmp::min(data[0].len() - offset, BLOCK_LEN - self.block_len);
+			for (block, row) in self.block.iter_mut().zip(&data) {
+				block[self.block_len..self.block_len + to_copy]
+					.copy_from_slice(&row[offset..offset + to_copy]);
+			}
+			self.block_len += to_copy;
+			offset += to_copy;
+		}
+	}
+
+	fn finalize_into(self, out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		self.finalize(out);
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		self.finalize(out);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		self.chunk_cv = IV.map(|word| [word; N]);
+		self.chunk_counter = 0;
+		self.blocks_compressed = 0;
+		self.block_len = 0;
+		self.cv_stack.clear();
+	}
+
+	fn digest(data: [&[u8]; N], out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		let mut digest = Self::default();
+		digest.update(data);
+		digest.finalize_into(out);
+	}
+}
+
+pub type Blake3ParallelDigest =
+	ParallelMulidigestImpl<Blake3MultiDigest<BLAKE3_PARALLEL_LANES>, BLAKE3_PARALLEL_LANES>;
+
+/// The inputs of a deferred compression call, which is either finalized as a chaining value or
+/// as the root output.
+struct CompressionInput<const N: usize> {
+	cv: [Words<N>; 8],
+	block_words: [Words<N>; 16],
+	counter: u64,
+	block_len: u32,
+	flags: u32,
+}
+
+impl<const N: usize> CompressionInput<N> {
+	fn chaining_value(&self) -> [Words<N>; 8] {
+		first_8_words(compress(
+			&self.cv,
+			&self.block_words,
+			self.counter,
+			self.block_len,
+			self.flags,
+		))
+	}
+}
+
+fn parent_output<const N: usize>(left: [Words<N>; 8], right: [Words<N>; 8]) -> CompressionInput<N> {
+	let mut block_words = [[0; N]; 16];
+	block_words[..8].copy_from_slice(&left);
+	block_words[8..].copy_from_slice(&right);
+	CompressionInput {
+		cv: IV.map(|word| [word; N]),
+		block_words,
+		counter: 0,
+		block_len: BLOCK_LEN as u32,
+		flags: PARENT,
+	}
+}
+
+fn block_words<const N: usize>(block: &[[u8; BLOCK_LEN]; N]) -> [Words<N>; 16] {
+	array::from_fn(|i| {
+		array::from_fn(|lane| {
+			u32::from_le_bytes(
+				block[lane][i * 4..i * 4 + 4]
+					.try_into()
+					.expect("chunk is 4 bytes"),
+			)
+		})
+	})
+}
+
+fn first_8_words<const N: usize>(words: [Words<N>; 16]) -> [Words<N>; 8] {
+	array::from_fn(|i| words[i])
+}
+
+#[inline(always)]
+fn add<const N: usize>(a: Words<N>, b: Words<N>) -> Words<N> {
+	array::from_fn(|i| a[i].wrapping_add(b[i]))
+}
+
+#[inline(always)]
+fn xor_rotate_right<const N: usize>(a: Words<N>, b: Words<N>, n: u32) -> Words<N> {
+	array::from_fn(|i| (a[i] ^ b[i]).rotate_right(n))
+}
+
+/// The BLAKE3 quarter-round function.
+#[inline(always)]
+#[allow(clippy::too_many_arguments)]
+fn g<const N: usize>(
+	state: &mut [Words<N>; 16],
+	a: usize,
+	b: usize,
+	c: usize,
+	d: usize,
+	mx: Words<N>,
+	my: Words<N>,
+) {
+	state[a] = add(add(state[a], state[b]), mx);
+	state[d] = xor_rotate_right(state[d], state[a], 16);
+	state[c] = add(state[c], state[d]);
+	state[b] = xor_rotate_right(state[b], state[c], 12);
+	state[a] = add(add(state[a], state[b]), my);
+	state[d] = xor_rotate_right(state[d], state[a], 8);
+	state[c] = add(state[c], state[d]);
+	state[b] = xor_rotate_right(state[b], state[c], 7);
+}
+
+#[inline(always)]
+fn round<const N: usize>(state: &mut [Words<N>; 16], m: &[Words<N>; 16]) {
+	// Mix the columns.
+	g(state, 0, 4, 8, 12, m[0], m[1]);
+	g(state, 1, 5, 9, 13, m[2], m[3]);
+	g(state, 2, 6, 10, 14, m[4], m[5]);
+	g(state, 3, 7, 11, 15, m[6], m[7]);
+	// Mix the diagonals.
+	g(state, 0, 5, 10, 15, m[8], m[9]);
+	g(state, 1, 6, 11, 12, m[10], m[11]);
+	g(state, 2, 7, 8, 13, m[12], m[13]);
+	g(state, 3, 4, 9, 14, m[14], m[15]);
+}
+
+/// The BLAKE3 compression function applied to `N` interleaved states.
+fn compress<const N: usize>(
+	cv: &[Words<N>; 8],
+	block_words: &[Words<N>; 16],
+	counter: u64,
+	block_len: u32,
+	flags: u32,
+) -> [Words<N>; 16] {
+	let mut state = [
+		cv[0],
+		cv[1],
+		cv[2],
+		cv[3],
+		cv[4],
+		cv[5],
+		cv[6],
+		cv[7],
+		[IV[0]; N],
+		[IV[1]; N],
+		[IV[2]; N],
+		[IV[3]; N],
+		[counter as u32; N],
+		[(counter >> 32) as u32; N],
+		[block_len; N],
+		[flags; N],
+	];
+
+	let mut block = *block_words;
+	for round_idx in 0..7 {
+		round(&mut state, &block);
+		if round_idx < 6 {
+			block = array::from_fn(|i| block[MSG_PERMUTATION[i]]);
+		}
+	}
+
+	for i in 0..8 {
+		state[i] = array::from_fn(|lane| state[i][lane] ^ state[i + 8][lane]);
+		state[i + 8] = array::from_fn(|lane| state[i + 8][lane] ^ cv[i][lane]);
+	}
+	state
+}
+
+#[cfg(test)]
+mod tests {
+	use digest::Digest;
+	use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+	use super::*;
+
+	fn check_multidigest_consistency<const N: usize>(chunks: &[[&[u8]; N]]) {
+		let mut scalar_digests = array::from_fn::<_, N, _>(|_| Blake3::new());
+		let mut multidigest = Blake3MultiDigest::<N>::new();
+
+		for chunk in chunks {
+			for (scalar_digest, data) in scalar_digests.iter_mut().zip(chunk.iter()) {
+				scalar_digest.update(data);
+			}
+
+			multidigest.update(*chunk);
+		}
+
+		let scalar_digests = scalar_digests.map(|d| d.finalize());
+		let mut output = array::from_fn(|_| MaybeUninit::uninit());
+		multidigest.finalize_into(&mut output);
+		let output = output.map(|x| unsafe { x.assume_init() });
+
+		assert_eq!(scalar_digests, output);
+	}
+
+	#[test]
+	fn test_multidigest_consistency() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut data = vec![[0u8; 5000]; BLAKE3_PARALLEL_LANES];
+		for row in &mut data {
+			rng.fill_bytes(row);
+		}
+
+		for len in [
+			0,
+			1,
+			BLOCK_LEN,
+			BLOCK_LEN + 1,
+			CHUNK_LEN - 1,
+			CHUNK_LEN,
+			CHUNK_LEN + 1,
+			2 * CHUNK_LEN,
+			3 * CHUNK_LEN + 7,
+			4 * CHUNK_LEN,
+			5000,
+		] {
+			check_multidigest_consistency(&[array::from_fn::<_, BLAKE3_PARALLEL_LANES, _>(|i| {
+				&data[i][..len]
+			})]);
+		}
+		check_multidigest_consistency(&[
+			array::from_fn::<_, BLAKE3_PARALLEL_LANES, _>(|i| &data[i][..5]),
+			array::from_fn(|i| &data[i][5..64]),
+			array::from_fn(|i| &data[i][64..1500]),
+			array::from_fn(|i| &data[i][1500..]),
+		]);
+	}
+
+	#[test]
+	fn test_compression_matches_concatenated_hash() {
+		let left = Output::<Blake3>::from(*blake3::hash(b"left").as_bytes());
+		let right = Output::<Blake3>::from(*blake3::hash(b"right").as_bytes());
+		let expected = Blake3::new()
+			.chain_update(left)
+			.chain_update(right)
+			.finalize();
+		assert_eq!(Blake3Compression.compress([left, right]), expected);
+	}
+}
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{arch::x86_64::*, mem::transmute_copy};
+use std::{arch::x86_64::*, array, mem::transmute_copy};
 
 use crate::groestl::GroestlShortInternal;
 
@@ -179,6 +179,33 @@ fn combined_perm_m512i(p_block: __m512i, q_block: __m512i) -> (__m512i, __m512i)
 	(q_block, p_block)
 }
 
+/// Applies the P and Q permutations to `N` pairs of blocks, interleaving all `2 * N` independent
+/// dependency chains in each round.
+#[inline]
+fn combined_perm_multi_m512i<const N: usize>(
+	p_blocks: &mut [__m512i; N],
+	q_blocks: &mut [__m512i; N],
+) {
+	for r in 0..ROUND_SIZE {
+		for (p_block, q_block) in p_blocks.iter_mut().zip(q_blocks.iter_mut()) {
+			*p_block = add_round_constants_p(*p_block, r as u8);
+			*q_block = add_round_constants_q(*q_block, r as u8);
+		}
+		for (p_block, q_block) in p_blocks.iter_mut().zip(q_blocks.iter_mut()) {
+			*p_block = sub_bytes(*p_block);
+			*q_block = sub_bytes(*q_block);
+		}
+		for (p_block, q_block) in p_blocks.iter_mut().zip(q_blocks.iter_mut()) {
+			*p_block = shift_bytes(*p_block, &SHIFT_ARRAY_P);
+			*q_block = shift_bytes(*q_block, &SHIFT_ARRAY_Q);
+		}
+		for (p_block, q_block) in p_blocks.iter_mut().zip(q_blocks.iter_mut()) {
+			*p_block = mix_bytes(*p_block);
+			*q_block = mix_bytes(*q_block);
+		}
+	}
+}
+
 impl GroestlShortInternal for GroestlShortImpl {
 	type State = __m512i;
 
@@ -211,4 +238,13 @@ impl GroestlShortInternal for GroestlShortImpl {
 		let (p, q) = combined_perm_m512i(p, q);
 		Self::xor_state(h, &xor_blocks(p, q));
 	}
+
+	fn compress_multi<const N: usize>(h: &mut [Self::State; N], m: [&[u8; 64]; N]) {
+		let mut q = m.map(Self::state_from_bytes);
+		let mut p = array::from_fn(|i| xor_blocks(h[i], q[i]));
+		combined_perm_multi_m512i(&mut p, &mut q);
+		for ((h, p), q) in h.iter_mut().zip(p).zip(q) {
+			*h = xor_blocks(*h, xor_blocks(p, q));
+		}
+	}
 }
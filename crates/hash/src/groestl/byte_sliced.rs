@@ -0,0 +1,205 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Byte-sliced Grøstl-256 that hashes one message per scalar of a packed AES field.
+//!
+//! The Grøstl state is stored byte-sliced: the state is 64 packed AES field elements, one for each
+//! state byte, and lane `i` of every packed element belongs to the `i`-th message. With this
+//! layout ShiftBytes is a renaming of the state registers, and SubBytes and MixBytes become packed
+//! field inversions, linear transformations and multiplications. On x86_64 with GFNI these are
+//! single `gf2p8*` instructions, so a [`PackedAESBinaryField32x8b`] state hashes 32 messages with
+//! AVX2 registers.
+
+use std::array;
+
+use binius_field::{
+	linear_transformation::{
+		FieldLinearTransformation, PackedTransformationFactory, Transformation,
+	},
+	AESTowerField8b, PackedAESBinaryField16x8b, PackedAESBinaryField32x8b,
+	PackedAESBinaryField64x8b, PackedField, PackedFieldIndexable,
+};
+use digest::Output;
+
+use super::{
+	digest::Groestl256,
+	multi_digest::{GroestlMultiState, BLOCK_SIZE, INITIAL_STATE, OUTPUT_SIZE},
+	Groestl256ByteSliced,
+};
+
+const ROUNDS: u8 = 10;
+
+/// Column offsets of ShiftBytes in the Q permutation, indexed by row.
+///
+/// The P permutation shifts row `i` by `i` columns.
+const SHIFT_Q: [usize; 8] = [1, 3, 5, 7, 0, 2, 4, 6];
+
+/// Images of the standard basis under the linear part of the AES S-box affine map.
+const SBOX_AFFINE_BASES: [AESTowerField8b; 8] = [
+	AESTowerField8b::new(0x1F),
+	AESTowerField8b::new(0x3E),
+	AESTowerField8b::new(0x7C),
+	AESTowerField8b::new(0xF8),
+	AESTowerField8b::new(0xF1),
+	AESTowerField8b::new(0xE3),
+	AESTowerField8b::new(0xC7),
+	AESTowerField8b::new(0x8F),
+];
+const SBOX_AFFINE_CONST: AESTowerField8b = AESTowerField8b::new(0x63);
+
+/// The byte-sliced Grøstl state. Element `8 * col + row` holds the state byte in the given row and
+/// column, which matches the byte order of the serialized Grøstl state.
+type State<P> = [P; BLOCK_SIZE];
+
+/// The chaining values of `P::WIDTH` Grøstl-256 computations, byte-sliced into packed AES field
+/// elements.
+#[derive(Clone)]
+pub struct ByteSlicedGroestlState<P>(State<P>);
+
+impl<P, const N: usize> GroestlMultiState<N> for ByteSlicedGroestlState<P>
+where
+	P: PackedFieldIndexable<Scalar = AESTowerField8b> + PackedTransformationFactory<P>,
+{
+	fn new() -> Self {
+		const { assert!(N == P::WIDTH, "the number of lanes must equal the packed field width") };
+
+		Self(INITIAL_STATE.map(|byte| P::broadcast(AESTowerField8b::new(byte))))
+	}
+
+	fn compress(&mut self, blocks: [&[u8; BLOCK_SIZE]; N]) {
+		let mut q = [P::zero(); BLOCK_SIZE];
+		let scalars = P::unpack_scalars_mut(&mut q);
+		for (lane, block) in blocks.iter().enumerate() {
+			for (i, &byte) in block.iter().enumerate() {
+				scalars[i * N + lane] = AESTowerField8b::new(byte);
+			}
+		}
+
+		let sbox_affine = make_sbox_affine_transformation::<P>();
+		let mut p = array::from_fn(|i| self.0[i] + q[i]);
+		p_perm(&mut p, &sbox_affine);
+		q_perm(&mut q, &sbox_affine);
+		for ((h, p), q) in self.0.iter_mut().zip(&p).zip(&q) {
+			*h += *p + *q;
+		}
+	}
+
+	fn output(&self, out: &mut [Output<Groestl256>; N]) {
+		let mut result = self.0;
+		p_perm(&mut result, &make_sbox_affine_transformation::<P>());
+		for (result, state) in result.iter_mut().zip(&self.0) {
+			*result += *state;
+		}
+
+		let scalars = P::unpack_scalars(&result[BLOCK_SIZE - OUTPUT_SIZE..]);
+		for (lane, out) in out.iter_mut().enumerate() {
+			for (i, byte) in out.iter_mut().enumerate() {
+				*byte = scalars[i * N + lane].val();
+			}
+		}
+	}
+}
+
+fn make_sbox_affine_transformation<P>() -> P::PackedTransformation<&'static [AESTowerField8b]>
+where
+	P: PackedTransformationFactory<P, Scalar = AESTowerField8b>,
+{
+	P::make_packed_transformation(FieldLinearTransformation::new_const(&SBOX_AFFINE_BASES))
+}
+
+#[inline]
+fn sub_bytes<P: PackedField<Scalar = AESTowerField8b>>(
+	state: &mut State<P>,
+	sbox_affine: &impl Transformation<P, P>,
+) {
+	let affine_const = P::broadcast(SBOX_AFFINE_CONST);
+	for byte in state.iter_mut() {
+		*byte = sbox_affine.transform(&byte.invert_or_zero()) + affine_const;
+	}
+}
+
+/// ShiftBytes moves the byte in row `i` and column `(j + shift[i]) % 8` to column `j`.
+#[inline]
+fn shift_bytes<P: PackedField>(state: &State<P>, shift: impl Fn(usize) -> usize) -> State<P> {
+	array::from_fn(|i| {
+		let (col, row) = (i / 8, i % 8);
+		state[8 * ((col + shift(row)) % 8) + row]
+	})
+}
+
+/// MixBytes multiplies every column by the circulant matrix `circ(02, 02, 03, 04, 05, 03, 05, 07)`.
+///
+/// Splitting the coefficients into their bits, output row `i` is `s1 + 02 * (s2 + 02 * s4)`, where
+/// `s1`, `s2` and `s4` sum the column bytes at the row offsets whose coefficient has bit 0, 1 and 2
+/// set, respectively. This takes two multiplications by a constant per byte.
+#[inline]
+fn mix_bytes<P: PackedField<Scalar = AESTowerField8b>>(state: &mut State<P>) {
+	let two = P::broadcast(AESTowerField8b::new(0x02));
+	for column in state.chunks_exact_mut(8) {
+		let a: [P; 8] = array::from_fn(|i| column[i]);
+		let sum =
+			|offsets: &[usize], i: usize| -> P { offsets.iter().map(|&d| a[(i + d) % 8]).sum() };
+		for (i, byte) in column.iter_mut().enumerate() {
+			let s1 = sum(&[2, 4, 5, 6, 7], i);
+			let s2 = sum(&[0, 1, 2, 5, 7], i);
+			let s4 = sum(&[3, 4, 6, 7], i);
+			*byte = s1 + two * (s2 + two * s4);
+		}
+	}
+}
+
+fn p_perm<P: PackedField<Scalar = AESTowerField8b>>(
+	state: &mut State<P>,
+	sbox_affine: &impl Transformation<P, P>,
+) {
+	for r in 0..ROUNDS {
+		for col in 0..8 {
+			state[8 * col] += P::broadcast(AESTowerField8b::new(((col as u8) << 4) ^ r));
+		}
+		sub_bytes(state, sbox_affine);
+		*state = shift_bytes(state, |row| row);
+		mix_bytes(state);
+	}
+}
+
+fn q_perm<P: PackedField<Scalar = AESTowerField8b>>(
+	state: &mut State<P>,
+	sbox_affine: &impl Transformation<P, P>,
+) {
+	let all_ones = P::broadcast(AESTowerField8b::new(0xFF));
+	for r in 0..ROUNDS {
+		for byte in state.iter_mut() {
+			*byte += all_ones;
+		}
+		for col in 0..8 {
+			state[8 * col + 7] += P::broadcast(AESTowerField8b::new(((col as u8) << 4) ^ r));
+		}
+		sub_bytes(state, sbox_affine);
+		*state = shift_bytes(state, |row| SHIFT_Q[row]);
+		mix_bytes(state);
+	}
+}
+
+pub type Groestl256ByteSliced16x = Groestl256ByteSliced<PackedAESBinaryField16x8b, 16>;
+pub type Groestl256ByteSliced32x = Groestl256ByteSliced<PackedAESBinaryField32x8b, 32>;
+pub type Groestl256ByteSliced64x = Groestl256ByteSliced<PackedAESBinaryField64x8b, 64>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::groestl::multi_digest::tests::check_lengths_and_splits;
+
+	#[test]
+	fn test_multidigest_consistency_16x() {
+		check_lengths_and_splits::<Groestl256ByteSliced16x, 16>();
+	}
+
+	#[test]
+	fn test_multidigest_consistency_32x() {
+		check_lengths_and_splits::<Groestl256ByteSliced32x, 32>();
+	}
+
+	#[test]
+	fn test_multidigest_consistency_64x() {
+		check_lengths_and_splits::<Groestl256ByteSliced64x, 64>();
+	}
+}
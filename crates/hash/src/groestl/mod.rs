@@ -1,8 +1,10 @@
 // Copyright 2025 Irreducible Inc.
 
 mod arch;
+mod byte_sliced;
 mod compression;
 mod digest;
+mod multi_digest;
 #[cfg(test)]
 mod tests;
 
@@ -32,8 +34,20 @@ pub trait GroestlShortInternal {
 		Self::xor_state(h, &p);
 		Self::xor_state(h, &q);
 	}
+
+	/// Compresses `N` independent states, each with its own message block.
+	///
+	/// The default implementation compresses the states one after another. Implementations may
+	/// override it to interleave the lanes and hide the latency of the permutation rounds.
+	fn compress_multi<const N: usize>(h: &mut [Self::State; N], m: [&[u8; 64]; N]) {
+		for (h, m) in h.iter_mut().zip(m) {
+			Self::compress(h, m);
+		}
+	}
 }
 
 pub use arch::GroestlShortImpl;
+pub use byte_sliced::*;
 pub use compression::*;
 pub use digest::Groestl256;
+pub use multi_digest::*;
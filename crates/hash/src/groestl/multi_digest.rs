@@ -0,0 +1,324 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Multi-lane Grøstl-256 digests for hashing many equal-length messages, such as Merkle leaves.
+
+use std::{array, mem::MaybeUninit};
+
+use cfg_if::cfg_if;
+use digest::Output;
+use stackalloc::helpers::slice_assume_init_mut;
+
+use super::{GroestlShortImpl, GroestlShortInternal};
+use crate::{
+	groestl::{digest::Groestl256, ByteSlicedGroestlState},
+	multi_digest::MultiDigest,
+};
+
+pub(super) const BLOCK_SIZE: usize = 64;
+pub(super) const OUTPUT_SIZE: usize = 32;
+
+/// The initial Grøstl-256 chaining value, which encodes the output size in bits.
+pub(super) const INITIAL_STATE: [u8; BLOCK_SIZE] = {
+	let mut state = [0; BLOCK_SIZE];
+	let output_bits = ((8 * OUTPUT_SIZE) as u64).to_be_bytes();
+	let mut i = 0;
+	while i < 8 {
+		state[BLOCK_SIZE - 8 + i] = output_bits[i];
+		i += 1;
+	}
+	state
+};
+
+/// The chaining values of `N` Grøstl-256 computations that advance in lockstep.
+pub trait GroestlMultiState<const N: usize>: Clone {
+	/// Returns the states of `N` fresh Grøstl-256 computations.
+	fn new() -> Self;
+
+	/// Compresses one message block into every lane.
+	fn compress(&mut self, blocks: [&[u8; BLOCK_SIZE]; N]);
+
+	/// Applies the output transformation and writes the truncated digest of every lane.
+	fn output(&self, out: &mut [Output<Groestl256>; N]);
+}
+
+/// `N` Grøstl states processed by [`GroestlShortInternal::compress_multi`].
+///
+/// The AVX-512 implementation interleaves the rounds of all lanes, which hides the latency of the
+/// GFNI and permutation instructions that bounds the throughput of a single state.
+#[derive(Clone)]
+pub struct InterleavedGroestlState<const N: usize>(
+	[<GroestlShortImpl as GroestlShortInternal>::State; N],
+);
+
+impl<const N: usize> GroestlMultiState<N> for InterleavedGroestlState<N> {
+	fn new() -> Self {
+		Self(array::from_fn(|_| GroestlShortImpl::state_from_bytes(&INITIAL_STATE)))
+	}
+
+	fn compress(&mut self, blocks: [&[u8; BLOCK_SIZE]; N]) {
+		GroestlShortImpl::compress_multi(&mut self.0, blocks);
+	}
+
+	fn output(&self, out: &mut [Output<Groestl256>; N]) {
+		for (state, out) in self.0.iter().zip(out) {
+			let mut result = *state;
+			GroestlShortImpl::p_perm(&mut result);
+			GroestlShortImpl::xor_state(&mut result, state);
+			out.copy_from_slice(
+				&GroestlShortImpl::state_to_bytes(&result)[BLOCK_SIZE - OUTPUT_SIZE..],
+			);
+		}
+	}
+}
+
+/// Computes `N` Grøstl-256 hashes of equal-length messages at once.
+///
+/// This handles buffering and padding, while the lane state `S` implements the compression.
+#[derive(Clone)]
+pub struct GroestlMultiDigest<S, const N: usize> {
+	state: S,
+	buffer: [[u8; BLOCK_SIZE]; N],
+	filled_bytes: usize,
+	blocks_len: u64,
+}
+
+impl<S: GroestlMultiState<N>, const N: usize> Default for GroestlMultiDigest<S, N> {
+	fn default() -> Self {
+		Self {
+			state: S::new(),
+			buffer: [[0; BLOCK_SIZE]; N],
+			filled_bytes: 0,
+			blocks_len: 0,
+		}
+	}
+}
+
+impl<S: GroestlMultiState<N>, const N: usize> GroestlMultiDigest<S, N> {
+	fn compress_buffer(&mut self) {
+		self.state.compress(array::from_fn(|i| &self.buffer[i]));
+	}
+
+	fn finalize(&mut self, out: &mut [MaybeUninit<Output<Groestl256>>; N]) {
+		// Grøstl pads with a single one bit, zeros, and the 64-bit big-endian count of blocks
+		// including padding, which may spill over into an extra block.
+		let padding_blocks = if self.filled_bytes + 1 + 8 > BLOCK_SIZE {
+			2
+		} else {
+			1
+		};
+		let blocks_len = self.blocks_len + padding_blocks;
+
+		for block in &mut self.buffer {
+			block[self.filled_bytes] = 0x80;
+			block[self.filled_bytes + 1..].fill(0);
+		}
+		if padding_blocks == 2 {
+			self.compress_buffer();
+			for block in &mut self.buffer {
+				block.fill(0);
+			}
+		}
+		for block in &mut self.buffer {
+			block[BLOCK_SIZE - 8..].copy_from_slice(&blocks_len.to_be_bytes());
+		}
+		self.compress_buffer();
+
+		let out: &mut [Output<Groestl256>; N] = unsafe { slice_assume_init_mut(out) }
+			.try_into()
+			.expect("array has N elements");
+		self.state.output(out);
+	}
+}
+
+impl<S: GroestlMultiState<N>, const N: usize> MultiDigest<N> for GroestlMultiDigest<S, N> {
+	type Digest = Groestl256;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: [&[u8]; N]) {
+		for row in 1..N {
+			debug_assert_eq!(data[row].len(), data[0].len());
+		}
+
+		let mut offset = if self.filled_bytes > 0 {
+			let to_copy = std::cmp::min(data[0].len(), BLOCK_SIZE - self.filled_bytes);
+			for (buffer, row) in self.buffer.iter_mut().zip(&data) {
+				buffer[self.filled_bytes..self.filled_bytes + to_copy]
+					.copy_from_slice(&row[..to_copy]);
+			}
+
+			self.filled_bytes += to_copy;
+
+			if self.filled_bytes == BLOCK_SIZE {
+				self.compress_buffer();
+				self.blocks_len += 1;
+				self.filled_bytes = 0;
+			}
+
+			to_copy
+		} else {
+			0
+		};
+
+		while offset + BLOCK_SIZE <= data[0].len() {
+			let chunk = array::from_fn(|i| {
+				(&data[i][offset..offset + BLOCK_SIZE])
+					.try_into()
+					.expect("array is BLOCK_SIZE bytes")
+			});
+			self.state.compress(chunk);
+			self.blocks_len += 1;
+			offset += BLOCK_SIZE;
+		}
+
+		if offset < data[0].len() {
+			for (buffer, row) in self.buffer.iter_mut().zip(&data) {
+				buffer[..row.len() - offset].copy_from_slice(&row[offset..]);
+			}
+
+			self.filled_bytes = data[0].len() - offset;
+		}
+	}
+
+	fn finalize_into(mut self, out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		self.finalize(out);
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		self.finalize(out);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		self.state = S::new();
+		self.filled_bytes = 0;
+		self.blocks_len = 0;
+	}
+
+	fn digest(data: [&[u8]; N], out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		let mut digest = Self::default();
+		digest.update(data);
+		digest.finalize_into(out);
+	}
+}
+
+/// Grøstl-256 over `N` interleaved lanes of the architecture-optimized implementation.
+pub type Groestl256MultiDigest<const N: usize> = GroestlMultiDigest<InterleavedGroestlState<N>, N>;
+
+/// Byte-sliced Grøstl-256 with one lane per scalar of the packed field `P`.
+pub type Groestl256ByteSliced<P, const N: usize> = GroestlMultiDigest<ByteSlicedGroestlState<P>, N>;
+
+// With AVX-512 a single Grøstl state fits into one register, so interleaving a few states keeps the
+// vector units busy. With GFNI but narrower vectors, byte-slicing 32 lanes into 256-bit registers
+// is fastest. Otherwise the table-based scalar implementation is used, relying on the blanket
+// `ParallelDigest` implementation for scalar digests.
+cfg_if! {
+	if #[cfg(all(
+		target_arch = "x86_64",
+		target_feature = "avx512bw",
+		target_feature = "avx512vbmi",
+		target_feature = "avx512f",
+		target_feature = "gfni"
+	))] {
+		/// The number of lanes hashed at once by [`Groestl256ParallelDigest`].
+		pub const GROESTL256_PARALLEL_LANES: usize = 8;
+
+		/// The fastest parallel Grøstl-256 digest for the target architecture.
+		pub type Groestl256ParallelDigest = crate::multi_digest::ParallelMulidigestImpl<
+			Groestl256MultiDigest<GROESTL256_PARALLEL_LANES>,
+			GROESTL256_PARALLEL_LANES,
+		>;
+	} else if #[cfg(all(target_arch = "x86_64", target_feature = "gfni"))] {
+		/// The number of lanes hashed at once by [`Groestl256ParallelDigest`].
+		pub const GROESTL256_PARALLEL_LANES: usize = 32;
+
+		/// The fastest parallel Grøstl-256 digest for the target architecture.
+		pub type Groestl256ParallelDigest = crate::multi_digest::ParallelMulidigestImpl<
+			Groestl256ByteSliced<binius_field::PackedAESBinaryField32x8b, GROESTL256_PARALLEL_LANES>,
+			GROESTL256_PARALLEL_LANES,
+		>;
+	} else {
+		/// The number of lanes hashed at once by [`Groestl256ParallelDigest`].
+		pub const GROESTL256_PARALLEL_LANES: usize = 1;
+
+		/// The fastest parallel Grøstl-256 digest for the target architecture.
+		pub type Groestl256ParallelDigest = Groestl256;
+	}
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+	use digest::Digest;
+	use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+	use super::*;
+
+	fn check_multidigest_consistency<D: MultiDigest<N, Digest = Groestl256>, const N: usize>(
+		chunks: &[[&[u8]; N]],
+	) {
+		let mut scalar_digests = array::from_fn::<_, N, _>(|_| Groestl256::new());
+		let mut multidigest = D::new();
+
+		for chunk in chunks {
+			for (scalar_digest, data) in scalar_digests.iter_mut().zip(chunk.iter()) {
+				Digest::update(scalar_digest, data);
+			}
+
+			multidigest.update(*chunk);
+		}
+
+		let scalar_digests = scalar_digests.map(|d| d.finalize());
+		let mut output = array::from_fn(|_| MaybeUninit::uninit());
+		multidigest.finalize_into(&mut output);
+		let output = output.map(|x| unsafe { x.assume_init() });
+
+		assert_eq!(scalar_digests, output);
+	}
+
+	pub(in crate::groestl) fn check_lengths_and_splits<
+		D: MultiDigest<N, Digest = Groestl256>,
+		const N: usize,
+	>() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut data = [[0u8; 300]; N];
+		for row in &mut data {
+			rng.fill_bytes(row);
+		}
+
+		for len in [0, 1, 55, 56, 63, 64, 65, 128, 300] {
+			check_multidigest_consistency::<D, N>(&[array::from_fn(|i| &data[i][..len])]);
+		}
+		check_multidigest_consistency::<D, N>(&[
+			array::from_fn(|i| &data[i][..5]),
+			array::from_fn(|i| &data[i][5..64]),
+			array::from_fn(|i| &data[i][64..64]),
+			array::from_fn(|i| &data[i][64..200]),
+			array::from_fn(|i| &data[i][200..]),
+		]);
+	}
+
+	#[test]
+	fn test_multidigest_consistency_4x() {
+		check_lengths_and_splits::<Groestl256MultiDigest<4>, 4>();
+	}
+
+	#[test]
+	fn test_multidigest_consistency_8x() {
+		check_lengths_and_splits::<Groestl256MultiDigest<8>, 8>();
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut multidigest = Groestl256MultiDigest::<4>::new();
+		multidigest.update([b"garbage".as_slice(); 4]);
+		multidigest.reset();
+		multidigest.update([b"abc".as_slice(); 4]);
+
+		let mut output = array::from_fn(|_| MaybeUninit::uninit());
+		multidigest.finalize_into(&mut output);
+		let output = output.map(|x| unsafe { x.assume_init() });
+		assert_eq!(output, [Groestl256::digest(b"abc"); 4]);
+	}
+}
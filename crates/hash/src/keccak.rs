@@ -0,0 +1,327 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Keccak-256 compression and a multi-lane Keccak-256 digest.
+//!
+//! Keccak-256 is the hash function exposed by the EVM `KECCAK256` opcode, so Merkle commitments and
+//! Fiat-Shamir transcripts built on it are cheap to verify in EVM smart contracts.
+
+use std::{array, mem::MaybeUninit};
+
+use digest::Output;
+use sha3::{Digest, Keccak256};
+use stackalloc::helpers::slice_assume_init_mut;
+
+use crate::{
+	multi_digest::{MultiDigest, ParallelMulidigestImpl},
+	CompressionFunction, PseudoCompressionFunction,
+};
+
+/// The rate of Keccak-256 in bytes.
+const RATE_AS_U8: usize = 136;
+/// The rate of Keccak-256 in 64-bit words.
+const RATE_AS_U64: usize = RATE_AS_U8 / 8;
+
+/// The number of hashes computed in parallel by [`Keccak256ParallelDigest`].
+pub const KECCAK256_PARALLEL_LANES: usize = 4;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+	0x0000000000000001,
+	0x0000000000008082,
+	0x800000000000808a,
+	0x8000000080008000,
+	0x000000000000808b,
+	0x0000000080000001,
+	0x8000000080008081,
+	0x8000000000008009,
+	0x000000000000008a,
+	0x0000000000000088,
+	0x0000000080008009,
+	0x000000008000000a,
+	0x000000008000808b,
+	0x800000000000008b,
+	0x8000000000008089,
+	0x8000000000008003,
+	0x8000000000008002,
+	0x8000000000000080,
+	0x000000000000800a,
+	0x800000008000000a,
+	0x8000000080008081,
+	0x8000000000008080,
+	0x0000000080000001,
+	0x8000000080008008,
+];
+
+/// Rotation offsets of the combined ρ and π steps, in the order the lanes are visited.
+const RHO: [u32; 24] = [
+	1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// Lane visiting order of the combined ρ and π steps.
+const PI: [usize; 24] = [
+	10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// A two-to-one compression function for Keccak-256 digests.
+///
+/// The compression is `Keccak256(left || right)`, which is exactly the parent node hash computed by
+/// Solidity Merkle proof verifiers with `keccak256(abi.encodePacked(left, right))`.
+#[derive(Debug, Default, Clone)]
+pub struct Keccak256Compression;
+
+impl PseudoCompressionFunction<Output<Keccak256>, 2> for Keccak256Compression {
+	fn compress(&self, input: [Output<Keccak256>; 2]) -> Output<Keccak256> {
+		Keccak256::new()
+			.chain_update(input[0].as_slice())
+			.chain_update(input[1].as_slice())
+			.finalize()
+	}
+}
+
+impl CompressionFunction<Output<Keccak256>, 2> for Keccak256Compression {}
+
+/// Computes `N` Keccak-256 hashes of equal-length messages at once.
+///
+/// The Keccak-f\[1600\] state is stored lane-interleaved, with each of the 25 state words held as
+/// an array of `N` words, so that every step of the permutation is a straight-line operation over
+/// `N` independent values that the compiler lowers to SIMD instructions.
+#[derive(Clone)]
+pub struct Keccak256MultiDigest<const N: usize> {
+	state: [[u64; N]; 25],
+	buffer: [[u8; RATE_AS_U8]; N],
+	filled_bytes: usize,
+}
+
+impl<const N: usize> Default for Keccak256MultiDigest<N> {
+	fn default() -> Self {
+		Self {
+			state: [[0; N]; 25],
+			buffer: [[0; RATE_AS_U8]; N],
+			filled_bytes: 0,
+		}
+	}
+}
+
+impl<const N: usize> Keccak256MultiDigest<N> {
+	fn absorb_block(state: &mut [[u64; N]; 25], data: [&[u8; RATE_AS_U8]; N]) {
+		for (i, word) in state[..RATE_AS_U64].iter_mut().enumerate() {
+			for (lane, row) in data.iter().enumerate() {
+				word[lane] ^=
+					u64::from_le_bytes(row[i * 8..i * 8 + 8].try_into().expect("chunk is 8 bytes"));
+			}
+		}
+
+		keccak_f1600(state);
+	}
+
+	fn finalize(&mut self, out: &mut [MaybeUninit<Output<Keccak256>>; N]) {
+		for row in &mut self.buffer {
+			row[self.filled_bytes..].fill(0);
+			row[self.filled_bytes] |= 0x01;
+			row[RATE_AS_U8 - 1] |= 0x80;
+		}
+		Self::absorb_block(&mut self.state, array::from_fn(|i| &self.buffer[i]));
+
+		let out: &mut [Output<Keccak256>; N] = unsafe { slice_assume_init_mut(out) }
+			.try_into()
+			.expect("array has N elements");
+		for (i, word) in self.state[..4].iter().enumerate() {
+			for (out, lane_word) in out.iter_mut().zip(word) {
+				out[i * 8..i * 8 + 8].copy_from_slice(&lane_word.to_le_bytes());
+			}
+		}
+	}
+}
+
+impl<const N: usize> MultiDigest<N> for Keccak256MultiDigest<N> {
+	type Digest = Keccak256;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: [&[u8]; N]) {
+		for row in 1..N {
+			debug_assert_eq!(data[row].len(), data[0].len());
+		}
+
+		let mut offset = if self.filled_bytes > 0 {
+			let to_copy = std::cmp::min(data[0].len(), RATE_AS_U8 - self.filled_bytes);
+			for (buffer, row) in self.buffer.iter_mut().zip(&data) {
+				buffer[self.filled_bytes..self.filled_bytes + to_copy]
+					.copy_from_slice(&row[..to_copy]);
+			}
+
+			self.filled_bytes += to_copy;
+
+			if self.filled_bytes == RATE_AS_U8 {
+				Self::absorb_block(&mut self.state, array::from_fn(|i| &self.buffer[i]));
+				self.filled_bytes = 0;
+			}
+
+			to_copy
+		} else {
+			0
+		};
+
+		while offset + RATE_AS_U8 <= data[0].len() {
+			let chunk = array::from_fn(|i| {
+				(&data[i][offset..offset + RATE_AS_U8])
+					.try_into()
+					.expect("array is RATE_AS_U8 bytes")
+			});
+			Self::absorb_block(&mut self.state, chunk);
+			offset += RATE_AS_U8;
+		}
+
+		if offset < data[0].len() {
+			for (buffer, row) in self.buffer.iter_mut().zip(&data) {
+				buffer[..row.len() - offset].copy_from_slice(&row[offset..]);
+			}
+
+			self.filled_bytes = data[0].len() - offset;
+		}
+	}
+
+	fn finalize_into(mut self, out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		self.finalize(out);
+	}
+
+	fn finalize_into_reset(&mut self, out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		self.finalize(out);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		bytemuck::fill_zeroes(&mut self.state);
+		self.filled_bytes = 0;
+	}
+
+	fn digest(data: [&[u8]; N], out: &mut [MaybeUninit<Output<Self::Digest>>; N]) {
+		let mut digest = Self::default();
+		digest.update(data);
+		digest.finalize_into(out);
+	}
+}
+
+pub type Keccak256ParallelDigest = ParallelMulidigestImpl<
+	Keccak256MultiDigest<KECCAK256_PARALLEL_LANES>,
+	KECCAK256_PARALLEL_LANES,
+>;
+
+#[inline(always)]
+fn xor<const N: usize>(a: [u64; N], b: [u64; N]) -> [u64; N] {
+	array::from_fn(|i| a[i] ^ b[i])
+}
+
+#[inline(always)]
+fn rotate_left<const N: usize>(a: [u64; N], n: u32) -> [u64; N] {
+	a.map(|x| x.rotate_left(n))
+}
+
+/// The Keccak-f\[1600\] permutation applied to `N` interleaved states.
+fn keccak_f1600<const N: usize>(state: &mut [[u64; N]; 25]) {
+	for round_constant in ROUND_CONSTANTS {
+		// θ step
+		let parities: [[u64; N]; 5] =
+			array::from_fn(|x| (1..5).fold(state[x], |parity, y| xor(parity, state[x + 5 * y])));
+		for x in 0..5 {
+			let d = xor(parities[(x + 4) % 5], rotate_left(parities[(x + 1) % 5], 1));
+			for y in 0..5 {
+				state[x + 5 * y] = xor(state[x + 5 * y], d);
+			}
+		}
+
+		// ρ and π steps
+		let mut last = state[1];
+		for (&pi, &rho) in PI.iter().zip(&RHO) {
+			let current = state[pi];
+			state[pi] = rotate_left(last, rho);
+			last = current;
+		}
+
+		// χ step
+		for y in 0..5 {
+			let row: [[u64; N]; 5] = array::from_fn(|x| state[x + 5 * y]);
+			for x in 0..5 {
+				state[x + 5 * y] =
+					array::from_fn(|i| row[x][i] ^ (!row[(x + 1) % 5][i] & row[(x + 2) % 5][i]));
+			}
+		}
+
+		// ι step
+		for lane in &mut state[0] {
+			*lane ^= round_constant;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+	use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+	use super::*;
+
+	fn check_multidigest_consistency<const N: usize>(chunks: &[[&[u8]; N]]) {
+		let mut scalar_digests = array::from_fn::<_, N, _>(|_| Keccak256::new());
+		let mut multidigest = Keccak256MultiDigest::<N>::new();
+
+		for chunk in chunks {
+			for (scalar_digest, data) in scalar_digests.iter_mut().zip(chunk.iter()) {
+				Digest::update(scalar_digest, data);
+			}
+
+			multidigest.update(*chunk);
+		}
+
+		let scalar_digests = scalar_digests.map(|d| d.finalize());
+		let mut output = array::from_fn(|_| MaybeUninit::uninit());
+		multidigest.finalize_into(&mut output);
+		let output = output.map(|x| unsafe { x.assume_init() });
+
+		assert_eq!(scalar_digests, output);
+	}
+
+	#[test]
+	fn test_empty_input() {
+		let mut output = [MaybeUninit::uninit()];
+		Keccak256MultiDigest::<1>::digest([&[]], &mut output);
+		let [output] = output.map(|x| unsafe { x.assume_init() });
+		assert_eq!(
+			&*output,
+			&hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+		);
+	}
+
+	#[test]
+	fn test_multidigest_consistency() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut data = [[0u8; 400]; KECCAK256_PARALLEL_LANES];
+		for row in &mut data {
+			rng.fill_bytes(row);
+		}
+
+		for len in [0, 1, RATE_AS_U8 - 1, RATE_AS_U8, RATE_AS_U8 + 1, 400] {
+			check_multidigest_consistency(&[array::from_fn::<_, KECCAK256_PARALLEL_LANES, _>(
+				|i| &data[i][..len],
+			)]);
+		}
+		check_multidigest_consistency(&[
+			array::from_fn::<_, KECCAK256_PARALLEL_LANES, _>(|i| &data[i][..5]),
+			array::from_fn(|i| &data[i][5..140]),
+			array::from_fn(|i| &data[i][140..272]),
+			array::from_fn(|i| &data[i][272..]),
+		]);
+	}
+
+	#[test]
+	fn test_compression_matches_concatenated_hash() {
+		let left = Keccak256::digest(b"left");
+		let right = Keccak256::digest(b"right");
+		let expected = Keccak256::new()
+			.chain_update(left)
+			.chain_update(right)
+			.finalize();
+		assert_eq!(Keccak256Compression.compress([left, right]), expected);
+	}
+}
@@ -8,21 +8,30 @@
 //! so can be arithmetized efficiently with a Binius constraint system.
 //!
 //! This crate also provides an implementation of [Vision Mark-32], a cryptographic sponge function
-//! designed for efficient Binius arithmetization.
+//! designed for efficient Binius arithmetization, and an experimental [Poseidon2]-style
+//! permutation over binary tower fields as an alternative arithmetization-friendly candidate.
+//!
+//! For proofs that are verified outside of a Binius circuit, the crate also provides compression
+//! functions and multi-lane digests for Keccak-256, which is cheap to verify on the EVM, and BLAKE3,
+//! which is fast to verify natively.
 //!
 //! [Grøstl-256]: <https://www.groestl.info/>
 //! [Vision Mark-32]: <https://eprint.iacr.org/2024/633>
+//! [Poseidon2]: <https://eprint.iacr.org/2023/323>
 
 #![cfg_attr(
 	all(target_arch = "x86_64", not(feature = "stable_only")),
 	feature(stdarch_x86_avx512)
 )]
 
+pub mod blake3;
 pub mod compression;
 pub mod groestl;
 pub mod hasher;
+pub mod keccak;
 pub mod multi_digest;
 pub mod permutation;
+pub mod poseidon2;
 mod serialization;
 pub mod sha2;
 mod vision;
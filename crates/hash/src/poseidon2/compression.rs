@@ -0,0 +1,85 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::array;
+
+use binius_field::{BinaryField32b, BinaryField64b};
+use digest::{consts::U32, generic_array::GenericArray};
+
+use super::{
+	constants::{POSEIDON2_B32_WIDTH, POSEIDON2_B64_WIDTH},
+	permutation::{POSEIDON2_B32_PERMUTATION, POSEIDON2_B64_PERMUTATION},
+};
+use crate::{permutation::Permutation, PseudoCompressionFunction};
+
+/// The 32-byte digest compressed by [`Poseidon2b32Compression`].
+pub type Poseidon2b32Digest = GenericArray<u8, U32>;
+
+/// The 32-byte digest compressed by [`Poseidon2b64Compression`].
+pub type Poseidon2b64Digest = GenericArray<u8, U32>;
+
+/// One-way compression function that compresses two 32-byte strings into a single 32-byte string
+/// using the Poseidon2 permutation over `BinaryField32b`.
+///
+/// The compression is computed in the Poseidon2 compression mode, `Trunc(P(x) + x)`, which feeds
+/// the input forward so that the permutation cannot be inverted.
+#[derive(Debug, Default, Clone)]
+pub struct Poseidon2b32Compression;
+
+impl PseudoCompressionFunction<Poseidon2b32Digest, 2> for Poseidon2b32Compression {
+	fn compress(&self, input: [Poseidon2b32Digest; 2]) -> Poseidon2b32Digest {
+		let state: [BinaryField32b; POSEIDON2_B32_WIDTH] = array::from_fn(|i| {
+			let bytes = &input[i / 8][(i % 8) * 4..(i % 8) * 4 + 4];
+			u32::from_le_bytes(bytes.try_into().expect("chunk is 4 bytes")).into()
+		});
+		let permuted = POSEIDON2_B32_PERMUTATION.permute(state);
+
+		let mut out = Poseidon2b32Digest::default();
+		for (i, chunk) in out.chunks_exact_mut(4).enumerate() {
+			chunk.copy_from_slice(&u32::from(permuted[i] + state[i]).to_le_bytes());
+		}
+		out
+	}
+}
+
+/// One-way compression function that compresses two 32-byte strings into a single 32-byte string
+/// using the Poseidon2 permutation over `BinaryField64b`.
+#[derive(Debug, Default, Clone)]
+pub struct Poseidon2b64Compression;
+
+impl PseudoCompressionFunction<Poseidon2b64Digest, 2> for Poseidon2b64Compression {
+	fn compress(&self, input: [Poseidon2b64Digest; 2]) -> Poseidon2b64Digest {
+		let state: [BinaryField64b; POSEIDON2_B64_WIDTH] = array::from_fn(|i| {
+			let bytes = &input[i / 4][(i % 4) * 8..(i % 4) * 8 + 8];
+			u64::from_le_bytes(bytes.try_into().expect("chunk is 8 bytes")).into()
+		});
+		let permuted = POSEIDON2_B64_PERMUTATION.permute(state);
+
+		let mut out = Poseidon2b64Digest::default();
+		for (i, chunk) in out.chunks_exact_mut(8).enumerate() {
+			chunk.copy_from_slice(&u64::from(permuted[i] + state[i]).to_le_bytes());
+		}
+		out
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_compression_depends_on_both_inputs() {
+		fn check(compression: impl PseudoCompressionFunction<Poseidon2b64Digest, 2>) {
+			let a = Poseidon2b64Digest::from([1u8; 32]);
+			let b = Poseidon2b64Digest::from([2u8; 32]);
+
+			let ab = compression.compress([a, b]);
+			assert_eq!(ab, compression.compress([a, b]));
+			assert_ne!(ab, compression.compress([b, a]));
+			assert_ne!(ab, compression.compress([a, a]));
+			assert_ne!(ab, compression.compress([b, b]));
+		}
+
+		check(Poseidon2b32Compression);
+		check(Poseidon2b64Compression);
+	}
+}
@@ -0,0 +1,40 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::Field;
+use binius_utils::SerializationMode;
+use sha2::{Digest, Sha256};
+
+/// The number of full (external) rounds. Half of them are applied before the partial rounds and
+/// half after.
+pub const POSEIDON2_EXTERNAL_ROUNDS: usize = 8;
+
+/// The number of partial (internal) rounds.
+pub const POSEIDON2_INTERNAL_ROUNDS: usize = 22;
+
+/// The width of the state over `BinaryField32b`, in field elements.
+pub const POSEIDON2_B32_WIDTH: usize = 16;
+
+/// The width of the state over `BinaryField64b`, in field elements.
+pub const POSEIDON2_B64_WIDTH: usize = 8;
+
+/// Deterministically samples `n` non-zero field elements from a domain separator.
+///
+/// The elements are sampled in counter mode from SHA-256 so that the constants are reproducible
+/// and clearly free of any hidden structure.
+pub(super) fn sample_nonzero_constants<F: Field>(domain: &[u8], n: usize) -> Vec<F> {
+	let elem_size = std::mem::size_of::<F>();
+	assert!(elem_size <= 32, "field elements must fit into a SHA-256 output");
+
+	(0u64..)
+		.map(|counter| {
+			let hash = Sha256::new()
+				.chain_update(domain)
+				.chain_update(counter.to_le_bytes())
+				.finalize();
+			F::deserialize(&hash[..elem_size], SerializationMode::Native)
+				.expect("buffer has exactly the size of the field element")
+		})
+		.filter(|elem| *elem != F::ZERO)
+		.take(n)
+		.collect()
+}
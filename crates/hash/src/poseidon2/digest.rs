@@ -0,0 +1,402 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{array, mem::MaybeUninit};
+
+use binius_field::{BinaryField32b, Field, PackedBinaryField16x32b, PackedField};
+use digest::{
+	consts::U32, core_api::BlockSizeUser, FixedOutput, FixedOutputReset, HashMarker, Output,
+	OutputSizeUser, Reset, Update,
+};
+use lazy_static::lazy_static;
+use stackalloc::helpers::slice_assume_init_mut;
+
+use super::{constants::POSEIDON2_B32_WIDTH, permutation::POSEIDON2_B32_PERMUTATION};
+use crate::{
+	multi_digest::{MultiDigest, ParallelMulidigestImpl},
+	permutation::Permutation,
+};
+
+const RATE_AS_U32: usize = 8;
+const RATE_AS_U8: usize = RATE_AS_U32 * std::mem::size_of::<u32>();
+
+const PADDING_START: u8 = 0x80;
+const PADDING_END: u8 = 0x01;
+
+/// The number of hashes computed in parallel by [`Poseidon2HasherDigestPacked`].
+pub const POSEIDON2_HASHES_PER_PACKED_PERMUTATION: usize = PackedBinaryField16x32b::WIDTH;
+
+lazy_static! {
+	// Padding block for the case when the input is a multiple of the rate.
+	static ref PADDING_BLOCK: [u8; RATE_AS_U8] = {
+		let mut block = [0; RATE_AS_U8];
+		block[0] = PADDING_START;
+		block[RATE_AS_U8 - 1] |= PADDING_END;
+		block
+	};
+}
+
+/// A sponge hash over the Poseidon2 permutation on `BinaryField32b`.
+///
+/// The sponge absorbs 32 bytes per permutation call into the first 8 state elements and keeps the
+/// remaining 8 elements as capacity. Bytes are read as little-endian `u32` values in the canonical
+/// tower basis.
+#[derive(Clone)]
+pub struct Poseidon2HasherDigest {
+	// The hashed state
+	state: [BinaryField32b; POSEIDON2_B32_WIDTH],
+	buffer: [u8; RATE_AS_U8],
+	filled_bytes: usize,
+}
+
+impl Default for Poseidon2HasherDigest {
+	fn default() -> Self {
+		Self {
+			state: [BinaryField32b::ZERO; POSEIDON2_B32_WIDTH],
+			buffer: [0; RATE_AS_U8],
+			filled_bytes: 0,
+		}
+	}
+}
+
+impl Poseidon2HasherDigest {
+	fn permute(state: &mut [BinaryField32b; POSEIDON2_B32_WIDTH], data: &[u8]) {
+		debug_assert_eq!(data.len(), RATE_AS_U8);
+
+		for (state_elem, chunk) in state.iter_mut().zip(data.chunks_exact(4)) {
+			*state_elem += BinaryField32b::from(u32::from_le_bytes(
+				chunk.try_into().expect("chunk is 4 bytes"),
+			));
+		}
+
+		POSEIDON2_B32_PERMUTATION.permute_mut(state);
+	}
+
+	fn finalize_into(&mut self, out: &mut Output<Self>) {
+		if self.filled_bytes != 0 {
+			fill_padding(&mut self.buffer[self.filled_bytes..]);
+			Self::permute(&mut self.state, &self.buffer);
+		} else {
+			Self::permute(&mut self.state, &*PADDING_BLOCK);
+		}
+
+		for (chunk, elem) in out.chunks_exact_mut(4).zip(&self.state) {
+			chunk.copy_from_slice(&u32::from(*elem).to_le_bytes());
+		}
+	}
+}
+
+impl HashMarker for Poseidon2HasherDigest {}
+
+impl Update for Poseidon2HasherDigest {
+	fn update(&mut self, mut data: &[u8]) {
+		if self.filled_bytes != 0 {
+			let to_copy = std::cmp::min(data.len(), RATE_AS_U8 - self.filled_bytes);
+			self.buffer[self.filled_bytes..self.filled_bytes + to_copy]
+				.copy_from_slice(&data[..to_copy]);
+			data = &data[to_copy..];
+			self.filled_bytes += to_copy;
+
+			if self.filled_bytes == RATE_AS_U8 {
+				Self::permute(&mut self.state, &self.buffer);
+				self.filled_bytes = 0;
+			}
+		}
+
+		let mut chunks = data.chunks_exact(RATE_AS_U8);
+		for chunk in &mut chunks {
+			Self::permute(&mut self.state, chunk);
+		}
+
+		let remaining = chunks.remainder();
+		if !remaining.is_empty() {
+			self.buffer[..remaining.len()].copy_from_slice(remaining);
+			self.filled_bytes = remaining.len();
+		}
+	}
+}
+
+impl OutputSizeUser for Poseidon2HasherDigest {
+	type OutputSize = U32;
+}
+
+impl BlockSizeUser for Poseidon2HasherDigest {
+	type BlockSize = U32;
+}
+
+impl FixedOutput for Poseidon2HasherDigest {
+	fn finalize_into(mut self, out: &mut Output<Self>) {
+		Self::finalize_into(&mut self, out);
+	}
+}
+
+impl Reset for Poseidon2HasherDigest {
+	fn reset(&mut self) {
+		*self = Self::default();
+	}
+}
+
+impl FixedOutputReset for Poseidon2HasherDigest {
+	fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+		Self::finalize_into(self, out);
+		Reset::reset(self);
+	}
+}
+
+/// Fill the data using Keccak padding scheme.
+#[inline(always)]
+fn fill_padding(data: &mut [u8]) {
+	debug_assert!(!data.is_empty() && data.len() <= RATE_AS_U8);
+
+	data.fill(0);
+	data[0] |= PADDING_START;
+	data[data.len() - 1] |= PADDING_END;
+}
+
+/// Computes [`POSEIDON2_HASHES_PER_PACKED_PERMUTATION`] instances of [`Poseidon2HasherDigest`] at
+/// once, with one hash per lane of `PackedBinaryField16x32b`.
+#[derive(Clone)]
+pub struct Poseidon2HasherDigestPacked {
+	// The hashed state
+	state: [PackedBinaryField16x32b; POSEIDON2_B32_WIDTH],
+	// Buffer to hold the temporary data
+	buffer: [[u8; RATE_AS_U8]; POSEIDON2_HASHES_PER_PACKED_PERMUTATION],
+	filled_bytes: usize,
+}
+
+impl Default for Poseidon2HasherDigestPacked {
+	fn default() -> Self {
+		Self {
+			state: [PackedBinaryField16x32b::zero(); POSEIDON2_B32_WIDTH],
+			buffer: [[0; RATE_AS_U8]; POSEIDON2_HASHES_PER_PACKED_PERMUTATION],
+			filled_bytes: 0,
+		}
+	}
+}
+
+impl Poseidon2HasherDigestPacked {
+	fn permute(
+		state: &mut [PackedBinaryField16x32b; POSEIDON2_B32_WIDTH],
+		data: [&[u8; RATE_AS_U8]; POSEIDON2_HASHES_PER_PACKED_PERMUTATION],
+	) {
+		for (i, state_elem) in state[..RATE_AS_U32].iter_mut().enumerate() {
+			*state_elem += PackedBinaryField16x32b::from_fn(|lane| {
+				u32::from_le_bytes(
+					data[lane][i * 4..i * 4 + 4]
+						.try_into()
+						.expect("chunk is 4 bytes"),
+				)
+				.into()
+			});
+		}
+
+		POSEIDON2_B32_PERMUTATION.permute_mut(state);
+	}
+
+	fn finalize(
+		&mut self,
+		out: &mut [MaybeUninit<Output<Poseidon2HasherDigest>>;
+			     POSEIDON2_HASHES_PER_PACKED_PERMUTATION],
+	) {
+		if self.filled_bytes > 0 {
+			for row in 0..POSEIDON2_HASHES_PER_PACKED_PERMUTATION {
+				fill_padding(&mut self.buffer[row][self.filled_bytes..]);
+			}
+
+			Self::permute(&mut self.state, array::from_fn(|i| &self.buffer[i]));
+		} else {
+			Self::permute(&mut self.state, array::from_fn(|_| &*PADDING_BLOCK));
+		}
+
+		let out: &mut [Output<Poseidon2HasherDigest>; POSEIDON2_HASHES_PER_PACKED_PERMUTATION] =
+			unsafe { slice_assume_init_mut(out) }
+				.try_into()
+				.expect("array has the number of lanes elements");
+		for (i, state_elem) in self.state[..RATE_AS_U32].iter().enumerate() {
+			for (lane, scalar) in state_elem.iter().enumerate() {
+				out[lane][i * 4..i * 4 + 4].copy_from_slice(&u32::from(scalar).to_le_bytes());
+			}
+		}
+	}
+}
+
+impl MultiDigest<POSEIDON2_HASHES_PER_PACKED_PERMUTATION> for Poseidon2HasherDigestPacked {
+	type Digest = Poseidon2HasherDigest;
+
+	fn new() -> Self {
+		Self::default()
+	}
+
+	fn update(&mut self, data: [&[u8]; POSEIDON2_HASHES_PER_PACKED_PERMUTATION]) {
+		for row in 1..POSEIDON2_HASHES_PER_PACKED_PERMUTATION {
+			debug_assert_eq!(data[row].len(), data[0].len());
+		}
+
+		let mut offset = if self.filled_bytes > 0 {
+			let to_copy = std::cmp::min(data[0].len(), RATE_AS_U8 - self.filled_bytes);
+			for (buffer, row) in self.buffer.iter_mut().zip(&data) {
+				buffer[self.filled_bytes..self.filled_bytes + to_copy]
+					.copy_from_slice(&row[..to_copy]);
+			}
+
+			self.filled_bytes += to_copy;
+
+			if self.filled_bytes == RATE_AS_U8 {
+				Self::permute(&mut self.state, array::from_fn(|i| &self.buffer[i]));
+				self.filled_bytes = 0;
+			}
+
+			to_copy
+		} else {
+			0
+		};
+
+		while offset + RATE_AS_U8 <= data[0].len() {
+			let chunk = array::from_fn(|i| {
+				(&data[i][offset..offset + RATE_AS_U8])
+					.try_into()
+					.expect("array is 32 bytes")
+			});
+			Self::permute(&mut self.state, chunk);
+			offset += RATE_AS_U8;
+		}
+
+		if offset < data[0].len() {
+			for (buffer, row) in self.buffer.iter_mut().zip(&data) {
+				buffer[..row.len() - offset].copy_from_slice(&row[offset..]);
+			}
+
+			self.filled_bytes = data[0].len() - offset;
+		}
+	}
+
+	fn finalize_into(
+		mut self,
+		out: &mut [MaybeUninit<Output<Self::Digest>>; POSEIDON2_HASHES_PER_PACKED_PERMUTATION],
+	) {
+		self.finalize(out);
+	}
+
+	fn finalize_into_reset(
+		&mut self,
+		out: &mut [MaybeUninit<Output<Self::Digest>>; POSEIDON2_HASHES_PER_PACKED_PERMUTATION],
+	) {
+		self.finalize(out);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		bytemuck::fill_zeroes(&mut self.state);
+		self.filled_bytes = 0;
+	}
+
+	fn digest(
+		data: [&[u8]; POSEIDON2_HASHES_PER_PACKED_PERMUTATION],
+		out: &mut [MaybeUninit<Output<Self::Digest>>; POSEIDON2_HASHES_PER_PACKED_PERMUTATION],
+	) {
+		let mut digest = Self::default();
+		digest.update(data);
+		digest.finalize_into(out);
+	}
+}
+
+pub type Poseidon2ParallelDigest =
+	ParallelMulidigestImpl<Poseidon2HasherDigestPacked, POSEIDON2_HASHES_PER_PACKED_PERMUTATION>;
+
+#[cfg(test)]
+mod tests {
+	use std::{array, mem::MaybeUninit};
+
+	use digest::Digest;
+	use hex_literal::hex;
+	use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn test_simple_hash() {
+		let mut hasher = Poseidon2HasherDigest::default();
+		Digest::update(&mut hasher, [0xde, 0xad, 0xbe, 0xef]);
+		let out = hasher.finalize();
+		// Regression vector for the current choice of round constants.
+		let expected = &hex!("87d123cd67d91f657373cf65431b1509b5f5c0b616a86cd41b6db6c48aabbe26");
+		assert_eq!(expected, &*out);
+	}
+
+	#[test]
+	fn test_incremental_update_matches_oneshot() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut data = [0u8; 200];
+		rng.fill_bytes(&mut data);
+
+		let expected = Poseidon2HasherDigest::digest(data);
+		for split in [0, 1, 31, 32, 33, 64, 100, 199, 200] {
+			let mut hasher = Poseidon2HasherDigest::new();
+			Digest::update(&mut hasher, &data[..split]);
+			Digest::update(&mut hasher, &data[split..]);
+			assert_eq!(hasher.finalize(), expected);
+		}
+	}
+
+	#[test]
+	fn test_padding_distinguishes_lengths() {
+		let empty = Poseidon2HasherDigest::digest([]);
+		let one_zero = Poseidon2HasherDigest::digest([0u8]);
+		let full_block = Poseidon2HasherDigest::digest([0u8; RATE_AS_U8]);
+		assert_ne!(empty, one_zero);
+		assert_ne!(empty, full_block);
+		assert_ne!(one_zero, full_block);
+	}
+
+	#[test]
+	fn test_finalize_reset() {
+		let mut hasher = Poseidon2HasherDigest::new();
+		Digest::update(&mut hasher, b"first message");
+		let first = hasher.finalize_reset();
+		Digest::update(&mut hasher, b"second message");
+		assert_eq!(hasher.finalize(), Poseidon2HasherDigest::digest(b"second message"));
+		assert_eq!(first, Poseidon2HasherDigest::digest(b"first message"));
+	}
+
+	fn check_multihash_consistency(chunks: &[[&[u8]; POSEIDON2_HASHES_PER_PACKED_PERMUTATION]]) {
+		let mut scalar_digests =
+			array::from_fn::<_, POSEIDON2_HASHES_PER_PACKED_PERMUTATION, _>(|_| {
+				Poseidon2HasherDigest::default()
+			});
+		let mut multidigest = Poseidon2HasherDigestPacked::default();
+
+		for chunk in chunks {
+			for (scalar_digest, data) in scalar_digests.iter_mut().zip(chunk.iter()) {
+				Digest::update(scalar_digest, data);
+			}
+
+			multidigest.update(*chunk);
+		}
+
+		let scalar_digests = scalar_digests.map(|d| d.finalize());
+		let mut output = array::from_fn(|_| MaybeUninit::uninit());
+		multidigest.finalize_into(&mut output);
+		let output = output.map(|x| unsafe { x.assume_init() });
+
+		for (scalar_digest, multi_digest) in scalar_digests.iter().zip(output.iter()) {
+			assert_eq!(scalar_digest, multi_digest);
+		}
+	}
+
+	#[test]
+	fn test_multidigest_consistency() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut data = [[0u8; 150]; POSEIDON2_HASHES_PER_PACKED_PERMUTATION];
+		for row in &mut data {
+			rng.fill_bytes(row);
+		}
+
+		check_multihash_consistency(&[array::from_fn(|i| &data[i][..0])]);
+		check_multihash_consistency(&[array::from_fn(|i| &data[i][..1])]);
+		check_multihash_consistency(&[array::from_fn(|i| &data[i][..RATE_AS_U8])]);
+		check_multihash_consistency(&[
+			array::from_fn(|i| &data[i][..5]),
+			array::from_fn(|i| &data[i][5..70]),
+			array::from_fn(|i| &data[i][70..]),
+		]);
+	}
+}
@@ -0,0 +1,24 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A Poseidon2-style permutation over binary tower fields.
+//!
+//! The construction follows [Poseidon2] with the changes needed to work in characteristic 2: the
+//! S-box is the power map $x \mapsto x^7$, which is a permutation of $GF(2^n)$ whenever $3 \nmid n$,
+//! and the external linear layer replaces the `2 * M4` diagonal blocks (which vanish in
+//! characteristic 2) with `c * M4` for a field constant `c`.
+//!
+//! This is an experimental hash candidate intended for comparing recursion costs against
+//! [Vision Mark-32](crate::Vision32bPermutation). The round numbers have not been subjected to a
+//! dedicated cryptanalysis and may change.
+//!
+//! [Poseidon2]: <https://eprint.iacr.org/2023/323>
+
+pub mod compression;
+pub mod constants;
+pub mod digest;
+pub mod permutation;
+
+pub use compression::*;
+pub use constants::*;
+pub use digest::*;
+pub use permutation::*;
@@ -0,0 +1,337 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::array;
+
+use binius_field::{BinaryField, BinaryField32b, BinaryField64b, BinaryField8b, PackedField};
+use lazy_static::lazy_static;
+
+use super::constants::{
+	sample_nonzero_constants, POSEIDON2_B32_WIDTH, POSEIDON2_B64_WIDTH, POSEIDON2_EXTERNAL_ROUNDS,
+	POSEIDON2_INTERNAL_ROUNDS,
+};
+use crate::permutation::{CryptographicPermutation, Permutation};
+
+/// Poseidon2-style permutation over `BinaryField32b` with a 512-bit state.
+pub type Poseidon2b32Permutation = Poseidon2Permutation<BinaryField32b, POSEIDON2_B32_WIDTH>;
+
+/// Poseidon2-style permutation over `BinaryField64b` with a 512-bit state.
+pub type Poseidon2b64Permutation = Poseidon2Permutation<BinaryField64b, POSEIDON2_B64_WIDTH>;
+
+lazy_static! {
+	pub static ref POSEIDON2_B32_PERMUTATION: Poseidon2b32Permutation =
+		Poseidon2b32Permutation::default();
+	pub static ref POSEIDON2_B64_PERMUTATION: Poseidon2b64Permutation =
+		Poseidon2b64Permutation::default();
+}
+
+/// A Poseidon2-style permutation of `T` elements of a binary field `F`.
+///
+/// The permutation implements `Permutation` and `CryptographicPermutation` for arrays of any packed
+/// field with scalar `F`. Each lane of a packed state is permuted independently, so a state of
+/// `PackedBinaryField16x32b` computes 16 permutation instances at once.
+///
+/// `T` must be a multiple of 4.
+#[derive(Debug, Clone)]
+pub struct Poseidon2Permutation<F, const T: usize> {
+	/// Round constants for the full rounds.
+	external_round_constants: Vec<[F; T]>,
+	/// Round constants for the partial rounds, added only to the first state element.
+	internal_round_constants: Vec<F>,
+	/// The diagonal `D` of the internal matrix `J + D`, where `J` is the all-ones matrix.
+	internal_diagonal: [F; T],
+	/// The coefficient `a` of the 4x4 MDS block `circ(a, a + 1, 1, 1)`.
+	mds_coeff: F,
+	/// The coefficient `c` of the external matrix `(J + c I) ⊗ M4`.
+	block_coeff: F,
+}
+
+impl<F: BinaryField, const T: usize> Poseidon2Permutation<F, T> {
+	/// Constructs the permutation, sampling all constants from the given domain separator.
+	///
+	/// ## Preconditions
+	///
+	/// * `T` must be a non-zero multiple of 4
+	/// * `mds_coeff` must make `circ(mds_coeff, mds_coeff + 1, 1, 1)` an MDS matrix
+	pub fn new(domain: &[u8], mds_coeff: F) -> Self {
+		assert!(T != 0 && T % 4 == 0, "state width must be a non-zero multiple of 4");
+
+		let external_round_constants = sample_nonzero_constants::<F>(
+			&[domain, b" EXTERNAL"].concat(),
+			POSEIDON2_EXTERNAL_ROUNDS * T,
+		)
+		.chunks_exact(T)
+		.map(|chunk| chunk.try_into().expect("chunk has T elements"))
+		.collect();
+		let internal_round_constants = sample_nonzero_constants::<F>(
+			&[domain, b" INTERNAL"].concat(),
+			POSEIDON2_INTERNAL_ROUNDS,
+		);
+		let internal_diagonal = sample_invertible_internal_diagonal(domain);
+
+		Self {
+			external_round_constants,
+			internal_round_constants,
+			internal_diagonal,
+			mds_coeff,
+			block_coeff: mds_coeff + F::ONE,
+		}
+	}
+
+	/// The diagonal of the internal matrix.
+	pub fn internal_diagonal(&self) -> &[F; T] {
+		&self.internal_diagonal
+	}
+
+	/// Multiplies a block of four elements by `circ(a, a + 1, 1, 1)`.
+	#[inline]
+	fn apply_m4<P: PackedField<Scalar = F>>(&self, x: &mut [P]) {
+		debug_assert_eq!(x.len(), 4);
+
+		// With s = x0 + x1 + x2 + x3, row i of the circulant equals s + x_i + a (x_i + x_{i+1}).
+		let mds_coeff = P::broadcast(self.mds_coeff);
+		let sum = x[0] + x[1] + x[2] + x[3];
+		let x0 = x[0];
+		for i in 0..4 {
+			let next = if i == 3 { x0 } else { x[i + 1] };
+			x[i] += sum + mds_coeff * (x[i] + next);
+		}
+	}
+
+	/// Multiplies the state by the external matrix `(J + c I) ⊗ M4`.
+	#[inline]
+	pub fn external_linear_layer<P: PackedField<Scalar = F>>(&self, state: &mut [P; T]) {
+		for block in state.chunks_exact_mut(4) {
+			self.apply_m4(block);
+		}
+
+		let block_sums: [P; 4] = array::from_fn(|j| state.iter().skip(j).step_by(4).copied().sum());
+		let block_coeff = P::broadcast(self.block_coeff);
+		for block in state.chunks_exact_mut(4) {
+			for (x, &sum) in block.iter_mut().zip(&block_sums) {
+				*x = sum + block_coeff * *x;
+			}
+		}
+	}
+
+	/// Multiplies the state by the internal matrix `J + D`.
+	#[inline]
+	pub fn internal_linear_layer<P: PackedField<Scalar = F>>(&self, state: &mut [P; T]) {
+		let sum: P = state.iter().copied().sum();
+		for (x, &diag) in state.iter_mut().zip(&self.internal_diagonal) {
+			*x = sum + *x * diag;
+		}
+	}
+
+	fn external_round<P: PackedField<Scalar = F>>(
+		&self,
+		state: &mut [P; T],
+		round_constants: &[F; T],
+	) {
+		for (x, &rc) in state.iter_mut().zip(round_constants) {
+			*x = sbox(*x + P::broadcast(rc));
+		}
+		self.external_linear_layer(state);
+	}
+
+	fn internal_round<P: PackedField<Scalar = F>>(&self, state: &mut [P; T], round_constant: F) {
+		state[0] = sbox(state[0] + P::broadcast(round_constant));
+		self.internal_linear_layer(state);
+	}
+}
+
+impl Default for Poseidon2b32Permutation {
+	fn default() -> Self {
+		Self::new(b"BINIUS POSEIDON2 B32", BinaryField8b::MULTIPLICATIVE_GENERATOR.into())
+	}
+}
+
+impl Default for Poseidon2b64Permutation {
+	fn default() -> Self {
+		Self::new(b"BINIUS POSEIDON2 B64", BinaryField8b::MULTIPLICATIVE_GENERATOR.into())
+	}
+}
+
+impl<F, P, const T: usize> Permutation<[P; T]> for Poseidon2Permutation<F, T>
+where
+	F: BinaryField,
+	P: PackedField<Scalar = F>,
+{
+	fn permute_mut(&self, state: &mut [P; T]) {
+		let (initial_round_constants, terminal_round_constants) = self
+			.external_round_constants
+			.split_at(POSEIDON2_EXTERNAL_ROUNDS / 2);
+
+		self.external_linear_layer(state);
+		for round_constants in initial_round_constants {
+			self.external_round(state, round_constants);
+		}
+		for &round_constant in &self.internal_round_constants {
+			self.internal_round(state, round_constant);
+		}
+		for round_constants in terminal_round_constants {
+			self.external_round(state, round_constants);
+		}
+	}
+}
+
+impl<F, P, const T: usize> CryptographicPermutation<[P; T]> for Poseidon2Permutation<F, T>
+where
+	F: BinaryField,
+	P: PackedField<Scalar = F>,
+{
+}
+
+/// The S-box $x \mapsto x^7$.
+#[inline]
+fn sbox<P: PackedField>(x: P) -> P {
+	let x2 = x.square();
+	let x4 = x2.square();
+	x4 * x2 * x
+}
+
+/// Samples the diagonal `D` such that `J + D` is invertible.
+///
+/// For a diagonal with non-zero entries, $\det(J + D) = \prod_i d_i (1 + \sum_i d_i^{-1})$, so the
+/// candidate diagonals are resampled until the sum of inverses differs from one.
+fn sample_invertible_internal_diagonal<F: BinaryField, const T: usize>(domain: &[u8]) -> [F; T] {
+	(0u64..)
+		.map(|attempt| {
+			let diagonal = sample_nonzero_constants::<F>(
+				&[domain, b" DIAGONAL", &attempt.to_le_bytes()].concat(),
+				T,
+			);
+			<[F; T]>::try_from(diagonal).expect("sampled exactly T elements")
+		})
+		.find(|diagonal| {
+			diagonal
+				.iter()
+				.map(|d| d.invert().expect("constants are non-zero"))
+				.sum::<F>() != F::ONE
+		})
+		.expect("iterator is infinite")
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{Field, PackedBinaryField16x32b, PackedBinaryField8x64b};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	/// Computes the determinant of a square matrix with Gaussian elimination.
+	fn determinant<F: Field>(mut matrix: Vec<Vec<F>>) -> F {
+		let n = matrix.len();
+		let mut det = F::ONE;
+		for col in 0..n {
+			let Some(pivot) = (col..n).find(|&row| matrix[row][col] != F::ZERO) else {
+				return F::ZERO;
+			};
+			matrix.swap(col, pivot);
+			let pivot_inv = matrix[col][col].invert().expect("pivot is non-zero");
+			det *= matrix[col][col];
+			for row in col + 1..n {
+				let factor = matrix[row][col] * pivot_inv;
+				for k in col..n {
+					let delta = factor * matrix[col][k];
+					matrix[row][k] += delta;
+				}
+			}
+		}
+		det
+	}
+
+	/// Returns the matrix of a linear map on `[F; T]` as a vector of columns.
+	fn matrix_of<F: Field, const T: usize>(map: impl Fn(&mut [F; T])) -> Vec<Vec<F>> {
+		(0..T)
+			.map(|i| {
+				let mut unit = [F::ZERO; T];
+				unit[i] = F::ONE;
+				map(&mut unit);
+				unit.to_vec()
+			})
+			.collect()
+	}
+
+	fn check_m4_is_mds<F: BinaryField, const T: usize>(permutation: &Poseidon2Permutation<F, T>) {
+		let m4 = matrix_of::<F, 4>(|x| permutation.apply_m4(x));
+		for mask_rows in 1..16u32 {
+			for mask_cols in 1..16u32 {
+				if mask_rows.count_ones() != mask_cols.count_ones() {
+					continue;
+				}
+				let minor = (0..4)
+					.filter(|i| mask_rows & (1 << i) != 0)
+					.map(|i| {
+						(0..4)
+							.filter(|j| mask_cols & (1 << j) != 0)
+							.map(|j| m4[i][j])
+							.collect()
+					})
+					.collect();
+				assert_ne!(determinant(minor), F::ZERO);
+			}
+		}
+	}
+
+	fn check_linear_layers_invertible<F: BinaryField, const T: usize>(
+		permutation: &Poseidon2Permutation<F, T>,
+	) {
+		let external = matrix_of::<F, T>(|x| permutation.external_linear_layer(x));
+		assert_ne!(determinant(external), F::ZERO);
+		let internal = matrix_of::<F, T>(|x| permutation.internal_linear_layer(x));
+		assert_ne!(determinant(internal), F::ZERO);
+	}
+
+	fn check_packed_consistency<P, const T: usize>(permutation: &Poseidon2Permutation<P::Scalar, T>)
+	where
+		P: PackedField<Scalar: BinaryField>,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		let input: [P; T] = array::from_fn(|_| P::random(&mut rng));
+		let output = permutation.permute(input);
+
+		for lane in 0..P::WIDTH {
+			let scalar_input: [P::Scalar; T] = array::from_fn(|i| input[i].get(lane));
+			let scalar_output = permutation.permute(scalar_input);
+			for i in 0..T {
+				assert_eq!(output[i].get(lane), scalar_output[i]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_m4_is_mds() {
+		check_m4_is_mds(&*POSEIDON2_B32_PERMUTATION);
+		check_m4_is_mds(&*POSEIDON2_B64_PERMUTATION);
+	}
+
+	#[test]
+	fn test_linear_layers_are_invertible() {
+		check_linear_layers_invertible(&*POSEIDON2_B32_PERMUTATION);
+		check_linear_layers_invertible(&*POSEIDON2_B64_PERMUTATION);
+	}
+
+	#[test]
+	fn test_packed_permutation_matches_scalar() {
+		check_packed_consistency::<PackedBinaryField16x32b, POSEIDON2_B32_WIDTH>(
+			&POSEIDON2_B32_PERMUTATION,
+		);
+		check_packed_consistency::<PackedBinaryField8x64b, POSEIDON2_B64_WIDTH>(
+			&POSEIDON2_B64_PERMUTATION,
+		);
+	}
+
+	#[test]
+	fn test_permutation_is_not_identity() {
+		let input = [BinaryField32b::ZERO; POSEIDON2_B32_WIDTH];
+		let output = POSEIDON2_B32_PERMUTATION.permute(input);
+		assert_ne!(input, output);
+
+		let mut other_input = input;
+		other_input[POSEIDON2_B32_WIDTH - 1] = BinaryField32b::ONE;
+		let other_output = POSEIDON2_B32_PERMUTATION.permute(other_input);
+		for (a, b) in output.iter().zip(&other_output) {
+			assert_ne!(a, b);
+		}
+	}
+}
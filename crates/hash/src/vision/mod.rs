@@ -4,8 +4,10 @@ pub mod compression;
 pub mod constants;
 pub mod digest;
 pub mod permutation;
+pub mod sponge;
 
 pub use compression::*;
 pub use constants::*;
 pub use digest::*;
 pub use permutation::{Vision32MDSTransform, Vision32bPermutation, INV_PACKED_TRANS_AES};
+pub use sponge::*;
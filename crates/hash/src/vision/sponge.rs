@@ -0,0 +1,305 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A general-purpose sponge over the Vision Mark-32 permutation.
+
+use std::array;
+
+use binius_field::{
+	linear_transformation::Transformation, BinaryField32b, Field, PackedAESBinaryField8x32b,
+	PackedBinaryField8x32b, PackedField,
+};
+
+use super::{
+	digest::{TRANS_AES_TO_CANONICAL, TRANS_CANONICAL_TO_AES},
+	permutation::PERMUTATION,
+};
+use crate::permutation::Permutation;
+
+/// The number of `BinaryField32b` elements in the Vision Mark-32 state.
+pub const VISION_STATE_SIZE: usize = 24;
+
+/// The rate used by [`VisionHasherDigest`](super::VisionHasherDigest), in field elements.
+pub const VISION_DEFAULT_RATE: usize = 16;
+
+/// Padding that starts right after the absorbed elements, matching the first padding byte of the
+/// byte-oriented digest.
+const PADDING_START: BinaryField32b = BinaryField32b::new(0x80);
+/// Padding that ends the final block, matching the last padding byte of the byte-oriented digest.
+const PADDING_END: BinaryField32b = BinaryField32b::new(0x01 << 24);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpongeMode {
+	Absorbing,
+	Squeezing,
+}
+
+/// A duplex sponge over the Vision Mark-32 permutation with a configurable rate.
+///
+/// The sponge absorbs in overwrite mode: each block of `rate` elements replaces the rate portion
+/// of the state before the permutation is applied. When switching from absorbing to squeezing, the
+/// last block is padded with the element equivalent of the Keccak `10*1` byte padding. With the
+/// default rate of 16 and no domain separator, absorbing the little-endian `u32` words of a
+/// message and squeezing 8 elements gives exactly the [`VisionHasherDigest`] output.
+///
+/// The domain separator is written into the last capacity element of the initial state, so sponges
+/// with different separators are independent random functions.
+///
+/// [`VisionHasherDigest`]: super::VisionHasherDigest
+#[derive(Debug, Clone)]
+pub struct VisionSponge {
+	state: [BinaryField32b; VISION_STATE_SIZE],
+	rate: usize,
+	mode: SpongeMode,
+	position: usize,
+}
+
+impl Default for VisionSponge {
+	fn default() -> Self {
+		Self::new(VISION_DEFAULT_RATE, BinaryField32b::ZERO)
+	}
+}
+
+impl VisionSponge {
+	/// Creates a sponge with the given rate and domain separator.
+	///
+	/// The capacity is `VISION_STATE_SIZE - rate` elements.
+	///
+	/// ## Panics
+	///
+	/// * if `rate` is zero or leaves no capacity
+	pub fn new(rate: usize, domain_separator: BinaryField32b) -> Self {
+		assert!(
+			rate > 0 && rate < VISION_STATE_SIZE,
+			"rate must be between 1 and {} elements",
+			VISION_STATE_SIZE - 1
+		);
+
+		let mut state = [BinaryField32b::ZERO; VISION_STATE_SIZE];
+		state[VISION_STATE_SIZE - 1] = domain_separator;
+		Self {
+			state,
+			rate,
+			mode: SpongeMode::Absorbing,
+			position: 0,
+		}
+	}
+
+	/// The number of elements absorbed or squeezed per permutation.
+	pub const fn rate(&self) -> usize {
+		self.rate
+	}
+
+	/// The number of state elements that are never directly read or written.
+	pub const fn capacity(&self) -> usize {
+		VISION_STATE_SIZE - self.rate
+	}
+
+	/// Absorbs field elements into the sponge.
+	///
+	/// Absorbing after squeezing starts a new input block, so the sponge can be used as a duplex
+	/// object, e.g. for Fiat-Shamir challengers.
+	pub fn absorb(&mut self, elems: &[BinaryField32b]) {
+		if self.mode == SpongeMode::Squeezing {
+			self.mode = SpongeMode::Absorbing;
+			self.position = 0;
+		}
+
+		for &elem in elems {
+			if self.position == self.rate {
+				self.permute();
+				self.position = 0;
+			}
+			self.state[self.position] = elem;
+			self.position += 1;
+		}
+	}
+
+	/// Squeezes `n` field elements out of the sponge.
+	pub fn squeeze(&mut self, n: usize) -> Vec<BinaryField32b> {
+		let mut out = vec![BinaryField32b::ZERO; n];
+		self.squeeze_into(&mut out);
+		out
+	}
+
+	/// Fills `out` with field elements squeezed out of the sponge.
+	pub fn squeeze_into(&mut self, out: &mut [BinaryField32b]) {
+		if self.mode == SpongeMode::Absorbing {
+			self.pad_and_permute();
+			self.mode = SpongeMode::Squeezing;
+			self.position = 0;
+		}
+
+		for elem in out {
+			if self.position == self.rate {
+				self.permute();
+				self.position = 0;
+			}
+			*elem = self.state[self.position];
+			self.position += 1;
+		}
+	}
+
+	fn pad_and_permute(&mut self) {
+		// A full block is only permuted once more input arrives, so padding then goes into a
+		// block of its own.
+		if self.position == self.rate {
+			self.permute();
+			self.position = 0;
+		}
+
+		self.state[self.position..self.rate].fill(BinaryField32b::ZERO);
+		self.state[self.position] += PADDING_START;
+		self.state[self.rate - 1] += PADDING_END;
+		self.permute();
+	}
+
+	fn permute(&mut self) {
+		let mut packed: [PackedAESBinaryField8x32b; 3] = array::from_fn(|i| {
+			let canonical = PackedBinaryField8x32b::from_fn(|j| self.state[8 * i + j]);
+			TRANS_CANONICAL_TO_AES.transform(&canonical)
+		});
+		PERMUTATION.permute_mut(&mut packed);
+		for (chunk, packed) in self.state.chunks_exact_mut(8).zip(&packed) {
+			let canonical: PackedBinaryField8x32b = TRANS_AES_TO_CANONICAL.transform(packed);
+			for (elem, value) in chunk.iter_mut().zip(canonical.iter()) {
+				*elem = value;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use digest::Digest;
+	use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+	use super::*;
+	use crate::VisionHasherDigest;
+
+	fn bytes_to_elems(bytes: &[u8]) -> Vec<BinaryField32b> {
+		bytes
+			.chunks_exact(4)
+			.map(|chunk| BinaryField32b::new(u32::from_le_bytes(chunk.try_into().unwrap())))
+			.collect()
+	}
+
+	fn elems_to_bytes(elems: &[BinaryField32b]) -> Vec<u8> {
+		elems
+			.iter()
+			.flat_map(|elem| elem.val().to_le_bytes())
+			.collect()
+	}
+
+	#[test]
+	fn test_matches_digest() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for n_elems in [0, 1, 15, 16, 17, 32, 40] {
+			let mut bytes = vec![0u8; 4 * n_elems];
+			rng.fill_bytes(&mut bytes);
+
+			let mut sponge = VisionSponge::default();
+			sponge.absorb(&bytes_to_elems(&bytes));
+			let out = elems_to_bytes(&sponge.squeeze(8));
+
+			assert_eq!(out, VisionHasherDigest::digest(&bytes).to_vec());
+		}
+	}
+
+	#[test]
+	fn test_simple_vector() {
+		// Same vector as the digest test for the input 0xdeadbeef.
+		let mut sponge = VisionSponge::default();
+		sponge.absorb(&bytes_to_elems(&[0xde, 0xad, 0xbe, 0xef]));
+		assert_eq!(
+			elems_to_bytes(&sponge.squeeze(8)),
+			hex_literal::hex!("8ed389809fabe91cead4786eb08e2d32647a9ac69143040de500e4465c72f173")
+		);
+	}
+
+	#[test]
+	fn test_absorb_in_pieces() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let elems = repeat_random(&mut rng, 50);
+
+		let mut whole = VisionSponge::default();
+		whole.absorb(&elems);
+
+		let mut pieces = VisionSponge::default();
+		pieces.absorb(&elems[..7]);
+		pieces.absorb(&elems[7..16]);
+		pieces.absorb(&elems[16..]);
+
+		assert_eq!(whole.squeeze(40), pieces.squeeze(40));
+	}
+
+	#[test]
+	fn test_squeeze_in_pieces() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let elems = repeat_random(&mut rng, 10);
+
+		let mut whole = VisionSponge::new(8, BinaryField32b::ZERO);
+		whole.absorb(&elems);
+		let expected = whole.squeeze(30);
+
+		let mut pieces = VisionSponge::new(8, BinaryField32b::ZERO);
+		pieces.absorb(&elems);
+		let mut squeezed = pieces.squeeze(3);
+		squeezed.extend(pieces.squeeze(13));
+		squeezed.extend(pieces.squeeze(14));
+
+		assert_eq!(squeezed, expected);
+	}
+
+	#[test]
+	fn test_domain_separation() {
+		let elems = [BinaryField32b::new(1), BinaryField32b::new(2)];
+
+		let mut sponge_a = VisionSponge::new(VISION_DEFAULT_RATE, BinaryField32b::new(1));
+		sponge_a.absorb(&elems);
+		let mut sponge_b = VisionSponge::new(VISION_DEFAULT_RATE, BinaryField32b::new(2));
+		sponge_b.absorb(&elems);
+
+		assert_ne!(sponge_a.squeeze(8), sponge_b.squeeze(8));
+	}
+
+	#[test]
+	fn test_rate_changes_output() {
+		let elems = [BinaryField32b::new(1), BinaryField32b::new(2)];
+
+		let mut sponge_a = VisionSponge::new(8, BinaryField32b::ZERO);
+		sponge_a.absorb(&elems);
+		let mut sponge_b = VisionSponge::new(12, BinaryField32b::ZERO);
+		sponge_b.absorb(&elems);
+
+		assert_eq!(sponge_a.capacity(), 16);
+		assert_ne!(sponge_a.squeeze(8), sponge_b.squeeze(8));
+	}
+
+	#[test]
+	fn test_duplex() {
+		let mut sponge_a = VisionSponge::default();
+		sponge_a.absorb(&[BinaryField32b::new(1)]);
+		let first = sponge_a.squeeze(2);
+		sponge_a.absorb(&[BinaryField32b::new(2)]);
+		let second_a = sponge_a.squeeze(2);
+
+		let mut sponge_b = VisionSponge::default();
+		sponge_b.absorb(&[BinaryField32b::new(1)]);
+		assert_eq!(sponge_b.squeeze(2), first);
+		sponge_b.absorb(&[BinaryField32b::new(3)]);
+
+		assert_ne!(sponge_b.squeeze(2), second_a);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_rate_without_capacity() {
+		VisionSponge::new(VISION_STATE_SIZE, BinaryField32b::ZERO);
+	}
+
+	fn repeat_random(mut rng: impl RngCore, n: usize) -> Vec<BinaryField32b> {
+		(0..n)
+			.map(|_| BinaryField32b::new(rng.next_u32()))
+			.collect()
+	}
+}
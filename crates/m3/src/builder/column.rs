@@ -134,10 +134,17 @@ pub enum ColumnDef<F: TowerField = B128> {
 		col: ColumnId,
 		log_degree: usize,
 	},
+	Unpacked {
+		col: ColumnId,
+		log_degree: usize,
+	},
 	Computed {
 		cols: Vec<ColumnIndex>,
 		expr: ArithExpr<F>,
 	},
+	Concatenated {
+		cols: Vec<ColumnId>,
+	},
 	Constant {
 		poly: Arc<dyn MultivariatePoly<F>>,
 	},
@@ -4,7 +4,11 @@ pub use binius_core::constraint_system::channel::{
 	Boundary, Flush as CompiledFlush, FlushDirection,
 };
 use binius_core::{
-	constraint_system::{channel::ChannelId, ConstraintSystem as CompiledConstraintSystem},
+	constraint_system::{
+		channel::{ChannelId, FlushMultiplicity},
+		permutation::Permutation as CompiledPermutation,
+		ConstraintSystem as CompiledConstraintSystem,
+	},
 	oracle::{
 		Constraint, ConstraintPredicate, ConstraintSet, MultilinearOracleSet, OracleId,
 		ProjectionVariant,
@@ -21,7 +25,7 @@ use super::{
 	column::{ColumnDef, ColumnInfo},
 	error::Error,
 	statement::Statement,
-	table::TablePartition,
+	table::{Permutation, TablePartition},
 	types::B128,
 	witness::{TableWitnessIndex, WitnessIndex},
 	Table, TableBuilder,
@@ -185,6 +189,7 @@ impl<F: TowerField> ConstraintSystem<F> {
 		let mut oracles = MultilinearOracleSet::new();
 		let mut table_constraints = Vec::new();
 		let mut compiled_flushes = Vec::new();
+		let mut compiled_permutations = Vec::new();
 		let mut non_zero_oracle_ids = Vec::new();
 
 		for (table, &count) in std::iter::zip(&self.tables, &statement.table_sizes) {
@@ -225,6 +230,7 @@ impl<F: TowerField> ConstraintSystem<F> {
 					flushes,
 					zero_constraints,
 					values_per_row,
+					permutations,
 					..
 				} = partition;
 
@@ -256,7 +262,19 @@ impl<F: TowerField> ConstraintSystem<F> {
 						channel_id: *channel_id,
 						direction: *direction,
 						selector: selector.unwrap_or(step_down),
-						multiplicity: 1,
+						multiplicity: FlushMultiplicity::Constant(1),
+					});
+				}
+
+				// Translate permutations for the compiled constraint system.
+				for Permutation { a, b } in permutations {
+					compiled_permutations.push(CompiledPermutation {
+						a: a.iter()
+							.map(|&column_index| oracle_lookup[column_index])
+							.collect(),
+						b: b.iter()
+							.map(|&column_index| oracle_lookup[column_index])
+							.collect(),
 					});
 				}
 
@@ -287,6 +305,7 @@ impl<F: TowerField> ConstraintSystem<F> {
 			non_zero_oracle_ids,
 			max_channel_id: self.channels.len().saturating_sub(1),
 			exponents: Vec::new(),
+			permutations: compiled_permutations,
 		})
 	}
 }
@@ -349,6 +368,9 @@ fn add_oracle_for_column<F: TowerField>(
 			// TODO: debug assert column at col.table_index has the same values_per_row as col.id
 			addition.packed(oracle_lookup[col.table_index], *log_degree)?
 		}
+		ColumnDef::Unpacked { col, log_degree } => {
+			addition.unpacked(oracle_lookup[col.table_index], *log_degree)?
+		}
 		ColumnDef::Computed { cols, expr } => {
 			if let Ok(LinearNormalForm {
 				constant: offset,
@@ -369,6 +391,9 @@ fn add_oracle_for_column<F: TowerField>(
 				addition.composite_mle(n_vars, inner_oracles, expr.clone())?
 			}
 		}
+		ColumnDef::Concatenated { cols } => {
+			addition.concatenated(n_vars, cols.iter().map(|col| oracle_lookup[col.table_index]))?
+		}
 		ColumnDef::Constant { .. } => addition.repeating(
 			transparent_single[id.table_index].unwrap(),
 			n_vars - shape.log_values_per_row,
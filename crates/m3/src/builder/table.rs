@@ -4,7 +4,10 @@ use std::sync::Arc;
 
 use binius_core::{
 	constraint_system::channel::{ChannelId, FlushDirection},
-	oracle::ShiftVariant,
+	oracle::{
+		AuxiliaryOracleKind, Constraint, ConstraintPredicate, ConstraintSet, DegreeReduction,
+		MultilinearOracleSet, ShiftVariant,
+	},
 	transparent::MultilinearExtensionTransparent,
 };
 use binius_field::{
@@ -13,6 +16,7 @@ use binius_field::{
 	packed::pack_slice,
 	ExtensionField, TowerField,
 };
+use binius_math::ArithExpr;
 use binius_utils::{
 	checked_arithmetics::{checked_log_2, log2_strict_usize},
 	sparse_index::SparseIndex,
@@ -21,6 +25,7 @@ use binius_utils::{
 use super::{
 	channel::Flush,
 	column::{upcast_col, Col, ColumnDef, ColumnInfo, ColumnShape},
+	error::Error,
 	expr::{Expr, ZeroConstraint},
 	types::B128,
 	ColumnIndex,
@@ -131,6 +136,35 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		)
 	}
 
+	/// Adds a column viewing a committed column over a subfield, with each value split into its
+	/// tower basis coordinates.
+	///
+	/// This is the inverse of [`Self::add_packed`]. The inner column must be committed.
+	pub fn add_unpacked<FSup, const VALUES_PER_ROW_SUP: usize, FSub, const VALUES_PER_ROW: usize>(
+		&mut self,
+		name: impl ToString,
+		col: Col<FSup, VALUES_PER_ROW_SUP>,
+	) -> Col<FSub, VALUES_PER_ROW>
+	where
+		FSub: TowerField,
+		FSup: TowerField + ExtensionField<FSub>,
+		F: ExtensionField<FSup> + ExtensionField<FSub>,
+	{
+		assert!(FSub::TOWER_LEVEL < FSup::TOWER_LEVEL);
+		assert!(VALUES_PER_ROW_SUP < VALUES_PER_ROW);
+		assert_eq!(
+			FSub::TOWER_LEVEL + log2_strict_usize(VALUES_PER_ROW),
+			FSup::TOWER_LEVEL + log2_strict_usize(VALUES_PER_ROW_SUP)
+		);
+		self.table.new_column(
+			self.namespaced_name(name),
+			ColumnDef::Unpacked {
+				col: col.id(),
+				log_degree: FSup::TOWER_LEVEL - FSub::TOWER_LEVEL,
+			},
+		)
+	}
+
 	pub fn add_computed<FSub, const V: usize>(
 		&mut self,
 		name: impl ToString,
@@ -175,6 +209,29 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 		)
 	}
 
+	/// Adds a column holding the values of all the given columns, one after the other, followed by
+	/// zeros.
+	///
+	/// The values are concatenated over the whole table, not row by row, so a row of the new column
+	/// generally does not hold values from the same row of the inner columns. The column is
+	/// populated from the inner columns when the witness is converted to a
+	/// [`MultilinearExtensionIndex`](binius_core::witness::MultilinearExtensionIndex).
+	pub fn add_concatenated<FSub, const VALUES_PER_ROW_INNER: usize, const VALUES_PER_ROW: usize>(
+		&mut self,
+		name: impl ToString,
+		cols: impl IntoIterator<Item = Col<FSub, VALUES_PER_ROW_INNER>>,
+	) -> Col<FSub, VALUES_PER_ROW>
+	where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		let cols = cols.into_iter().map(|col| col.id()).collect::<Vec<_>>();
+		assert!(!cols.is_empty());
+		assert!(cols.len() * VALUES_PER_ROW_INNER <= VALUES_PER_ROW);
+		self.table
+			.new_column(self.namespaced_name(name), ColumnDef::Concatenated { cols })
+	}
+
 	pub fn add_selected<FSub, const VALUES_PER_ROW: usize>(
 		&mut self,
 		name: impl ToString,
@@ -292,6 +349,34 @@ impl<'a, F: TowerField> TableBuilder<'a, F> {
 			.push_selected(channel, cols, selector);
 	}
 
+	/// Constrains the rows of the columns in `a` to be a permutation of the rows of the columns in
+	/// `b`.
+	///
+	/// The constraint covers all rows up to the table capacity, including the padding rows.
+	pub fn assert_permutation<FSub, const V: usize>(
+		&mut self,
+		a: impl IntoIterator<Item = Col<FSub, V>>,
+		b: impl IntoIterator<Item = Col<FSub, V>>,
+	) where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		self.table.partition_mut(V).assert_permutation(a, b)
+	}
+
+	/// Rewrites the zero constraints asserted so far to have compositions of at most the target
+	/// degree.
+	///
+	/// Subexpressions exceeding the degree are replaced with auxiliary columns, one reduction per
+	/// table partition. Their witnesses are computed from the other columns when the witness is
+	/// converted to a
+	/// [`MultilinearExtensionIndex`](binius_core::witness::MultilinearExtensionIndex), so they
+	/// need not be populated by the table filler.
+	pub fn reduce_degree(&mut self, reduction: DegreeReduction) -> Result<(), Error> {
+		let name = self.namespaced_name("degree_reduction");
+		self.table.reduce_degree(reduction, &name)
+	}
+
 	fn namespaced_name(&self, name: impl ToString) -> String {
 		let name = name.to_string();
 		match &self.namespace {
@@ -315,6 +400,17 @@ pub struct Table<F: TowerField = B128> {
 	pub name: String,
 	pub columns: Vec<ColumnInfo<F>>,
 	pub(super) partitions: SparseIndex<TablePartition<F>>,
+	pub(super) auxiliary: Vec<AuxiliaryColumn<F>>,
+}
+
+/// A column introduced by degree reduction, whose values are a composition of other columns of
+/// the table.
+#[derive(Debug)]
+pub(super) struct AuxiliaryColumn<F: TowerField> {
+	pub col: ColumnIndex,
+	/// The columns the composition is evaluated over.
+	pub inner: Vec<ColumnIndex>,
+	pub composition: ArithExpr<F>,
 }
 
 /// A table partition describes a part of a table where everything has the same pack factor (as well as height)
@@ -328,6 +424,15 @@ pub(super) struct TablePartition<F: TowerField = B128> {
 	pub flushes: Vec<Flush>,
 	pub columns: Vec<ColumnIndex>,
 	pub zero_constraints: Vec<ZeroConstraint<F>>,
+	pub permutations: Vec<Permutation>,
+}
+
+/// A constraint that the rows of the columns in `a` are a permutation of the rows of the columns
+/// in `b`.
+#[derive(Debug)]
+pub(super) struct Permutation {
+	pub a: Vec<ColumnIndex>,
+	pub b: Vec<ColumnIndex>,
 }
 
 impl<F: TowerField> TablePartition<F> {
@@ -338,6 +443,7 @@ impl<F: TowerField> TablePartition<F> {
 			flushes: Vec::new(),
 			columns: Vec::new(),
 			zero_constraints: Vec::new(),
+			permutations: Vec::new(),
 		}
 	}
 
@@ -407,6 +513,29 @@ impl<F: TowerField> TablePartition<F> {
 		)
 	}
 
+	pub fn assert_permutation<FSub, const V: usize>(
+		&mut self,
+		a: impl IntoIterator<Item = Col<FSub, V>>,
+		b: impl IntoIterator<Item = Col<FSub, V>>,
+	) where
+		FSub: TowerField,
+		F: ExtensionField<FSub>,
+	{
+		let column_indices = |cols: Vec<Col<FSub, V>>| {
+			cols.into_iter()
+				.map(|col| {
+					assert_eq!(col.table_id, self.table_id);
+					col.table_index
+				})
+				.collect::<Vec<_>>()
+		};
+		let a = column_indices(a.into_iter().collect());
+		let b = column_indices(b.into_iter().collect());
+		assert!(!a.is_empty(), "permutation sides must not be empty");
+		assert_eq!(a.len(), b.len(), "permutation sides must have the same number of columns");
+		self.permutations.push(Permutation { a, b });
+	}
+
 	fn flush(
 		&mut self,
 		channel_id: ChannelId,
@@ -438,6 +567,7 @@ impl<F: TowerField> Table<F> {
 			name: name.to_string(),
 			columns: Vec::new(),
 			partitions: SparseIndex::new(),
+			auxiliary: Vec::new(),
 		}
 	}
 
@@ -454,9 +584,23 @@ impl<F: TowerField> Table<F> {
 		FSub: TowerField,
 		F: ExtensionField<FSub>,
 	{
+		let shape = ColumnShape {
+			tower_height: FSub::TOWER_LEVEL,
+			log_values_per_row: log2_strict_usize(V),
+		};
+		let (id, partition_index) = self.new_column_with_shape(name, col, shape);
+		Col::new(id, partition_index)
+	}
+
+	fn new_column_with_shape(
+		&mut self,
+		name: impl ToString,
+		col: ColumnDef<F>,
+		shape: ColumnShape,
+	) -> (ColumnId, usize) {
 		let table_id = self.id;
 		let table_index = self.columns.len();
-		let partition = self.partition_mut(V);
+		let partition = self.partition_mut(1 << shape.log_values_per_row);
 		let id = ColumnId {
 			table_id,
 			table_index,
@@ -465,17 +609,88 @@ impl<F: TowerField> Table<F> {
 			id,
 			col,
 			name: name.to_string(),
-			shape: ColumnShape {
-				tower_height: FSub::TOWER_LEVEL,
-				log_values_per_row: log2_strict_usize(V),
-			},
+			shape,
 			is_nonzero: false,
 		};
 
 		let partition_index = partition.columns.len();
 		partition.columns.push(table_index);
 		self.columns.push(info);
-		Col::new(id, partition_index)
+		(id, partition_index)
+	}
+
+	fn reduce_degree(&mut self, reduction: DegreeReduction, name: &str) -> Result<(), Error> {
+		let log_values_per_rows = self.partitions.keys().collect::<Vec<_>>();
+		for log_values_per_row in log_values_per_rows {
+			let partition = &mut self.partitions[log_values_per_row];
+			if partition.zero_constraints.is_empty() {
+				continue;
+			}
+
+			// Reduce the constraints over stand-in oracles, one per partition column, so that the
+			// variables of the rewritten constraints are partition indices.
+			let mut oracles = MultilinearOracleSet::new();
+			let mut oracle_columns = partition.columns.clone();
+			let oracle_ids = partition
+				.columns
+				.iter()
+				.map(|&col| oracles.add_committed(0, self.columns[col].shape.tower_height))
+				.collect();
+			let constraints = partition
+				.zero_constraints
+				.drain(..)
+				.map(|zero_constraint| Constraint {
+					name: zero_constraint.name,
+					composition: zero_constraint.expr,
+					predicate: ConstraintPredicate::Zero,
+				})
+				.collect();
+			let mut constraint_set = ConstraintSet {
+				n_vars: 0,
+				oracle_ids,
+				constraints,
+			};
+			let auxiliary = constraint_set.reduce_degree(&mut oracles, reduction, name)?;
+
+			for aux in auxiliary {
+				let inner = aux
+					.inner
+					.iter()
+					.map(|&oracle_id| oracle_columns[oracle_id])
+					.collect::<Vec<_>>();
+				let col = match aux.kind {
+					AuxiliaryOracleKind::Committed => ColumnDef::Committed {
+						tower_level: aux.tower_level,
+					},
+					AuxiliaryOracleKind::Composite => ColumnDef::Computed {
+						cols: inner.clone(),
+						expr: aux.composition.clone(),
+					},
+				};
+				let shape = ColumnShape {
+					tower_height: aux.tower_level,
+					log_values_per_row,
+				};
+				let (id, _) = self.new_column_with_shape(oracles.label(aux.id), col, shape);
+				debug_assert_eq!(oracle_columns.len(), aux.id);
+				oracle_columns.push(id.table_index);
+				self.auxiliary.push(AuxiliaryColumn {
+					col: id.table_index,
+					inner,
+					composition: aux.composition,
+				});
+			}
+
+			self.partitions[log_values_per_row].zero_constraints = constraint_set
+				.constraints
+				.into_iter()
+				.map(|constraint| ZeroConstraint {
+					name: constraint.name,
+					expr: constraint.composition,
+				})
+				.collect();
+		}
+		Ok(())
 	}
 
 	fn partition_mut(&mut self, values_per_row: usize) -> &mut TablePartition<F> {
@@ -2,22 +2,30 @@
 
 use std::{
 	cell::{Ref, RefCell, RefMut},
-	iter, slice,
+	fmt::Debug,
+	iter,
+	ops::{Deref, DerefMut},
+	slice,
 	sync::Arc,
 };
 
 use anyhow::ensure;
 use binius_core::{
-	oracle::OracleId, polynomial::ArithCircuitPoly, transparent::step_down::StepDown,
-	witness::MultilinearExtensionIndex,
+	oracle::OracleId,
+	polynomial::ArithCircuitPoly,
+	transparent::step_down::StepDown,
+	witness::{composite_witness, MultilinearExtensionIndex, MultilinearWitness},
 };
 use binius_field::{
 	arch::OptimalUnderlier,
 	as_packed_field::{PackScalar, PackedType},
+	packed::{get_packed_slice, set_packed_slice},
 	underlier::{UnderlierType, WithUnderlier},
 	ExtensionField, PackedField, TowerField,
 };
-use binius_math::{CompositionPoly, MultilinearExtension, MultilinearPoly};
+use binius_math::{
+	ArithExpr, CompositionPoly, MultilinearExtension, MultilinearPoly, PackingDeref,
+};
 use binius_maybe_rayon::prelude::*;
 use binius_utils::checked_arithmetics::{checked_log_2, log2_ceil_usize};
 use bytemuck::{must_cast_slice, must_cast_slice_mut, zeroed_vec, Pod};
@@ -70,12 +78,18 @@ impl<'cs, 'alloc, U: UnderlierType, F: TowerField> WitnessIndex<'cs, 'alloc, U,
 			+ PackScalar<B32>
 			+ PackScalar<B64>
 			+ PackScalar<B128>,
+		B128: ExtensionField<F>,
 	{
 		let mut index = MultilinearExtensionIndex::new();
 		let mut first_oracle_id_in_table = 0;
 		for table in self.tables {
 			let table_id = table.table_id();
-			let cols = immutable_witness_index_columns(table.cols);
+			let cols = immutable_witness_index_columns(
+				table.table,
+				table.oracle_offset,
+				table.log_capacity,
+				table.cols,
+			);
 
 			let mut count = 0;
 			for (oracle_id_offset, col) in cols.into_iter().enumerate() {
@@ -88,15 +102,46 @@ impl<'cs, 'alloc, U: UnderlierType, F: TowerField> WitnessIndex<'cs, 'alloc, U,
 				let n_vars = log_capacity + col.shape.log_values_per_row;
 				let underlier_count =
 					1 << (n_vars + col.shape.tower_height).saturating_sub(U::LOG_BITS);
-				let witness = multilin_poly_from_underlier_data(
-					&col.data[..underlier_count],
-					n_vars,
-					col.shape.tower_height,
-				);
+				let witness = match col.data {
+					ColumnData::Arena(data) => multilin_poly_from_underlier_data(
+						&data[..underlier_count],
+						n_vars,
+						col.shape.tower_height,
+					),
+					ColumnData::Heap(data) => {
+						multilin_poly_from_underlier_data(data, n_vars, col.shape.tower_height)
+					}
+				};
 				index.update_multilin_poly([(oracle_id, witness)]).unwrap();
 				count += 1;
 			}
 
+			// Auxiliary columns introduced by degree reduction are computed from the other columns,
+			// in order, as they may depend on earlier auxiliary columns.
+			let first_column_oracle_id = first_oracle_id_in_table + table.oracle_offset;
+			for aux in &table.table.auxiliary {
+				let shape = table.table.columns[aux.col].shape;
+				let n_vars = table.log_capacity + shape.log_values_per_row;
+				let inner = aux
+					.inner
+					.iter()
+					.map(|&col| {
+						index
+							.get_multilin_poly(first_column_oracle_id + col)
+							.expect("inner columns are inserted before auxiliary columns")
+					})
+					.collect();
+				let witness = composite_witness_from_tower_height::<U>(
+					n_vars,
+					inner,
+					&aux.composition.convert_field(),
+					shape.tower_height,
+				);
+				index
+					.update_multilin_poly([(first_column_oracle_id + aux.col, witness)])
+					.unwrap();
+			}
+
 			// Every table partition has a step_down appended to the end of the table to support non-power of two height tables
 			for log_values_per_row in table.selector_log_values_per_rows.into_iter() {
 				let oracle_id = first_oracle_id_in_table + count;
@@ -117,11 +162,37 @@ impl<'cs, 'alloc, U: UnderlierType, F: TowerField> WitnessIndex<'cs, 'alloc, U,
 	}
 }
 
-fn multilin_poly_from_underlier_data<U>(
-	data: &[U],
+fn composite_witness_from_tower_height<'a, U>(
+	n_vars: usize,
+	inner: Vec<MultilinearWitness<'a, PackedType<U, B128>>>,
+	composition: &ArithExpr<B128>,
+	tower_height: usize,
+) -> MultilinearWitness<'a, PackedType<U, B128>>
+where
+	U: PackScalar<B1>
+		+ PackScalar<B8>
+		+ PackScalar<B16>
+		+ PackScalar<B32>
+		+ PackScalar<B64>
+		+ PackScalar<B128>,
+{
+	let witness = match tower_height {
+		0 => composite_witness::<U, B128, B1>(n_vars, inner, composition),
+		3 => composite_witness::<U, B128, B8>(n_vars, inner, composition),
+		4 => composite_witness::<U, B128, B16>(n_vars, inner, composition),
+		5 => composite_witness::<U, B128, B32>(n_vars, inner, composition),
+		6 => composite_witness::<U, B128, B64>(n_vars, inner, composition),
+		7 => composite_witness::<U, B128, B128>(n_vars, inner, composition),
+		_ => panic!("Unsupported tower height: {tower_height}"),
+	};
+	witness.expect("auxiliary column tower height is that of its composition")
+}
+
+fn multilin_poly_from_underlier_data<'a, U, Data>(
+	data: Data,
 	n_vars: usize,
 	tower_height: usize,
-) -> Arc<dyn MultilinearPoly<PackedType<U, B128>> + Send + Sync + '_>
+) -> Arc<dyn MultilinearPoly<PackedType<U, B128>> + Send + Sync + 'a>
 where
 	U: PackScalar<B1>
 		+ PackScalar<B8>
@@ -129,24 +200,25 @@ where
 		+ PackScalar<B32>
 		+ PackScalar<B64>
 		+ PackScalar<B128>,
+	Data: Deref<Target = [U]> + Send + Sync + Debug + 'a,
 {
 	match tower_height {
-		0 => MultilinearExtension::new(n_vars, PackedType::<U, B1>::from_underliers_ref(data))
+		0 => MultilinearExtension::new(n_vars, PackingDeref::<U, B1, _>::new(data))
 			.unwrap()
 			.specialize_arc_dyn(),
-		3 => MultilinearExtension::new(n_vars, PackedType::<U, B8>::from_underliers_ref(data))
+		3 => MultilinearExtension::new(n_vars, PackingDeref::<U, B8, _>::new(data))
 			.unwrap()
 			.specialize_arc_dyn(),
-		4 => MultilinearExtension::new(n_vars, PackedType::<U, B16>::from_underliers_ref(data))
+		4 => MultilinearExtension::new(n_vars, PackingDeref::<U, B16, _>::new(data))
 			.unwrap()
 			.specialize_arc_dyn(),
-		5 => MultilinearExtension::new(n_vars, PackedType::<U, B32>::from_underliers_ref(data))
+		5 => MultilinearExtension::new(n_vars, PackingDeref::<U, B32, _>::new(data))
 			.unwrap()
 			.specialize_arc_dyn(),
-		6 => MultilinearExtension::new(n_vars, PackedType::<U, B64>::from_underliers_ref(data))
+		6 => MultilinearExtension::new(n_vars, PackingDeref::<U, B64, _>::new(data))
 			.unwrap()
 			.specialize_arc_dyn(),
-		7 => MultilinearExtension::new(n_vars, PackedType::<U, B128>::from_underliers_ref(data))
+		7 => MultilinearExtension::new(n_vars, PackingDeref::<U, B128, _>::new(data))
 			.unwrap()
 			.specialize_arc_dyn(),
 		_ => {
@@ -182,7 +254,7 @@ pub struct WitnessIndexColumn<'a, U: UnderlierType> {
 
 #[derive(Debug)]
 pub enum WitnessDataMut<'alloc, U: UnderlierType> {
-	Owned(&'alloc mut [U]),
+	Owned(ColumnStorage<'alloc, U>),
 	SameAsOracleIndex(usize),
 }
 
@@ -190,7 +262,75 @@ impl<'alloc, U: UnderlierType> WitnessDataMut<'alloc, U> {
 	pub fn new_owned(allocator: &'alloc bumpalo::Bump, log_underlier_count: usize) -> Self {
 		// TODO: Allocate uninitialized memory and avoid filling. That should be OK because
 		// Underlier is Pod.
-		Self::Owned(allocator.alloc_slice_fill_default(1 << log_underlier_count))
+		Self::Owned(ColumnStorage::Arena(
+			allocator.alloc_slice_fill_default(1 << log_underlier_count),
+		))
+	}
+
+	pub fn new_committed(log_underlier_count: usize) -> Self {
+		Self::Owned(ColumnStorage::Heap(
+			iter::repeat_n(U::default(), 1 << log_underlier_count).collect(),
+		))
+	}
+}
+
+/// Storage of a witness column that is being populated.
+#[derive(Debug)]
+pub enum ColumnStorage<'alloc, U: UnderlierType> {
+	/// Storage allocated in the arena.
+	Arena(&'alloc mut [U]),
+	/// Storage allocated on its own, which the multilinear extension index takes ownership of.
+	///
+	/// Committed columns are stored this way, so that the prover can release their memory once
+	/// they have been committed. The storage is not shared until the witness is complete.
+	Heap(Arc<[U]>),
+}
+
+impl<U: UnderlierType> Deref for ColumnStorage<'_, U> {
+	type Target = [U];
+
+	fn deref(&self) -> &[U] {
+		match self {
+			Self::Arena(data) => data,
+			Self::Heap(data) => data,
+		}
+	}
+}
+
+impl<U: UnderlierType> DerefMut for ColumnStorage<'_, U> {
+	fn deref_mut(&mut self) -> &mut [U] {
+		match self {
+			Self::Arena(data) => data,
+			Self::Heap(data) => Arc::get_mut(data)
+				.expect("column storage is not shared until the witness is complete"),
+		}
+	}
+}
+
+/// Storage of a complete witness column.
+#[derive(Debug, Clone)]
+pub enum ColumnData<'alloc, U: UnderlierType> {
+	Arena(&'alloc [U]),
+	Heap(Arc<[U]>),
+}
+
+impl<U: UnderlierType> Deref for ColumnData<'_, U> {
+	type Target = [U];
+
+	fn deref(&self) -> &[U] {
+		match self {
+			Self::Arena(data) => data,
+			Self::Heap(data) => data,
+		}
+	}
+}
+
+impl<'alloc, U: UnderlierType> From<ColumnStorage<'alloc, U>> for ColumnData<'alloc, U> {
+	fn from(storage: ColumnStorage<'alloc, U>) -> Self {
+		match storage {
+			ColumnStorage::Arena(data) => Self::Arena(data),
+			ColumnStorage::Heap(data) => Self::Heap(data),
+		}
 	}
 }
 
@@ -203,20 +343,38 @@ pub enum RefCellData<'alloc, U: UnderlierType> {
 #[derive(Debug)]
 pub struct ImmutableWitnessIndexColumn<'a, U: UnderlierType> {
 	pub shape: ColumnShape,
-	pub data: &'a [U],
+	pub data: ColumnData<'a, U>,
 	pub is_single_row: bool,
 }
 
-fn immutable_witness_index_columns<U: UnderlierType>(
-	cols: Vec<WitnessIndexColumn<U>>,
-) -> Vec<ImmutableWitnessIndexColumn<U>> {
+fn immutable_witness_index_columns<'a, U: PackScalar<B1>, F: TowerField>(
+	table: &Table<F>,
+	oracle_offset: usize,
+	log_capacity: usize,
+	cols: Vec<WitnessIndexColumn<'a, U>>,
+) -> Vec<ImmutableWitnessIndexColumn<'a, U>> {
 	let mut result = Vec::<ImmutableWitnessIndexColumn<_>>::with_capacity(cols.len());
-	for col in cols {
+	for (index, col) in cols.into_iter().enumerate() {
 		result.push(ImmutableWitnessIndexColumn {
 			shape: col.shape,
 			data: match col.data {
-				WitnessDataMut::Owned(data) => data,
-				WitnessDataMut::SameAsOracleIndex(index) => result[index].data,
+				WitnessDataMut::Owned(mut data) => {
+					let col_def = index
+						.checked_sub(oracle_offset)
+						.map(|table_index| &table.columns[table_index].col);
+					if let Some(ColumnDef::Concatenated { cols: inner_cols }) = col_def {
+						let inner_data = inner_cols
+							.iter()
+							.map(|inner_col| &*result[oracle_offset + inner_col.table_index].data);
+						let log_inner_bits = log_capacity
+							+ table.columns[inner_cols[0].table_index]
+								.shape
+								.log_cell_size();
+						concatenate_underlier_data(inner_data, log_inner_bits, &mut data);
+					}
+					data.into()
+				}
+				WitnessDataMut::SameAsOracleIndex(index) => result[index].data.clone(),
 			},
 			is_single_row: col.is_single_row,
 		});
@@ -224,6 +382,28 @@ fn immutable_witness_index_columns<U: UnderlierType>(
 	result
 }
 
+/// Writes the first `2^log_inner_bits` bits of each inner column one after the other into `dst`.
+fn concatenate_underlier_data<'a, U: PackScalar<B1>>(
+	inner_data: impl Iterator<Item = &'a [U]>,
+	log_inner_bits: usize,
+	dst: &mut [U],
+) {
+	if log_inner_bits >= U::LOG_BITS {
+		let inner_len = 1 << (log_inner_bits - U::LOG_BITS);
+		for (dst, inner) in iter::zip(dst.chunks_exact_mut(inner_len), inner_data) {
+			dst.copy_from_slice(&inner[..inner_len]);
+		}
+	} else {
+		let dst = PackedType::<U, B1>::from_underliers_ref_mut(dst);
+		for (i, inner) in inner_data.enumerate() {
+			let inner = PackedType::<U, B1>::from_underliers_ref(inner);
+			for j in 0..1 << log_inner_bits {
+				set_packed_slice(dst, (i << log_inner_bits) | j, get_packed_slice(inner, j));
+			}
+		}
+	}
+}
+
 impl<'cs, 'alloc, U: UnderlierType, F: TowerField> TableWitnessIndex<'cs, 'alloc, U, F> {
 	pub fn new(allocator: &'alloc bumpalo::Bump, table: &'cs Table<F>, table_size: usize) -> Self {
 		// TODO: Make packed columns alias the same slice data
@@ -251,12 +431,16 @@ impl<'cs, 'alloc, U: UnderlierType, F: TowerField> TableWitnessIndex<'cs, 'alloc
 		cols.extend(table.columns.iter().map(|col| WitnessIndexColumn {
 			shape: col.shape,
 			data: match col.col {
-				ColumnDef::Packed { col: inner_col, .. } => {
+				ColumnDef::Packed { col: inner_col, .. }
+				| ColumnDef::Unpacked { col: inner_col, .. } => {
 					WitnessDataMut::SameAsOracleIndex(oracle_offset + inner_col.table_index)
 				}
 				ColumnDef::Constant { .. } => WitnessDataMut::SameAsOracleIndex(
 					transparent_single_backing[col.id.table_index].unwrap(),
 				),
+				ColumnDef::Committed { .. } => WitnessDataMut::new_committed(
+					(col.shape.log_cell_size() + log_capacity).saturating_sub(U::LOG_BITS),
+				),
 				_ => WitnessDataMut::new_owned(
 					allocator,
 					(col.shape.log_cell_size() + log_capacity).saturating_sub(U::LOG_BITS),
@@ -304,7 +488,7 @@ impl<'cs, 'alloc, U: UnderlierType, F: TowerField> TableWitnessIndex<'cs, 'alloc
 			.iter_mut()
 			.map(|col| match &mut col.data {
 				WitnessDataMut::SameAsOracleIndex(index) => RefCellData::SameAsOracleIndex(*index),
-				WitnessDataMut::Owned(data) => RefCellData::Owned(RefCell::new(data)),
+				WitnessDataMut::Owned(data) => RefCellData::Owned(RefCell::new(&mut **data)),
 			})
 			.collect();
 		TableWitnessIndexSegment {
@@ -0,0 +1,326 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Gadgets for the group law of an ordinary binary curve over [`B128`].
+//!
+//! The gadgets verify affine point addition and doubling on a [`BinaryCurve`]. The slope of the
+//! line through the points is committed as an auxiliary column, which keeps every constraint of
+//! degree two and avoids inversions in the constraint system.
+
+use anyhow::{ensure, Result};
+use binius_field::{
+	arithmetic_traits::Square, as_packed_field::PackScalar, util::batch_invert_or_zero,
+	BinaryCurve, Field,
+};
+use bytemuck::Pod;
+
+use crate::builder::{column::Col, types::B128, witness::TableWitnessIndexSegment, TableBuilder};
+
+/// A gadget for adding two distinct affine points on a binary curve.
+///
+/// This gadget has input columns `(x1, y1)` and `(x2, y2)` for the points to be added and
+/// committed output columns `(x3, y3)` for their sum. It constrains that `x1 != x2`, so the
+/// points are neither equal nor opposite and their sum is a finite point, and that
+///
+/// * $\lambda (x_1 + x_2) = y_1 + y_2$,
+/// * $x_3 = \lambda^2 + \lambda + x_1 + x_2 + a$,
+/// * $y_3 = \lambda (x_1 + x_3) + x_3 + y_1$.
+///
+/// The inputs are not constrained to lie on the curve.
+#[derive(Debug)]
+pub struct PointAdd {
+	pub curve: BinaryCurve<B128>,
+
+	// Inputs
+	pub x1: Col<B128>,
+	pub y1: Col<B128>,
+	pub x2: Col<B128>,
+	pub y2: Col<B128>,
+
+	// Private
+	x_sum: Col<B128>,
+	lambda: Col<B128>,
+
+	// Outputs
+	pub x3: Col<B128>,
+	pub y3: Col<B128>,
+}
+
+impl PointAdd {
+	pub fn new(
+		table: &mut TableBuilder,
+		curve: BinaryCurve<B128>,
+		x1: Col<B128>,
+		y1: Col<B128>,
+		x2: Col<B128>,
+		y2: Col<B128>,
+	) -> Self {
+		let x_sum = table.add_computed("x_sum", x1 + x2);
+		let lambda = table.add_committed("lambda");
+		let x3 = table.add_committed("x3");
+		let y3 = table.add_committed("y3");
+
+		table.assert_nonzero(x_sum);
+		table.assert_zero("lambda", lambda * x_sum - (y1 + y2));
+		table.assert_zero("x3", lambda * lambda + lambda + x_sum + curve.a() - x3);
+		table.assert_zero("y3", lambda * (x1 + x3) + x3 + y1 - y3);
+
+		Self {
+			curve,
+			x1,
+			y1,
+			x2,
+			y2,
+			x_sum,
+			lambda,
+			x3,
+			y3,
+		}
+	}
+
+	pub fn populate<U>(&self, index: &mut TableWitnessIndexSegment<U>) -> Result<()>
+	where
+		U: Pod + PackScalar<B128>,
+	{
+		let x1 = index.get_as::<B128, _, 1>(self.x1)?;
+		let y1 = index.get_as::<B128, _, 1>(self.y1)?;
+		let x2 = index.get_as::<B128, _, 1>(self.x2)?;
+		let y2 = index.get_as::<B128, _, 1>(self.y2)?;
+		let mut x_sum = index.get_mut_as::<B128, _, 1>(self.x_sum)?;
+		let mut lambda = index.get_mut_as::<B128, _, 1>(self.lambda)?;
+		let mut x3 = index.get_mut_as::<B128, _, 1>(self.x3)?;
+		let mut y3 = index.get_mut_as::<B128, _, 1>(self.y3)?;
+
+		for i in 0..index.size() {
+			x_sum[i] = x1[i] + x2[i];
+			ensure!(x_sum[i] != B128::ZERO, "points in row {i} have the same x coordinate");
+		}
+
+		lambda.copy_from_slice(&x_sum);
+		batch_invert_or_zero(&mut lambda);
+		for i in 0..index.size() {
+			lambda[i] *= y1[i] + y2[i];
+			x3[i] = lambda[i].square() + lambda[i] + x_sum[i] + self.curve.a();
+			y3[i] = lambda[i] * (x1[i] + x3[i]) + x3[i] + y1[i];
+		}
+		Ok(())
+	}
+}
+
+/// A gadget for doubling an affine point on a binary curve.
+///
+/// This gadget has input columns `(x1, y1)` for the point to be doubled and committed output
+/// columns `(x3, y3)` for its double. It constrains that `x1 != 0`, so the point is not of order
+/// two and its double is a finite point, and that
+///
+/// * $\lambda x_1 = x_1^2 + y_1$,
+/// * $x_3 = \lambda^2 + \lambda + a$,
+/// * $y_3 = x_1^2 + (\lambda + 1) x_3$.
+///
+/// The non-zero constraint is placed on the input column `x1` itself. The input is not
+/// constrained to lie on the curve.
+#[derive(Debug)]
+pub struct PointDouble {
+	pub curve: BinaryCurve<B128>,
+
+	// Inputs
+	pub x1: Col<B128>,
+	pub y1: Col<B128>,
+
+	// Private
+	lambda: Col<B128>,
+
+	// Outputs
+	pub x3: Col<B128>,
+	pub y3: Col<B128>,
+}
+
+impl PointDouble {
+	pub fn new(
+		table: &mut TableBuilder,
+		curve: BinaryCurve<B128>,
+		x1: Col<B128>,
+		y1: Col<B128>,
+	) -> Self {
+		let lambda = table.add_committed("lambda");
+		let x3 = table.add_committed("x3");
+		let y3 = table.add_committed("y3");
+
+		table.assert_nonzero(x1);
+		table.assert_zero("lambda", lambda * x1 - (x1 * x1 + y1));
+		table.assert_zero("x3", lambda * lambda + lambda + curve.a() - x3);
+		table.assert_zero("y3", x1 * x1 + (lambda + B128::ONE) * x3 - y3);
+
+		Self {
+			curve,
+			x1,
+			y1,
+			lambda,
+			x3,
+			y3,
+		}
+	}
+
+	pub fn populate<U>(&self, index: &mut TableWitnessIndexSegment<U>) -> Result<()>
+	where
+		U: Pod + PackScalar<B128>,
+	{
+		let x1 = index.get_as::<B128, _, 1>(self.x1)?;
+		let y1 = index.get_as::<B128, _, 1>(self.y1)?;
+		let mut lambda = index.get_mut_as::<B128, _, 1>(self.lambda)?;
+		let mut x3 = index.get_mut_as::<B128, _, 1>(self.x3)?;
+		let mut y3 = index.get_mut_as::<B128, _, 1>(self.y3)?;
+
+		for i in 0..index.size() {
+			ensure!(x1[i] != B128::ZERO, "point in row {i} has order two");
+		}
+
+		lambda.copy_from_slice(&x1);
+		batch_invert_or_zero(&mut lambda);
+		for i in 0..index.size() {
+			let x1_sq = x1[i].square();
+			lambda[i] *= x1_sq + y1[i];
+			x3[i] = lambda[i].square() + lambda[i] + self.curve.a();
+			y3[i] = x1_sq + (lambda[i] + B128::ONE) * x3[i];
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{arch::OptimalUnderlier128b, AffinePoint, LopezDahabPoint};
+	use bumpalo::Bump;
+	use rand::{prelude::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::builder::{ConstraintSystem, Statement};
+
+	fn random_curve(rng: &mut StdRng) -> BinaryCurve<B128> {
+		BinaryCurve::new(B128::random(&mut *rng), B128::random(&mut *rng)).unwrap()
+	}
+
+	fn random_points<'a>(
+		curve: &'a BinaryCurve<B128>,
+		rng: &'a mut StdRng,
+	) -> impl Iterator<Item = AffinePoint<B128>> + 'a {
+		repeat_with(move || curve.lift_x(B128::random(&mut *rng), rng.gen())).flatten()
+	}
+
+	#[test]
+	fn test_point_add_and_double() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let curve = random_curve(&mut rng);
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("binary curve test");
+
+		let x1 = table.add_committed::<B128, 1>("x1");
+		let y1 = table.add_committed::<B128, 1>("y1");
+		let x2 = table.add_committed::<B128, 1>("x2");
+		let y2 = table.add_committed::<B128, 1>("y2");
+		let add = PointAdd::new(&mut table, curve, x1, y1, x2, y2);
+		let double = PointDouble::new(&mut table, curve, x1, y1);
+
+		let table_id = table.id();
+
+		let allocator = Bump::new();
+
+		let statement = Statement {
+			boundaries: vec![],
+			table_sizes: vec![1 << 6],
+		};
+		let mut witness = cs
+			.build_witness::<OptimalUnderlier128b>(&allocator, &statement)
+			.unwrap();
+
+		let table_witness = witness.get_table(table_id).unwrap();
+		let mut segment = table_witness.full_segment();
+
+		let points = random_points(&curve, &mut rng)
+			.take(2 << 6)
+			.collect::<Vec<_>>();
+		{
+			let mut x1 = segment.get_mut_as::<B128, _, 1>(x1).unwrap();
+			let mut y1 = segment.get_mut_as::<B128, _, 1>(y1).unwrap();
+			let mut x2 = segment.get_mut_as::<B128, _, 1>(x2).unwrap();
+			let mut y2 = segment.get_mut_as::<B128, _, 1>(y2).unwrap();
+			for (i, pair) in points.chunks_exact(2).enumerate() {
+				(x1[i], y1[i]) = (pair[0].x, pair[0].y);
+				(x2[i], y2[i]) = (pair[1].x, pair[1].y);
+			}
+		}
+
+		add.populate(&mut segment).unwrap();
+		double.populate(&mut segment).unwrap();
+
+		{
+			let sum_x = segment.get_as::<B128, _, 1>(add.x3).unwrap();
+			let sum_y = segment.get_as::<B128, _, 1>(add.y3).unwrap();
+			let double_x = segment.get_as::<B128, _, 1>(double.x3).unwrap();
+			let double_y = segment.get_as::<B128, _, 1>(double.y3).unwrap();
+			for (i, pair) in points.chunks_exact(2).enumerate() {
+				let p = LopezDahabPoint::from(pair[0]);
+				let sum = AffinePoint {
+					x: sum_x[i],
+					y: sum_y[i],
+				};
+				let double = AffinePoint {
+					x: double_x[i],
+					y: double_y[i],
+				};
+				assert_eq!(curve.add_affine(&p, &pair[1]).to_affine(), Some(sum));
+				assert_eq!(curve.double(&p).to_affine(), Some(double));
+			}
+		}
+
+		let ccs = cs.compile(&statement).unwrap();
+		let witness = witness.into_multilinear_extension_index(&statement);
+
+		binius_core::constraint_system::validate::validate_witness(&ccs, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_point_add_same_x() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let curve = random_curve(&mut rng);
+
+		let mut cs = ConstraintSystem::new();
+		let mut table = cs.add_table("binary curve test");
+
+		let x1 = table.add_committed::<B128, 1>("x1");
+		let y1 = table.add_committed::<B128, 1>("y1");
+		let x2 = table.add_committed::<B128, 1>("x2");
+		let y2 = table.add_committed::<B128, 1>("y2");
+		let add = PointAdd::new(&mut table, curve, x1, y1, x2, y2);
+
+		let table_id = table.id();
+
+		let allocator = Bump::new();
+
+		let statement = Statement {
+			boundaries: vec![],
+			table_sizes: vec![1 << 2],
+		};
+		let mut witness = cs
+			.build_witness::<OptimalUnderlier128b>(&allocator, &statement)
+			.unwrap();
+
+		let table_witness = witness.get_table(table_id).unwrap();
+		let mut segment = table_witness.full_segment();
+
+		{
+			let mut x1 = segment.get_mut_as::<B128, _, 1>(x1).unwrap();
+			let mut y1 = segment.get_mut_as::<B128, _, 1>(y1).unwrap();
+			let mut x2 = segment.get_mut_as::<B128, _, 1>(x2).unwrap();
+			let mut y2 = segment.get_mut_as::<B128, _, 1>(y2).unwrap();
+			for (i, p) in random_points(&curve, &mut rng).take(4).enumerate() {
+				(x1[i], y1[i]) = (p.x, p.y);
+				(x2[i], y2[i]) = (p.x, p.x + p.y);
+			}
+		}
+
+		assert!(add.populate(&mut segment).is_err());
+	}
+}
@@ -0,0 +1,400 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Offline memory checking for read/write memories.
+//!
+//! Channels are unordered multisets, so on their own they cannot express that a read returns the
+//! value of the latest write to the same address. The gadgets in this module implement the
+//! offline memory checking technique of [BEGKN91], in the timestamped form used by Spice and
+//! Lasso, on top of a channel carrying `(address, value, timestamp)` tuples:
+//!
+//! * The [`Memory`] table pushes `(address, initial_value, 0)` for every memory cell and pulls
+//!   `(address, final_value, final_timestamp)` once all accesses are done.
+//! * Every [`MemoryAccess`] pulls the tuple `(address, previous_value, previous_timestamp)` left
+//!   by the previous access and pushes `(address, value, timestamp)`. It checks that
+//!   `previous_timestamp < timestamp` as 32-bit integers. A read pushes back the value it
+//!   pulled, while a write pushes a new value.
+//!
+//! The initial memory image is part of the statement. The verifier pushes one boundary value
+//! `(address, initial_value)` per cell to an initialization channel, see
+//! [`Memory::init_boundaries`], which the [`Memory`] table pulls. This ensures that the table
+//! contains every cell of the image exactly once.
+//!
+//! The memory channel is balanced if and only if every access reads the value left by the latest
+//! access to the same address, provided that the timestamps of all accesses are distinct and
+//! non-zero. The gadgets do not check this themselves. Timestamps are usually taken from the
+//! clock of the VM that performs the accesses, which is constrained elsewhere.
+//!
+//! [BEGKN91]: <https://doi.org/10.1109/SFCS.1991.185352>
+
+use std::collections::BTreeMap;
+
+use anyhow::ensure;
+use binius_core::constraint_system::channel::{Boundary, ChannelId, FlushDirection};
+use binius_field::{as_packed_field::PackScalar, Field};
+use bytemuck::Pod;
+
+use super::u32::{U32Add, U32AddFlags};
+use crate::builder::{
+	column::Col,
+	types::{B1, B128, B32},
+	witness::{TableFiller, TableWitnessIndexSegment},
+	ConstraintSystem, TableBuilder, TableId,
+};
+
+/// The table of memory cells of a read/write memory, along with the channels that connect it to
+/// the [`MemoryAccess`] gadgets.
+///
+/// Each row of the table is a memory cell. The table must be filled with the rows returned by
+/// [`MemoryEmulator::final_cells`].
+#[derive(Debug)]
+pub struct Memory {
+	id: TableId,
+	/// The channel of `(address, value, timestamp)` tuples that the accesses flush to.
+	pub channel: ChannelId,
+	/// The channel of `(address, initial_value)` tuples that the statement boundaries push to.
+	pub init_channel: ChannelId,
+	addr: Col<B32>,
+	init_value: Col<B32>,
+	final_value: Col<B32>,
+	final_timestamp: Col<B32>,
+	_zero_timestamp: Col<B32>,
+}
+
+impl Memory {
+	pub fn new(cs: &mut ConstraintSystem, name: impl ToString) -> Self {
+		let name = name.to_string();
+		let channel = cs.add_channel(format!("{name}::memory"));
+		let init_channel = cs.add_channel(format!("{name}::init"));
+
+		let mut table = cs.add_table(format!("{name}::cells"));
+		let addr = table.add_committed::<B32, 1>("addr");
+		let init_value = table.add_committed::<B32, 1>("init_value");
+		let final_value = table.add_committed::<B32, 1>("final_value");
+		let final_timestamp = table.add_committed::<B32, 1>("final_timestamp");
+		let zero_timestamp = table.add_constant("zero_timestamp", [B32::ZERO]);
+
+		table.pull(init_channel, [addr, init_value]);
+		table.push(channel, [addr, init_value, zero_timestamp]);
+		table.pull(channel, [addr, final_value, final_timestamp]);
+
+		Self {
+			id: table.id(),
+			channel,
+			init_channel,
+			addr,
+			init_value,
+			final_value,
+			final_timestamp,
+			_zero_timestamp: zero_timestamp,
+		}
+	}
+
+	/// Returns the statement boundaries that initialize the memory with the given image of
+	/// `(address, value)` pairs.
+	///
+	/// ## Panics
+	///
+	/// * If the image contains the same address twice.
+	pub fn init_boundaries(
+		&self,
+		image: impl IntoIterator<Item = (u32, u32)>,
+	) -> Vec<Boundary<B128>> {
+		let mut addrs = Vec::new();
+		let boundaries = image
+			.into_iter()
+			.map(|(addr, value)| {
+				addrs.push(addr);
+				Boundary {
+					values: vec![B128::new(addr as u128), B128::new(value as u128)],
+					channel_id: self.init_channel,
+					direction: FlushDirection::Push,
+					multiplicity: 1,
+				}
+			})
+			.collect();
+
+		addrs.sort_unstable();
+		assert!(
+			addrs.windows(2).all(|pair| pair[0] != pair[1]),
+			"memory image must not contain the same address twice"
+		);
+		boundaries
+	}
+}
+
+/// A cell of a [`Memory`] after all accesses are performed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryCell {
+	pub addr: u32,
+	pub init_value: u32,
+	pub final_value: u32,
+	pub final_timestamp: u32,
+}
+
+impl<U> TableFiller<U> for Memory
+where
+	U: Pod + PackScalar<B1>,
+{
+	type Event = MemoryCell;
+
+	fn id(&self) -> TableId {
+		self.id
+	}
+
+	fn fill<'a>(
+		&'a self,
+		rows: impl Iterator<Item = &'a Self::Event>,
+		witness: &'a mut TableWitnessIndexSegment<U>,
+	) -> Result<(), anyhow::Error> {
+		let mut addr = witness.get_mut_as(self.addr)?;
+		let mut init_value = witness.get_mut_as(self.init_value)?;
+		let mut final_value = witness.get_mut_as(self.final_value)?;
+		let mut final_timestamp = witness.get_mut_as(self.final_timestamp)?;
+
+		for (i, cell) in rows.enumerate() {
+			addr[i] = cell.addr;
+			init_value[i] = cell.init_value;
+			final_value[i] = cell.final_value;
+			final_timestamp[i] = cell.final_timestamp;
+		}
+		Ok(())
+	}
+}
+
+/// A gadget for a read or a write of a [`Memory`] in every row of a table.
+///
+/// This gadget has input columns `addr` and `timestamp`, and for writes an input column `value`
+/// with the value written. For reads, `value` is an output column with the value read.
+#[derive(Debug)]
+pub struct MemoryAccess {
+	// Inputs
+	pub addr: Col<B32>,
+	pub timestamp: Col<B1, 32>,
+
+	// Private
+	prev_value: Col<B32>,
+	prev_timestamp: Col<B1, 32>,
+	delta: Col<B1, 32>,
+	carry_in: Col<B1, 32>,
+	timestamp_check: U32Add,
+
+	/// The value after the access, an input for writes and an output for reads.
+	pub value: Col<B32>,
+	/// Whether the access is a write.
+	pub is_write: bool,
+}
+
+impl MemoryAccess {
+	/// Creates a gadget that reads the value at `addr`.
+	pub fn read(
+		table: &mut TableBuilder,
+		memory: &Memory,
+		addr: Col<B32>,
+		timestamp: Col<B1, 32>,
+	) -> Self {
+		let value = table.add_committed::<B32, 1>("value");
+		Self::new(table, memory, addr, timestamp, value, value, false)
+	}
+
+	/// Creates a gadget that writes `value` to `addr`.
+	pub fn write(
+		table: &mut TableBuilder,
+		memory: &Memory,
+		addr: Col<B32>,
+		value: Col<B32>,
+		timestamp: Col<B1, 32>,
+	) -> Self {
+		let prev_value = table.add_committed::<B32, 1>("prev_value");
+		Self::new(table, memory, addr, timestamp, prev_value, value, true)
+	}
+
+	fn new(
+		table: &mut TableBuilder,
+		memory: &Memory,
+		addr: Col<B32>,
+		timestamp: Col<B1, 32>,
+		prev_value: Col<B32>,
+		value: Col<B32>,
+		is_write: bool,
+	) -> Self {
+		let prev_timestamp = table.add_committed::<B1, 32>("prev_timestamp");
+		let delta = table.add_committed::<B1, 32>("delta");
+		let carry_in = table.add_constant("carry_in", carry_in_bits());
+
+		// prev_timestamp + delta + 1 = timestamp without overflow, hence prev_timestamp < timestamp
+		let timestamp_check = U32Add::new(
+			&mut table.with_namespace("timestamp_check"),
+			prev_timestamp,
+			delta,
+			U32AddFlags {
+				carry_in_bit: Some(carry_in),
+				expose_final_carry: true,
+				..U32AddFlags::default()
+			},
+		);
+		let final_carry = timestamp_check
+			.final_carry
+			.expect("expose_final_carry is set");
+		table.assert_zero("timestamp increases", timestamp_check.zout - timestamp);
+		table.assert_zero("timestamp does not overflow", final_carry.into());
+
+		let prev_timestamp_packed =
+			table.add_packed::<_, 32, B32, 1>("prev_timestamp_packed", prev_timestamp);
+		let timestamp_packed = table.add_packed::<_, 32, B32, 1>("timestamp_packed", timestamp);
+
+		table.pull(memory.channel, [addr, prev_value, prev_timestamp_packed]);
+		table.push(memory.channel, [addr, value, timestamp_packed]);
+
+		Self {
+			addr,
+			timestamp,
+			prev_value,
+			prev_timestamp,
+			delta,
+			carry_in,
+			timestamp_check,
+			value,
+			is_write,
+		}
+	}
+
+	/// Populates the gadget columns, except for the inputs, from the recorded access events.
+	pub fn populate<'a, U>(
+		&self,
+		index: &mut TableWitnessIndexSegment<U>,
+		events: impl Iterator<Item = &'a MemoryAccessEvent>,
+	) -> Result<(), anyhow::Error>
+	where
+		U: Pod + PackScalar<B1>,
+	{
+		{
+			let mut prev_value = index.get_mut_as::<u32, _, 1>(self.prev_value)?;
+			let mut prev_timestamp = index.get_mut_as(self.prev_timestamp)?;
+			let mut delta = index.get_mut_as(self.delta)?;
+			let mut carry_in = index.get_mut_as(self.carry_in)?;
+
+			for (i, event) in events.enumerate() {
+				ensure!(
+					event.is_write == self.is_write,
+					"memory access event does not match the gadget kind"
+				);
+				ensure!(
+					event.prev_timestamp < event.timestamp,
+					"memory access timestamps must increase"
+				);
+
+				prev_value[i] = event.prev_value;
+				prev_timestamp[i] = event.prev_timestamp;
+				delta[i] = event.timestamp - event.prev_timestamp - 1;
+				carry_in[i] = 1u32;
+			}
+		}
+		self.timestamp_check.populate(index)
+	}
+}
+
+fn carry_in_bits() -> [B1; 32] {
+	std::array::from_fn(|i| if i == 0 { B1::ONE } else { B1::ZERO })
+}
+
+/// A read or a write recorded by a [`MemoryEmulator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryAccessEvent {
+	pub addr: u32,
+	/// The value after the access. For reads, this is the value read.
+	pub value: u32,
+	pub timestamp: u32,
+	pub prev_value: u32,
+	pub prev_timestamp: u32,
+	pub is_write: bool,
+}
+
+/// Emulates a read/write memory and records the events needed to populate the [`Memory`] table
+/// and the [`MemoryAccess`] gadgets.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryEmulator {
+	// Maps addresses to their initial value, current value, and the timestamp of the last access.
+	cells: BTreeMap<u32, (u32, u32, u32)>,
+}
+
+impl MemoryEmulator {
+	/// Creates a memory with the given image of `(address, value)` pairs.
+	///
+	/// Only the addresses in the image can be accessed.
+	pub fn new(image: impl IntoIterator<Item = (u32, u32)>) -> Self {
+		Self {
+			cells: image
+				.into_iter()
+				.map(|(addr, value)| (addr, (value, value, 0)))
+				.collect(),
+		}
+	}
+
+	/// Reads the value at `addr` at the given timestamp.
+	///
+	/// ## Panics
+	///
+	/// * If `addr` is not in the memory image.
+	/// * If `timestamp` is not greater than the timestamp of the last access to `addr`.
+	pub fn read(&mut self, addr: u32, timestamp: u32) -> MemoryAccessEvent {
+		let (_, value, _) = self.cells[&addr];
+		self.access(addr, value, timestamp, false)
+	}
+
+	/// Writes `value` to `addr` at the given timestamp.
+	///
+	/// ## Panics
+	///
+	/// * If `addr` is not in the memory image.
+	/// * If `timestamp` is not greater than the timestamp of the last access to `addr`.
+	pub fn write(&mut self, addr: u32, value: u32, timestamp: u32) -> MemoryAccessEvent {
+		self.access(addr, value, timestamp, true)
+	}
+
+	fn access(
+		&mut self,
+		addr: u32,
+		value: u32,
+		timestamp: u32,
+		is_write: bool,
+	) -> MemoryAccessEvent {
+		let (_, cell_value, cell_timestamp) = self
+			.cells
+			.get_mut(&addr)
+			.expect("address must be in the memory image");
+		assert!(*cell_timestamp < timestamp, "memory access timestamps must increase");
+
+		let event = MemoryAccessEvent {
+			addr,
+			value,
+			timestamp,
+			prev_value: *cell_value,
+			prev_timestamp: *cell_timestamp,
+			is_write,
+		};
+		*cell_value = value;
+		*cell_timestamp = timestamp;
+		event
+	}
+
+	/// Returns the rows of the [`Memory`] table, ordered by address.
+	pub fn final_cells(&self) -> Vec<MemoryCell> {
+		self.cells
+			.iter()
+			.map(|(&addr, &(init_value, final_value, final_timestamp))| MemoryCell {
+				addr,
+				init_value,
+				final_value,
+				final_timestamp,
+			})
+			.collect()
+	}
+
+	/// Returns the memory image of `(address, initial_value)` pairs, ordered by address.
+	pub fn image(&self) -> Vec<(u32, u32)> {
+		self.cells
+			.iter()
+			.map(|(&addr, &(init_value, _, _))| (addr, init_value))
+			.collect()
+	}
+}
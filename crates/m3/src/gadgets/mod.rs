@@ -1,4 +1,6 @@
 // Copyright 2025 Irreducible Inc.
 
+pub mod binary_curve;
 pub mod hash;
+pub mod memory;
 pub mod u32;
@@ -0,0 +1,118 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::{fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
+use binius_field::{
+	arch::OptimalUnderlier128b,
+	packed::{get_packed_slice, set_packed_slice},
+	Field,
+};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::builder::{Col, ConstraintSystem, Statement, B1, B128, B32};
+use bumpalo::Bump;
+
+const LOG_INV_RATE: usize = 1;
+const SECURITY_BITS: usize = 30;
+
+fn check_concatenated_col(n_rows: usize, prove: bool) {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let mut table = cs.add_table("concatenated");
+	let words = table.add_committed_multiple::<B32, 1, 2>("words");
+	let bits = table.add_committed_multiple::<B1, 1, 3>("bits");
+	let words_concat: Col<B32, 2> = table.add_concatenated("words_concat", words);
+	let bits_concat: Col<B1, 4> = table.add_concatenated("bits_concat", bits);
+	let words_expected = table.add_committed::<B32, 2>("words_expected");
+	let bits_expected = table.add_committed::<B1, 4>("bits_expected");
+	table.assert_zero("words_concat", words_concat - words_expected);
+	table.assert_zero("bits_concat", bits_concat - bits_expected);
+	let table_id = table.id();
+
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![n_rows],
+	};
+	let mut witness = cs
+		.build_witness::<OptimalUnderlier128b>(&allocator, &statement)
+		.unwrap();
+
+	{
+		let table_witness = witness.get_table(table_id).unwrap();
+		let segment = table_witness.full_segment();
+
+		let words = words.map(|col| segment.get_mut_as::<u32, _, 1>(col).unwrap());
+		let mut words_expected = segment.get_mut_as::<u32, _, 2>(words_expected).unwrap();
+		for (piece, mut col) in words.into_iter().enumerate() {
+			for i in 0..n_rows {
+				col[i] = ((piece * n_rows + i) as u32).wrapping_mul(0x9e3779b9);
+				words_expected[piece * n_rows + i] = col[i];
+			}
+		}
+
+		let bits = bits.map(|col| segment.get_mut(col).unwrap());
+		let mut bits_expected = segment.get_mut(bits_expected).unwrap();
+		for (piece, mut col) in bits.into_iter().enumerate() {
+			for i in 0..n_rows {
+				let bit = if (i * (piece + 1)) % 3 == 0 {
+					B1::ONE
+				} else {
+					B1::ZERO
+				};
+				set_packed_slice(&mut col, i, bit);
+				set_packed_slice(&mut bits_expected, piece * n_rows + i, bit);
+			}
+		}
+		// The values after the concatenated columns are zero.
+		assert_eq!(get_packed_slice(&bits_expected, 3 * n_rows), B1::ZERO);
+	}
+
+	let constraint_system = cs.compile(&statement).unwrap();
+	let witness = witness.into_multilinear_extension_index(&statement);
+
+	binius_core::constraint_system::validate::validate_witness(
+		&constraint_system,
+		&statement.boundaries,
+		&witness,
+	)
+	.unwrap();
+
+	if !prove {
+		return;
+	}
+
+	let proof = binius_core::constraint_system::prove::<
+		_,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		&statement.boundaries,
+		witness,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+
+	binius_core::constraint_system::verify::<
+		OptimalUnderlier128b,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, LOG_INV_RATE, SECURITY_BITS, &statement.boundaries, proof)
+	.unwrap();
+}
+
+#[test]
+fn test_m3_concatenated_col() {
+	check_concatenated_col(1 << 7, true);
+}
+
+#[test]
+fn test_m3_concatenated_col_sub_underlier() {
+	// The bit columns are smaller than an underlier, but too small to be committed
+	check_concatenated_col(1 << 4, false);
+}
@@ -0,0 +1,103 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::{
+	fiat_shamir::HasherChallenger,
+	oracle::{AuxiliaryOracleKind, DegreeReduction},
+	tower::CanonicalTowerFamily,
+};
+use binius_field::{arch::OptimalUnderlier128b, Field};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::builder::{ConstraintSystem, Expr, Statement, B128, B8};
+use bumpalo::Bump;
+
+const LOG_INV_RATE: usize = 1;
+const SECURITY_BITS: usize = 30;
+
+fn check_sbox_degree_reduction(oracle_kind: AuxiliaryOracleKind) {
+	let n_rows = 1 << 7;
+	let max_degree = 3;
+
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let mut table = cs.add_table("sbox");
+	let x = table.add_committed::<B8, 1>("x");
+	let y = table.add_committed::<B8, 1>("y");
+	// y = x^254, the inverse of x in B8
+	table.assert_zero("sbox", y - Expr::from(x).pow(254));
+	table
+		.reduce_degree(DegreeReduction::new(max_degree).with_oracle_kind(oracle_kind))
+		.unwrap();
+	let table_id = table.id();
+
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![n_rows],
+	};
+	let mut witness = cs
+		.build_witness::<OptimalUnderlier128b>(&allocator, &statement)
+		.unwrap();
+
+	// Only the original columns are populated, the auxiliary columns are computed from them.
+	{
+		let table_witness = witness.get_table(table_id).unwrap();
+		let segment = table_witness.full_segment();
+		let mut xs = segment.get_mut_as::<u8, _, 1>(x).unwrap();
+		let mut ys = segment.get_mut_as::<u8, _, 1>(y).unwrap();
+		for i in 0..n_rows {
+			let x = B8::new((i as u8).wrapping_mul(37));
+			xs[i] = x.val();
+			ys[i] = x.invert().unwrap_or(B8::ZERO).val();
+		}
+	}
+
+	let constraint_system = cs.compile(&statement).unwrap();
+	for constraint_set in &constraint_system.table_constraints {
+		for constraint in &constraint_set.constraints {
+			assert!(constraint.composition.degree() <= max_degree);
+		}
+	}
+	let witness = witness.into_multilinear_extension_index(&statement);
+
+	binius_core::constraint_system::validate::validate_witness(
+		&constraint_system,
+		&statement.boundaries,
+		&witness,
+	)
+	.unwrap();
+
+	let proof = binius_core::constraint_system::prove::<
+		_,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		&statement.boundaries,
+		witness,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+
+	binius_core::constraint_system::verify::<
+		OptimalUnderlier128b,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, LOG_INV_RATE, SECURITY_BITS, &statement.boundaries, proof)
+	.unwrap();
+}
+
+#[test]
+fn test_m3_degree_reduction_committed() {
+	check_sbox_degree_reduction(AuxiliaryOracleKind::Committed);
+}
+
+#[test]
+fn test_m3_degree_reduction_composite() {
+	check_sbox_degree_reduction(AuxiliaryOracleKind::Composite);
+}
@@ -0,0 +1,259 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Example of a read/write memory checked with the M3 memory gadgets.
+
+use binius_core::{
+	constraint_system::channel::Boundary, fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily, witness::MultilinearExtensionIndex,
+};
+use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackScalar};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::{
+	builder::{
+		Col, ConstraintSystem, Statement, TableFiller, TableId, TableWitnessIndexSegment, B1, B128,
+		B32,
+	},
+	gadgets::memory::{Memory, MemoryAccess, MemoryAccessEvent, MemoryEmulator},
+};
+use bumpalo::Bump;
+use bytemuck::Pod;
+
+/// A table where every row writes a value to memory.
+struct WritesTable {
+	id: TableId,
+	addr: Col<B32>,
+	value: Col<B32>,
+	timestamp: Col<B1, 32>,
+	write: MemoryAccess,
+}
+
+impl WritesTable {
+	fn new(cs: &mut ConstraintSystem, memory: &Memory) -> Self {
+		let mut table = cs.add_table("writes");
+		let addr = table.add_committed("addr");
+		let value = table.add_committed("value");
+		let timestamp = table.add_committed("timestamp");
+		let write =
+			MemoryAccess::write(&mut table.with_namespace("write"), memory, addr, value, timestamp);
+		Self {
+			id: table.id(),
+			addr,
+			value,
+			timestamp,
+			write,
+		}
+	}
+}
+
+impl<U> TableFiller<U> for WritesTable
+where
+	U: Pod + PackScalar<B1>,
+{
+	type Event = MemoryAccessEvent;
+
+	fn id(&self) -> TableId {
+		self.id
+	}
+
+	fn fill<'a>(
+		&'a self,
+		rows: impl Iterator<Item = &'a Self::Event>,
+		witness: &'a mut TableWitnessIndexSegment<U>,
+	) -> Result<(), anyhow::Error> {
+		let rows = rows.collect::<Vec<_>>();
+		{
+			let mut addr = witness.get_mut_as(self.addr)?;
+			let mut value = witness.get_mut_as(self.value)?;
+			let mut timestamp = witness.get_mut_as(self.timestamp)?;
+			for (i, event) in rows.iter().enumerate() {
+				addr[i] = event.addr;
+				value[i] = event.value;
+				timestamp[i] = event.timestamp;
+			}
+		}
+		self.write.populate(witness, rows.into_iter())
+	}
+}
+
+/// A table where every row reads a value from memory.
+struct ReadsTable {
+	id: TableId,
+	addr: Col<B32>,
+	timestamp: Col<B1, 32>,
+	read: MemoryAccess,
+}
+
+impl ReadsTable {
+	fn new(cs: &mut ConstraintSystem, memory: &Memory) -> Self {
+		let mut table = cs.add_table("reads");
+		let addr = table.add_committed("addr");
+		let timestamp = table.add_committed("timestamp");
+		let read = MemoryAccess::read(&mut table.with_namespace("read"), memory, addr, timestamp);
+		Self {
+			id: table.id(),
+			addr,
+			timestamp,
+			read,
+		}
+	}
+}
+
+impl<U> TableFiller<U> for ReadsTable
+where
+	U: Pod + PackScalar<B1>,
+{
+	type Event = MemoryAccessEvent;
+
+	fn id(&self) -> TableId {
+		self.id
+	}
+
+	fn fill<'a>(
+		&'a self,
+		rows: impl Iterator<Item = &'a Self::Event>,
+		witness: &'a mut TableWitnessIndexSegment<U>,
+	) -> Result<(), anyhow::Error> {
+		let rows = rows.collect::<Vec<_>>();
+		{
+			let mut addr = witness.get_mut_as(self.addr)?;
+			let mut timestamp = witness.get_mut_as(self.timestamp)?;
+			for (i, event) in rows.iter().enumerate() {
+				addr[i] = event.addr;
+				timestamp[i] = event.timestamp;
+			}
+		}
+		self.read.populate(witness, rows.into_iter())
+	}
+}
+
+struct Instance<'a> {
+	statement: Statement,
+	witness: MultilinearExtensionIndex<'a, OptimalUnderlier128b, B128>,
+	constraint_system: binius_core::constraint_system::ConstraintSystem<B128>,
+}
+
+/// Runs a short program over a small memory, optionally corrupting the value of a read.
+fn memory_instance(allocator: &Bump, corrupt_read: bool) -> Instance {
+	let mut cs = ConstraintSystem::new();
+	let memory = Memory::new(&mut cs, "ram");
+	let writes_table = WritesTable::new(&mut cs, &memory);
+	let reads_table = ReadsTable::new(&mut cs, &memory);
+
+	let mut emulator = MemoryEmulator::new((0..6).map(|addr| (addr * 4, addr + 100)));
+	let mut writes = Vec::new();
+	let mut reads = Vec::new();
+	let mut clock = 0;
+	let mut tick = || {
+		clock += 1;
+		clock
+	};
+	reads.push(emulator.read(8, tick()));
+	writes.push(emulator.write(8, 7, tick()));
+	reads.push(emulator.read(8, tick()));
+	writes.push(emulator.write(0, 3, tick()));
+	writes.push(emulator.write(8, 9, tick()));
+	reads.push(emulator.read(8, tick()));
+	reads.push(emulator.read(20, tick()));
+	reads.push(emulator.read(0, tick()));
+	assert_eq!(reads.iter().map(|event| event.value).collect::<Vec<_>>(), [102, 7, 9, 105, 3]);
+
+	if corrupt_read {
+		// Read the initial value instead of the latest write
+		reads[1].value = 102;
+		reads[1].prev_value = 102;
+	}
+
+	let cells = emulator.final_cells();
+	let boundaries: Vec<Boundary<B128>> = memory.init_boundaries(emulator.image());
+	let statement = Statement {
+		boundaries,
+		table_sizes: vec![cells.len(), writes.len(), reads.len()],
+	};
+	let mut witness = cs
+		.build_witness::<OptimalUnderlier128b>(allocator, &statement)
+		.unwrap();
+	witness.fill_table_sequential(&memory, &cells).unwrap();
+	witness
+		.fill_table_sequential(&writes_table, &writes)
+		.unwrap();
+	witness.fill_table_sequential(&reads_table, &reads).unwrap();
+
+	Instance {
+		constraint_system: cs.compile(&statement).unwrap(),
+		witness: witness.into_multilinear_extension_index(&statement),
+		statement,
+	}
+}
+
+#[test]
+fn test_memory_validate_witness() {
+	let allocator = Bump::new();
+	let Instance {
+		statement,
+		witness,
+		constraint_system,
+	} = memory_instance(&allocator, false);
+
+	binius_core::constraint_system::validate::validate_witness(
+		&constraint_system,
+		&statement.boundaries,
+		&witness,
+	)
+	.unwrap();
+}
+
+#[test]
+fn test_memory_rejects_stale_read() {
+	let allocator = Bump::new();
+	let Instance {
+		statement,
+		witness,
+		constraint_system,
+	} = memory_instance(&allocator, true);
+
+	assert!(binius_core::constraint_system::validate::validate_witness(
+		&constraint_system,
+		&statement.boundaries,
+		&witness,
+	)
+	.is_err());
+}
+
+#[test]
+fn test_memory_prove_verify() {
+	let allocator = Bump::new();
+	let Instance {
+		statement,
+		witness,
+		constraint_system,
+	} = memory_instance(&allocator, false);
+
+	const LOG_INV_RATE: usize = 1;
+	const SECURITY_BITS: usize = 100;
+
+	let proof = binius_core::constraint_system::prove::<
+		_,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		&statement.boundaries,
+		witness,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+
+	binius_core::constraint_system::verify::<
+		OptimalUnderlier128b,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, LOG_INV_RATE, SECURITY_BITS, &statement.boundaries, proof)
+	.unwrap();
+}
@@ -0,0 +1,160 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::{fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
+use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackScalar};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::builder::{
+	Col, ConstraintSystem, Statement, TableFiller, TableId, TableWitnessIndexSegment, B1, B128, B32,
+};
+use bumpalo::Bump;
+use bytemuck::Pod;
+
+const LOG_INV_RATE: usize = 1;
+const SECURITY_BITS: usize = 30;
+
+/// A table whose `(sorted_key, sorted_value)` rows are a permutation of its `(key, value)` rows.
+pub struct SortedTable {
+	id: TableId,
+	key: Col<B32>,
+	value: Col<B32>,
+	sorted_key: Col<B32>,
+	sorted_value: Col<B32>,
+}
+
+impl SortedTable {
+	pub fn new(cs: &mut ConstraintSystem) -> Self {
+		let mut table = cs.add_table("sorted");
+		let key = table.add_committed("key");
+		let value = table.add_committed("value");
+		let sorted_key = table.add_committed("sorted_key");
+		let sorted_value = table.add_committed("sorted_value");
+		table.assert_permutation([key, value], [sorted_key, sorted_value]);
+		Self {
+			id: table.id(),
+			key,
+			value,
+			sorted_key,
+			sorted_value,
+		}
+	}
+}
+
+impl<U> TableFiller<U> for SortedTable
+where
+	U: Pod + PackScalar<B1>,
+{
+	type Event = ((u32, u32), (u32, u32));
+
+	fn id(&self) -> TableId {
+		self.id
+	}
+
+	fn fill<'a>(
+		&'a self,
+		rows: impl Iterator<Item = &'a Self::Event>,
+		witness: &'a mut TableWitnessIndexSegment<U>,
+	) -> Result<(), anyhow::Error> {
+		let mut key = witness.get_mut_as(self.key)?;
+		let mut value = witness.get_mut_as(self.value)?;
+		let mut sorted_key = witness.get_mut_as(self.sorted_key)?;
+		let mut sorted_value = witness.get_mut_as(self.sorted_value)?;
+
+		for (i, &((k, v), (sorted_k, sorted_v))) in rows.enumerate() {
+			key[i] = k;
+			value[i] = v;
+			sorted_key[i] = sorted_k;
+			sorted_value[i] = sorted_v;
+		}
+		Ok(())
+	}
+}
+
+fn sorted_rows(n_rows: u32) -> Vec<((u32, u32), (u32, u32))> {
+	let rows = (0..n_rows)
+		.map(|i| ((i * 13) % 7, i ^ 0x55))
+		.collect::<Vec<_>>();
+	let mut sorted = rows.clone();
+	sorted.sort();
+	rows.into_iter().zip(sorted).collect()
+}
+
+#[test]
+fn test_m3_permutation() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = SortedTable::new(&mut cs);
+
+	let rows = sorted_rows(32);
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![rows.len()],
+	};
+	let mut witness = cs
+		.build_witness::<OptimalUnderlier128b>(&allocator, &statement)
+		.unwrap();
+	witness.fill_table_sequential(&table, &rows).unwrap();
+
+	let constraint_system = cs.compile(&statement).unwrap();
+	let witness = witness.into_multilinear_extension_index(&statement);
+
+	binius_core::constraint_system::validate::validate_witness(
+		&constraint_system,
+		&statement.boundaries,
+		&witness,
+	)
+	.unwrap();
+
+	let proof = binius_core::constraint_system::prove::<
+		_,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		&statement.boundaries,
+		witness,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+
+	binius_core::constraint_system::verify::<
+		OptimalUnderlier128b,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, LOG_INV_RATE, SECURITY_BITS, &statement.boundaries, proof)
+	.unwrap();
+}
+
+#[test]
+fn test_m3_permutation_rejects_mismatch() {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = SortedTable::new(&mut cs);
+
+	let mut rows = sorted_rows(32);
+	rows[3].1 .1 += 1;
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![rows.len()],
+	};
+	let mut witness = cs
+		.build_witness::<OptimalUnderlier128b>(&allocator, &statement)
+		.unwrap();
+	witness.fill_table_sequential(&table, &rows).unwrap();
+
+	let constraint_system = cs.compile(&statement).unwrap();
+	let witness = witness.into_multilinear_extension_index(&statement);
+
+	assert!(binius_core::constraint_system::validate::validate_witness(
+		&constraint_system,
+		&statement.boundaries,
+		&witness,
+	)
+	.is_err());
+}
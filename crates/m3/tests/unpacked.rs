@@ -0,0 +1,148 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::path::Path;
+
+use binius_core::{fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
+use binius_field::{arch::OptimalUnderlier128b, as_packed_field::PackScalar};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_m3::builder::{
+	Col, ConstraintSystem, Statement, TableFiller, TableId, TableWitnessIndexSegment, B1, B128,
+	B32, B8,
+};
+use bumpalo::Bump;
+use bytemuck::Pod;
+
+const N_ROWS: usize = 16;
+const LOG_INV_RATE: usize = 1;
+const SECURITY_BITS: usize = 30;
+
+pub struct UnpackedTable {
+	id: TableId,
+	words: Col<B32>,
+	bits: Col<B1, 32>,
+	bytes: Col<B8, 4>,
+}
+
+impl UnpackedTable {
+	pub fn new(cs: &mut ConstraintSystem) -> Self {
+		let mut table = cs.add_table("unpacked");
+		let words = table.add_committed::<B32, 1>("words");
+		let bits = table.add_committed::<B1, 32>("bits");
+		let bytes = table.add_committed::<B8, 4>("bytes");
+		let words_bits = table.add_unpacked::<_, 1, B1, 32>("words_bits", words);
+		let words_bytes = table.add_unpacked::<_, 1, B8, 4>("words_bytes", words);
+		table.assert_zero("words_bits", words_bits - bits);
+		table.assert_zero("words_bytes", words_bytes - bytes);
+		Self {
+			id: table.id(),
+			words,
+			bits,
+			bytes,
+		}
+	}
+}
+
+impl<U> TableFiller<U> for UnpackedTable
+where
+	U: Pod + PackScalar<B1>,
+{
+	type Event = u32;
+
+	fn id(&self) -> TableId {
+		self.id
+	}
+
+	fn fill<'a>(
+		&'a self,
+		rows: impl Iterator<Item = &'a Self::Event>,
+		witness: &'a mut TableWitnessIndexSegment<U>,
+	) -> Result<(), anyhow::Error> {
+		let mut words = witness.get_mut_as(self.words)?;
+		let mut bits = witness.get_mut_as(self.bits)?;
+		let mut bytes = witness.get_mut_as(self.bytes)?;
+		for (i, &word) in rows.enumerate() {
+			words[i] = word;
+			bits[i] = word;
+			bytes[i] = word;
+		}
+		Ok(())
+	}
+}
+
+fn prove_and_verify_unpacked(spill_dir: Option<&Path>) {
+	let allocator = Bump::new();
+	let mut cs = ConstraintSystem::<B128>::new();
+	let table = UnpackedTable::new(&mut cs);
+	let statement = Statement {
+		boundaries: vec![],
+		table_sizes: vec![N_ROWS],
+	};
+	let mut witness = cs
+		.build_witness::<OptimalUnderlier128b>(&allocator, &statement)
+		.unwrap();
+	witness
+		.fill_table_sequential(
+			&table,
+			&(0..N_ROWS as u32)
+				.map(|i| i.wrapping_mul(0x9e3779b9))
+				.collect::<Vec<_>>(),
+		)
+		.unwrap();
+
+	let constraint_system = cs.compile(&statement).unwrap();
+	let mut witness = witness.into_multilinear_extension_index(&statement);
+	if let Some(spill_dir) = spill_dir {
+		witness.set_spill_dir(spill_dir);
+	}
+
+	binius_core::constraint_system::validate::validate_witness(
+		&constraint_system,
+		&statement.boundaries,
+		&witness,
+	)
+	.unwrap();
+
+	let proof = binius_core::constraint_system::prove::<
+		_,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		LOG_INV_RATE,
+		SECURITY_BITS,
+		&statement.boundaries,
+		witness,
+		&binius_hal::make_portable_backend(),
+	)
+	.unwrap();
+
+	binius_core::constraint_system::verify::<
+		OptimalUnderlier128b,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, LOG_INV_RATE, SECURITY_BITS, &statement.boundaries, proof)
+	.unwrap();
+}
+
+#[test]
+fn test_m3_unpacked_col() {
+	prove_and_verify_unpacked(None);
+}
+
+#[test]
+fn test_m3_unpacked_col_spilled() {
+	// The unpacked columns share the storage of the committed column they unpack, and are moved
+	// onto its spill file
+	let spill_dir = std::env::temp_dir().join(format!("binius-m3-spill-{}", std::process::id()));
+	std::fs::create_dir_all(&spill_dir).unwrap();
+
+	prove_and_verify_unpacked(Some(&spill_dir));
+
+	assert_eq!(std::fs::read_dir(&spill_dir).unwrap().count(), 0);
+	std::fs::remove_dir(&spill_dir).unwrap();
+}
@@ -281,6 +281,26 @@ impl<F: Field> ArithExpr<F> {
 		usage
 	}
 
+	/// Returns whether the expression is guaranteed to evaluate to zero when all the variables
+	/// flagged in `zero_vars` are zero.
+	///
+	/// The check is structural and conservative: it may return `false` for expressions that
+	/// vanish only after simplification, like `x - x`. If it returns `true`, it also returns `true`
+	/// for the [`Self::leading_term`] of the expression.
+	pub fn vanishes_when_zero(&self, zero_vars: &[bool]) -> bool {
+		match self {
+			Self::Const(value) => *value == F::ZERO,
+			Self::Var(index) => zero_vars.get(*index).copied().unwrap_or(false),
+			Self::Add(left, right) => {
+				left.vanishes_when_zero(zero_vars) && right.vanishes_when_zero(zero_vars)
+			}
+			Self::Mul(left, right) => {
+				left.vanishes_when_zero(zero_vars) || right.vanishes_when_zero(zero_vars)
+			}
+			Self::Pow(base, exp) => *exp > 0 && base.vanishes_when_zero(zero_vars),
+		}
+	}
+
 	fn mark_vars_usage(&self, usage: &mut [bool]) {
 		match self {
 			Self::Const(_) => (),
@@ -434,6 +454,24 @@ mod tests {
 		assert_eq!(expr.leading_term_with_degree(), (3, expected_expr));
 	}
 
+	#[test]
+	fn test_vanishes_when_zero() {
+		type F = BinaryField8b;
+		// selector * (x * y - z)
+		let expr = ArithExpr::Var(0) * (ArithExpr::Var(1) * ArithExpr::Var(2) - ArithExpr::Var(3))
+			+ ArithExpr::Const(F::ZERO);
+
+		assert!(expr.vanishes_when_zero(&[true]));
+		assert!(expr.vanishes_when_zero(&[false, true, false, true]));
+		assert!(!expr.vanishes_when_zero(&[false, true]));
+		assert!(!expr.vanishes_when_zero(&[]));
+		assert!(expr.leading_term().vanishes_when_zero(&[true]));
+
+		let expr = ArithExpr::Var(0).pow(2) + ArithExpr::Const(F::ONE);
+		assert!(!expr.vanishes_when_zero(&[true]));
+		assert!(!ArithExpr::<F>::Var(0).pow(0).vanishes_when_zero(&[true]));
+	}
+
 	#[test]
 	fn test_remap_vars_with_too_few_vars() {
 		type F = BinaryField8b;
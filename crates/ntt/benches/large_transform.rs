@@ -7,7 +7,7 @@ use binius_field::{
 	BinaryField128b, BinaryField128bPolyval, BinaryField32b, ByteSlicedAES32x128b, PackedExtension,
 	TowerField,
 };
-use binius_ntt::{AdditiveNTT, SingleThreadedNTT};
+use binius_ntt::{AdditiveNTT, NTTKernel, SingleThreadedNTT};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use rand::thread_rng;
 
@@ -38,6 +38,27 @@ fn bench_large_transform<F: TowerField, PE: PackedExtension<F>>(c: &mut Criterio
 			group.bench_function(BenchmarkId::new("multithread/precompute", &params), |b| {
 				b.iter(|| ntt.forward_transform_ext(&mut data, 0, log_batch_size, log_dim));
 			});
+
+			for (kernel, name) in [(NTTKernel::Radix4, "radix4"), (NTTKernel::Radix8, "radix8")] {
+				let ntt = SingleThreadedNTT::<F>::new(log_dim)
+					.unwrap()
+					.with_kernel(kernel)
+					.precompute_twiddles();
+				group.bench_function(
+					BenchmarkId::new(format!("single-thread/precompute/{name}"), &params),
+					|b| {
+						b.iter(|| ntt.forward_transform_ext(&mut data, 0, log_batch_size, log_dim));
+					},
+				);
+
+				let ntt = ntt.multithreaded();
+				group.bench_function(
+					BenchmarkId::new(format!("multithread/precompute/{name}"), &params),
+					|b| {
+						b.iter(|| ntt.forward_transform_ext(&mut data, 0, log_batch_size, log_dim));
+					},
+				);
+			}
 		}
 	}
 }
@@ -5,7 +5,7 @@ use binius_math::BinarySubspace;
 use binius_utils::rayon::get_log_max_threads;
 
 use super::{
-	additive_ntt::AdditiveNTT, error::Error, multithreaded::MultithreadedNTT,
+	additive_ntt::AdditiveNTT, error::Error, multithreaded::MultithreadedNTT, radix::NTTKernel,
 	single_threaded::SingleThreadedNTT, twiddle::PrecomputedTwiddleAccess,
 };
 
@@ -45,6 +45,8 @@ impl ThreadingSettings {
 pub struct NTTOptions {
 	pub precompute_twiddles: bool,
 	pub thread_settings: ThreadingSettings,
+	/// The butterfly kernel, see [`NTTKernel`].
+	pub kernel: NTTKernel,
 }
 
 /// An enum that can be used to switch between different NTT implementations without passing AdditiveNTT as a type parameter.
@@ -60,18 +62,13 @@ impl<F: BinaryField> DynamicDispatchNTT<F> {
 	/// Create a new AdditiveNTT based on the given settings.
 	pub fn new(log_domain_size: usize, options: &NTTOptions) -> Result<Self, Error> {
 		let log_threads = options.thread_settings.log_threads_count();
+		let ntt = SingleThreadedNTT::new(log_domain_size)?.with_kernel(options.kernel);
 		let result = match (options.precompute_twiddles, log_threads) {
-			(false, 0) => Self::SingleThreaded(SingleThreadedNTT::new(log_domain_size)?),
-			(true, 0) => Self::SingleThreadedPrecompute(
-				SingleThreadedNTT::new(log_domain_size)?.precompute_twiddles(),
-			),
-			(false, _) => Self::MultiThreaded(
-				SingleThreadedNTT::new(log_domain_size)?
-					.multithreaded_with_max_threads(log_threads),
-			),
+			(false, 0) => Self::SingleThreaded(ntt),
+			(true, 0) => Self::SingleThreadedPrecompute(ntt.precompute_twiddles()),
+			(false, _) => Self::MultiThreaded(ntt.multithreaded_with_max_threads(log_threads)),
 			(true, _) => Self::MultiThreadedPrecompute(
-				SingleThreadedNTT::new(log_domain_size)?
-					.precompute_twiddles()
+				ntt.precompute_twiddles()
 					.multithreaded_with_max_threads(log_threads),
 			),
 		};
@@ -162,12 +159,14 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::SingleThreaded,
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreaded(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::SingleThreaded,
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreadedPrecompute(_)));
 
@@ -175,6 +174,7 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::MultithreadedDefault,
+			..Default::default()
 		});
 		if multithreaded {
 			assert!(matches!(ntt, DynamicDispatchNTT::MultiThreaded(_)));
@@ -185,6 +185,7 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::MultithreadedDefault,
+			..Default::default()
 		});
 		if multithreaded {
 			assert!(matches!(ntt, DynamicDispatchNTT::MultiThreadedPrecompute(_)));
@@ -195,19 +196,39 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 2 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::MultiThreaded(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 0 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreadedPrecompute(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 0 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreaded(_)));
 	}
+
+	#[test]
+	fn test_creation_with_kernel() {
+		let ntt = DynamicDispatchNTT::<BinaryField8b>::new(
+			6,
+			&NTTOptions {
+				precompute_twiddles: true,
+				thread_settings: ThreadingSettings::SingleThreaded,
+				kernel: NTTKernel::Radix8,
+			},
+		)
+		.unwrap();
+		let DynamicDispatchNTT::SingleThreadedPrecompute(ntt) = ntt else {
+			panic!("expected a single-threaded NTT with precomputed twiddles");
+		};
+		assert_eq!(ntt.kernel(), NTTKernel::Radix8);
+	}
 }
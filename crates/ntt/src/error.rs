@@ -22,6 +22,12 @@ pub enum Error {
 	BatchTooLarge,
 	#[error("odd interpolation length mismatch, expected to be exactly {expected_len}")]
 	OddInterpolateIncorrectLength { expected_len: usize },
+	#[error("division by the zero polynomial")]
+	DivisionByZero,
+	#[error("the number of interpolation points and values must be equal")]
+	InterpolationLengthMismatch,
+	#[error("interpolation points must be distinct")]
+	DuplicateInterpolationPoints,
 	#[error("math error: {0}")]
 	MathError(#[from] binius_math::Error),
 }
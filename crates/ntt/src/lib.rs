@@ -12,6 +12,8 @@ mod dynamic_dispatch;
 mod error;
 mod multithreaded;
 mod odd_interpolate;
+pub mod poly_ops;
+mod radix;
 mod single_threaded;
 mod strided_array;
 #[cfg(test)]
@@ -23,4 +25,5 @@ pub use dynamic_dispatch::{DynamicDispatchNTT, NTTOptions, ThreadingSettings};
 pub use error::Error;
 pub use multithreaded::MultithreadedNTT;
 pub use odd_interpolate::OddInterpolate;
+pub use radix::NTTKernel;
 pub use single_threaded::SingleThreadedNTT;
@@ -7,6 +7,7 @@ use binius_utils::rayon::get_log_max_threads;
 
 use super::{
 	error::Error,
+	radix::NTTKernel,
 	single_threaded::{self, check_batch_transform_inputs_and_params},
 	strided_array::StridedArray2DViewMut,
 	twiddle::TwiddleAccess,
@@ -73,6 +74,7 @@ where
 			log_batch_size,
 			log_n,
 			self.log_max_threads,
+			self.single_threaded.kernel(),
 		)
 	}
 
@@ -91,10 +93,12 @@ where
 			log_batch_size,
 			log_n,
 			self.log_max_threads,
+			self.single_threaded.kernel(),
 		)
 	}
 }
 
+#[allow(clippy::too_many_arguments)]
 fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 	log_domain_size: usize,
 	s_evals: &[impl TwiddleAccess<F> + Sync],
@@ -103,6 +107,7 @@ fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 	log_batch_size: usize,
 	log_n: usize,
 	log_max_threads: usize,
+	kernel: NTTKernel,
 ) -> Result<(), Error> {
 	match data.len() {
 		0 => return Ok(()),
@@ -116,6 +121,7 @@ fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 					coset,
 					log_batch_size,
 					log_n,
+					kernel,
 				),
 			};
 		}
@@ -183,12 +189,14 @@ fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 				coset << par_rounds | (inner_coset as u32),
 				log_batch_size,
 				single_thread_log_n,
+				kernel,
 			)
 		})?;
 
 	Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 	log_domain_size: usize,
 	s_evals: &[impl TwiddleAccess<F> + Sync],
@@ -197,6 +205,7 @@ fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 	log_batch_size: usize,
 	log_n: usize,
 	log_max_threads: usize,
+	kernel: NTTKernel,
 ) -> Result<(), Error> {
 	match data.len() {
 		0 => return Ok(()),
@@ -210,6 +219,7 @@ fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 					coset,
 					log_batch_size,
 					log_n,
+					kernel,
 				),
 			};
 		}
@@ -241,6 +251,7 @@ fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 				coset << par_rounds | (inner_coset as u32),
 				log_batch_size,
 				single_thread_log_n,
+				kernel,
 			)
 		})?;
 
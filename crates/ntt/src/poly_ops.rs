@@ -0,0 +1,761 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Fast arithmetic on univariate polynomials over binary fields.
+//!
+//! Polynomials are represented by their coefficients in the monomial basis, in ascending order
+//! of degree. Multiplication evaluates both factors with the additive NTT, multiplies the
+//! evaluations pointwise and interpolates the product with [`OddInterpolate`], which lets the
+//! evaluation domain be $3 \times 2^{\ell}$ points instead of the next power of two. The NTT
+//! operates on coefficients in the novel polynomial basis of [LCH14], so products in the
+//! monomial basis additionally convert between the two bases.
+//!
+//! Division uses Newton iteration for the reciprocal of the reversed divisor, and multipoint
+//! evaluation reduces the polynomial down a subproduct tree, both on top of fast multiplication.
+//!
+//! [LCH14]: <https://arxiv.org/abs/1404.3458>
+
+use binius_field::BinaryField;
+use binius_math::evaluate_univariate;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+
+use crate::{
+	additive_ntt::AdditiveNTT,
+	error::Error,
+	odd_interpolate::OddInterpolate,
+	twiddle::{OnTheFlyTwiddleAccess, TwiddleAccess},
+};
+
+/// Below this many coefficients in the smaller operand, products and quotients are computed with
+/// the quadratic schoolbook algorithms.
+const SCHOOLBOOK_THRESHOLD: usize = 32;
+
+/// Fast univariate polynomial arithmetic over the evaluation domain of an additive NTT.
+///
+/// Operations on polynomials whose product has more than $2^{\ell}$ coefficients, where $\ell$ is
+/// the log domain size of the NTT, fail with [`Error::DomainTooSmall`].
+#[derive(Debug)]
+pub struct PolyOps<'a, F: BinaryField, NTT> {
+	ntt: &'a NTT,
+	twiddles: Vec<OnTheFlyTwiddleAccess<F>>,
+	/// Coefficients of the linearized normalized subspace polynomials: entry $i$ holds $a_j$ with
+	/// $\hat{W}_i(X) = \sum_{j=0}^{i} a_j X^{2^j}$.
+	subspace_polys: Vec<Vec<F>>,
+	/// Inverses of the leading coefficients of $\hat{W}_i$.
+	subspace_poly_lead_invs: Vec<F>,
+}
+
+impl<'a, F, NTT> PolyOps<'a, F, NTT>
+where
+	F: BinaryField,
+	NTT: AdditiveNTT<F>,
+{
+	/// Prepares polynomial arithmetic over the domain of `ntt`.
+	///
+	/// Time complexity is $\mathcal{O}(\ell^2)$ for an NTT with log domain size $\ell$.
+	pub fn new(ntt: &'a NTT) -> Result<Self, Error> {
+		let log_domain_size = ntt.log_domain_size();
+		if log_domain_size == 0 {
+			return Ok(Self {
+				ntt,
+				twiddles: Vec::new(),
+				subspace_polys: Vec::new(),
+				subspace_poly_lead_invs: Vec::new(),
+			});
+		}
+
+		let subspace = ntt.subspace(0);
+		let basis = subspace.basis();
+		if basis[0] != F::ONE {
+			bail!(Error::DomainMustIncludeOne);
+		}
+
+		// $\hat{W}_0(X) = X$ and $\hat{W}_{i+1}(X) \propto \hat{W}_i(X)^2 + \hat{W}_i(X)$, using the
+		// normalization $\hat{W}_i(\beta_i) = 1$.
+		let mut subspace_polys = Vec::with_capacity(log_domain_size);
+		subspace_polys.push(vec![F::ONE]);
+		for i in 1..log_domain_size {
+			let prev = &subspace_polys[i - 1];
+			let mut next = vec![F::ZERO; i + 1];
+			for (j, &coeff) in prev.iter().enumerate() {
+				next[j] += coeff;
+				next[j + 1] += coeff.square();
+			}
+			let norm = evaluate_linearized(&next, basis[i])
+				.invert()
+				.expect("basis elements are linearly independent");
+			for coeff in &mut next {
+				*coeff *= norm;
+			}
+			subspace_polys.push(next);
+		}
+
+		let subspace_poly_lead_invs = subspace_polys
+			.iter()
+			.map(|coeffs| {
+				coeffs[coeffs.len() - 1]
+					.invert()
+					.expect("subspace polynomials have exact degree")
+			})
+			.collect();
+
+		Ok(Self {
+			ntt,
+			twiddles: OnTheFlyTwiddleAccess::generate(&subspace)?,
+			subspace_polys,
+			subspace_poly_lead_invs,
+		})
+	}
+
+	/// Converts coefficients in the monomial basis to the novel polynomial basis, in place.
+	///
+	/// The length of `coeffs` must be a power of two no greater than the NTT domain size.
+	/// Time complexity is $\mathcal{O}(n \log^2 n)$.
+	pub fn monomial_to_novel(&self, coeffs: &mut [F]) -> Result<(), Error> {
+		let log_n = self.check_basis_conversion_len(coeffs.len())?;
+		self.monomial_to_novel_rec(coeffs, log_n);
+		Ok(())
+	}
+
+	/// Converts coefficients in the novel polynomial basis to the monomial basis, in place.
+	///
+	/// The length of `coeffs` must be a power of two no greater than the NTT domain size.
+	/// Time complexity is $\mathcal{O}(n \log^2 n)$.
+	pub fn novel_to_monomial(&self, coeffs: &mut [F]) -> Result<(), Error> {
+		let log_n = self.check_basis_conversion_len(coeffs.len())?;
+		self.novel_to_monomial_rec(coeffs, log_n);
+		Ok(())
+	}
+
+	/// Multiplies two polynomials given in the novel polynomial basis.
+	///
+	/// Returns the `a.len() + b.len() - 1` novel basis coefficients of the product, or an empty
+	/// vector if either operand is empty. Time complexity is $\mathcal{O}(n \log n)$.
+	pub fn mul_novel(&self, a: &[F], b: &[F]) -> Result<Vec<F>, Error> {
+		if a.is_empty() || b.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let len = a.len() + b.len() - 1;
+		let log_len = log2_ceil_usize(len);
+		if log_len > self.ntt.log_domain_size() {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size: log_len
+			});
+		}
+
+		// Evaluate on three cosets of size 2^(log_len - 2) if they are enough to interpolate the
+		// product, saving a quarter of the work over a domain of size 2^log_len.
+		let (d, ell) = if log_len >= 2 && len <= 3 << (log_len - 2) {
+			(3, log_len - 2)
+		} else {
+			(1, log_len)
+		};
+
+		let mut evals = self.evaluate_on_cosets(a, d, ell)?;
+		let b_evals = self.evaluate_on_cosets(b, d, ell)?;
+		for (eval, b_eval) in evals.iter_mut().zip(b_evals) {
+			*eval *= b_eval;
+		}
+
+		if d == 1 {
+			self.ntt.inverse_transform(&mut evals, 0, 0, ell)?;
+		} else {
+			OddInterpolate::new(d, ell, &self.twiddles)?.inverse_transform(self.ntt, &mut evals)?;
+		}
+
+		evals.truncate(len);
+		Ok(evals)
+	}
+
+	/// Multiplies two polynomials given in the monomial basis.
+	///
+	/// Returns the `a.len() + b.len() - 1` coefficients of the product, or an empty vector if
+	/// either operand is empty. Time complexity is $\mathcal{O}(n \log^2 n)$, dominated by the
+	/// basis conversions around [`Self::mul_novel`].
+	pub fn mul(&self, a: &[F], b: &[F]) -> Result<Vec<F>, Error> {
+		if a.len().min(b.len()) <= SCHOOLBOOK_THRESHOLD {
+			return Ok(mul_schoolbook(a, b));
+		}
+
+		let a_novel = self.to_novel_padded(a)?;
+		let b_novel = self.to_novel_padded(b)?;
+		let len = a.len() + b.len() - 1;
+
+		let mut product = self.mul_novel(&a_novel[..a.len()], &b_novel[..b.len()])?;
+		product.resize(len.next_power_of_two(), F::ZERO);
+		self.novel_to_monomial(&mut product)?;
+		product.truncate(len);
+		Ok(product)
+	}
+
+	/// Divides `a` by `b`, returning the quotient and the remainder.
+	///
+	/// The remainder has exactly $\deg(b)$ coefficients and the quotient has
+	/// $\deg(a) - \deg(b) + 1$ coefficients, or none if $\deg(a) < \deg(b)$. Fails with
+	/// [`Error::DivisionByZero`] if `b` is the zero polynomial.
+	pub fn div_rem(&self, a: &[F], b: &[F]) -> Result<(Vec<F>, Vec<F>), Error> {
+		let b = trim_zeros(b);
+		if b.is_empty() {
+			bail!(Error::DivisionByZero);
+		}
+		let a = trim_zeros(a);
+		let deg_b = b.len() - 1;
+
+		if a.len() <= deg_b {
+			let mut remainder = a.to_vec();
+			remainder.resize(deg_b, F::ZERO);
+			return Ok((Vec::new(), remainder));
+		}
+
+		let quotient_len = a.len() - deg_b;
+		if quotient_len.min(b.len()) <= SCHOOLBOOK_THRESHOLD {
+			return Ok(div_rem_schoolbook(a, b));
+		}
+
+		// The reversed quotient is the reversed dividend divided by the reversed divisor, modulo
+		// X^quotient_len.
+		let a_rev = a
+			.iter()
+			.rev()
+			.take(quotient_len)
+			.copied()
+			.collect::<Vec<_>>();
+		let b_rev = b
+			.iter()
+			.rev()
+			.take(quotient_len)
+			.copied()
+			.collect::<Vec<_>>();
+		let b_rev_inv = self.inverse_mod_monomial(&b_rev, quotient_len)?;
+		let mut quotient = self.mul(&a_rev, &b_rev_inv)?;
+		quotient.truncate(quotient_len);
+		quotient.reverse();
+
+		let mut remainder = self.mul(&quotient, b)?;
+		remainder.truncate(deg_b);
+		for (r, &a_i) in remainder.iter_mut().zip(a) {
+			*r += a_i;
+		}
+
+		Ok((quotient, remainder))
+	}
+
+	/// Reduces `a` modulo `b`, returning $\deg(b)$ coefficients.
+	pub fn rem(&self, a: &[F], b: &[F]) -> Result<Vec<F>, Error> {
+		let (_, remainder) = self.div_rem(a, b)?;
+		Ok(remainder)
+	}
+
+	/// Returns the monic polynomial $\prod_i (X - r_i)$ vanishing on `roots`.
+	///
+	/// Time complexity is $\mathcal{O}(M(n) \log n)$, where $M(n)$ is the cost of multiplication.
+	pub fn vanishing_polynomial(&self, roots: &[F]) -> Result<Vec<F>, Error> {
+		let tree = self.subproduct_tree(roots)?;
+		Ok(tree
+			.last()
+			.and_then(|level| level.first())
+			.cloned()
+			.unwrap_or_else(|| vec![F::ONE]))
+	}
+
+	/// Evaluates the polynomial with monomial coefficients `coeffs` at each of the `points`.
+	///
+	/// Reduces the polynomial modulo the nodes of a subproduct tree over the points. Time
+	/// complexity is $\mathcal{O}(M(n) \log n)$, where $M(n)$ is the cost of multiplication.
+	pub fn evaluate_many(&self, coeffs: &[F], points: &[F]) -> Result<Vec<F>, Error> {
+		if points.len() <= SCHOOLBOOK_THRESHOLD {
+			return Ok(points
+				.iter()
+				.map(|&x| evaluate_univariate(coeffs, x))
+				.collect());
+		}
+
+		let tree = self.subproduct_tree(points)?;
+		self.evaluate_on_tree(coeffs, &tree)
+	}
+
+	/// Returns the monomial coefficients of the unique polynomial of degree less than
+	/// `points.len()` that takes the given `values` at the `points`.
+	///
+	/// The points must be distinct. Time complexity is $\mathcal{O}(M(n) \log n)$, where $M(n)$
+	/// is the cost of multiplication.
+	pub fn interpolate(&self, points: &[F], values: &[F]) -> Result<Vec<F>, Error> {
+		if points.len() != values.len() {
+			bail!(Error::InterpolationLengthMismatch);
+		}
+		if points.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		// With m(X) the vanishing polynomial of the points, the interpolant is
+		// $\sum_i y_i / m'(x_i) \cdot m(X) / (X - x_i)$. The derivative in characteristic 2 only
+		// keeps the odd-degree terms.
+		let tree = self.subproduct_tree(points)?;
+		let vanishing = &tree[tree.len() - 1][0];
+		let derivative = vanishing
+			.iter()
+			.enumerate()
+			.skip(1)
+			.map(|(i, &coeff)| if i % 2 == 1 { coeff } else { F::ZERO })
+			.collect::<Vec<_>>();
+
+		let mut interpolants = self
+			.evaluate_on_tree(&derivative, &tree)?
+			.into_iter()
+			.zip(values)
+			.map(|(derivative_eval, &value)| {
+				let weight = derivative_eval
+					.invert()
+					.ok_or(Error::DuplicateInterpolationPoints)?;
+				Ok(vec![value * weight])
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		// Combine the children interpolants as $f_l m_r + f_r m_l$ up the tree.
+		for level in &tree[..tree.len() - 1] {
+			interpolants = level
+				.chunks(2)
+				.zip(interpolants.chunks(2))
+				.map(|(nodes, children)| match (nodes, children) {
+					([lhs_node, rhs_node], [lhs, rhs]) => {
+						let mut sum = self.mul(lhs, rhs_node)?;
+						let rhs_term = self.mul(rhs, lhs_node)?;
+						sum.resize(sum.len().max(rhs_term.len()), F::ZERO);
+						for (coeff, term) in sum.iter_mut().zip(rhs_term) {
+							*coeff += term;
+						}
+						Ok(sum)
+					}
+					(_, [single]) => Ok(single.clone()),
+					_ => unreachable!("nodes and interpolants are paired by construction"),
+				})
+				.collect::<Result<_, Error>>()?;
+		}
+
+		let mut interpolant = interpolants.pop().expect("the tree has a root");
+		interpolant.resize(points.len(), F::ZERO);
+		Ok(interpolant)
+	}
+
+	/// Reduces the polynomial modulo every node of the subproduct tree, from the root down to the
+	/// leaves, and returns the evaluations at the points of the leaves.
+	fn evaluate_on_tree(&self, coeffs: &[F], tree: &[Vec<Vec<F>>]) -> Result<Vec<F>, Error> {
+		let mut remainders = vec![self.rem(coeffs, &tree[tree.len() - 1][0])?];
+		for level in tree.iter().rev().skip(1) {
+			remainders = level
+				.chunks(2)
+				.zip(&remainders)
+				.flat_map(|(children, parent_rem)| {
+					children.iter().map(move |child| {
+						if children.len() == 1 {
+							// The child is identical to its parent.
+							Ok(parent_rem.clone())
+						} else {
+							self.rem(parent_rem, child)
+						}
+					})
+				})
+				.collect::<Result<_, _>>()?;
+		}
+
+		// The remainders modulo the linear leaves are the evaluations.
+		Ok(remainders.into_iter().map(|rem| rem[0]).collect())
+	}
+
+	/// Builds the subproduct tree over `points`, from the linear leaves up to the root.
+	fn subproduct_tree(&self, points: &[F]) -> Result<Vec<Vec<Vec<F>>>, Error> {
+		if points.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let mut tree = vec![points.iter().map(|&x| vec![x, F::ONE]).collect::<Vec<_>>()];
+		while tree[tree.len() - 1].len() > 1 {
+			let level = tree[tree.len() - 1]
+				.chunks(2)
+				.map(|pair| match pair {
+					[lhs, rhs] => self.mul(lhs, rhs),
+					[single] => Ok(single.clone()),
+					_ => unreachable!("chunks has at most two elements"),
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+			tree.push(level);
+		}
+		Ok(tree)
+	}
+
+	/// Computes the inverse of `f` modulo $X^n$ with Newton iteration.
+	///
+	/// In characteristic 2 the Newton step $g \mapsto 2g - f g^2$ simplifies to $g \mapsto f g^2$,
+	/// and squaring a polynomial only squares and spreads its coefficients.
+	fn inverse_mod_monomial(&self, f: &[F], n: usize) -> Result<Vec<F>, Error> {
+		let f0_inv = f[0].invert().ok_or(Error::DivisionByZero)?;
+		let mut inverse = vec![f0_inv];
+		while inverse.len() < n {
+			let precision = (2 * inverse.len()).min(n);
+			let mut inverse_squared = vec![F::ZERO; 2 * inverse.len() - 1];
+			for (i, &coeff) in inverse.iter().enumerate() {
+				inverse_squared[2 * i] = coeff.square();
+			}
+			inverse_squared.truncate(precision);
+
+			inverse = self.mul(&f[..f.len().min(precision)], &inverse_squared)?;
+			inverse.resize(precision, F::ZERO);
+		}
+		Ok(inverse)
+	}
+
+	/// Evaluates a polynomial with the given novel basis coefficients on the first `d` cosets of
+	/// size $2^{\ell}$.
+	///
+	/// The novel basis polynomial $X_{h 2^{\ell} + j}$ factors as $X_{h 2^{\ell}} X_j$, and
+	/// $X_{h 2^{\ell}}$ is a product of $\hat{W}_{\ell + t}$, which are constant on every coset of
+	/// size $2^{\ell}$. Hence the coefficient chunks of size $2^{\ell}$ are folded with these
+	/// constants before a forward transform over the coset.
+	fn evaluate_on_cosets(&self, coeffs: &[F], d: usize, ell: usize) -> Result<Vec<F>, Error> {
+		let mut evals = vec![F::ZERO; d << ell];
+		for (coset, coset_evals) in evals.chunks_exact_mut(1 << ell).enumerate() {
+			for (h, chunk) in coeffs.chunks(1 << ell).enumerate() {
+				let scale = (0..usize::BITS as usize - h.leading_zeros() as usize)
+					.filter(|t| (h >> t) & 1 == 1)
+					.map(|t| self.coset_subspace_eval(ell + t, coset >> t))
+					.product::<F>();
+				for (eval, &coeff) in coset_evals.iter_mut().zip(chunk) {
+					*eval += scale * coeff;
+				}
+			}
+			self.ntt
+				.forward_transform(coset_evals, coset as u32, 0, ell)?;
+		}
+		Ok(evals)
+	}
+
+	/// Evaluates $\hat{W}_i$ on the coset of size $2^i$ with the given index.
+	fn coset_subspace_eval(&self, i: usize, coset: usize) -> F {
+		let constant = if coset & 1 == 1 { F::ONE } else { F::ZERO };
+		constant + self.twiddles[i].get(coset >> 1)
+	}
+
+	fn to_novel_padded(&self, coeffs: &[F]) -> Result<Vec<F>, Error> {
+		let mut novel = coeffs.to_vec();
+		novel.resize(coeffs.len().next_power_of_two(), F::ZERO);
+		self.monomial_to_novel(&mut novel)?;
+		Ok(novel)
+	}
+
+	fn check_basis_conversion_len(&self, len: usize) -> Result<usize, Error> {
+		if !len.is_power_of_two() {
+			bail!(Error::PowerOfTwoLengthRequired);
+		}
+		let log_len = len.ilog2() as usize;
+		if log_len > self.ntt.log_domain_size() {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size: log_len
+			});
+		}
+		Ok(log_len)
+	}
+
+	/// Splits the polynomial as $A + \hat{W}_{k-1} B$ with $\deg A, \deg B < 2^{k-1}$, stores $A$
+	/// and $B$ in the low and high halves, and recurses into both.
+	fn monomial_to_novel_rec(&self, coeffs: &mut [F], log_n: usize) {
+		if log_n == 0 {
+			return;
+		}
+
+		let half = 1 << (log_n - 1);
+		let subspace_poly = &self.subspace_polys[log_n - 1];
+		let lead_inv = self.subspace_poly_lead_invs[log_n - 1];
+		for index in (half..1 << log_n).rev() {
+			let quotient = coeffs[index] * lead_inv;
+			coeffs[index] = quotient;
+			for (j, &a_j) in subspace_poly[..log_n - 1].iter().enumerate() {
+				coeffs[index - half + (1 << j)] += quotient * a_j;
+			}
+		}
+
+		let (lo, hi) = coeffs.split_at_mut(half);
+		self.monomial_to_novel_rec(lo, log_n - 1);
+		self.monomial_to_novel_rec(hi, log_n - 1);
+	}
+
+	/// Inverts [`Self::monomial_to_novel_rec`] by undoing its steps in reverse order.
+	fn novel_to_monomial_rec(&self, coeffs: &mut [F], log_n: usize) {
+		if log_n == 0 {
+			return;
+		}
+
+		let half = 1 << (log_n - 1);
+		let (lo, hi) = coeffs.split_at_mut(half);
+		self.novel_to_monomial_rec(lo, log_n - 1);
+		self.novel_to_monomial_rec(hi, log_n - 1);
+
+		let subspace_poly = &self.subspace_polys[log_n - 1];
+		for index in half..1 << log_n {
+			let quotient = coeffs[index];
+			for (j, &a_j) in subspace_poly[..log_n - 1].iter().enumerate() {
+				coeffs[index - half + (1 << j)] += quotient * a_j;
+			}
+			coeffs[index] = quotient * subspace_poly[log_n - 1];
+		}
+	}
+}
+
+/// Evaluates the linearized polynomial $\sum_j a_j X^{2^j}$ at `x`.
+fn evaluate_linearized<F: BinaryField>(coeffs: &[F], x: F) -> F {
+	let mut power = x;
+	let mut eval = F::ZERO;
+	for &coeff in coeffs {
+		eval += coeff * power;
+		power = power.square();
+	}
+	eval
+}
+
+fn trim_zeros<F: BinaryField>(coeffs: &[F]) -> &[F] {
+	let len = coeffs
+		.iter()
+		.rposition(|&coeff| coeff != F::ZERO)
+		.map_or(0, |i| i + 1);
+	&coeffs[..len]
+}
+
+fn mul_schoolbook<F: BinaryField>(a: &[F], b: &[F]) -> Vec<F> {
+	if a.is_empty() || b.is_empty() {
+		return Vec::new();
+	}
+
+	let mut product = vec![F::ZERO; a.len() + b.len() - 1];
+	for (i, &a_i) in a.iter().enumerate() {
+		for (j, &b_j) in b.iter().enumerate() {
+			product[i + j] += a_i * b_j;
+		}
+	}
+	product
+}
+
+/// Long division of `a` by `b`, where `b` has a non-zero leading coefficient and
+/// `a.len() >= b.len()`.
+fn div_rem_schoolbook<F: BinaryField>(a: &[F], b: &[F]) -> (Vec<F>, Vec<F>) {
+	let deg_b = b.len() - 1;
+	let lead_inv = b[deg_b]
+		.invert()
+		.expect("divisor has a non-zero leading coefficient");
+
+	let mut remainder = a.to_vec();
+	let mut quotient = vec![F::ZERO; a.len() - deg_b];
+	for i in (0..quotient.len()).rev() {
+		let q = remainder[i + deg_b] * lead_inv;
+		quotient[i] = q;
+		for (j, &b_j) in b.iter().enumerate() {
+			remainder[i + j] += q * b_j;
+		}
+	}
+	remainder.truncate(deg_b);
+	(quotient, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField32b, Field};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::single_threaded::SingleThreadedNTT;
+
+	type F = BinaryField32b;
+
+	fn random_poly(rng: &mut StdRng, len: usize) -> Vec<F> {
+		repeat_with(|| F::random(&mut *rng)).take(len).collect()
+	}
+
+	#[test]
+	fn test_novel_basis_matches_ntt_evaluations() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(10).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+		let domain = ntt.subspace(0);
+
+		for log_n in 0..=8 {
+			let monomial = random_poly(&mut rng, 1 << log_n);
+			let mut evals = monomial.clone();
+			poly_ops.monomial_to_novel(&mut evals).unwrap();
+			for coset in 0..4 {
+				let mut coset_evals = evals.clone();
+				ntt.forward_transform(&mut coset_evals, coset, 0, log_n)
+					.unwrap();
+				for (i, &eval) in coset_evals.iter().enumerate() {
+					let x = domain.get((coset as usize) << log_n | i);
+					assert_eq!(eval, evaluate_univariate(&monomial, x));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_basis_conversion_roundtrip() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(10).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+
+		for log_n in 0..=10 {
+			let monomial = random_poly(&mut rng, 1 << log_n);
+			let mut coeffs = monomial.clone();
+			poly_ops.monomial_to_novel(&mut coeffs).unwrap();
+			poly_ops.novel_to_monomial(&mut coeffs).unwrap();
+			assert_eq!(coeffs, monomial);
+		}
+
+		assert!(matches!(
+			poly_ops.monomial_to_novel(&mut [F::ZERO; 3]),
+			Err(Error::PowerOfTwoLengthRequired)
+		));
+		assert!(matches!(
+			poly_ops.monomial_to_novel(&mut vec![F::ZERO; 1 << 11]),
+			Err(Error::DomainTooSmall { .. })
+		));
+	}
+
+	#[test]
+	fn test_mul_matches_schoolbook() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(12).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+
+		// Covers both the power-of-two domain and the three-coset domain of the product.
+		for (a_len, b_len) in [
+			(0, 5),
+			(1, 1),
+			(7, 13),
+			(33, 33),
+			(40, 57),
+			(64, 65),
+			(100, 150),
+			(257, 300),
+			(1000, 1049),
+		] {
+			let a = random_poly(&mut rng, a_len);
+			let b = random_poly(&mut rng, b_len);
+			assert_eq!(poly_ops.mul(&a, &b).unwrap(), mul_schoolbook(&a, &b));
+		}
+	}
+
+	#[test]
+	fn test_mul_novel_domain_too_small() {
+		let ntt = SingleThreadedNTT::<F>::new(4).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+		assert!(matches!(
+			poly_ops.mul_novel(&[F::ONE; 9], &[F::ONE; 9]),
+			Err(Error::DomainTooSmall {
+				log_required_domain_size: 5
+			})
+		));
+	}
+
+	#[test]
+	fn test_div_rem_matches_schoolbook() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(12).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+
+		for (a_len, b_len) in [
+			(5, 7),
+			(20, 3),
+			(100, 40),
+			(300, 77),
+			(1000, 500),
+			(1500, 2),
+		] {
+			let a = random_poly(&mut rng, a_len);
+			let b = random_poly(&mut rng, b_len);
+
+			let (quotient, remainder) = poly_ops.div_rem(&a, &b).unwrap();
+			assert_eq!(remainder.len(), b_len - 1);
+			if a_len >= b_len {
+				assert_eq!((quotient.clone(), remainder.clone()), div_rem_schoolbook(&a, &b));
+			}
+
+			let mut reconstructed = mul_schoolbook(&quotient, &b);
+			reconstructed.resize(a_len.max(b_len - 1), F::ZERO);
+			for (coeff, &r) in reconstructed.iter_mut().zip(&remainder) {
+				*coeff += r;
+			}
+			assert_eq!(&reconstructed[..a_len], a.as_slice());
+		}
+	}
+
+	#[test]
+	fn test_div_rem_trailing_zeros_and_zero_divisor() {
+		let ntt = SingleThreadedNTT::<F>::new(8).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+
+		// (X^2 + 1) / (X + 1) with padded operands
+		let a = [F::ONE, F::ZERO, F::ONE, F::ZERO];
+		let b = [F::ONE, F::ONE, F::ZERO];
+		assert_eq!(poly_ops.div_rem(&a, &b).unwrap(), (vec![F::ONE, F::ONE], vec![F::ZERO]));
+
+		assert!(matches!(poly_ops.div_rem(&a, &[F::ZERO; 3]), Err(Error::DivisionByZero)));
+	}
+
+	#[test]
+	fn test_evaluate_many_matches_horner() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(12).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+
+		for (poly_len, n_points) in [(0, 4), (10, 40), (200, 100), (513, 300), (100, 1000)] {
+			let poly = random_poly(&mut rng, poly_len);
+			let points = random_poly(&mut rng, n_points);
+			let expected = points
+				.iter()
+				.map(|&x| evaluate_univariate(&poly, x))
+				.collect::<Vec<_>>();
+			assert_eq!(poly_ops.evaluate_many(&poly, &points).unwrap(), expected);
+		}
+	}
+
+	#[test]
+	fn test_interpolate() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(12).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+
+		for n_points in [0, 1, 5, 33, 100, 257] {
+			let poly = random_poly(&mut rng, n_points);
+			let points = random_poly(&mut rng, n_points);
+			let values = points
+				.iter()
+				.map(|&x| evaluate_univariate(&poly, x))
+				.collect::<Vec<_>>();
+			assert_eq!(poly_ops.interpolate(&points, &values).unwrap(), poly);
+		}
+
+		let points = [F::ONE, F::ZERO, F::ONE];
+		assert!(matches!(
+			poly_ops.interpolate(&points, &[F::ONE; 3]),
+			Err(Error::DuplicateInterpolationPoints)
+		));
+		assert!(matches!(
+			poly_ops.interpolate(&points, &[F::ONE; 2]),
+			Err(Error::InterpolationLengthMismatch)
+		));
+	}
+
+	#[test]
+	fn test_vanishing_polynomial() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<F>::new(10).unwrap();
+		let poly_ops = PolyOps::new(&ntt).unwrap();
+
+		assert_eq!(poly_ops.vanishing_polynomial(&[]).unwrap(), vec![F::ONE]);
+
+		let roots = random_poly(&mut rng, 100);
+		let vanishing = poly_ops.vanishing_polynomial(&roots).unwrap();
+		assert_eq!(vanishing.len(), 101);
+		assert_eq!(vanishing[100], F::ONE);
+		for &root in &roots {
+			assert_eq!(evaluate_univariate(&vanishing, root), F::ZERO);
+		}
+	}
+}
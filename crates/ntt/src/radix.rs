@@ -0,0 +1,246 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Higher-radix butterfly kernels for the additive NTT.
+//!
+//! The radix-2 network of [LCH14] makes one pass over the data for every butterfly layer. For
+//! large domains the transform is bound by memory bandwidth, so the kernels here fuse two or
+//! three consecutive layers into a single radix-4 or radix-8 butterfly. Each fused butterfly
+//! loads its `2^n_layers` inputs once, applies all fused layers in registers, and stores the
+//! results, and the butterflies are processed block by block so that the twiddle factors of a
+//! block are loaded once and reused for all of its butterflies.
+//!
+//! The layer groups are additionally tiled. Once the butterflies of the remaining layers only
+//! span `2^LOG_TILE_BYTES` bytes, the data is split into tiles of that size and each tile runs
+//! through all remaining layer groups while it is in cache, instead of making one pass over the
+//! whole data per group.
+//!
+//! The fused kernels perform exactly the same field operations as the radix-2 network, so the
+//! output is bit-identical.
+//!
+//! [LCH14]: <https://arxiv.org/abs/1404.3458>
+
+use binius_field::{BinaryField, PackedField};
+
+use crate::twiddle::TwiddleAccess;
+
+/// The maximum number of layers that a kernel fuses.
+const MAX_LOG_RADIX: usize = 3;
+
+/// The base-2 logarithm of the tile size in bytes, chosen to fit comfortably in an L2 cache.
+const LOG_TILE_BYTES: usize = 16;
+
+/// The butterfly kernel used for the NTT layers that operate on whole packed elements.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NTTKernel {
+	/// Process one butterfly layer per pass over the data.
+	#[default]
+	Radix2,
+	/// Fuse two butterfly layers per pass over the data.
+	Radix4,
+	/// Fuse three butterfly layers per pass over the data.
+	Radix8,
+}
+
+impl NTTKernel {
+	/// The number of butterfly layers processed per pass over the data.
+	pub const fn log_radix(&self) -> usize {
+		match self {
+			Self::Radix2 => 1,
+			Self::Radix4 => 2,
+			Self::Radix8 => 3,
+		}
+	}
+}
+
+/// Parameters of a transform shared by all fused layer groups.
+pub(crate) struct FusedLayers<'a, TA> {
+	pub log_domain_size: usize,
+	pub s_evals: &'a [TA],
+	pub coset: u32,
+	pub log_batch_size: usize,
+	pub log_n: usize,
+	pub kernel: NTTKernel,
+}
+
+impl<TA> FusedLayers<'_, TA> {
+	/// Applies the forward butterfly layers `cutoff..log_n`, from the highest layer down.
+	///
+	/// All layers must operate on whole packed elements, ie. `cutoff + log_batch_size >=
+	/// P::LOG_WIDTH`.
+	pub fn forward<F, P>(&self, data: &mut [P], cutoff: usize)
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		TA: TwiddleAccess<F>,
+	{
+		let mut groups = Vec::new();
+		let mut hi = self.log_n;
+		while hi > cutoff {
+			let n_layers = self.kernel.log_radix().min(hi - cutoff);
+			groups.push((hi - n_layers, n_layers));
+			hi -= n_layers;
+		}
+
+		// The groups go down from the highest layer, so the tiled ones are a suffix.
+		let log_tile = self.log_tile(data);
+		let n_untiled = groups
+			.iter()
+			.position(|&(lo, n_layers)| self.fits_in_tile::<P>(lo, n_layers, log_tile))
+			.unwrap_or(groups.len());
+		for &(lo, n_layers) in &groups[..n_untiled] {
+			self.apply(data, 0, lo, n_layers, forward_butterfly);
+		}
+		if n_untiled < groups.len() {
+			for (tile_index, tile) in data.chunks_exact_mut(1 << log_tile).enumerate() {
+				for &(lo, n_layers) in &groups[n_untiled..] {
+					self.apply(tile, tile_index, lo, n_layers, forward_butterfly);
+				}
+			}
+		}
+	}
+
+	/// Applies the inverse butterfly layers `cutoff..log_n`, from the lowest layer up.
+	///
+	/// All layers must operate on whole packed elements, ie. `cutoff + log_batch_size >=
+	/// P::LOG_WIDTH`.
+	pub fn inverse<F, P>(&self, data: &mut [P], cutoff: usize)
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		TA: TwiddleAccess<F>,
+	{
+		let mut groups = Vec::new();
+		let mut lo = cutoff;
+		while lo < self.log_n {
+			let n_layers = self.kernel.log_radix().min(self.log_n - lo);
+			groups.push((lo, n_layers));
+			lo += n_layers;
+		}
+
+		// The groups go up from the lowest layer, so the tiled ones are a prefix.
+		let log_tile = self.log_tile(data);
+		let n_tiled = groups
+			.iter()
+			.position(|&(lo, n_layers)| !self.fits_in_tile::<P>(lo, n_layers, log_tile))
+			.unwrap_or(groups.len());
+		if n_tiled > 0 {
+			for (tile_index, tile) in data.chunks_exact_mut(1 << log_tile).enumerate() {
+				for &(lo, n_layers) in &groups[..n_tiled] {
+					self.apply(tile, tile_index, lo, n_layers, inverse_butterfly);
+				}
+			}
+		}
+		for &(lo, n_layers) in &groups[n_tiled..] {
+			self.apply(data, 0, lo, n_layers, inverse_butterfly);
+		}
+	}
+
+	/// Returns the base-2 logarithm of the tile length in packed elements.
+	fn log_tile<P>(&self, data: &[P]) -> usize {
+		let log_tile = LOG_TILE_BYTES.saturating_sub(size_of::<P>().ilog2() as usize);
+		log_tile.min(data.len().ilog2() as usize)
+	}
+
+	/// Whether the butterflies of the layers `lo..lo + n_layers` stay within one tile.
+	fn fits_in_tile<P: PackedField>(&self, lo: usize, n_layers: usize, log_tile: usize) -> bool {
+		lo + n_layers + self.log_batch_size - P::LOG_WIDTH <= log_tile
+	}
+
+	/// Applies the layers `lo..lo + n_layers` to `data`, which is the tile with index
+	/// `tile_index` among the tiles of length `data.len()`.
+	fn apply<F, P>(
+		&self,
+		data: &mut [P],
+		tile_index: usize,
+		lo: usize,
+		n_layers: usize,
+		butterfly: fn(
+			&mut [P; 1 << MAX_LOG_RADIX],
+			&[[P; 1 << (MAX_LOG_RADIX - 1)]; MAX_LOG_RADIX],
+			usize,
+		),
+	) where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		TA: TwiddleAccess<F>,
+	{
+		debug_assert!((1..=MAX_LOG_RADIX).contains(&n_layers));
+		debug_assert!(lo + self.log_batch_size >= P::LOG_WIDTH);
+		debug_assert!(lo + n_layers <= self.log_n);
+
+		let hi = lo + n_layers;
+		// The distance in packed elements between the inputs of a lowest-layer butterfly
+		let log_stride = lo + self.log_batch_size - P::LOG_WIDTH;
+		let coset_twiddles = (lo..hi)
+			.map(|layer| {
+				self.s_evals[layer].coset(self.log_domain_size - self.log_n, self.coset as usize)
+			})
+			.collect::<Vec<_>>();
+
+		let first_block = tile_index * (data.len() >> (log_stride + n_layers));
+		let mut values = [P::zero(); 1 << MAX_LOG_RADIX];
+		let mut twiddles = [[P::zero(); 1 << (MAX_LOG_RADIX - 1)]; MAX_LOG_RADIX];
+		for (j, block) in data
+			.chunks_exact_mut(1 << (log_stride + n_layers))
+			.enumerate()
+		{
+			// Butterflies at layer `lo + li` in block `j` use the twiddles with indices
+			// `j << (n_layers - 1 - li) | t`.
+			let j = first_block + j;
+			for (li, coset_twiddle) in coset_twiddles.iter().enumerate() {
+				for t in 0..1 << (n_layers - 1 - li) {
+					twiddles[li][t] = P::broadcast(coset_twiddle.get(j << (n_layers - 1 - li) | t));
+				}
+			}
+
+			for k in 0..1 << log_stride {
+				for m in 0..1 << n_layers {
+					values[m] = block[m << log_stride | k];
+				}
+				butterfly(&mut values, &twiddles, n_layers);
+				for m in 0..1 << n_layers {
+					block[m << log_stride | k] = values[m];
+				}
+			}
+		}
+	}
+}
+
+/// Applies `n_layers` forward butterfly layers to `values`, from the highest layer down.
+fn forward_butterfly<P: PackedField>(
+	values: &mut [P; 1 << MAX_LOG_RADIX],
+	twiddles: &[[P; 1 << (MAX_LOG_RADIX - 1)]; MAX_LOG_RADIX],
+	n_layers: usize,
+) {
+	for li in (0..n_layers).rev() {
+		for t in 0..1 << (n_layers - 1 - li) {
+			let twiddle = twiddles[li][t];
+			for s in 0..1 << li {
+				let idx0 = t << (li + 1) | s;
+				let idx1 = idx0 | 1 << li;
+				values[idx0] += values[idx1] * twiddle;
+				values[idx1] += values[idx0];
+			}
+		}
+	}
+}
+
+/// Applies `n_layers` inverse butterfly layers to `values`, from the lowest layer up.
+fn inverse_butterfly<P: PackedField>(
+	values: &mut [P; 1 << MAX_LOG_RADIX],
+	twiddles: &[[P; 1 << (MAX_LOG_RADIX - 1)]; MAX_LOG_RADIX],
+	n_layers: usize,
+) {
+	#[allow(clippy::needless_range_loop)]
+	for li in 0..n_layers {
+		for t in 0..1 << (n_layers - 1 - li) {
+			let twiddle = twiddles[li][t];
+			for s in 0..1 << li {
+				let idx0 = t << (li + 1) | s;
+				let idx1 = idx0 | 1 << li;
+				values[idx1] += values[idx0];
+				values[idx0] += values[idx1] * twiddle;
+			}
+		}
+	}
+}
@@ -5,7 +5,12 @@ use std::{cmp, marker::PhantomData};
 use binius_field::{BinaryField, PackedField, TowerField};
 use binius_math::BinarySubspace;
 
-use super::{additive_ntt::AdditiveNTT, error::Error, twiddle::TwiddleAccess};
+use super::{
+	additive_ntt::AdditiveNTT,
+	error::Error,
+	radix::{FusedLayers, NTTKernel},
+	twiddle::TwiddleAccess,
+};
 use crate::twiddle::{expand_subspace_evals, OnTheFlyTwiddleAccess, PrecomputedTwiddleAccess};
 
 /// Implementation of `AdditiveNTT` that performs the computation single-threaded.
@@ -13,6 +18,7 @@ use crate::twiddle::{expand_subspace_evals, OnTheFlyTwiddleAccess, PrecomputedTw
 pub struct SingleThreadedNTT<F: BinaryField, TA: TwiddleAccess<F> = OnTheFlyTwiddleAccess<F>> {
 	// TODO: Figure out how to make this private, it should not be `pub(super)`.
 	pub(super) s_evals: Vec<TA>,
+	kernel: NTTKernel,
 	_marker: PhantomData<F>,
 }
 
@@ -39,6 +45,7 @@ impl<F: BinaryField> SingleThreadedNTT<F> {
 
 	pub fn precompute_twiddles(&self) -> SingleThreadedNTT<F, PrecomputedTwiddleAccess<F>> {
 		SingleThreadedNTT::with_twiddle_access(expand_subspace_evals(&self.s_evals))
+			.with_kernel(self.kernel)
 	}
 }
 
@@ -53,9 +60,21 @@ impl<F: BinaryField, TA: TwiddleAccess<F>> SingleThreadedNTT<F, TA> {
 	const fn with_twiddle_access(twiddle_access: Vec<TA>) -> Self {
 		Self {
 			s_evals: twiddle_access,
+			kernel: NTTKernel::Radix2,
 			_marker: PhantomData,
 		}
 	}
+
+	/// Returns the NTT with the butterfly kernel used for the layers on whole packed elements.
+	pub const fn with_kernel(mut self, kernel: NTTKernel) -> Self {
+		self.kernel = kernel;
+		self
+	}
+
+	/// The butterfly kernel used for the layers on whole packed elements.
+	pub const fn kernel(&self) -> NTTKernel {
+		self.kernel
+	}
 }
 
 impl<F: BinaryField, TA: TwiddleAccess<F>> SingleThreadedNTT<F, TA> {
@@ -90,7 +109,15 @@ where
 		log_batch_size: usize,
 		log_n: usize,
 	) -> Result<(), Error> {
-		forward_transform(self.log_domain_size(), &self.s_evals, data, coset, log_batch_size, log_n)
+		forward_transform(
+			self.log_domain_size(),
+			&self.s_evals,
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+			self.kernel,
+		)
 	}
 
 	fn inverse_transform<P: PackedField<Scalar = F>>(
@@ -100,7 +127,15 @@ where
 		log_batch_size: usize,
 		log_n: usize,
 	) -> Result<(), Error> {
-		inverse_transform(self.log_domain_size(), &self.s_evals, data, coset, log_batch_size, log_n)
+		inverse_transform(
+			self.log_domain_size(),
+			&self.s_evals,
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+			self.kernel,
+		)
 	}
 }
 
@@ -111,6 +146,7 @@ pub fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 	coset: u32,
 	log_batch_size: usize,
 	log_n: usize,
+	kernel: NTTKernel,
 ) -> Result<(), Error> {
 	match data.len() {
 		0 => return Ok(()),
@@ -133,6 +169,7 @@ pub fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 						coset,
 						log_batch_size,
 						log_n,
+						kernel,
 					)?;
 
 					data[0] = buffer[0];
@@ -154,18 +191,30 @@ pub fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 	// packed base field elements.
 	let cutoff = log_w.saturating_sub(log_b);
 
-	for i in (cutoff..log_n).rev() {
-		let coset_twiddle = s_evals[i].coset(log_domain_size - log_n, coset as usize);
-
-		for j in 0..1 << (log_n - 1 - i) {
-			let twiddle = P::broadcast(coset_twiddle.get(j));
-			for k in 0..1 << (i + log_b - log_w) {
-				let idx0 = j << (i + log_b - log_w + 1) | k;
-				let idx1 = idx0 | 1 << (i + log_b - log_w);
-				data[idx0] += data[idx1] * twiddle;
-				data[idx1] += data[idx0];
+	if kernel == NTTKernel::Radix2 {
+		for i in (cutoff..log_n).rev() {
+			let coset_twiddle = s_evals[i].coset(log_domain_size - log_n, coset as usize);
+
+			for j in 0..1 << (log_n - 1 - i) {
+				let twiddle = P::broadcast(coset_twiddle.get(j));
+				for k in 0..1 << (i + log_b - log_w) {
+					let idx0 = j << (i + log_b - log_w + 1) | k;
+					let idx1 = idx0 | 1 << (i + log_b - log_w);
+					data[idx0] += data[idx1] * twiddle;
+					data[idx1] += data[idx0];
+				}
 			}
 		}
+	} else {
+		let fused = FusedLayers {
+			log_domain_size,
+			s_evals,
+			coset,
+			log_batch_size,
+			log_n,
+			kernel,
+		};
+		fused.forward(data, cutoff);
 	}
 
 	for i in (0..cmp::min(cutoff, log_n)).rev() {
@@ -201,6 +250,7 @@ pub fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 	coset: u32,
 	log_batch_size: usize,
 	log_n: usize,
+	kernel: NTTKernel,
 ) -> Result<(), Error> {
 	match data.len() {
 		0 => return Ok(()),
@@ -223,6 +273,7 @@ pub fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 						coset,
 						log_batch_size,
 						log_n,
+						kernel,
 					)?;
 
 					data[0] = buffer[0];
@@ -267,19 +318,31 @@ pub fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
 		}
 	}
 
-	#[allow(clippy::needless_range_loop)]
-	for i in cutoff..log_n {
-		let coset_twiddle = s_evals[i].coset(log_domain_size - log_n, coset as usize);
-
-		for j in 0..1 << (log_n - 1 - i) {
-			let twiddle = P::broadcast(coset_twiddle.get(j));
-			for k in 0..1 << (i + log_b - log_w) {
-				let idx0 = j << (i + log_b - log_w + 1) | k;
-				let idx1 = idx0 | 1 << (i + log_b - log_w);
-				data[idx1] += data[idx0];
-				data[idx0] += data[idx1] * twiddle;
+	if kernel == NTTKernel::Radix2 {
+		#[allow(clippy::needless_range_loop)]
+		for i in cutoff..log_n {
+			let coset_twiddle = s_evals[i].coset(log_domain_size - log_n, coset as usize);
+
+			for j in 0..1 << (log_n - 1 - i) {
+				let twiddle = P::broadcast(coset_twiddle.get(j));
+				for k in 0..1 << (i + log_b - log_w) {
+					let idx0 = j << (i + log_b - log_w + 1) | k;
+					let idx1 = idx0 | 1 << (i + log_b - log_w);
+					data[idx1] += data[idx0];
+					data[idx0] += data[idx1] * twiddle;
+				}
 			}
 		}
+	} else {
+		let fused = FusedLayers {
+			log_domain_size,
+			s_evals,
+			coset,
+			log_batch_size,
+			log_n,
+			kernel,
+		};
+		fused.inverse(data, cutoff);
 	}
 
 	Ok(())
@@ -416,7 +479,15 @@ mod tests {
 
 		let unpacked = &mut PackedBinaryField8x16b::unpack_scalars_mut(&mut packed_copy)[0..4];
 
-		let _ = forward_transform(s.log_domain_size(), &s.s_evals, &mut packed, 3, 0, 2);
+		let _ = forward_transform(
+			s.log_domain_size(),
+			&s.s_evals,
+			&mut packed,
+			3,
+			0,
+			2,
+			NTTKernel::Radix2,
+		);
 
 		let _ = s.forward_transform(unpacked, 3, 0, 2);
 
@@ -434,7 +505,15 @@ mod tests {
 
 		let unpacked = &mut PackedBinaryField8x16b::unpack_scalars_mut(&mut packed_copy)[0..4];
 
-		let _ = inverse_transform(s.log_domain_size(), &s.s_evals, &mut packed, 3, 0, 2);
+		let _ = inverse_transform(
+			s.log_domain_size(),
+			&s.s_evals,
+			&mut packed,
+			3,
+			0,
+			2,
+			NTTKernel::Radix2,
+		);
 
 		let _ = s.inverse_transform(unpacked, 3, 0, 2);
 
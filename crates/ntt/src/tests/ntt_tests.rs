@@ -15,7 +15,7 @@ use binius_field::{
 };
 use rand::{rngs::StdRng, SeedableRng};
 
-use crate::{dynamic_dispatch::DynamicDispatchNTT, AdditiveNTT, SingleThreadedNTT};
+use crate::{dynamic_dispatch::DynamicDispatchNTT, AdditiveNTT, NTTKernel, SingleThreadedNTT};
 
 /// Check that forward and inverse transformation of `ntt` on `data` is the same as forward and inverse transformation of `reference_ntt` on `data`
 /// and that the result of the roundtrip is the same as the original data.
@@ -87,6 +87,27 @@ fn check_roundtrip_all_ntts<P>(
 	let dynamic_dispatch_ntt = DynamicDispatchNTT::SingleThreaded(
 		SingleThreadedNTT::<P::Scalar>::new(log_domain_size).unwrap(),
 	);
+	let fused_ntts = [NTTKernel::Radix4, NTTKernel::Radix8]
+		.into_iter()
+		.flat_map(|kernel| {
+			let ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+				.unwrap()
+				.with_kernel(kernel);
+			[
+				DynamicDispatchNTT::SingleThreadedPrecompute(ntt.precompute_twiddles()),
+				DynamicDispatchNTT::MultiThreaded(
+					SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+						.unwrap()
+						.with_kernel(kernel)
+						.multithreaded(),
+				),
+				DynamicDispatchNTT::MultiThreadedPrecompute(
+					ntt.precompute_twiddles().multithreaded(),
+				),
+				DynamicDispatchNTT::SingleThreaded(ntt),
+			]
+		})
+		.collect::<Vec<_>>();
 
 	let mut rng = StdRng::seed_from_u64(0);
 	let data = (0..1u128 << log_data_size)
@@ -143,6 +164,16 @@ fn check_roundtrip_all_ntts<P>(
 				log_batch_size,
 				log_n,
 			);
+			for fused_ntt in &fused_ntts {
+				check_roundtrip_with_reference(
+					&simple_ntt,
+					fused_ntt,
+					&data,
+					cosets.clone(),
+					log_batch_size,
+					log_n,
+				);
+			}
 		}
 	}
 }
@@ -172,6 +203,13 @@ fn tests_field_512_bits() {
 	check_roundtrip_all_ntts::<PackedBinaryField16x32b>(12, 6, 4, 0);
 }
 
+#[test]
+fn tests_larger_than_tile() {
+	// 2^11 elements of 64 bytes span two tiles, so the fused kernels run both the untiled
+	// and the tiled layer groups.
+	check_roundtrip_all_ntts::<PackedBinaryField16x32b>(15, 11, 1, 0);
+}
+
 #[test]
 fn test_sub_packing_width() {
 	check_roundtrip_all_ntts::<PackedBinaryField32x16b>(12, 0, 2, 1);
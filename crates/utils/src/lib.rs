@@ -12,6 +12,7 @@ pub mod examples;
 pub mod felts;
 pub mod graph;
 pub mod iter;
+pub mod memory;
 pub mod rayon;
 pub mod serialization;
 pub mod sorting;
@@ -0,0 +1,24 @@
+// Copyright 2025 Irreducible Inc.
+
+use cfg_if::cfg_if;
+
+/// Peak resident set size of the current process in KiB.
+///
+/// Returns `None` on platforms that do not report it.
+pub fn peak_rss_kib() -> Option<usize> {
+	cfg_if! {
+		if #[cfg(target_os = "linux")] {
+			std::fs::read_to_string("/proc/self/status")
+				.ok()?
+				.lines()
+				.find_map(|line| line.strip_prefix("VmHWM:"))?
+				.trim()
+				.strip_suffix("kB")?
+				.trim()
+				.parse()
+				.ok()
+		} else {
+			None
+		}
+	}
+}
@@ -5,7 +5,7 @@ use binius_circuits::builder::{types::U, ConstraintSystemBuilder};
 use binius_core::{constraint_system, fiat_shamir::HasherChallenger, tower::CanonicalTowerFamily};
 use binius_field::{BinaryField32b, TowerField};
 use binius_hal::make_portable_backend;
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression, Groestl256ParallelDigest};
 use binius_macros::arith_expr;
 use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
 use bytesize::ByteSize;
@@ -93,7 +93,7 @@ fn main() -> Result<()> {
 		constraint_system::prove::<
 			U,
 			CanonicalTowerFamily,
-			Groestl256,
+			Groestl256ParallelDigest,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
 			_,
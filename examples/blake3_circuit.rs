@@ -15,7 +15,7 @@ use binius_core::{
 };
 use binius_field::BinaryField1b;
 use binius_hal::make_portable_backend;
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression, Groestl256ParallelDigest};
 use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
 use bytesize::ByteSize;
 use clap::{value_parser, Parser};
@@ -86,7 +86,7 @@ fn main() -> Result<()> {
 		constraint_system::prove::<
 			U,
 			CanonicalTowerFamily,
-			Groestl256,
+			Groestl256ParallelDigest,
 			Groestl256ByteCompression,
 			HasherChallenger<Groestl256>,
 			_,
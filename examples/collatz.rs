@@ -11,7 +11,7 @@ use binius_core::{
 	tower::CanonicalTowerFamily,
 };
 use binius_hal::make_portable_backend;
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression, Groestl256ParallelDigest};
 use binius_utils::rayon::adjust_thread_pool;
 use clap::{value_parser, Parser};
 use tracing_profile::init_tracing;
@@ -67,7 +67,7 @@ fn prove(x0: u32, log_inv_rate: usize) -> Result<(Advice, Proof), anyhow::Error>
 	let proof = constraint_system::prove::<
 		U,
 		CanonicalTowerFamily,
-		Groestl256,
+		Groestl256ParallelDigest,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
 		_,
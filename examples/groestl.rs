@@ -10,7 +10,7 @@ use binius_field::{
 	linear_transformation::PackedTransformationFactory,
 	Field,
 };
-use binius_hash::groestl::{Groestl256, Groestl256ByteCompression};
+use binius_hash::groestl::{Groestl256, Groestl256ByteCompression, Groestl256ParallelDigest};
 use binius_m3::{
 	builder::{
 		ConstraintSystem, Statement, TableFiller, TableId, TableWitnessIndexSegment, B1, B8,
@@ -117,7 +117,7 @@ fn main() -> Result<()> {
 	let proof = binius_core::constraint_system::prove::<
 		_,
 		CanonicalTowerFamily,
-		Groestl256,
+		Groestl256ParallelDigest,
 		Groestl256ByteCompression,
 		HasherChallenger<Groestl256>,
 		_,